@@ -1,6 +1,7 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use kani_metadata::DiagnosticCategory;
 use strum_macros::{AsRefStr, Display, EnumString, VariantNames};
 use tracing_subscriber::filter::Directive;
 
@@ -42,6 +43,17 @@ pub struct Arguments {
     /// Option name used to enable assertion reachability checks.
     #[clap(long = "assertion-reach-checks")]
     pub check_assertion_reachability: bool,
+    /// Option used to turn `kani::assume_satisfiable` into a plain `kani::assume`.
+    #[clap(long)]
+    pub no_assume_satisfiable_checks: bool,
+    /// Option used to remove the `kani::assume` calls that only constrain values that no property
+    /// depends on, so CBMC's formula slicing can drop them.
+    #[clap(long)]
+    pub enable_slicing: bool,
+    /// Option used to only generate cover properties, and drop every other check, including
+    /// user assertions.
+    #[clap(long)]
+    pub cover_only: bool,
     /// Option name used to enable coverage checks.
     #[clap(long = "coverage-checks")]
     pub check_coverage: bool,
@@ -51,9 +63,49 @@ pub struct Arguments {
     /// Option name used to use json pretty-print for output files.
     #[clap(long = "pretty-json-files")]
     pub output_pretty_json: bool,
+    /// Option name used to also write the symbol table of each model in JSON format.
+    #[clap(long)]
+    pub write_json_symtab: bool,
+    /// Option name used to dump the MIR of the items reachable from the given harness.
+    #[clap(long)]
+    pub dump_mir_for_harness: Option<String>,
+    /// Option name used to generate a harness for the contract of the given function, if it has
+    /// no `proof_for_contract` harness.
+    #[clap(long)]
+    pub check_contract: Option<String>,
+    /// Option name used to replace some standard library functions by abstractions of their
+    /// postconditions.
+    #[clap(long)]
+    pub std_abstractions: bool,
+    /// Option name used to model `HashMap` as an association list with the given capacity.
+    #[clap(long)]
+    pub hashmap_capacity: Option<usize>,
+    /// Option name used to assume that the functions from the given crates never panic.
+    #[clap(long)]
+    pub assume_panic_free: Vec<String>,
+    /// Option name used to assume that memory allocations never fail, which prunes the paths that
+    /// handle allocation failures.
+    #[clap(long)]
+    pub assume_alloc_never_fails: bool,
+    /// Option name used to pin the value of a `kani::concrete::value` site or of a call to
+    /// `kani::any()`. Each value is either `id`, which pins the site to its default value, or
+    /// `id=value`.
+    #[clap(long)]
+    pub fix: Vec<String>,
+    /// Option name used to keep generating the models of the other harnesses when the code
+    /// generation of a harness panics. The error is recorded in the metadata of the harness.
+    #[clap(long)]
+    pub keep_going: bool,
+    /// Option name used to turn off the notes about `assert!` and `debug_assert!` from the
+    /// standard library in the local crate.
+    #[clap(long)]
+    pub no_std_assert_notes: bool,
     /// Option used for suppressing global ASM error.
     #[clap(long)]
     pub ignore_global_asm: bool,
+    /// Option name used to report the warnings of the given category as errors.
+    #[clap(long)]
+    pub deny: Vec<DiagnosticCategory>,
     /// Option name used to select which reachability analysis to perform.
     #[clap(long = "reachability", default_value = "none")]
     pub reachability_analysis: ReachabilityType,
@@ -90,6 +142,38 @@ pub struct Arguments {
     pub print_llbc: bool,
 }
 
+impl Arguments {
+    /// All the `kani::concrete::value` and `kani::any()` sites pinned with `--fix`.
+    pub fn pinned_values(&self) -> impl Iterator<Item = (&str, PinnedValue)> {
+        self.fix.iter().map(|fix| match fix.split_once('=') {
+            Some((id, value)) => (id, PinnedValue::Value(value.to_string())),
+            None => (fix.as_str(), PinnedValue::Default),
+        })
+    }
+
+    /// The pin given to the site `id` with `--fix`, if any.
+    ///
+    /// Returns an error if the site was pinned more than once to different values.
+    pub fn pinned_value(&self, id: &str) -> Result<Option<PinnedValue>, String> {
+        let mut pins = self.pinned_values().filter(|(pin_id, _)| *pin_id == id);
+        let Some((_, pin)) = pins.next() else { return Ok(None) };
+        if pins.any(|(_, other)| other != pin) {
+            Err(format!("the value of `{id}` was pinned more than once with `--fix`"))
+        } else {
+            Ok(Some(pin))
+        }
+    }
+}
+
+/// How the value of a `kani::concrete::value` site was pinned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PinnedValue {
+    /// Pin the site to the default value given in the code.
+    Default,
+    /// Pin the site to the given value.
+    Value(String),
+}
+
 #[derive(Debug, Clone, Copy, AsRefStr, EnumString, VariantNames, PartialEq, Eq)]
 #[strum(serialize_all = "snake_case")]
 pub enum ExtraChecks {
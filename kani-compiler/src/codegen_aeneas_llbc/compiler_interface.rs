@@ -7,7 +7,7 @@
 use crate::codegen_aeneas_llbc::mir_to_ullbc::Context;
 use crate::kani_middle::attributes::KaniAttributes;
 use crate::kani_middle::check_reachable_items;
-use crate::kani_middle::codegen_units::{CodegenUnit, CodegenUnits};
+use crate::kani_middle::codegen_units::{CodegenUnit, CodegenUnits, contract_harness_target};
 use crate::kani_middle::provide;
 use crate::kani_middle::reachability::{collect_reachable_items, filter_crate_items};
 use crate::kani_middle::transform::{BodyTransformation, GlobalPasses};
@@ -233,7 +233,7 @@ fn codegen_crate(
                         for harness in &unit.harnesses {
                             let model_path = units.harness_model_path(*harness).unwrap();
                             let contract_metadata =
-                                contract_metadata_for_harness(tcx, harness.def.def_id()).unwrap();
+                                contract_metadata_for_harness(tcx, *harness).unwrap();
                             let (_items, contract_info) = self.codegen_items(
                                 tcx,
                                 &[MonoItem::Fn(*harness)],
@@ -349,9 +349,12 @@ fn new_archive_builder<'a>(&self, sess: &'a Session) -> Box<dyn ArchiveBuilder +
 
 fn contract_metadata_for_harness(
     tcx: TyCtxt,
-    def_id: DefId,
+    harness: Instance,
 ) -> Result<Option<InternalDefId>, ErrorGuaranteed> {
-    let attrs = KaniAttributes::for_def_id(tcx, def_id);
+    if let Some(target) = contract_harness_target(harness) {
+        return Ok(Some(rustc_internal::internal(tcx, target.def_id())));
+    }
+    let attrs = KaniAttributes::for_instance(tcx, harness);
     Ok(attrs.interpret_for_contract_attribute().map(|(_, id, _)| id))
 }
 
@@ -0,0 +1,237 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This file contains the code generation of the calls to `kani::any()`, which get a stable
+//! identifier so their values can be found in the counterexample trace and pinned with `--fix`.
+//!
+//! The identifier of a call site is `<function>::any#<n>`, where `<function>` is the path of the
+//! function that contains the call, e.g., `verify::check_parse`, and `<n>` is the position of
+//! the call among the calls to `kani::any()` in that function, in source order, starting at `0`.
+//! Thus, the identifier only changes if a call to `kani::any()` is added, removed or moved
+//! before it in the same function. Calls that can't be told apart by their span, e.g., the ones
+//! generated by a macro, get no identifier.
+
+use crate::args::PinnedValue;
+use crate::codegen_cprover_gotoc::GotocCtx;
+use crate::codegen_cprover_gotoc::codegen::PropertyClass;
+use crate::codegen_cprover_gotoc::utils::span_err;
+use crate::kani_middle::kani_functions::{KaniFunction, KaniModel};
+use cbmc::goto_program::{Expr, Location, Stmt, Symbol};
+use rustc_middle::ty::TyCtxt;
+use rustc_smir::rustc_internal;
+use stable_mir::CrateDef;
+use stable_mir::mir::mono::Instance;
+use stable_mir::mir::{Body, Place, TerminatorKind};
+use stable_mir::ty::{RigidTy, Span, Ty, TyKind};
+
+/// The prefix of the name of the variable that holds the value created by a call site. The
+/// driver relies on it to find the values of the call sites in the trace.
+const ANY_SITE_VAR_PREFIX: &str = "kani_any_site$";
+
+/// The prefix of the name of the variable that holds the value created by a
+/// `kani::concrete::value` site. The driver relies on it to label the values of concrete playback.
+const CONCRETE_VALUE_VAR_PREFIX: &str = "kani_concrete_value$";
+
+/// Compute the identifiers of the calls to `kani::any()` in the body of `instance`, which are
+/// identified by their span.
+pub fn any_sites(tcx: TyCtxt, instance: Instance, body: &Body) -> Vec<(Span, String)> {
+    let mut calls: Vec<_> = body
+        .blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, bb)| {
+            let TerminatorKind::Call { func, .. } = &bb.terminator.kind else { return None };
+            let TyKind::RigidTy(RigidTy::FnDef(def, _)) = func.ty(body.locals()).ok()?.kind()
+            else {
+                return None;
+            };
+            (KaniFunction::try_from(def) == Ok(KaniModel::Any.into()))
+                .then_some((bb.terminator.span, idx))
+        })
+        .collect();
+    // Sort by position in the source, so the identifiers don't depend on the block order,
+    // which changes with unrelated code. The block index is only used to break ties.
+    calls.sort_by_cached_key(|(span, idx)| {
+        let lines = span.get_lines();
+        (span.get_filename(), lines.start_line, lines.start_col, *idx)
+    });
+
+    let def_id = rustc_internal::internal(tcx, instance.def.def_id());
+    let fn_path = tcx.def_path_str(def_id);
+    let mut sites: Vec<(Span, String)> = vec![];
+    for (span, _) in &calls {
+        // Calls that share their span, e.g., the ones generated by a macro, can't be told apart,
+        // so they don't get an identifier.
+        if calls.iter().filter(|(other, _)| other == span).count() == 1 {
+            let id = format!("{fn_path}::any#{}", sites.len());
+            sites.push((*span, id));
+        }
+    }
+    sites
+}
+
+impl GotocCtx<'_> {
+    /// The identifier of the call to `kani::any()` at `span` in the current function, if any.
+    pub fn any_site_id(&self, span: Span) -> Option<String> {
+        self.current_fn().any_site(span).map(str::to_string)
+    }
+
+    /// Codegen the call `call` to `kani::any()` with identifier `id`.
+    ///
+    /// The value is stored in a variable named after the site before it is assigned to
+    /// `destination`, and it is restricted to the value given with `--fix <id>=<value>`, if any.
+    pub fn codegen_any_site(
+        &mut self,
+        id: &str,
+        call: Expr,
+        destination: &Place,
+        loc: Location,
+    ) -> Vec<Stmt> {
+        let ty = self.place_ty_stable(destination);
+        if self.is_zst_stable(ty) {
+            return vec![self.codegen_expr_to_place_stable(destination, call, loc)];
+        }
+        let mut stmts = vec![];
+        let var = self.codegen_site_var(ANY_SITE_VAR_PREFIX, id, ty, call, &mut stmts, loc);
+        match self.queries.args().pinned_value(id) {
+            Ok(Some(PinnedValue::Value(pinned))) => {
+                let site = format!("`kani::any()` with id `{id}`");
+                stmts.push(self.codegen_assume_pinned(&site, var.clone(), ty, &pinned, loc));
+            }
+            Ok(Some(PinnedValue::Default)) => {
+                let msg = format!(
+                    "cannot pin `kani::any()` with id `{id}` to a default value, since it has \
+                    none. Use `--fix {id}=<value>` instead"
+                );
+                stmts.push(self.codegen_failing_pin(&msg, loc));
+            }
+            Ok(None) => {}
+            Err(msg) => stmts.push(self.codegen_failing_pin(&msg, loc)),
+        }
+        stmts.push(self.codegen_expr_to_place_stable(destination, var, loc));
+        stmts
+    }
+
+    /// Codegen a call to `kani::concrete::value(default, id)`, where `fargs` are the arguments.
+    ///
+    /// The pin of the site is looked up here, so the identifier must be a constant. The call is
+    /// replaced by a call to `kani::concrete::any_or_default`, which restricts the value to
+    /// `default` if the site was pinned with `--fix <id>`. A value given with
+    /// `--fix <id>=<value>` is assumed after the call. Like for `kani::any()`, the value is
+    /// stored in a variable named after the site, so the driver can find it in the trace.
+    pub fn codegen_concrete_value(
+        &mut self,
+        instance: Instance,
+        mut fargs: Vec<Expr>,
+        destination: &Place,
+        span: Span,
+    ) -> Vec<Stmt> {
+        let loc = self.codegen_span_stable(span);
+        let id = self.extract_const_message(&fargs[1]);
+        if id.is_none() {
+            span_err(
+                self.tcx,
+                span,
+                "the id of `kani::concrete::value` must be a string literal".to_string(),
+            );
+        }
+        let id = id.unwrap_or_default();
+        let pin = self.queries.args().pinned_value(&id);
+        let pin_default = matches!(pin, Ok(Some(PinnedValue::Default)));
+        let any_or_default = self.queries.kani_functions()[&KaniModel::ConcreteAnyOrDefault.into()];
+        let any_or_default = Instance::resolve(any_or_default, &instance.args()).unwrap();
+        fargs[1] = Expr::c_bool_constant(pin_default);
+        let call = self.codegen_func_expr(any_or_default, loc).call(fargs);
+
+        let ty = self.place_ty_stable(destination);
+        if self.is_zst_stable(ty) {
+            return vec![self.codegen_expr_to_place_stable(destination, call, loc)];
+        }
+        let mut stmts = vec![];
+        let var = self.codegen_site_var(CONCRETE_VALUE_VAR_PREFIX, &id, ty, call, &mut stmts, loc);
+        match pin {
+            Ok(Some(PinnedValue::Value(pinned))) => {
+                let site = format!("`kani::concrete::value` with id `{id}`");
+                stmts.push(self.codegen_assume_pinned(&site, var.clone(), ty, &pinned, loc));
+            }
+            Ok(_) => {}
+            Err(msg) => stmts.push(self.codegen_failing_pin(&msg, loc)),
+        }
+        stmts.push(self.codegen_expr_to_place_stable(destination, var, loc));
+        stmts
+    }
+
+    /// Declare the variable `<prefix><id>` of type `ty` in the current function, and assign
+    /// `value` to it.
+    fn codegen_site_var(
+        &mut self,
+        prefix: &str,
+        id: &str,
+        ty: Ty,
+        value: Expr,
+        stmts: &mut Vec<Stmt>,
+        loc: Location,
+    ) -> Expr {
+        let typ = self.codegen_ty_stable(ty);
+        let base_name = format!("{prefix}{id}");
+        let name = format!("{}::{base_name}", self.current_fn().name());
+        let var = Symbol::variable(name, base_name, typ, loc);
+        self.symbol_table.insert(var.clone());
+        let var = var.to_expr();
+        stmts.push(Stmt::decl(var.clone(), None, loc));
+        stmts.push(var.clone().assign(value, loc));
+        var
+    }
+
+    /// Codegen the assumption that `var` is equal to the value `pinned` given to `site` with
+    /// `--fix`, or a failing check if `pinned` is not a valid value of type `ty`.
+    fn codegen_assume_pinned(
+        &mut self,
+        site: &str,
+        var: Expr,
+        ty: Ty,
+        pinned: &str,
+        loc: Location,
+    ) -> Stmt {
+        if let Some(pinned) = codegen_pinned_constant(self, ty, pinned) {
+            self.codegen_assume(var.eq(pinned), loc)
+        } else {
+            let msg = format!(
+                "cannot pin {site} to `{pinned}`, since it is not a valid `{ty}`. Only integer, \
+                `bool` and `char` values can be given explicitly"
+            );
+            self.codegen_failing_pin(&msg, loc)
+        }
+    }
+
+    /// Codegen a check that fails with `msg` if the site is reached, for pins that can't hold.
+    fn codegen_failing_pin(&mut self, msg: &str, loc: Location) -> Stmt {
+        self.codegen_assert_assume(Expr::bool_false(), PropertyClass::Assertion, msg, loc)
+    }
+}
+
+/// Codegen the constant represented by `value` for the type `ty`. Returns `None` if the type is
+/// not supported or if `value` is not a valid value of that type.
+fn codegen_pinned_constant(gcx: &mut GotocCtx, ty: Ty, value: &str) -> Option<Expr> {
+    let typ = gcx.codegen_ty_stable(ty);
+    let width = typ.sizeof_in_bits(&gcx.symbol_table);
+    match ty.kind().rigid()? {
+        RigidTy::Bool => value.parse().ok().map(Expr::c_bool_constant),
+        RigidTy::Char => {
+            let mut chars = value.chars();
+            let c = chars.next().filter(|_| chars.next().is_none())?;
+            Some(Expr::int_constant(c as u32, typ))
+        }
+        RigidTy::Int(_) => {
+            let value: i128 = value.parse().ok()?;
+            let fits = width >= 128 || (value >> (width - 1)) == 0 || (value >> (width - 1)) == -1;
+            fits.then(|| Expr::int_constant(value, typ))
+        }
+        RigidTy::Uint(_) => {
+            let value: u128 = value.parse().ok()?;
+            let fits = width >= 128 || (value >> width) == 0;
+            fits.then(|| Expr::int_constant(value, typ))
+        }
+        _ => None,
+    }
+}
@@ -29,6 +29,9 @@
 
 use super::intrinsic::SizeAlign;
 
+/// Name of the global flag that tracks whether panics are permitted in the current scope.
+const PANIC_PERMITTED_VAR_NAME: &str = "KaniPanicPermitted";
+
 /// Classifies the type of CBMC `assert`, as different assertions can have different semantics (e.g. cover)
 ///
 /// Each property class should justify its existence with a note about the special handling it recieves.
@@ -40,10 +43,22 @@ pub enum PropertyClass {
     ///
     /// SPECIAL BEHAVIOR: Same as SafetyCheck. TODO: Replace this with `SafetyCheck`.
     ArithmeticOverflow,
+    /// Reaching the handler of a failed memory allocation, e.g., in `Vec::push`.
+    ///
+    /// SPECIAL BEHAVIOR: Listed in a separate section of the results. Not generated with
+    /// `--assume-alloc-never-fails`, which prunes these paths instead.
+    AllocationFailure,
     /// The Rust `assume` instrinsic is `assert`'d by Kani, and gets this property class.
     ///
     /// SPECIAL BEHAVIOR: None? Possibly confusing to customers that a Rust assume is a Kani assert.
     Assume,
+    /// Checks generated by `kani::assume_satisfiable` to detect contradictory assumptions.
+    /// Encoded as an `assert(false)` right after the assumption.
+    ///
+    /// SPECIAL BEHAVIOR: Like `Cover`, "errors" mean "reachable", which is the expected outcome.
+    /// The CBMC output handler flips the result so the check fails if the assumption prunes
+    /// every path that reaches it.
+    AssumeSatisfiable,
     /// See [GotocCtx::codegen_cover] below. Generally just an `assert(false)` that's not an error.
     ///
     /// SPECIAL BEHAVIOR: "Errors" for this type of assertion just mean "reachable" not failure.
@@ -106,10 +121,25 @@ impl PropertyClass {
     pub fn as_str(&self) -> &str {
         self.as_ref()
     }
+
+    /// Whether this property is kept with `--cover-only`, i.e., whether it's needed to determine
+    /// the status of cover properties.
+    fn is_kept_in_cover_only(&self) -> bool {
+        matches!(
+            self,
+            PropertyClass::Cover
+                | PropertyClass::CodeCoverage
+                | PropertyClass::ReachabilityCheck
+                | PropertyClass::UnsupportedConstruct
+        )
+    }
 }
 
 impl GotocCtx<'_> {
     /// Generates a CBMC assertion. Note: Does _NOT_ assume.
+    ///
+    /// With `--cover-only`, only the properties that determine the status of cover properties
+    /// are generated, and this is a no-op for any other property.
     pub fn codegen_assert(
         &self,
         cond: Expr,
@@ -117,6 +147,9 @@ pub fn codegen_assert(
         message: &str,
         loc: Location,
     ) -> Stmt {
+        if self.queries.args().cover_only && !property_class.is_kept_in_cover_only() {
+            return Stmt::skip(loc);
+        }
         let property_name = property_class.as_str();
         Stmt::assert(cond, property_name, message, loc)
     }
@@ -179,31 +212,44 @@ pub fn codegen_coverage(
     /// If reachability checks are disabled, the function returns the message
     /// unmodified and an empty (skip) statement.
     pub fn codegen_reachability_check(&mut self, msg: String, span: SpanStable) -> (String, Stmt) {
-        let loc = self.codegen_caller_span_stable(span);
         if self.queries.args().check_assertion_reachability {
-            // Generate a unique ID for the assert
-            let assert_id = self.next_check_id();
-            // Also add the unique ID as a prefix to the assert message so that it can be
-            // easily paired with the reachability check
-            let msg = GotocCtx::add_prefix_to_msg(&msg, &assert_id);
-            // Generate a message for the reachability check that includes the unique ID
-            let reach_msg = assert_id;
-            // inject a reachability check, which is a (non-blocking)
-            // assert(false) whose failure indicates that this line is reachable.
-            // The property class (`PropertyClass:ReachabilityCheck`) is used by
-            // the CBMC output parser to distinguish those checks from others.
-            let check = self.codegen_assert(
-                Expr::bool_false(),
-                PropertyClass::ReachabilityCheck,
-                &reach_msg,
-                loc,
-            );
-            (msg, check)
+            self.codegen_unconditional_reachability_check(msg, span)
         } else {
-            (msg, Stmt::skip(loc))
+            (msg, Stmt::skip(self.codegen_caller_span_stable(span)))
         }
     }
 
+    /// Same as [GotocCtx::codegen_reachability_check], but the check is generated even if
+    /// assertion reachability checks are disabled.
+    ///
+    /// This is used for cover properties, since their status depends on whether they are
+    /// reachable or not.
+    pub fn codegen_unconditional_reachability_check(
+        &mut self,
+        msg: String,
+        span: SpanStable,
+    ) -> (String, Stmt) {
+        let loc = self.codegen_caller_span_stable(span);
+        // Generate a unique ID for the assert
+        let assert_id = self.next_check_id();
+        // Also add the unique ID as a prefix to the assert message so that it can be
+        // easily paired with the reachability check
+        let msg = GotocCtx::add_prefix_to_msg(&msg, &assert_id);
+        // Generate a message for the reachability check that includes the unique ID
+        let reach_msg = assert_id;
+        // inject a reachability check, which is a (non-blocking)
+        // assert(false) whose failure indicates that this line is reachable.
+        // The property class (`PropertyClass:ReachabilityCheck`) is used by
+        // the CBMC output parser to distinguish those checks from others.
+        let check = self.codegen_assert(
+            Expr::bool_false(),
+            PropertyClass::ReachabilityCheck,
+            &reach_msg,
+            loc,
+        );
+        (msg, check)
+    }
+
     /// A shorthand for generating a CBMC assert-assume(false)
     pub fn codegen_assert_assume_false(
         &self,
@@ -226,14 +272,73 @@ pub fn codegen_fatal_error(
     }
 
     /// Kani hooks function calls to `panic` and calls this intead.
-    pub fn codegen_panic(&self, span: SpanStable, fargs: Vec<Expr>) -> Stmt {
+    pub fn codegen_panic(&mut self, span: SpanStable, fargs: Vec<Expr>) -> Stmt {
         // CBMC requires that the argument to the assertion must be a string constant.
         // If there is one in the MIR, use it; otherwise, explain that we can't.
         assert!(!fargs.is_empty(), "Panic requires a string message");
         let msg = self.extract_const_message(&fargs[0]).unwrap_or(String::from(
             "This is a placeholder message; Kani doesn't support message formatted at runtime",
         ));
-        self.codegen_fatal_error(PropertyClass::Assertion, &msg, span)
+        let loc = self.codegen_caller_span_stable(span);
+        self.codegen_panic_check(Expr::bool_false(), &msg, loc)
+    }
+
+    /// Generates an assert-assume for a condition that causes a panic if it doesn't hold.
+    ///
+    /// When `-Z panic-scopes` is enabled, the assertion only fails if panics are not
+    /// permitted in the current scope (see `kani::panic::catch`). In both cases, the execution
+    /// path is terminated if the condition doesn't hold.
+    ///
+    /// If the current function comes from a crate given to `--assume-panic-free`, the condition
+    /// is only assumed.
+    pub fn codegen_panic_check(&mut self, cond: Expr, msg: &str, loc: Location) -> Stmt {
+        if self.is_assumed_panic_free() {
+            debug!(function = self.current_fn().readable_name(), msg, "assume panic free");
+            return self.codegen_assume(cond, loc);
+        }
+        if self.queries.args().unstable_features.contains(&"panic-scopes".to_string()) {
+            let permitted = self.codegen_panic_permitted_var(loc).cast_to(Type::bool());
+            Stmt::block(
+                vec![
+                    self.codegen_assert(
+                        cond.clone().or(permitted),
+                        PropertyClass::Assertion,
+                        msg,
+                        loc,
+                    ),
+                    self.codegen_assume(cond, loc),
+                ],
+                loc,
+            )
+        } else {
+            self.codegen_assert_assume(cond, PropertyClass::Assertion, msg, loc)
+        }
+    }
+
+    /// Whether the current function comes from a crate that the user assumes to be panic free.
+    /// Panics in the crate being compiled are always checked.
+    fn is_assumed_panic_free(&self) -> bool {
+        let krate = self.current_fn().krate();
+        let assumed = &self.queries.args().assume_panic_free;
+        !assumed.is_empty()
+            && krate != stable_mir::local_crate().name
+            && assumed.iter().any(|name| name == krate)
+    }
+
+    /// Returns the global flag that tracks whether panics are permitted in the current scope.
+    ///
+    /// The flag is initialized to `false`, and it is only modified by the panic scope functions
+    /// in `kani::panic`.
+    pub fn codegen_panic_permitted_var(&mut self, loc: Location) -> Expr {
+        self.ensure_global_var_init(
+            PANIC_PERMITTED_VAR_NAME,
+            false,
+            false,
+            Type::c_bool(),
+            loc,
+            |_, _| Expr::c_false(),
+        )
+        .to_expr()
     }
 
     /// Kani does not currently support all MIR constructs.
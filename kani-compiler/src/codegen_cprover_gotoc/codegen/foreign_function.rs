@@ -39,6 +39,15 @@
     };
 }
 
+/// Foreign functions used by the standard library to read the system clock.
+const CLOCK_FNS: &[&str] = &[
+    "clock_gettime",
+    "gettimeofday",
+    "mach_absolute_time",
+    "QueryPerformanceCounter",
+    "GetSystemTimePreciseAsFileTime",
+];
+
 impl GotocCtx<'_> {
     /// Generate the symbol and symbol table entry for foreign items.
     ///
@@ -180,10 +189,17 @@ fn codegen_ffi_unsupported(&mut self, instance: Instance, loc: Location) -> Stmt
         } else {
             "https://github.com/model-checking/kani/issues/new/choose"
         };
+        let mut msg = GotocCtx::unsupported_msg(&msg, Some(url));
+        if CLOCK_FNS.contains(&fn_name.as_str()) {
+            msg.push_str(
+                ". This function reads the system clock, e.g., for `std::time::Instant` or \
+                `std::time::SystemTime`. Consider using `kani::time::any_duration()` instead",
+            );
+        }
         self.codegen_assert_assume(
             Expr::bool_false(),
             PropertyClass::UnsupportedConstruct,
-            &GotocCtx::unsupported_msg(&msg, Some(url)),
+            &msg,
             loc,
         )
     }
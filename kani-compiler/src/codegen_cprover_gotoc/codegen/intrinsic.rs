@@ -326,9 +326,7 @@ macro_rules! unstable_codegen {
             Intrinsic::Bitreverse => {
                 self.codegen_expr_to_place_stable(place, fargs.remove(0).bitreverse(), loc)
             }
-            // black_box is an identity function that hints to the compiler
-            // to be maximally pessimistic to limit optimizations
-            Intrinsic::BlackBox => self.codegen_expr_to_place_stable(place, fargs.remove(0), loc),
+            Intrinsic::BlackBox => self.codegen_black_box(fargs.remove(0), place, loc),
             Intrinsic::Breakpoint => Stmt::skip(loc),
             Intrinsic::Bswap => {
                 self.codegen_expr_to_place_stable(place, fargs.remove(0).bswap(), loc)
@@ -506,7 +504,9 @@ macro_rules! unstable_codegen {
                 place,
                 loc,
             ),
-            Intrinsic::Transmute => self.codegen_intrinsic_transmute(fargs, ret_ty, place, loc),
+            Intrinsic::Transmute => {
+                self.codegen_intrinsic_transmute(fargs, farg_types, ret_ty, place, loc)
+            }
             Intrinsic::TruncF32 => codegen_simple_intrinsic!(Truncf),
             Intrinsic::TruncF64 => codegen_simple_intrinsic!(Trunc),
             Intrinsic::TypeId => codegen_intrinsic_const!(),
@@ -553,6 +553,39 @@ macro_rules! unstable_codegen {
         }
     }
 
+    /// `black_box` is an identity function that hints to the compiler to be maximally
+    /// pessimistic about what it may do with its argument. We model it as an opaque identity:
+    /// the result is a new non-deterministic value that is assumed to be equal to the argument.
+    /// So the value doesn't change, but CBMC can't propagate constants through it, e.g., to prune
+    /// the branches that depend on it.
+    ///
+    /// This is only done for scalars, which are compared bitwise, i.e., floats are compared
+    /// through their bits so NaN values are preserved. Other values, including pointers, are
+    /// copied as they are, since a non-deterministic pointer doesn't keep the provenance of the
+    /// argument.
+    fn codegen_black_box(&mut self, arg: Expr, place: &Place, loc: Location) -> Stmt {
+        let typ = arg.typ().clone();
+        if !(typ.is_integer() || typ.is_bool() || typ.is_floating_point()) {
+            return self.codegen_expr_to_place_stable(place, arg, loc);
+        }
+        let (opaque, decl) = self.decl_temp_variable(typ.clone(), Some(typ.nondet()), loc);
+        let same_value = if typ.is_floating_point() {
+            let bits = Type::unsigned_int(typ.sizeof_in_bits(&self.symbol_table));
+            let opaque_bits = opaque.clone().transmute_to(bits.clone(), &self.symbol_table);
+            opaque_bits.eq(arg.transmute_to(bits, &self.symbol_table))
+        } else {
+            opaque.clone().eq(arg)
+        };
+        Stmt::block(
+            vec![
+                decl,
+                self.codegen_assume(same_value, loc),
+                self.codegen_expr_to_place_stable(place, opaque, loc),
+            ],
+            loc,
+        )
+    }
+
     /// Perform type checking and code generation for the `ctpop` rust intrinsic.
     fn codegen_ctpop(
         &mut self,
@@ -798,15 +831,15 @@ fn codegen_atomic_load(
     ///  * the previous value
     ///  * a boolean value indicating whether the operation was successful or not
     ///
-    /// In a sequential context, the update is always sucessful so we assume the
-    /// second value to be true.
+    /// In a sequential context, the operation succeeds iff the previous value is equal
+    /// to the expected one. Thus, weak variants never fail spuriously.
     /// -------------------------
     /// var = atomic_cxchg(var1, var2, var3)
     /// -------------------------
     /// unsigned char tmp;
     /// tmp = *var1;
     /// if (*var1 == var2) *var1 = var3;
-    /// var = (tmp, true);
+    /// var = (tmp, tmp == var2);
     /// -------------------------
     fn codegen_atomic_cxchg(
         &mut self,
@@ -822,13 +855,14 @@ fn codegen_atomic_cxchg(
             self.decl_temp_variable(var1.typ().clone(), Some(var1.to_owned()), loc);
         let var2 = fargs.remove(0).with_location(loc);
         let var3 = fargs.remove(0).with_location(loc);
-        let eq_expr = (var1.clone()).eq(var2);
+        let eq_expr = (var1.clone()).eq(var2.clone());
         let assign_stmt = var1.assign(var3, loc);
         let cond_update_stmt = Stmt::if_then_else(eq_expr, assign_stmt, None, loc);
         let place_type = self.place_ty_stable(p);
         let res_type = self.codegen_ty_stable(place_type);
+        let success = tmp.clone().eq(var2).cast_to(Type::c_bool());
         let tuple_expr =
-            Expr::struct_expr_from_values(res_type, vec![tmp, Expr::c_true()], &self.symbol_table)
+            Expr::struct_expr_from_values(res_type, vec![tmp, success], &self.symbol_table)
                 .with_location(loc);
         let res_stmt = self.codegen_expr_to_place_stable(p, tuple_expr, loc);
         Stmt::atomic_block(vec![decl_stmt, cond_update_stmt, res_stmt], loc)
@@ -884,7 +918,8 @@ fn codegen_atomic_noop(&mut self, intrinsic: &str, loc: Location) -> Stmt {
     ///  * (Exclusive to nonoverlapping copy) The region of memory beginning
     ///    at `src` with a size of `count * size_of::<T>()` bytes must *not*
     ///    overlap with the region of memory beginning at `dst` with the same
-    ///    size.
+    ///    size (done by calls to `memcpy`, which reports a distinct
+    ///    `memcpy src/dst overlap` property).
     ///
     /// In addition, we check that computing `count` in bytes (i.e., the third
     /// argument of the copy built-in call) would not overflow.
@@ -1045,18 +1080,18 @@ fn codegen_arith_offset(&mut self, mut fargs: Vec<Expr>, p: &Place, loc: Locatio
     ///
     /// Note(std): An earlier attempt to add alignment checks for both the argument and result types
     /// had catastrophic results in the regression. Hence, we don't perform any additional checks
-    /// and only encode the transmute operation here.
+    /// other than the ones in [GotocCtx::codegen_transmute].
     fn codegen_intrinsic_transmute(
         &mut self,
         mut fargs: Vec<Expr>,
+        farg_types: &[Ty],
         ret_ty: Ty,
         p: &Place,
         loc: Location,
     ) -> Stmt {
         assert!(fargs.len() == 1, "transmute had unexpected arguments {fargs:?}");
         let arg = fargs.remove(0);
-        let cbmc_ret_ty = self.codegen_ty_stable(ret_ty);
-        let expr = arg.transmute_to(cbmc_ret_ty, &self.symbol_table);
+        let expr = self.codegen_transmute(arg, farg_types[0], ret_ty, loc);
         self.codegen_expr_to_place_stable(p, expr, loc)
     }
 
@@ -1795,6 +1830,10 @@ fn is_ptr_aligned(&mut self, ty: Ty, ptr: Expr) -> Expr {
     /// The standard library API requires that `x` and `y` are readable and writable as their
     /// (common) type (which auto-generated checks for dereferencing will take care of), and the
     /// memory regions pointed to must be non-overlapping.
+    ///
+    /// Since `mem::swap` is implemented with this intrinsic, swapping values is modeled as three
+    /// value moves, regardless of the size of the type, instead of a byte-wise copy loop. Nothing is
+    /// dropped, so values with drop glue are only dropped once by their new owner.
     pub fn codegen_swap(&mut self, mut fargs: Vec<Expr>, farg_types: &[Ty], loc: Location) -> Stmt {
         // two parameters, and both must be raw pointers with the same base type
         assert!(fargs.len() == 2);
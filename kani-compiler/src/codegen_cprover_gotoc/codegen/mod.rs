@@ -4,6 +4,7 @@
 //! This module does that actual translation of MIR constructs to goto constructs.
 //! Each subfile is named for the MIR construct it translates.
 
+mod any_site;
 mod assert;
 mod block;
 mod foreign_function;
@@ -22,6 +23,7 @@
 mod ty_stable;
 pub(super) mod typ;
 
+pub use any_site::any_sites;
 pub use assert::PropertyClass;
 pub use block::bb_label;
 pub use typ::TypeExt;
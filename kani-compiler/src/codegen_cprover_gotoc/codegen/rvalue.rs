@@ -1,6 +1,7 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use crate::args::ExtraChecks;
 use crate::codegen_cprover_gotoc::codegen::PropertyClass;
 use crate::codegen_cprover_gotoc::codegen::place::ProjectedPlace;
 use crate::codegen_cprover_gotoc::codegen::ty_stable::pointee_type_stable;
@@ -10,6 +11,7 @@
 use crate::kani_middle::coercion::{
     CoerceUnsizedInfo, CoerceUnsizedIterator, CoercionBaseStable, extract_unsize_casting_stable,
 };
+use crate::kani_middle::transform::transmute_reads_padding;
 use crate::unwrap_or_return_codegen_unimplemented;
 use cbmc::MachineModel;
 use cbmc::goto_program::{
@@ -801,8 +803,9 @@ pub fn codegen_rvalue_stable(&mut self, rv: &Rvalue, loc: Location) -> Expr {
                 self.codegen_pointer_cast(k, e, *t, loc)
             }
             Rvalue::Cast(CastKind::Transmute, operand, ty) => {
-                let goto_typ = self.codegen_ty_stable(*ty);
-                self.codegen_operand_stable(operand).transmute_to(goto_typ, &self.symbol_table)
+                let src_ty = self.operand_ty_stable(operand);
+                let expr = self.codegen_operand_stable(operand);
+                self.codegen_transmute(expr, src_ty, *ty, loc)
             }
             Rvalue::BinaryOp(op, e1, e2) => self.codegen_rvalue_binary_op(res_ty, op, e1, e2, loc),
             Rvalue::CheckedBinaryOp(op, e1, e2) => {
@@ -1167,6 +1170,45 @@ fn codegen_misc_cast(&mut self, src: &Operand, dst_ty: Ty) -> Expr {
         }
     }
 
+    /// Reinterpret the bytes of `expr`, which has type `src_ty`, as a value of type `dst_ty`.
+    ///
+    /// Rust guarantees that both types have the same size, but if their Goto representations
+    /// differ in size, we report an unsupported construct with the types and sizes involved
+    /// instead of generating an ill-formed expression. With `-Z uninit-checks`, transmutes that
+    /// read padding bytes of the source value are reported as a safety check failure, since
+    /// padding is uninitialized.
+    pub fn codegen_transmute(&mut self, expr: Expr, src_ty: Ty, dst_ty: Ty, loc: Location) -> Expr {
+        let dst_typ = self.codegen_ty_stable(dst_ty);
+        let src_bits = expr.typ().sizeof_in_bits(&self.symbol_table);
+        let dst_bits = dst_typ.sizeof_in_bits(&self.symbol_table);
+        if src_bits != dst_bits {
+            let msg = format!(
+                "transmute from `{src_ty}` ({} bytes) to `{dst_ty}` ({} bytes)",
+                src_bits / 8,
+                dst_bits / 8
+            );
+            return self.codegen_unimplemented_expr(
+                &msg,
+                dst_typ,
+                loc,
+                "https://github.com/model-checking/kani/issues/new/choose",
+            );
+        }
+        let result = expr.transmute_to(dst_typ.clone(), &self.symbol_table);
+        if self.queries.args().ub_check.contains(&ExtraChecks::Uninit)
+            && transmute_reads_padding(&src_ty, &dst_ty)
+        {
+            let msg = format!(
+                "transmute from `{src_ty}` to `{dst_ty}` reads padding bytes of `{src_ty}`, \
+                which are uninitialized"
+            );
+            let check = self.codegen_assert_assume_false(PropertyClass::SafetyCheck, &msg, loc);
+            Expr::statement_expression(vec![check, result.as_stmt(loc)], dst_typ, loc)
+        } else {
+            result
+        }
+    }
+
     /// "Pointer casts" are particular kinds of pointer-to-pointer casts.
     /// See the [`PointerCoercion`] type for specifics.
     /// Note that this does not include all casts involving pointers,
@@ -87,6 +87,10 @@ pub fn codegen_caller_span_stable(&self, sp: SpanStable) -> Location {
     /// This function uses rustc_span methods designed to returns span for the macro which
     /// originally caused the expansion to happen.
     /// Note: The API stops backtracing at include! boundary.
+    ///
+    /// This is the same location that `Location::caller()` returns for a `#[track_caller]`
+    /// function, which is why we use it for the properties generated by the `kani::assert`,
+    /// `kani::assume` and `kani::cover` hooks.
     pub fn codegen_caller_span(&self, span: &Span) -> Location {
         let topmost = span.ctxt().outer_expn().expansion_cause().unwrap_or(*span);
         self.codegen_span(&topmost)
@@ -5,6 +5,7 @@
 use super::{PropertyClass, bb_label};
 use crate::codegen_cprover_gotoc::codegen::function::rustc_smir::region_from_coverage_opaque;
 use crate::codegen_cprover_gotoc::{GotocCtx, VtableCtx};
+use crate::kani_middle::kani_functions::{KaniFunction, KaniModel};
 use crate::unwrap_or_return_codegen_unimplemented_stmt;
 use cbmc::goto_program::{Expr, Location, Stmt, Type};
 use rustc_middle::ty::layout::LayoutOf;
@@ -272,12 +273,7 @@ pub fn codegen_terminator(&mut self, term: &Terminator) -> Stmt {
                 Stmt::block(
                     vec![
                         reach_stmt,
-                        self.codegen_assert_assume(
-                            cond.cast_to(Type::bool()),
-                            PropertyClass::Assertion,
-                            &msg_str,
-                            loc,
-                        ),
+                        self.codegen_panic_check(cond.cast_to(Type::bool()), &msg_str, loc),
                         Stmt::goto(bb_label(*target), loc),
                     ],
                     loc,
@@ -402,7 +398,9 @@ fn codegen_drop(&mut self, place: &Place, target: &BasicBlockIdx, loc: Location)
         // Once upon a time we did a `hook_applies` check here, but we no longer seem to hook drops
         let drop_implementation = match drop_instance.kind {
             InstanceKind::Shim if drop_instance.is_empty_shim() => {
-                // We can skip empty DropGlue functions
+                // We can skip empty DropGlue functions. This includes the drop glue of
+                // `ManuallyDrop<T>`, so values that are leaked with `ManuallyDrop` or
+                // `Box::leak` are never dropped, and their memory stays valid.
                 Stmt::skip(loc)
             }
             InstanceKind::Shim => {
@@ -645,6 +643,14 @@ fn codegen_funcall(
                         let func_exp = self.codegen_func_expr(instance, loc);
                         if instance.is_foreign_item() {
                             vec![self.codegen_foreign_call(func_exp, fargs, destination, loc)]
+                        } else if KaniFunction::try_from(instance)
+                            == Ok(KaniModel::ConcreteValue.into())
+                        {
+                            self.codegen_concrete_value(instance, fargs, destination, span)
+                        } else if let Some(id) = self.any_site_id(span).filter(|_| {
+                            KaniFunction::try_from(instance) == Ok(KaniModel::Any.into())
+                        }) {
+                            self.codegen_any_site(&id, func_exp.call(fargs), destination, loc)
                         } else {
                             vec![self.codegen_expr_to_place_stable(
                                 destination,
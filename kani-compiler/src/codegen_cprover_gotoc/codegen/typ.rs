@@ -699,6 +699,8 @@ fn codegen_alignment_padding(
     ///
     /// once the order is determined, this function also computes padding fields based on the size
     /// and the offset of each field as appropriate.
+    /// Since CBMC does not add any padding of its own, this also models the layout of
+    /// `#[repr(packed)]` structs, whose fields may be at misaligned offsets.
     ///
     /// * name - the name of the struct
     /// * flds - list of field name and type pairs, but the order is not specified by this list
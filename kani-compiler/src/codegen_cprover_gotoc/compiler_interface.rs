@@ -7,21 +7,25 @@
 use crate::codegen_cprover_gotoc::GotocCtx;
 use crate::kani_middle::analysis;
 use crate::kani_middle::attributes::{KaniAttributes, is_test_harness_description};
-use crate::kani_middle::check_reachable_items;
-use crate::kani_middle::codegen_units::{CodegenUnit, CodegenUnits};
+use crate::kani_middle::codegen_units::{CodegenUnit, CodegenUnits, contract_harness_target};
 use crate::kani_middle::metadata::gen_test_metadata;
 use crate::kani_middle::provide;
 use crate::kani_middle::reachability::{
     collect_reachable_items, filter_const_crate_items, filter_crate_items,
 };
 use crate::kani_middle::transform::{BodyTransformation, GlobalPasses};
+use crate::kani_middle::{
+    check_reachable_items, check_std_asserts, check_test_harness_determinism,
+};
 use crate::kani_queries::QueryDb;
 use cbmc::RoundingMode;
 use cbmc::goto_program::Location;
 use cbmc::irep::goto_binary_serde::write_goto_binary_file;
 use cbmc::{InternedString, MachineModel};
 use kani_metadata::artifact::convert_type;
-use kani_metadata::{ArtifactType, HarnessMetadata, KaniMetadata, UnsupportedFeature};
+use kani_metadata::{
+    ArtifactType, DiagnosticCategory, HarnessMetadata, KaniMetadata, UnsupportedFeature,
+};
 use kani_metadata::{AssignsContract, CompilerArtifactStub};
 use rustc_codegen_ssa::back::archive::{
     ArArchiveBuilder, ArchiveBuilder, ArchiveBuilderBuilder, DEFAULT_OBJECT_READER,
@@ -30,7 +34,7 @@
 use rustc_codegen_ssa::traits::CodegenBackend;
 use rustc_codegen_ssa::{CodegenResults, CrateInfo};
 use rustc_data_structures::fx::{FxHashMap, FxIndexMap};
-use rustc_errors::DEFAULT_LOCALE_RESOURCE;
+use rustc_errors::{DEFAULT_LOCALE_RESOURCE, ExplicitBug};
 use rustc_hir::def_id::{DefId as InternalDefId, LOCAL_CRATE};
 use rustc_metadata::EncodedMetadata;
 use rustc_middle::dep_graph::{WorkProduct, WorkProductId};
@@ -40,15 +44,17 @@
 use rustc_session::config::{CrateType, OutputFilenames, OutputType};
 use rustc_session::output::out_filename;
 use rustc_smir::rustc_internal;
+use rustc_span::fatal_error::FatalErrorMarker;
 use rustc_target::abi::Endian;
 use rustc_target::spec::PanicStrategy;
+use stable_mir::CrateDef;
 use stable_mir::mir::mono::{Instance, MonoItem};
-use stable_mir::{CrateDef, DefId};
 use std::any::Any;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 use std::fmt::Write;
 use std::fs::File;
 use std::io::BufWriter;
+use std::panic::{self, AssertUnwindSafe};
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
@@ -72,6 +78,9 @@ pub fn new(queries: Arc<Mutex<QueryDb>>) -> Self {
 
     /// Generate code that is reachable from the given starting points.
     ///
+    /// This also returns the cycles of recursive functions that are reachable from the starting
+    /// points, ignoring the ones that only include functions from the standard library.
+    ///
     /// Invariant: iff `check_contract.is_some()` then `return.2.is_some()`
     fn codegen_items<'tcx>(
         &self,
@@ -81,7 +90,7 @@ fn codegen_items<'tcx>(
         machine_model: &MachineModel,
         check_contract: Option<InternalDefId>,
         mut transformer: BodyTransformation,
-    ) -> (GotocCtx<'tcx>, Vec<MonoItem>, Option<AssignsContract>) {
+    ) -> (GotocCtx<'tcx>, Vec<MonoItem>, Option<AssignsContract>, Vec<Vec<String>>) {
         // This runs reachability analysis before global passes are applied.
         //
         // Alternatively, we could run reachability only once after the global passes are applied
@@ -93,6 +102,12 @@ fn codegen_items<'tcx>(
             || collect_reachable_items(tcx, &mut transformer, starting_items),
             "codegen reachability analysis",
         );
+        let recursive_cycles = call_graph
+            .recursive_cycles()
+            .into_iter()
+            .filter(|cycle| cycle.iter().any(|instance| !is_std_instance(instance)))
+            .map(|cycle| cycle.iter().map(Instance::name).collect())
+            .collect();
 
         // Retrieve all instances from the currently codegened items.
         let instances = items
@@ -200,9 +215,15 @@ fn codegen_items<'tcx>(
 
         // No output should be generated if user selected no_codegen.
         if !tcx.sess.opts.unstable_opts.no_codegen && tcx.sess.opts.output_types.should_codegen() {
-            let pretty = self.queries.lock().unwrap().args().output_pretty_json;
+            let (pretty, write_json_symtab) = {
+                let queries = self.queries.lock().unwrap();
+                (queries.args().output_pretty_json, queries.args().write_json_symtab)
+            };
             write_file(&symtab_goto, ArtifactType::PrettyNameMap, &pretty_name_map, pretty);
             write_goto_binary_file(symtab_goto, &gcx.symbol_table);
+            if write_json_symtab {
+                write_file(&symtab_goto, ArtifactType::SymTab, &gcx.symbol_table, pretty);
+            }
             write_file(&symtab_goto, ArtifactType::TypeMap, &type_map, pretty);
             // If they exist, write out vtable virtual call function pointer restrictions
             if let Some(restrictions) = vtable_restrictions {
@@ -210,7 +231,7 @@ fn codegen_items<'tcx>(
             }
         }
 
-        (gcx, items, contract_info)
+        (gcx, items, contract_info, recursive_cycles)
     }
 }
 
@@ -268,6 +289,11 @@ fn codegen_crate(
                     let mut units = CodegenUnits::new(&queries, tcx);
                     let mut modifies_instances = vec![];
                     let mut loop_contracts_instances = vec![];
+                    let mut recursive_cycles = vec![];
+                    let mut harness_unsupported = vec![];
+                    let mut codegen_errors = vec![];
+                    let mut std_asserts = HashSet::new();
+                    let keep_going = queries.args().keep_going;
                     // Cross-crate collecting of all items that are reachable from the crate harnesses.
                     for unit in units.iter() {
                         // We reset the body cache for now because each codegen unit has different
@@ -275,19 +301,40 @@ fn codegen_crate(
                         for harness in &unit.harnesses {
                             let transformer = BodyTransformation::new(&queries, tcx, &unit);
                             let model_path = units.harness_model_path(*harness).unwrap();
-                            let contract_metadata =
-                                contract_metadata_for_harness(tcx, harness.def.def_id());
-                            let (gcx, items, contract_info) = self.codegen_items(
-                                tcx,
-                                &[MonoItem::Fn(*harness)],
-                                model_path,
-                                &results.machine_model,
-                                contract_metadata,
-                                transformer,
-                            );
+                            let contract_metadata = contract_metadata_for_harness(tcx, *harness);
+                            let codegen = || {
+                                self.codegen_items(
+                                    tcx,
+                                    &[MonoItem::Fn(*harness)],
+                                    model_path,
+                                    &results.machine_model,
+                                    contract_metadata,
+                                    transformer,
+                                )
+                            };
+                            let (gcx, items, contract_info, cycles) = if keep_going {
+                                match catch_codegen_panic(tcx, codegen) {
+                                    Ok(codegen_result) => codegen_result,
+                                    Err(error) => {
+                                        codegen_errors.push((*harness, error));
+                                        continue;
+                                    }
+                                }
+                            } else {
+                                codegen()
+                            };
+                            check_test_harness_determinism(tcx, *harness, &items);
+                            if !queries.args().no_std_assert_notes {
+                                check_std_asserts(tcx, &items, &mut std_asserts);
+                            }
                             if gcx.has_loop_contracts {
                                 loop_contracts_instances.push(*harness);
                             }
+                            recursive_cycles.push((*harness, cycles));
+                            harness_unsupported.push((
+                                *harness,
+                                unsupported_features(&gcx.unsupported_constructs),
+                            ));
                             results.extend(gcx, items, None);
                             if let Some(assigns_contract) = contract_info {
                                 modifies_instances.push((*harness, assigns_contract));
@@ -296,6 +343,9 @@ fn codegen_crate(
                     }
                     units.store_modifies(&modifies_instances);
                     units.store_loop_contracts(&loop_contracts_instances);
+                    units.store_recursive_cycles(recursive_cycles);
+                    units.store_unsupported_features(harness_unsupported);
+                    units.store_codegen_errors(codegen_errors);
                     units.write_metadata(&queries, tcx);
                 }
                 ReachabilityType::Tests => {
@@ -318,7 +368,7 @@ fn codegen_crate(
                     // We will be able to remove this once we optimize all calls to CBMC utilities.
                     // https://github.com/model-checking/kani/issues/1971
                     let model_path = base_filename.with_extension(ArtifactType::SymTabGoto);
-                    let (gcx, items, contract_info) = self.codegen_items(
+                    let (gcx, items, contract_info, _) = self.codegen_items(
                         tcx,
                         &harnesses,
                         &model_path,
@@ -358,7 +408,7 @@ fn codegen_crate(
                     .map(MonoItem::Fn)
                     .collect::<Vec<_>>();
                     let model_path = base_filename.with_extension(ArtifactType::SymTabGoto);
-                    let (gcx, items, contract_info) = self.codegen_items(
+                    let (gcx, items, contract_info, _) = self.codegen_items(
                         tcx,
                         &local_reachable,
                         &model_path,
@@ -373,7 +423,8 @@ fn codegen_crate(
 
             if reachability != ReachabilityType::None {
                 // Print compilation report.
-                results.print_report(tcx);
+                results.print_report(tcx, &queries.args().deny);
+                tcx.dcx().abort_if_errors();
 
                 if reachability != ReachabilityType::Harnesses {
                     // In a workspace, cargo seems to be using the same file prefix to build a crate that is
@@ -451,8 +502,51 @@ fn new_archive_builder<'a>(&self, sess: &'a Session) -> Box<dyn ArchiveBuilder +
     }
 }
 
-fn contract_metadata_for_harness(tcx: TyCtxt, def_id: DefId) -> Option<InternalDefId> {
-    let attrs = KaniAttributes::for_def_id(tcx, def_id);
+/// Run the code generation of one harness, catching the panics so the other harnesses can still
+/// be generated with `--keep-going`. Returns the error that made the codegen fail.
+///
+/// The panic message is also printed by the panic hook, together with the item that was being
+/// generated. If the codegen reported an error before it unwound, e.g., from `abort_if_errors`,
+/// that error is returned instead, since it is the actual cause. Errors from `bug!` or
+/// `span_bug!` are only printed by the compiler, so they are reported with a generic message.
+///
+/// The errors emitted to the session would make the compilation of the whole crate fail, so we
+/// reset the error count, which also discards delayed bugs and stashed diagnostics. We only do
+/// this if the session had no errors nor delayed bugs before this harness, so everything that we
+/// discard comes from the failed codegen, which is reported as the error of this harness.
+fn catch_codegen_panic<T>(tcx: TyCtxt, codegen: impl FnOnce() -> T) -> Result<T, String> {
+    let had_errors = tcx.dcx().has_errors_or_delayed_bugs().is_some();
+    let _ = super::utils::take_codegen_errors();
+    let result = panic::catch_unwind(AssertUnwindSafe(codegen));
+    let errors = super::utils::take_codegen_errors();
+    result.map_err(|payload| {
+        if !had_errors {
+            tcx.dcx().reset_err_count();
+        }
+        if !errors.is_empty() {
+            errors.join("\n")
+        } else if let Some(msg) = payload.downcast_ref::<&str>() {
+            msg.to_string()
+        } else if let Some(msg) = payload.downcast_ref::<String>() {
+            msg.clone()
+        } else if payload.is::<ExplicitBug>() || payload.is::<FatalErrorMarker>() {
+            "the compiler reported an error while generating the model of this harness".to_string()
+        } else {
+            "the compiler panicked while generating the model of this harness".to_string()
+        }
+    })
+}
+
+/// Whether the given instance was defined in one of the standard library crates.
+fn is_std_instance(instance: &Instance) -> bool {
+    matches!(instance.def.krate().name.as_str(), "core" | "alloc" | "std")
+}
+
+fn contract_metadata_for_harness(tcx: TyCtxt, harness: Instance) -> Option<InternalDefId> {
+    if let Some(target) = contract_harness_target(harness) {
+        return Some(rustc_internal::internal(tcx, target.def_id()));
+    }
+    let attrs = KaniAttributes::for_instance(tcx, harness);
     attrs.interpret_for_contract_attribute().map(|(_, id, _)| id)
 }
 
@@ -576,27 +670,7 @@ pub fn new(tcx: TyCtxt, reachability: ReachabilityType) -> Self {
     }
     /// Method that generates `KaniMetadata` from the given compilation results.
     pub fn generate_metadata(&self) -> KaniMetadata {
-        // Maps the goto-context "unsupported features" data into the KaniMetadata "unsupported features" format.
-        // TODO: Do we really need different formats??
-        let unsupported_features = self
-            .unsupported_constructs
-            .iter()
-            .map(|(construct, location)| UnsupportedFeature {
-                feature: construct.to_string(),
-                locations: location
-                    .iter()
-                    .map(|l| {
-                        // We likely (and should) have no instances of
-                        // calling `codegen_unimplemented` without file/line.
-                        // So while we map out of `Option` here, we expect them to always be `Some`
-                        kani_metadata::Location {
-                            filename: l.filename().unwrap_or_default(),
-                            start_line: l.start_line().unwrap_or_default(),
-                        }
-                    })
-                    .collect(),
-            })
-            .collect();
+        let unsupported_features = unsupported_features(&self.unsupported_constructs);
         let (proofs, tests) = if self.reachability == ReachabilityType::Harnesses {
             (self.harnesses.clone(), vec![])
         } else {
@@ -611,6 +685,7 @@ pub fn generate_metadata(&self) -> KaniMetadata {
             // removes any contracts logic for ReachabilityType::Test or ReachabilityType::PubFns,
             // which are the two ReachabilityTypes under which the compiler calls this function.
             contracted_functions: vec![],
+            inactive_harnesses: vec![],
         }
     }
 
@@ -629,7 +704,18 @@ fn extend(
     }
 
     /// Prints a report at the end of the compilation.
-    fn print_report(&self, tcx: TyCtxt) {
+    ///
+    /// The warnings of the categories in `denied` are reported as errors instead.
+    fn print_report(&self, tcx: TyCtxt, denied: &[DiagnosticCategory]) {
+        let report = |category: DiagnosticCategory, msg: String| {
+            if denied.contains(&category) {
+                tcx.dcx().err(format!(
+                    "{msg}\nThis is an error because the `{category}` diagnostics are denied."
+                ));
+            } else {
+                tcx.dcx().warn(msg);
+            }
+        };
         // Print all unsupported constructs.
         if !self.unsupported_constructs.is_empty() {
             // Sort alphabetically.
@@ -645,7 +731,7 @@ fn print_report(&self, tcx: TyCtxt) {
             msg += "\nVerification will fail if one or more of these constructs is reachable.";
             msg += "\nSee https://model-checking.github.io/kani/rust-feature-support.html for more \
             details.";
-            tcx.dcx().warn(msg);
+            report(DiagnosticCategory::UnsupportedSoft, msg);
         }
 
         if !self.concurrent_constructs.is_empty() {
@@ -656,7 +742,7 @@ fn print_report(&self, tcx: TyCtxt) {
             for (construct, locations) in self.concurrent_constructs.iter() {
                 writeln!(&mut msg, "    - {construct} ({})", locations.len()).unwrap();
             }
-            tcx.dcx().warn(msg);
+            report(DiagnosticCategory::Concurrency, msg);
         }
 
         // Print some compilation stats.
@@ -666,6 +752,30 @@ fn print_report(&self, tcx: TyCtxt) {
     }
 }
 
+/// Maps the goto-context "unsupported features" data into the KaniMetadata "unsupported features"
+/// format.
+// TODO: Do we really need different formats??
+fn unsupported_features(constructs: &UnsupportedConstructs) -> Vec<UnsupportedFeature> {
+    constructs
+        .iter()
+        .map(|(construct, location)| UnsupportedFeature {
+            feature: construct.to_string(),
+            locations: location
+                .iter()
+                .map(|l| {
+                    // We likely (and should) have no instances of
+                    // calling `codegen_unimplemented` without file/line.
+                    // So while we map out of `Option` here, we expect them to always be `Some`
+                    kani_metadata::Location {
+                        filename: l.filename().unwrap_or_default(),
+                        start_line: l.start_line().unwrap_or_default(),
+                    }
+                })
+                .collect(),
+        })
+        .collect()
+}
+
 /// Builds a machine model which is required by CBMC
 fn new_machine_model(sess: &Session) -> MachineModel {
     // The model assumes a `x86_64-unknown-linux-gnu`, `x86_64-apple-darwin`
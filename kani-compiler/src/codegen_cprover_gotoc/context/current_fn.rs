@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use crate::codegen_cprover_gotoc::GotocCtx;
+use crate::codegen_cprover_gotoc::codegen::any_sites;
 use cbmc::InternedString;
 use cbmc::goto_program::Stmt;
 use rustc_middle::ty::Instance as InstanceInternal;
@@ -9,6 +10,7 @@
 use stable_mir::CrateDef;
 use stable_mir::mir::mono::Instance;
 use stable_mir::mir::{Body, Local, LocalDecl, Rvalue, visit::Location, visit::MirVisitor};
+use stable_mir::ty::Span;
 use std::collections::{HashMap, HashSet};
 
 /// This structure represents useful data about the function we are currently compiling.
@@ -34,6 +36,8 @@ pub struct CurrentFnCtx<'tcx> {
     readable_name: String,
     /// A counter to enable creating temporary variables
     temp_var_counter: u64,
+    /// The identifiers of the calls to `kani::any()` in this function, by their span.
+    any_sites: Vec<(Span, String)>,
 }
 
 struct AddressTakenLocalsCollector {
@@ -68,6 +72,7 @@ pub fn new(instance: Instance, gcx: &GotocCtx<'tcx>, body: &Body) -> Self {
             .collect::<HashMap<_, _>>();
         let mut visitor = AddressTakenLocalsCollector { address_taken_locals: HashSet::new() };
         visitor.visit_body(body);
+        let any_sites = any_sites(gcx.tcx, instance, body);
         Self {
             block: vec![],
             instance,
@@ -79,6 +84,7 @@ pub fn new(instance: Instance, gcx: &GotocCtx<'tcx>, body: &Body) -> Self {
             name,
             readable_name,
             temp_var_counter: 0,
+            any_sites,
         }
     }
 }
@@ -122,6 +128,11 @@ pub fn readable_name(&self) -> &str {
         &self.readable_name
     }
 
+    /// The name of the crate the function we are currently compiling is from
+    pub fn krate(&self) -> &str {
+        &self.krate
+    }
+
     pub fn locals(&self) -> &[LocalDecl] {
         &self.locals
     }
@@ -133,6 +144,11 @@ pub fn local_name(&self, local: Local) -> Option<InternedString> {
     pub fn is_address_taken_local(&self, local: Local) -> bool {
         self.address_taken_locals.contains(&local)
     }
+
+    /// The identifier of the call to `kani::any()` at `span`, if any.
+    pub fn any_site(&self, span: Span) -> Option<&str> {
+        self.any_sites.iter().find(|(site, _)| *site == span).map(|(_, id)| id.as_str())
+    }
 }
 
 /// Utility functions
@@ -19,7 +19,7 @@
 use rustc_smir::rustc_internal;
 use stable_mir::mir::mono::Instance;
 use stable_mir::mir::{BasicBlockIdx, Place};
-use stable_mir::ty::RigidTy;
+use stable_mir::ty::{RigidTy, Ty};
 use stable_mir::{CrateDef, ty::Span};
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -73,7 +73,7 @@ fn handle(
         let target = target.unwrap();
         let caller_loc = gcx.codegen_caller_span_stable(span);
 
-        let (msg, reach_stmt) = gcx.codegen_reachability_check(msg, span);
+        let (msg, reach_stmt) = gcx.codegen_unconditional_reachability_check(msg, span);
 
         Stmt::block(
             vec![
@@ -104,12 +104,48 @@ fn handle(
         assert_eq!(fargs.len(), 1);
         let cond = fargs.remove(0).cast_to(Type::bool());
         let target = target.unwrap();
-        let loc = gcx.codegen_span_stable(span);
+        let loc = gcx.codegen_caller_span_stable(span);
 
         Stmt::block(vec![gcx.codegen_assume(cond, loc), Stmt::goto(bb_label(target), loc)], loc)
     }
 }
 
+struct AssumeSatisfiable;
+impl GotocHook for AssumeSatisfiable {
+    fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
+        unreachable!("{UNEXPECTED_CALL}")
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        _instance: Instance,
+        mut fargs: Vec<Expr>,
+        _assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        assert_eq!(fargs.len(), 1);
+        let cond = fargs.remove(0).cast_to(Type::bool());
+        let target = target.unwrap();
+        let loc = gcx.codegen_caller_span_stable(span);
+        let assume = gcx.codegen_assume(cond, loc);
+        let goto = Stmt::goto(bb_label(target), loc);
+        if gcx.queries.args().no_assume_satisfiable_checks {
+            return Stmt::block(vec![assume, goto], loc);
+        }
+
+        // The reachability check must come before the assumption. Otherwise, a contradictory
+        // assumption would make the check unreachable, and it would be reported as such instead
+        // of as a failure.
+        let msg = "contradictory assumption: no execution satisfies `kani::assume_satisfiable`";
+        let (msg, reach_stmt) = gcx.codegen_unconditional_reachability_check(msg.into(), span);
+        let check =
+            gcx.codegen_assert(Expr::bool_false(), PropertyClass::AssumeSatisfiable, &msg, loc);
+        Stmt::block(vec![reach_stmt, assume, check, goto], loc)
+    }
+}
+
 struct Assert;
 impl GotocHook for Assert {
     fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
@@ -311,6 +347,133 @@ fn handle(
     }
 }
 
+/// A hook for `Option::unwrap`, `Option::expect`, `Result::unwrap` and `Result::expect`.
+///
+/// The panic inside these methods is reported at a location in the standard library, and the
+/// message of `Result::unwrap` and `expect` is formatted at runtime. This hook checks the variant
+/// before the call instead, so the failure names the call site, the kind of unwrap, and the
+/// custom message of `expect` if it is a constant. The call itself is kept as is:
+/// ```c
+/// self_var = self;
+/// assert(discriminant(self_var) != failing_variant, "unwrap on None");
+/// assume(discriminant(self_var) != failing_variant);
+/// ret = unwrap(self_var);
+/// ```
+struct Unwrap;
+
+impl Unwrap {
+    /// Return the name of the unwrapped type and of its failing variant, as well as the
+    /// discriminant of that variant, if `instance` is one of the methods handled by this hook.
+    fn failing_variant(tcx: TyCtxt, instance: Instance) -> Option<(&'static str, u128)> {
+        let def_id = rustc_internal::internal(tcx, instance.def.def_id());
+        // Closures and other anonymous items have no name.
+        if !matches!(tcx.opt_item_name(def_id)?.as_str(), "unwrap" | "expect") {
+            return None;
+        }
+        let impl_id = tcx.impl_of_method(def_id)?;
+        let adt = tcx.type_of(impl_id).instantiate_identity().ty_adt_def()?;
+        if tcx.is_diagnostic_item(rustc_span::sym::Option, adt.did()) {
+            Some(("None", 0))
+        } else if tcx.is_diagnostic_item(rustc_span::sym::Result, adt.did()) {
+            Some(("Err", 1))
+        } else {
+            None
+        }
+    }
+}
+
+impl GotocHook for Unwrap {
+    fn hook_applies(&self, tcx: TyCtxt, instance: Instance) -> bool {
+        Unwrap::failing_variant(tcx, instance).is_some()
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        instance: Instance,
+        mut fargs: Vec<Expr>,
+        assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        let (variant, failing_discr) = Unwrap::failing_variant(gcx.tcx, instance).unwrap();
+        let method = instance.def.name();
+        let method = method.rsplit("::").next().unwrap();
+        let msg = match fargs.get(1).and_then(|msg| gcx.extract_const_message(msg)) {
+            Some(custom) => format!("{method} on {variant}: {custom}"),
+            None => format!("{method} on {variant}"),
+        };
+
+        let loc = gcx.codegen_caller_span_stable(span);
+        let target = target.unwrap();
+        let self_ty = instance.fn_abi().unwrap().args[0].ty;
+        let self_expr = fargs.remove(0);
+        let (self_var, self_decl) =
+            gcx.decl_temp_variable(self_expr.typ().clone(), Some(self_expr), loc);
+        let discr = gcx.codegen_get_discriminant(self_var.clone(), self_ty, Ty::usize_ty());
+        let cond = discr.neq(Expr::int_constant(failing_discr, Type::size_t()));
+        let check = gcx.codegen_panic_check(cond, &msg, loc);
+
+        fargs.insert(0, self_var);
+        let call = gcx.codegen_func_expr(instance, loc).call(fargs);
+        let place_expr = unwrap_or_return_codegen_unimplemented_stmt!(
+            gcx,
+            gcx.codegen_place_stable(assign_to, loc)
+        )
+        .goto_expr;
+        Stmt::block(
+            vec![self_decl, check, place_expr.assign(call, loc), Stmt::goto(bb_label(target), loc)],
+            loc,
+        )
+    }
+}
+
+/// A hook for `kani::panic` scope functions (declared in `library/kani_core/src/panic.rs`).
+/// The function takes a boolean that indicates whether panics are permitted in the new scope,
+/// and returns whether they were permitted in the previous one.
+///
+/// This compiles `prev = set_panic_permitted(permitted)` to:
+/// ```c
+/// prev = KaniPanicPermitted;
+/// KaniPanicPermitted = permitted;
+/// ```
+struct SetPanicPermitted;
+impl GotocHook for SetPanicPermitted {
+    fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
+        unreachable!("{UNEXPECTED_CALL}")
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        _instance: Instance,
+        mut fargs: Vec<Expr>,
+        assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        assert_eq!(fargs.len(), 1);
+        let permitted = fargs.pop().unwrap().cast_to(Type::c_bool());
+        let target = target.unwrap();
+        let loc = gcx.codegen_caller_span_stable(span);
+        let flag = gcx.codegen_panic_permitted_var(loc);
+        let ret_place = unwrap_or_return_codegen_unimplemented_stmt!(
+            gcx,
+            gcx.codegen_place_stable(assign_to, loc)
+        );
+        let ret_type = ret_place.goto_expr.typ().clone();
+
+        Stmt::block(
+            vec![
+                ret_place.goto_expr.assign(flag.clone().cast_to(ret_type), loc),
+                flag.assign(permitted, loc),
+                Stmt::goto(bb_label(target), loc),
+            ],
+            loc,
+        )
+    }
+}
+
 /// Encodes __CPROVER_r_ok(ptr, size)
 struct IsAllocated;
 impl GotocHook for IsAllocated {
@@ -467,6 +630,56 @@ fn handle(
     }
 }
 
+/// Encodes whether offsetting a pointer by a number of bytes keeps it within its allocation.
+///
+/// The resulting pointer may point to 1-byte past the end of the allocation.
+struct OffsetInAllocation;
+impl GotocHook for OffsetInAllocation {
+    fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
+        unreachable!("{UNEXPECTED_CALL}")
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        _instance: Instance,
+        mut fargs: Vec<Expr>,
+        assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        assert_eq!(fargs.len(), 2);
+        let delta = fargs.pop().unwrap().cast_to(Type::ssize_t());
+        let ptr = fargs.pop().unwrap().cast_to(Type::void_pointer());
+        let target = target.unwrap();
+        let loc = gcx.codegen_caller_span_stable(span);
+        let ret_place = unwrap_or_return_codegen_unimplemented_stmt!(
+            gcx,
+            gcx.codegen_place_stable(assign_to, loc)
+        );
+        let ret_type = ret_place.goto_expr.typ().clone();
+
+        let offset = Expr::pointer_offset(ptr.clone());
+        let size = Expr::object_size(ptr).cast_to(Type::ssize_t());
+        let zero = Type::ssize_t().zero();
+        // For positive offsets, check against the remaining bytes in the object, and for
+        // negative ones, check that we don't go before its start. Both sides are bounded by the
+        // object size, so neither computation overflows.
+        let in_bounds = delta
+            .clone()
+            .ge(zero.clone())
+            .ternary(delta.clone().le(size.sub(offset.clone())), offset.plus(delta).ge(zero));
+
+        Stmt::block(
+            vec![
+                ret_place.goto_expr.assign(in_bounds.cast_to(ret_type), loc),
+                Stmt::goto(bb_label(target), loc),
+            ],
+            loc,
+        )
+    }
+}
+
 struct RustAlloc;
 // Removing this hook causes regression failures.
 // https://github.com/model-checking/kani/issues/1170
@@ -509,6 +722,41 @@ fn handle(
     }
 }
 
+/// A hook for `handle_alloc_error`, which the standard library calls when a memory allocation
+/// fails, e.g., in `Vec::push`.
+///
+/// Reaching it fails an allocation failure check, unless the user assumes that allocations never
+/// fail with `--assume-alloc-never-fails`, in which case the path is pruned.
+struct HandleAllocError;
+impl GotocHook for HandleAllocError {
+    fn hook_applies(&self, _tcx: TyCtxt, instance: Instance) -> bool {
+        instance.name() == "alloc::alloc::handle_alloc_error"
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        _instance: Instance,
+        _fargs: Vec<Expr>,
+        _assign_to: &Place,
+        _target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        let loc = gcx.codegen_caller_span_stable(span);
+        if gcx.queries.args().assume_alloc_never_fails {
+            debug!(?loc, "assume allocation never fails");
+            gcx.codegen_assume(Expr::bool_false(), loc)
+        } else {
+            gcx.codegen_assert_assume(
+                Expr::bool_false(),
+                PropertyClass::AllocationFailure,
+                "memory allocation failed",
+                loc,
+            )
+        }
+    }
+}
+
 /// This hook intercepts calls to `memcmp` and skips CBMC's pointer checks if the number of bytes to be compared is zero.
 /// See issue <https://github.com/model-checking/kani/issues/1489>
 ///
@@ -733,14 +981,17 @@ pub fn fn_hooks() -> GotocHooks {
     let kani_lib_hooks = [
         (KaniHook::Assert, Rc::new(Assert) as Rc<dyn GotocHook>),
         (KaniHook::Assume, Rc::new(Assume)),
+        (KaniHook::AssumeSatisfiable, Rc::new(AssumeSatisfiable)),
         (KaniHook::Panic, Rc::new(Panic)),
         (KaniHook::Check, Rc::new(Check)),
         (KaniHook::Cover, Rc::new(Cover)),
         (KaniHook::AnyRaw, Rc::new(Nondet)),
         (KaniHook::SafetyCheck, Rc::new(SafetyCheck)),
+        (KaniHook::SetPanicPermitted, Rc::new(SetPanicPermitted)),
         (KaniHook::IsAllocated, Rc::new(IsAllocated)),
         (KaniHook::PointerObject, Rc::new(PointerObject)),
         (KaniHook::PointerOffset, Rc::new(PointerOffset)),
+        (KaniHook::OffsetInAllocation, Rc::new(OffsetInAllocation)),
         (KaniHook::UnsupportedCheck, Rc::new(UnsupportedCheck)),
         (KaniHook::UntrackedDeref, Rc::new(UntrackedDeref)),
         (KaniHook::InitContracts, Rc::new(InitContracts)),
@@ -750,7 +1001,9 @@ pub fn fn_hooks() -> GotocHooks {
         kani_lib_hooks: HashMap::from(kani_lib_hooks),
         other_hooks: vec![
             Rc::new(Panic),
+            Rc::new(Unwrap),
             Rc::new(RustAlloc),
+            Rc::new(HandleAllocError),
             Rc::new(MemCmp),
             Rc::new(LoopInvariantRegister),
         ],
@@ -7,8 +7,13 @@
 use rustc_middle::ty::TyCtxt;
 use rustc_smir::rustc_internal;
 use stable_mir::ty::Span;
+use std::cell::RefCell;
 use tracing::debug;
 
+// Use a thread-local global variable to keep the errors reported during codegen, so that a harness
+// whose codegen fails with `--keep-going` can be reported with the error that caused it.
+thread_local!(static CODEGEN_ERRORS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) });
+
 // Should move into rvalue
 //make this a member function
 pub fn slice_fat_ptr(typ: Type, data: Expr, len: Expr, symbol_table: &SymbolTable) -> Expr {
@@ -194,5 +199,11 @@ fn assert_is_non_null_like(&self, t: &Type) {
 }
 
 pub fn span_err(tcx: TyCtxt, span: Span, msg: String) {
+    CODEGEN_ERRORS.with(|errors| errors.borrow_mut().push(msg.clone()));
     tcx.dcx().span_err(rustc_internal::internal(tcx, span), msg);
 }
+
+/// Remove and return the errors reported by `span_err` since the last call.
+pub fn take_codegen_errors() -> Vec<String> {
+    CODEGEN_ERRORS.with(|errors| errors.take())
+}
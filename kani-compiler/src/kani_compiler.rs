@@ -24,6 +24,7 @@
 use crate::kani_queries::QueryDb;
 use crate::session::init_session;
 use clap::Parser;
+use rustc_ast::{AttrStyle, Attribute, Crate};
 use rustc_codegen_ssa::traits::CodegenBackend;
 use rustc_driver::{Callbacks, Compilation, RunCompiler};
 use rustc_interface::Config;
@@ -82,12 +83,16 @@ struct KaniCompiler {
     /// compiler state is Init.
     /// Note that we need to share the queries with the backend before `config` is called.
     pub queries: Arc<Mutex<QueryDb>>,
+    /// Whether the crate root has an inner `kanitool` attribute, e.g., `#![kanitool::unwind(n)]`,
+    /// which requires the `custom_inner_attributes` feature. Since the features are fixed before
+    /// we can inspect the crate attributes, the compilation is restarted once this is found.
+    inner_kanitool_attrs: bool,
 }
 
 impl KaniCompiler {
     /// Create a new [KaniCompiler] instance.
     pub fn new() -> KaniCompiler {
-        KaniCompiler { queries: QueryDb::new() }
+        KaniCompiler { queries: QueryDb::new(), inner_kanitool_attrs: false }
     }
 
     /// Compile the current crate with the given arguments.
@@ -96,10 +101,14 @@ pub fn new() -> KaniCompiler {
     /// actually invoke the rust compiler multiple times.
     pub fn run(&mut self, args: Vec<String>) {
         debug!(?args, "run_compilation_session");
+        let restart = !self.inner_kanitool_attrs;
         let queries = self.queries.clone();
         let mut compiler = RunCompiler::new(&args, self);
         compiler.set_make_codegen_backend(Some(Box::new(move |_cfg| backend(queries))));
         compiler.run();
+        if restart && self.inner_kanitool_attrs {
+            self.run(args);
+        }
     }
 }
 
@@ -110,7 +119,14 @@ fn config(&mut self, config: &mut Config) {
         let mut args = vec!["kani-compiler".to_string()];
         args.extend(config.opts.cg.llvm_args.iter().cloned());
         let args = Arguments::parse_from(args);
-        init_session(&args, matches!(config.opts.error_format, ErrorOutputType::Json { .. }));
+        if self.inner_kanitool_attrs {
+            // The session was initialized by the run that found the inner `kanitool` attributes.
+            // The feature is only enabled for the crates that use them, so it isn't silently
+            // enabled for every crate.
+            config.opts.unstable_opts.crate_attr.push("feature(custom_inner_attributes)".into());
+        } else {
+            init_session(&args, matches!(config.opts.error_format, ErrorOutputType::Json { .. }));
+        }
 
         // Configure queries.
         let queries = &mut (*self.queries.lock().unwrap());
@@ -118,6 +134,25 @@ fn config(&mut self, config: &mut Config) {
         debug!(?queries, "config end");
     }
 
+    /// Restart the compilation if the crate root has inner `kanitool` attributes, but doesn't
+    /// enable the `custom_inner_attributes` feature needed to use them.
+    fn after_crate_root_parsing(
+        &mut self,
+        _compiler: &rustc_interface::interface::Compiler,
+        krate: &Crate,
+    ) -> Compilation {
+        let inner_attrs = || krate.attrs.iter().filter(|attr| attr.style == AttrStyle::Inner);
+        if !self.inner_kanitool_attrs
+            && inner_attrs().any(is_kanitool_attr)
+            && !inner_attrs().any(enables_custom_inner_attributes)
+        {
+            debug!("restart compilation with custom_inner_attributes");
+            self.inner_kanitool_attrs = true;
+            return Compilation::Stop;
+        }
+        Compilation::Continue
+    }
+
     /// After analysis, we check the crate items for Kani API misuse or configuration issues.
     fn after_analysis(
         &mut self,
@@ -131,3 +166,29 @@ fn after_analysis(
         Compilation::Continue
     }
 }
+
+/// Whether the given attribute is a `kanitool` attribute, or a `cfg_attr` that may expand to one,
+/// e.g., `#![cfg_attr(kani, kanitool::unwind(n))]`.
+fn is_kanitool_attr(attr: &Attribute) -> bool {
+    let is_kanitool = |path: &rustc_ast::Path| {
+        path.segments.first().is_some_and(|segment| segment.ident.as_str() == "kanitool")
+    };
+    if attr.has_name(rustc_span::sym::cfg_attr) {
+        attr.meta_item_list().is_some_and(|items| {
+            items
+                .iter()
+                .skip(1)
+                .any(|item| item.meta_item().is_some_and(|meta| is_kanitool(&meta.path)))
+        })
+    } else {
+        !attr.is_doc_comment() && is_kanitool(&attr.get_normal_item().path)
+    }
+}
+
+/// Whether the given attribute is `#![feature(..)]` with `custom_inner_attributes`.
+fn enables_custom_inner_attributes(attr: &Attribute) -> bool {
+    attr.has_name(rustc_span::sym::feature)
+        && attr.meta_item_list().is_some_and(|items| {
+            items.iter().any(|item| item.has_name(rustc_span::sym::custom_inner_attributes))
+        })
+}
@@ -2,13 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 //! This module contains code for processing Rust attributes (like `kani::proof`).
 
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
-use kani_metadata::{CbmcSolver, HarnessAttributes, HarnessKind, Stub};
+use kani_metadata::{CbmcSolver, ExpectedOutcome, HarnessAttributes, HarnessKind, Stub};
 use quote::ToTokens;
 use rustc_ast::{LitKind, MetaItem, MetaItemKind, attr};
 use rustc_errors::ErrorGuaranteed;
-use rustc_hir::{AttrArgs, AttrKind, Attribute, def::DefKind, def_id::DefId};
+use rustc_hir::{AttrArgs, AttrKind, Attribute, def::DefKind, def_id::CRATE_DEF_ID, def_id::DefId};
 use rustc_middle::ty::{Instance, TyCtxt, TyKind};
 use rustc_session::Session;
 use rustc_smir::rustc_internal;
@@ -29,9 +29,17 @@
 #[strum(serialize_all = "snake_case")]
 enum KaniAttributeKind {
     Proof,
+    /// Internal attribute added by `#[kani::proof]` to harnesses that are also `#[test]`
+    /// functions.
+    FromTest,
     ShouldPanic,
+    /// The verdict a harness is expected to have, added by `#[kani::proof(expected = "...")]`.
+    Expected,
     Solver,
     Stub,
+    /// Marks an anonymous constant at the crate root generated by `kani::register_default_stub!`,
+    /// which registers a stub for every harness in the crate.
+    StubByDefault,
     /// Attribute used to mark unstable APIs.
     Unstable,
     Unwind,
@@ -83,13 +91,16 @@ impl KaniAttributeKind {
     pub fn is_harness_only(self) -> bool {
         match self {
             KaniAttributeKind::Proof
+            | KaniAttributeKind::FromTest
             | KaniAttributeKind::ShouldPanic
+            | KaniAttributeKind::Expected
             | KaniAttributeKind::Solver
             | KaniAttributeKind::Stub
             | KaniAttributeKind::ProofForContract
             | KaniAttributeKind::StubVerified
             | KaniAttributeKind::Unwind => true,
             KaniAttributeKind::Unstable
+            | KaniAttributeKind::StubByDefault
             | KaniAttributeKind::FnMarker
             | KaniAttributeKind::Recursion
             | KaniAttributeKind::RecursionTracker
@@ -345,7 +356,7 @@ pub(super) fn check_attributes(&self) {
                 ));
             }
             match kind {
-                KaniAttributeKind::ShouldPanic => {
+                KaniAttributeKind::ShouldPanic | KaniAttributeKind::FromTest => {
                     expect_single(self.tcx, kind, &attrs);
                     attrs.iter().for_each(|attr| {
                         expect_no_args(self.tcx, kind, attr);
@@ -357,6 +368,22 @@ pub(super) fn check_attributes(&self) {
                         expect_no_args(self.tcx, kind, attr);
                     })
                 }
+                KaniAttributeKind::Expected => {
+                    let attr = expect_single(self.tcx, kind, &attrs);
+                    let expected = parse_expected(self.tcx, attr);
+                    if expected == Some(ExpectedOutcome::Fail)
+                        && self.map.contains_key(&KaniAttributeKind::ShouldPanic)
+                    {
+                        self.tcx
+                            .dcx()
+                            .struct_span_err(
+                                attr.span,
+                                "`expected = \"fail\"` cannot be combined with `should_panic`",
+                            )
+                            .with_help("a harness that should panic is expected to pass verification; remove one of the two attributes")
+                            .emit();
+                    }
+                }
                 KaniAttributeKind::Solver => {
                     expect_single(self.tcx, kind, &attrs);
                     attrs.iter().for_each(|attr| {
@@ -366,6 +393,21 @@ pub(super) fn check_attributes(&self) {
                 KaniAttributeKind::Stub => {
                     parse_stubs(self.tcx, self.item, attrs);
                 }
+                KaniAttributeKind::StubByDefault => {
+                    let at_crate_root = self.tcx.def_kind(self.item) == DefKind::Const
+                        && self
+                            .tcx
+                            .parent_module_from_def_id(self.item.expect_local())
+                            .is_top_level_module();
+                    if at_crate_root {
+                        parse_stubs(self.tcx, self.item, attrs);
+                    } else {
+                        local_error(
+                            "`kani::register_default_stub!` can only be used at the crate root"
+                                .to_string(),
+                        );
+                    }
+                }
                 KaniAttributeKind::Unwind => {
                     expect_single(self.tcx, kind, &attrs);
                     attrs.iter().for_each(|attr| {
@@ -496,6 +538,11 @@ fn is_proof_harness(&self) -> bool {
             || self.map.contains_key(&KaniAttributeKind::ProofForContract)
     }
 
+    /// Is this harness also a `#[test]` function?
+    pub fn is_from_test(&self) -> bool {
+        self.map.contains_key(&KaniAttributeKind::FromTest)
+    }
+
     /// Check that the function specified in the `proof_for_contract` attribute
     /// is reachable and emit an error if it isn't
     pub fn check_proof_for_contract(&self, reachable_functions: &HashSet<DefId>) {
@@ -529,6 +576,9 @@ pub fn harness_attributes(&self) -> HarnessAttributes {
         self.map.iter().fold(harness_attrs, |mut harness, (kind, attributes)| {
             match kind {
                 KaniAttributeKind::ShouldPanic => harness.should_panic = true,
+                KaniAttributeKind::Expected => {
+                    harness.expected = parse_expected(self.tcx, attributes[0]).unwrap_or_default();
+                }
                 KaniAttributeKind::Recursion => {
                     self.tcx.dcx().span_err(self.tcx.def_span(self.item), "The attribute `kani::recursion` should only be used in combination with function contracts.");
                 }
@@ -541,10 +591,10 @@ pub fn harness_attributes(&self) -> HarnessAttributes {
                 KaniAttributeKind::Unwind => {
                     harness.unwind_value = parse_unwind(self.tcx, attributes[0])
                 }
-                KaniAttributeKind::Proof => { /* no-op */ }
+                KaniAttributeKind::Proof | KaniAttributeKind::FromTest => { /* no-op */ }
                 KaniAttributeKind::ProofForContract => self.handle_proof_for_contract(&mut harness),
                 KaniAttributeKind::StubVerified => self.handle_stub_verified(&mut harness),
-                KaniAttributeKind::Unstable => {
+                KaniAttributeKind::Unstable | KaniAttributeKind::StubByDefault => {
                     // Internal attribute which shouldn't exist here.
                     unreachable!()
                 }
@@ -612,7 +662,8 @@ fn handle_stub_verified(&self, harness: &mut HarnessAttributes) {
                     .emit();
                 return;
             }
-            harness.verified_stubs.push(name.to_string())
+            harness.verified_stubs.push(name.to_string());
+            harness.verified_stub_paths.push(self.tcx.def_path_str(def_id));
         }
     }
 
@@ -790,6 +841,102 @@ fn try_from(attr: &'a Attribute) -> Result<Self, Self::Error> {
     }
 }
 
+/// Check the Kani attributes applied to the crate root, and the default stubs of the crate.
+///
+/// Only `#![kanitool::unwind(n)]` is supported, which sets the default unwind bound for the
+/// harnesses in the crate that don't have their own `#[kani::unwind]` attribute.
+pub(super) fn check_crate_attributes(tcx: TyCtxt) {
+    check_default_stubs(tcx);
+    let attributes = KaniAttributes::for_item(tcx, CRATE_DEF_ID.to_def_id());
+    for (&kind, attrs) in attributes.map.iter() {
+        if kind == KaniAttributeKind::Unwind {
+            if attrs.len() > 1 {
+                tcx.dcx().span_err(
+                    attrs[1].span,
+                    "only one `#![kanitool::unwind]` attribute is allowed per crate",
+                );
+            }
+            parse_unwind(tcx, attrs[0]);
+        } else {
+            tcx.dcx().span_err(
+                attrs[0].span,
+                format!("the `{}` attribute cannot be applied to a crate", kind.as_ref()),
+            );
+        }
+    }
+}
+
+/// Return the default unwind bound given by `#![kanitool::unwind(n)]` on the crate root, if any.
+pub fn crate_unwind_value(tcx: TyCtxt) -> Option<u32> {
+    let attributes = KaniAttributes::for_item(tcx, CRATE_DEF_ID.to_def_id());
+    let attrs = attributes.map.get(&KaniAttributeKind::Unwind)?;
+    parse_unwind(tcx, attrs[0])
+}
+
+/// Return the stubs registered with `kani::register_default_stub!` in the crate. Their paths are
+/// relative to the crate root.
+pub fn crate_default_stubs(tcx: TyCtxt) -> Vec<Stub> {
+    let mut stubs: Vec<Stub> = vec![];
+    for (stub, _) in default_stubs_with_span(tcx) {
+        if !stubs.contains(&stub) {
+            stubs.push(stub);
+        }
+    }
+    stubs
+}
+
+/// Return each stub registered with `kani::register_default_stub!`, together with the span of
+/// its registration.
+fn default_stubs_with_span(tcx: TyCtxt) -> Vec<(Stub, Span)> {
+    tcx.hir()
+        .items()
+        .flat_map(|item| {
+            let def_id = item.owner_id.def_id.to_def_id();
+            let attributes = KaniAttributes::for_item(tcx, def_id);
+            let attrs = attributes.map.get(&KaniAttributeKind::StubByDefault).cloned();
+            attrs.unwrap_or_default().into_iter().flat_map(move |attr| {
+                parse_stubs(tcx, def_id, &[attr]).into_iter().map(move |stub| (stub, attr.span))
+            })
+        })
+        .collect()
+}
+
+/// Report default stubs that replace the same function with different replacements, since Kani
+/// can't tell which one should be applied.
+fn check_default_stubs(tcx: TyCtxt) {
+    let mut registered: HashMap<DefId, (DefId, Span)> = HashMap::default();
+    for (stub, span) in default_stubs_with_span(tcx) {
+        let resolve = |path: &str| resolve_fn(tcx, CRATE_DEF_ID, path).ok();
+        let (Some(original), Some(replacement)) =
+            (resolve(&stub.original), resolve(&stub.replacement))
+        else {
+            // The resolution error was already reported.
+            continue;
+        };
+        match registered.get(&original) {
+            Some((previous, previous_span)) if *previous != replacement => {
+                tcx.dcx()
+                    .struct_span_err(
+                        span,
+                        format!(
+                            "conflicting default stubs for `{}`: `{}` and `{}`",
+                            tcx.def_path_str(original),
+                            tcx.def_path_str(*previous),
+                            tcx.def_path_str(replacement)
+                        ),
+                    )
+                    .with_span_note(*previous_span, "the first default stub is registered here")
+                    .with_help("remove one of them, and use `#[kani::stub]` in the harnesses that need the other one")
+                    .emit();
+            }
+            Some(_) => { /* Same stub registered twice. */ }
+            None => {
+                registered.insert(original, (replacement, span));
+            }
+        }
+    }
+}
+
 fn expect_no_args(tcx: TyCtxt, kind: KaniAttributeKind, attr: &Attribute) {
     if !attr.is_word() {
         tcx.dcx()
@@ -882,6 +1029,21 @@ fn parse_stubs(tcx: TyCtxt, harness: DefId, attributes: &[&Attribute]) -> Vec<St
         .collect()
 }
 
+/// Return the expected outcome from the given `#[kanitool::expected = "pass" | "fail"]` attribute.
+fn parse_expected(tcx: TyCtxt, attr: &Attribute) -> Option<ExpectedOutcome> {
+    match parse_str_value(attr).as_deref() {
+        Some("pass") => Some(ExpectedOutcome::Pass),
+        Some("fail") => Some(ExpectedOutcome::Fail),
+        _ => {
+            tcx.dcx().span_err(
+                attr.span,
+                "invalid value for the `expected` option of `#[kani::proof]`, expected `\"pass\"` or `\"fail\"`",
+            );
+            None
+        }
+    }
+}
+
 fn parse_solver(tcx: TyCtxt, attr: &Attribute) -> Option<CbmcSolver> {
     // TODO: Argument validation should be done as part of the `kani_macros` crate
     // <https://github.com/model-checking/kani/issues/2192>
@@ -8,20 +8,28 @@
 //! according to their stub configuration.
 
 use crate::args::ReachabilityType;
-use crate::kani_middle::attributes::is_proof_harness;
-use crate::kani_middle::metadata::{gen_contracts_metadata, gen_proof_metadata};
+use crate::kani_middle::attributes::{KaniAttributes, crate_default_stubs, is_proof_harness};
+use crate::kani_middle::kani_functions::{KaniFunction, KaniIntrinsic, KaniModel};
+use crate::kani_middle::metadata::{
+    gen_contract_harness_metadata, gen_contracts_metadata, gen_inactive_harnesses_metadata,
+    gen_proof_metadata,
+};
 use crate::kani_middle::reachability::filter_crate_items;
 use crate::kani_middle::resolve::expect_resolve_fn;
 use crate::kani_middle::stubbing::{check_compatibility, harness_stub_map};
 use crate::kani_queries::QueryDb;
-use kani_metadata::{ArtifactType, AssignsContract, HarnessKind, HarnessMetadata, KaniMetadata};
+use kani_metadata::{
+    ArtifactType, AssignsContract, HarnessKind, HarnessMetadata, KaniMetadata, Stub,
+    UnsupportedFeature,
+};
 use rustc_hir::def_id::DefId;
 use rustc_middle::ty::TyCtxt;
 use rustc_session::config::OutputType;
 use rustc_smir::rustc_internal;
 use stable_mir::CrateDef;
+use stable_mir::mir::TerminatorKind;
 use stable_mir::mir::mono::Instance;
-use stable_mir::ty::{FnDef, IndexedVal, RigidTy, TyKind};
+use stable_mir::ty::{FnDef, GenericArgKind, GenericArgs, IndexedVal, RigidTy, Ty, TyKind};
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs::File;
 use std::io::BufWriter;
@@ -61,13 +69,26 @@ pub fn new(queries: &QueryDb, tcx: TyCtxt) -> Self {
             let base_filepath = tcx.output_filenames(()).path(OutputType::Object);
             let base_filename = base_filepath.as_path();
             let harnesses = filter_crate_items(tcx, |_, instance| is_proof_harness(tcx, instance));
-            let all_harnesses = harnesses
+            let default_stubs = crate_default_stubs(tcx);
+            let mut all_harnesses = harnesses
                 .into_iter()
                 .map(|harness| {
-                    let metadata = gen_proof_metadata(tcx, harness, &base_filename);
+                    let metadata = gen_proof_metadata(tcx, harness, &base_filename, &default_stubs);
                     (harness, metadata)
                 })
                 .collect::<HashMap<_, _>>();
+            if let Some(function) = &queries.args().check_contract {
+                let contract_harnesses = gen_contract_harnesses(
+                    tcx,
+                    queries,
+                    function,
+                    &all_harnesses,
+                    base_filename,
+                    &default_stubs,
+                );
+                tcx.dcx().abort_if_errors();
+                all_harnesses.extend(contract_harnesses);
+            }
 
             // Even if no_stubs is empty we still need to store rustc metadata.
             let units = group_by_stubs(tcx, &all_harnesses);
@@ -98,6 +119,31 @@ pub fn store_loop_contracts(&mut self, harnesses: &[Harness]) {
         }
     }
 
+    /// We store the cycles of recursive functions that are reachable from each harness.
+    pub fn store_recursive_cycles(&mut self, harness_cycles: Vec<(Harness, Vec<Vec<String>>)>) {
+        for (harness, cycles) in harness_cycles {
+            self.harness_info.get_mut(&harness).unwrap().recursive_cycles = cycles;
+        }
+    }
+
+    /// We store the unsupported features that are reachable from each harness.
+    pub fn store_unsupported_features(
+        &mut self,
+        harness_features: Vec<(Harness, Vec<UnsupportedFeature>)>,
+    ) {
+        for (harness, features) in harness_features {
+            self.harness_info.get_mut(&harness).unwrap().unsupported_features = features;
+        }
+    }
+
+    /// We store the errors that stopped the code generation of some harnesses with
+    /// `--keep-going`.
+    pub fn store_codegen_errors(&mut self, harness_errors: Vec<(Harness, String)>) {
+        for (harness, error) in harness_errors {
+            self.harness_info.get_mut(&harness).unwrap().codegen_error = Some(error);
+        }
+    }
+
     /// Write compilation metadata into a file.
     pub fn write_metadata(&self, queries: &QueryDb, tcx: TyCtxt) {
         let metadata = self.generate_metadata(tcx);
@@ -113,12 +159,23 @@ pub fn harness_model_path(&self, harness: Harness) -> Option<&PathBuf> {
     fn generate_metadata(&self, tcx: TyCtxt) -> KaniMetadata {
         let (proof_harnesses, test_harnesses) =
             self.harness_info.values().cloned().partition(|md| md.attributes.is_proof_harness());
+        let mut contracted_functions = gen_contracts_metadata(tcx);
+        // The generated contract harnesses are named after the function they verify.
+        for (harness, metadata) in &self.harness_info {
+            if contract_harness_target(*harness).is_some() {
+                contracted_functions
+                    .iter_mut()
+                    .filter(|function| function.function == metadata.pretty_name)
+                    .for_each(|function| function.harnesses.push(metadata.pretty_name.clone()));
+            }
+        }
         KaniMetadata {
             crate_name: self.crate_info.name.clone(),
             proof_harnesses,
             unsupported_features: vec![],
             test_harnesses,
-            contracted_functions: gen_contracts_metadata(tcx),
+            contracted_functions,
+            inactive_harnesses: gen_inactive_harnesses_metadata(tcx),
         }
     }
 }
@@ -178,7 +235,9 @@ fn extract_contracts(
 ) -> BTreeSet<ContractUsage> {
     let def = harness.def;
     let mut result = BTreeSet::new();
-    if let HarnessKind::ProofForContract { target_fn } = &metadata.attributes.kind {
+    if let Some(target) = contract_harness_target(harness) {
+        result.insert(ContractUsage::Check(target.def_id().to_index()));
+    } else if let HarnessKind::ProofForContract { target_fn } = &metadata.attributes.kind {
         if let Ok(check_def) = expect_resolve_fn(tcx, def, target_fn, "proof_for_contract") {
             result.insert(ContractUsage::Check(check_def.def_id().to_index()));
         }
@@ -192,6 +251,109 @@ fn extract_contracts(
     result
 }
 
+/// Return the function verified by a harness that was generated for `--check-contract`, or `None`
+/// if `harness` is a harness written by the user.
+pub fn contract_harness_target(harness: Harness) -> Option<FnDef> {
+    if KaniFunction::try_from(harness) != Ok(KaniIntrinsic::ContractHarness.into()) {
+        return None;
+    }
+    let fn_ty = *harness.args().0[0].expect_ty();
+    let TyKind::RigidTy(RigidTy::FnDef(target, _)) = fn_ty.kind() else {
+        unreachable!("Expected a function type, but found: {:?}", harness.args())
+    };
+    Some(target)
+}
+
+/// Generate the harnesses for `--check-contract`: one for each function with a contract named
+/// `function` that has no `#[kani::proof_for_contract]` harness among the crate `harnesses`.
+///
+/// The generated harness calls the function with one `kani::any()` value per argument, so the
+/// function cannot be generic, and its arguments must implement `kani::Arbitrary`. Otherwise, we
+/// emit an error asking the user to write the harness.
+fn gen_contract_harnesses(
+    tcx: TyCtxt,
+    queries: &QueryDb,
+    function: &str,
+    harnesses: &HashMap<Harness, HarnessMetadata>,
+    base_filename: &Path,
+    default_stubs: &[Stub],
+) -> Vec<(Harness, HarnessMetadata)> {
+    let kani_fns = queries.kani_functions();
+    let harness_def = kani_fns[&KaniIntrinsic::ContractHarness.into()];
+    let any_def = kani_fns[&KaniModel::Any.into()];
+    let checked: HashSet<DefId> = harnesses
+        .keys()
+        .filter_map(|harness| {
+            let attributes = KaniAttributes::for_instance(tcx, *harness);
+            attributes.interpret_for_contract_attribute().map(|(_, def_id, _)| def_id)
+        })
+        .collect();
+    stable_mir::all_local_items()
+        .into_iter()
+        .filter(|item| {
+            matches_function_name(&item.name(), function)
+                && KaniAttributes::for_def_id(tcx, item.def_id()).has_contract()
+                && !checked.contains(&rustc_internal::internal(tcx, item.def_id()))
+        })
+        .filter_map(|item| {
+            let error = |reason: String| {
+                tcx.dcx()
+                    .struct_span_err(
+                        rustc_internal::internal(tcx, item.span()),
+                        format!(
+                            "cannot generate a harness to check the contract of `{}`",
+                            item.name()
+                        ),
+                    )
+                    .with_note(reason)
+                    .with_help(format!(
+                        "add a harness annotated with `#[kani::proof_for_contract({})]`",
+                        item.name()
+                    ))
+                    .emit();
+            };
+            let Ok(instance) = Instance::try_from(item) else {
+                error("the function is generic".to_string());
+                return None;
+            };
+            let fn_ty = instance.ty();
+            let TyKind::RigidTy(RigidTy::FnDef(target, _)) = fn_ty.kind() else {
+                unreachable!("Expected a function definition, but found: {fn_ty}")
+            };
+            let fn_abi = instance.fn_abi().unwrap();
+            let mut inputs = fn_abi.args.iter().map(|arg| arg.ty);
+            if let Some(ty) = inputs.find(|ty| !implements_arbitrary(*ty, any_def)) {
+                error(format!("the argument type `{ty}` doesn't implement `kani::Arbitrary`"));
+                return None;
+            }
+            let harness =
+                Instance::resolve(harness_def, &GenericArgs(vec![GenericArgKind::Type(fn_ty)]))
+                    .unwrap();
+            let metadata =
+                gen_contract_harness_metadata(tcx, harness, target, base_filename, default_stubs);
+            Some((harness, metadata))
+        })
+        .collect()
+}
+
+/// Check whether `name` is the fully qualified name `full_name` or a `::`-separated suffix of it.
+fn matches_function_name(full_name: &str, name: &str) -> bool {
+    full_name == name || full_name.strip_suffix(name).is_some_and(|prefix| prefix.ends_with("::"))
+}
+
+/// Check whether `ty` implements `kani::Arbitrary`, by resolving the calls in the body of
+/// `kani::any::<ty>()`.
+fn implements_arbitrary(ty: Ty, any_def: FnDef) -> bool {
+    let any = Instance::resolve(any_def, &GenericArgs(vec![GenericArgKind::Type(ty)])).unwrap();
+    let body = any.body().unwrap();
+    body.blocks.iter().all(|bb| {
+        let TerminatorKind::Call { func, .. } = &bb.terminator.kind else { return true };
+        let Ok(func_ty) = func.ty(body.locals()) else { return true };
+        let TyKind::RigidTy(RigidTy::FnDef(def, args)) = func_ty.kind() else { return true };
+        Instance::resolve(def, &args).is_ok()
+    })
+}
+
 /// Extract the filename for the metadata file.
 fn metadata_output_path(tcx: TyCtxt) -> PathBuf {
     let filepath = tcx.output_filenames(()).path(OutputType::Object);
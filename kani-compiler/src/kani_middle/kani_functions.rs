@@ -48,6 +48,8 @@ pub enum KaniIntrinsic {
     CheckedAlignOf,
     #[strum(serialize = "CheckedSizeOfIntrinsic")]
     CheckedSizeOf,
+    #[strum(serialize = "ContractHarnessIntrinsic")]
+    ContractHarness,
     #[strum(serialize = "IsInitializedIntrinsic")]
     IsInitialized,
     #[strum(serialize = "ValidValueIntrinsic")]
@@ -65,10 +67,26 @@ pub enum KaniModel {
     AlignOfVal,
     #[strum(serialize = "AnyModel")]
     Any,
+    #[strum(serialize = "BinarySearchModel")]
+    BinarySearch,
+    #[strum(serialize = "ConcreteAnyOrDefaultModel")]
+    ConcreteAnyOrDefault,
+    #[strum(serialize = "ConcreteValueModel")]
+    ConcreteValue,
     #[strum(serialize = "CopyInitStateModel")]
     CopyInitState,
     #[strum(serialize = "CopyInitStateSingleModel")]
     CopyInitStateSingle,
+    #[strum(serialize = "HashMapEntryModel")]
+    HashMapEntry,
+    #[strum(serialize = "HashMapExtendModel")]
+    HashMapExtend,
+    #[strum(serialize = "HashMapExtendCopiedModel")]
+    HashMapExtendCopied,
+    #[strum(serialize = "HashMapInsertModel")]
+    HashMapInsert,
+    #[strum(serialize = "HashMapMakeHashModel")]
+    HashMapMakeHash,
     #[strum(serialize = "LoadArgumentModel")]
     LoadArgument,
     #[strum(serialize = "InitializeMemoryInitializationStateModel")]
@@ -105,6 +123,12 @@ pub enum KaniModel {
     SizeOfSliceObject,
     #[strum(serialize = "SizeOfValRawModel")]
     SizeOfVal,
+    #[strum(serialize = "SortModel")]
+    Sort,
+    #[strum(serialize = "SortByModel")]
+    SortBy,
+    #[strum(serialize = "SortByKeyModel")]
+    SortByKey,
     #[strum(serialize = "StoreArgumentModel")]
     StoreArgument,
     #[strum(serialize = "WriteAnySliceModel")]
@@ -127,6 +151,8 @@ pub enum KaniHook {
     Assert,
     #[strum(serialize = "AssumeHook")]
     Assume,
+    #[strum(serialize = "AssumeSatisfiableHook")]
+    AssumeSatisfiable,
     #[strum(serialize = "CheckHook")]
     Check,
     #[strum(serialize = "CoverHook")]
@@ -138,6 +164,8 @@ pub enum KaniHook {
     InitContracts,
     #[strum(serialize = "IsAllocatedHook")]
     IsAllocated,
+    #[strum(serialize = "OffsetInAllocationHook")]
+    OffsetInAllocation,
     #[strum(serialize = "PanicHook")]
     Panic,
     #[strum(serialize = "PointerObjectHook")]
@@ -146,12 +174,30 @@ pub enum KaniHook {
     PointerOffset,
     #[strum(serialize = "SafetyCheckHook")]
     SafetyCheck,
+    #[strum(serialize = "SetPanicPermittedHook")]
+    SetPanicPermitted,
     #[strum(serialize = "UnsupportedCheckHook")]
     UnsupportedCheck,
     #[strum(serialize = "UntrackedDerefHook")]
     UntrackedDeref,
 }
 
+impl KaniFunction {
+    /// Whether this function depends on `std`, and hence it is not defined by `kani_core`.
+    fn is_std_only(&self) -> bool {
+        matches!(
+            self,
+            KaniFunction::Model(
+                KaniModel::HashMapEntry
+                    | KaniModel::HashMapExtend
+                    | KaniModel::HashMapExtendCopied
+                    | KaniModel::HashMapInsert
+                    | KaniModel::HashMapMakeHash
+            )
+        )
+    }
+}
+
 impl From<KaniIntrinsic> for KaniFunction {
     fn from(value: KaniIntrinsic) -> Self {
         KaniFunction::Intrinsic(value)
@@ -256,7 +302,7 @@ pub fn validate_kani_functions(kani_funcs: &HashMap<KaniFunction, FnDef>) {
     {
         if let Some(fn_def) = kani_funcs.get(&func) {
             assert_eq!(KaniFunction::try_from(*fn_def), Ok(func), "Unexpected function marker");
-        } else {
+        } else if !func.is_std_only() {
             tracing::error!(?func, "Missing kani function");
             missing += 1;
         }
@@ -3,19 +3,36 @@
 //! This module handles Kani metadata generation. For example, generating HarnessMetadata for a
 //! given function.
 
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
-use crate::kani_middle::attributes::{KaniAttributes, test_harness_name};
+use crate::kani_middle::attributes::{KaniAttributes, crate_unwind_value, test_harness_name};
+use crate::kani_middle::resolve::resolve_fn;
 use crate::kani_middle::{SourceLocation, stable_fn_def};
-use kani_metadata::ContractedFunction;
-use kani_metadata::{ArtifactType, HarnessAttributes, HarnessKind, HarnessMetadata};
+use kani_metadata::{ArtifactType, HarnessAttributes, HarnessKind, HarnessMetadata, Stub};
+use kani_metadata::{ContractedFunction, InactiveHarness};
+use proc_macro2::LineColumn;
+use rustc_ast::{MetaItem, MetaItemInner};
+use rustc_ast_pretty::pprust;
+use rustc_hir::def_id::{CRATE_DEF_ID, DefId as InternalDefId, LOCAL_CRATE};
 use rustc_middle::ty::TyCtxt;
+use rustc_smir::rustc_internal;
+use rustc_span::{FileName, Pos};
 use stable_mir::mir::mono::Instance;
+use stable_mir::ty::FnDef;
 use stable_mir::{CrateDef, CrateItems, DefId};
+use syn::punctuated::Punctuated;
 
 /// Create the harness metadata for a proof harness for a given function.
-pub fn gen_proof_metadata(tcx: TyCtxt, instance: Instance, base_name: &Path) -> HarnessMetadata {
+///
+/// The `default_stubs` are the stubs registered for the whole crate, as returned by
+/// `crate_default_stubs`, which are computed once for all the harnesses.
+pub fn gen_proof_metadata(
+    tcx: TyCtxt,
+    instance: Instance,
+    base_name: &Path,
+    default_stubs: &[Stub],
+) -> HarnessMetadata {
     let def = instance.def;
     let kani_attributes = KaniAttributes::for_instance(tcx, instance);
     let pretty_name = instance.name();
@@ -24,8 +41,7 @@ pub fn gen_proof_metadata(tcx: TyCtxt, instance: Instance, base_name: &Path) ->
     // We get the body span to include the entire function definition.
     // This is required for concrete playback to properly position the generated test.
     let loc = SourceLocation::new(instance.body().unwrap().span);
-    let file_stem = format!("{}_{mangled_name}", base_name.file_stem().unwrap().to_str().unwrap());
-    let model_file = base_name.with_file_name(file_stem).with_extension(ArtifactType::SymTabGoto);
+    let model_file = model_file(base_name, &mangled_name);
 
     HarnessMetadata {
         pretty_name,
@@ -34,12 +50,98 @@ pub fn gen_proof_metadata(tcx: TyCtxt, instance: Instance, base_name: &Path) ->
         original_file: loc.filename,
         original_start_line: loc.start_line,
         original_end_line: loc.end_line,
-        attributes: kani_attributes.harness_attributes(),
+        attributes: with_default_stubs(
+            tcx,
+            rustc_internal::internal(tcx, def.def_id()),
+            with_crate_unwind(tcx, kani_attributes.harness_attributes()),
+            default_stubs,
+        ),
         // TODO: This no longer needs to be an Option.
         goto_file: Some(model_file),
         contract: Default::default(),
         has_loop_contracts: false,
+        recursive_cycles: vec![],
+        unsupported_features: vec![],
+        codegen_error: None,
+    }
+}
+
+/// Create the metadata of the harness generated for `--check-contract` to verify the contract of
+/// `target`. The harness takes the name and the location of the target function.
+pub fn gen_contract_harness_metadata(
+    tcx: TyCtxt,
+    harness: Instance,
+    target: FnDef,
+    base_name: &Path,
+    default_stubs: &[Stub],
+) -> HarnessMetadata {
+    let pretty_name = target.name();
+    let mangled_name = harness.mangled_name();
+    let loc = SourceLocation::new(target.span());
+    let model_file = model_file(base_name, &mangled_name);
+    let attributes =
+        HarnessAttributes::new(HarnessKind::ProofForContract { target_fn: pretty_name.clone() });
+
+    HarnessMetadata {
+        pretty_name,
+        mangled_name,
+        crate_name: target.krate().name,
+        original_file: loc.filename,
+        original_start_line: loc.start_line,
+        original_end_line: loc.end_line,
+        attributes: with_default_stubs(
+            tcx,
+            rustc_internal::internal(tcx, target.def_id()),
+            with_crate_unwind(tcx, attributes),
+            default_stubs,
+        ),
+        goto_file: Some(model_file),
+        contract: Default::default(),
+        has_loop_contracts: false,
+        recursive_cycles: vec![],
+        unsupported_features: vec![],
+        codegen_error: None,
+    }
+}
+
+/// The path of the model of the harness with the given mangled name.
+fn model_file(base_name: &Path, mangled_name: &str) -> PathBuf {
+    let file_stem = format!("{}_{mangled_name}", base_name.file_stem().unwrap().to_str().unwrap());
+    base_name.with_file_name(file_stem).with_extension(ArtifactType::SymTabGoto)
+}
+
+/// Use the crate-level unwind bound for harnesses that don't have their own.
+fn with_crate_unwind(tcx: TyCtxt, mut attributes: HarnessAttributes) -> HarnessAttributes {
+    if attributes.unwind_value.is_none() {
+        attributes.unwind_value = crate_unwind_value(tcx);
+        attributes.unwind_from_crate = attributes.unwind_value.is_some();
     }
+    attributes
+}
+
+/// Apply the default stubs of the crate to a harness, except for the functions that the harness
+/// already stubs. Resolution errors were already reported when checking the attributes.
+fn with_default_stubs(
+    tcx: TyCtxt,
+    harness: InternalDefId,
+    mut attributes: HarnessAttributes,
+    default_stubs: &[Stub],
+) -> HarnessAttributes {
+    let module = tcx.parent_module_from_def_id(harness.expect_local()).to_local_def_id();
+    let stubbed: HashSet<_> = attributes
+        .stubs
+        .iter()
+        .filter_map(|stub| resolve_fn(tcx, module, &stub.original).ok())
+        .collect();
+    attributes.default_stubs = default_stubs
+        .iter()
+        .filter(|stub| {
+            resolve_fn(tcx, CRATE_DEF_ID, &stub.original)
+                .is_ok_and(|original| !stubbed.contains(&original))
+        })
+        .cloned()
+        .collect();
+    attributes
 }
 
 /// Collects contract and contract harness metadata.
@@ -51,6 +153,10 @@ pub fn gen_contracts_metadata(tcx: TyCtxt) -> Vec<ContractedFunction> {
     let crate_items: CrateItems = stable_mir::all_local_items();
 
     let mut fn_to_data: HashMap<DefId, ContractedFunction> = HashMap::new();
+    // The harnesses of each target, with the name of the target and the file of the first
+    // harness, for targets without contracts. These are collected separately since a harness may
+    // be visited before or after its target.
+    let mut harnesses: HashMap<DefId, (String, String, Vec<String>)> = HashMap::new();
 
     for item in crate_items {
         let function = item.name();
@@ -69,19 +175,157 @@ pub fn gen_contracts_metadata(tcx: TyCtxt) -> Vec<ContractedFunction> {
             let target_def_id = stable_fn_def(tcx, internal_def_id)
                 .expect("The target of a proof for contract should be a function definition")
                 .def_id();
-            if let Some(cf) = fn_to_data.get_mut(&target_def_id) {
-                cf.harnesses.push(function);
-            } else {
-                fn_to_data.insert(target_def_id, ContractedFunction {
-                    function: target_name.to_string(),
-                    file,
-                    harnesses: vec![function],
+            harnesses
+                .entry(target_def_id)
+                .or_insert_with(|| (target_name.to_string(), file, vec![]))
+                .2
+                .push(function);
+        }
+    }
+
+    for (target_def_id, (target_name, file, target_harnesses)) in harnesses {
+        fn_to_data
+            .entry(target_def_id)
+            .or_insert_with(|| ContractedFunction {
+                function: target_name,
+                file,
+                harnesses: vec![],
+            })
+            .harnesses
+            .extend(target_harnesses);
+    }
+
+    fn_to_data.into_values().collect()
+}
+
+/// Collects the proof harnesses that were removed from the crate by their `#[cfg(...)]` attribute.
+///
+/// The compiler only records the name and the `cfg` predicate of the items it removes, so we parse
+/// the source file of each item to find its attributes. Harnesses inside a module that was removed
+/// as a whole are not reported.
+pub fn gen_inactive_harnesses_metadata(tcx: TyCtxt) -> Vec<InactiveHarness> {
+    let source_map = tcx.sess.source_map();
+    let mut harnesses_per_file: HashMap<FileName, HashSet<LineColumn>> = HashMap::new();
+    tcx.stripped_cfg_items(LOCAL_CRATE)
+        .iter()
+        .filter_map(|item| {
+            let name_loc = source_map.lookup_char_pos(item.name.span.lo());
+            let harnesses =
+                harnesses_per_file.entry(name_loc.file.name.clone()).or_insert_with(|| {
+                    name_loc.file.src.as_ref().map(|src| harness_locations(src)).unwrap_or_default()
                 });
+            let name_pos = LineColumn { line: name_loc.line, column: name_loc.col.to_usize() };
+            if !harnesses.contains(&name_pos) {
+                return None;
             }
+            let parent = tcx.def_path_str(item.parent_module);
+            let pretty_name = if parent.is_empty() {
+                item.name.to_string()
+            } else {
+                format!("{parent}::{}", item.name)
+            };
+            let loc = SourceLocation::new(rustc_internal::stable(item.name.span));
+            Some(InactiveHarness {
+                pretty_name,
+                original_file: loc.filename,
+                original_line: loc.start_line,
+                cfg: cfg_predicate(&item.cfg),
+            })
+        })
+        .collect()
+}
+
+/// The predicate of a `cfg(...)` meta item, e.g., `windows` for `cfg(windows)`.
+fn cfg_predicate(cfg: &MetaItem) -> String {
+    match cfg.meta_item_list() {
+        Some([predicate]) => pprust::meta_list_item_to_string(predicate),
+        _ => pprust::meta_list_item_to_string(&MetaItemInner::MetaItem(cfg.clone())),
+    }
+}
+
+/// Parse the given source file and return the location of the name of every function that has a
+/// harness attribute, whether it is enabled or not. Return an empty set if the file cannot be
+/// parsed.
+fn harness_locations(src: &str) -> HashSet<LineColumn> {
+    let mut locations = HashSet::new();
+    if let Ok(file) = syn::parse_file(src) {
+        collect_harness_locations(&file.items, &mut locations);
+    }
+    locations
+}
+
+/// Collect the location of the name of the harnesses in the given items and in their inline
+/// modules.
+fn collect_harness_locations(items: &[syn::Item], locations: &mut HashSet<LineColumn>) {
+    // The names that refer to the harness attributes in this module, e.g., `proof` after
+    // `use kani::proof;`.
+    let mut imported = HashSet::new();
+    for item in items {
+        if let syn::Item::Use(item_use) = item {
+            collect_harness_imports(&item_use.tree, &[], &mut imported);
         }
     }
+    for item in items {
+        match item {
+            syn::Item::Fn(item_fn)
+                if item_fn.attrs.iter().any(|attr| is_harness_attr(&attr.meta, &imported)) =>
+            {
+                locations.insert(item_fn.sig.ident.span().start());
+            }
+            syn::Item::Mod(syn::ItemMod { content: Some((_, items)), .. }) => {
+                collect_harness_locations(items, locations)
+            }
+            _ => {}
+        }
+    }
+}
 
-    fn_to_data.into_values().collect()
+/// Collect the names that the given `use` tree binds to the harness attributes.
+fn collect_harness_imports(tree: &syn::UseTree, prefix: &[String], imported: &mut HashSet<String>) {
+    let path = |ident: &syn::Ident| [prefix, &[ident.to_string()]].concat();
+    match tree {
+        syn::UseTree::Path(use_path) => {
+            collect_harness_imports(&use_path.tree, &path(&use_path.ident), imported);
+        }
+        syn::UseTree::Name(use_name) if is_harness_path(&path(&use_name.ident)) => {
+            imported.insert(use_name.ident.to_string());
+        }
+        syn::UseTree::Rename(use_rename) if is_harness_path(&path(&use_rename.ident)) => {
+            imported.insert(use_rename.rename.to_string());
+        }
+        syn::UseTree::Group(group) => {
+            for tree in &group.items {
+                collect_harness_imports(tree, prefix, imported);
+            }
+        }
+        syn::UseTree::Name(_) | syn::UseTree::Rename(_) | syn::UseTree::Glob(_) => {}
+    }
+}
+
+/// Whether the given attribute marks an item as a proof harness, including with `cfg_attr`,
+/// e.g., `#[cfg_attr(kani, kani::proof)]`.
+fn is_harness_attr(meta: &syn::Meta, imported: &HashSet<String>) -> bool {
+    match meta {
+        syn::Meta::List(list) if list.path.is_ident("cfg_attr") => {
+            // The first argument is the predicate, and the others are the attributes.
+            let parser = Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated;
+            list.parse_args_with(parser)
+                .is_ok_and(|metas| metas.iter().skip(1).any(|meta| is_harness_attr(meta, imported)))
+        }
+        _ => match meta.path().get_ident() {
+            Some(ident) => imported.contains(&ident.to_string()),
+            None => {
+                let segments: Vec<_> =
+                    meta.path().segments.iter().map(|seg| seg.ident.to_string()).collect();
+                is_harness_path(&segments)
+            }
+        },
+    }
+}
+
+/// Whether the given path is the path of one of the harness attributes.
+fn is_harness_path(segments: &[String]) -> bool {
+    matches!(segments, [krate, attr] if krate == "kani" && (attr == "proof" || attr == "proof_for_contract"))
 }
 
 /// Create the harness metadata for a test description.
@@ -105,10 +349,13 @@ pub fn gen_test_metadata(
         original_file: loc.filename,
         original_start_line: loc.start_line,
         original_end_line: loc.end_line,
-        attributes: HarnessAttributes::new(HarnessKind::Test),
+        attributes: with_crate_unwind(tcx, HarnessAttributes::new(HarnessKind::Test)),
         // TODO: This no longer needs to be an Option.
         goto_file: Some(model_file),
         contract: Default::default(),
         has_loop_contracts: false,
+        recursive_cycles: vec![],
+        unsupported_features: vec![],
+        codegen_error: None,
     }
 }
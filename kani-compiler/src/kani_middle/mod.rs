@@ -9,13 +9,16 @@
 use rustc_hir::{def::DefKind, def_id::DefId as InternalDefId, def_id::LOCAL_CRATE};
 use rustc_middle::ty::TyCtxt;
 use rustc_smir::rustc_internal;
+use rustc_span::Symbol;
+use rustc_span::hygiene::{ExpnKind, MacroKind};
 use stable_mir::CrateDef;
-use stable_mir::mir::mono::MonoItem;
+use stable_mir::mir::mono::{Instance, MonoItem};
 use stable_mir::ty::{FnDef, RigidTy, Span as SpanStable, Ty, TyKind};
 use stable_mir::visitor::{Visitable, Visitor as TyVisitor};
 use std::ops::ControlFlow;
 
 use self::attributes::KaniAttributes;
+use self::kani_functions::{KaniFunction, KaniHook};
 
 pub mod abi;
 pub mod analysis;
@@ -55,6 +58,7 @@ pub fn check_crate_items(tcx: TyCtxt, ignore_asm: bool) {
             }
         }
     }
+    attributes::check_crate_attributes(tcx);
     tcx.dcx().abort_if_errors();
 }
 
@@ -121,6 +125,85 @@ pub fn check_reachable_items(tcx: TyCtxt, queries: &QueryDb, items: &[MonoItem])
     tcx.dcx().abort_if_errors();
 }
 
+/// Warn if `harness` is also a `#[test]` function, but its verification uses non-deterministic
+/// values, e.g., from `kani::any()`. Such a harness doesn't verify the behavior of the test.
+pub fn check_test_harness_determinism(tcx: TyCtxt, harness: Instance, items: &[MonoItem]) {
+    let def_id = rustc_internal::internal(tcx, harness.def.def_id());
+    if !KaniAttributes::for_item(tcx, def_id).is_from_test() {
+        return;
+    }
+    let is_nondet = |item: &MonoItem| {
+        matches!(item, MonoItem::Fn(instance)
+            if KaniFunction::try_from(*instance) == Ok(KaniFunction::Hook(KaniHook::AnyRaw)))
+    };
+    if items.iter().any(is_nondet) {
+        tcx.dcx()
+            .struct_span_warn(
+                tcx.def_span(def_id),
+                format!(
+                    "harness `{}` is a `#[test]`, but it uses non-deterministic values",
+                    tcx.def_path_str(def_id)
+                ),
+            )
+            .with_note(
+                "the test may behave differently when it runs with `cargo test`, since \
+                Kani checks every possible value, e.g., of `kani::any()`",
+            )
+            .emit();
+    }
+}
+
+/// Emit a note for each `assert!` and `debug_assert!` from the standard library that is reachable
+/// from a harness in the local crate. These are checked as panics, so their description is
+/// derived from the macro arguments, while `kani::assert` lets the user name the property.
+///
+/// Each call site is only reported once, even if several harnesses reach it, so `reported`
+/// keeps the call sites that were already reported. The note doesn't affect verification, and it
+/// can be turned off with `--no-std-assert-notes`.
+pub fn check_std_asserts(
+    tcx: TyCtxt,
+    items: &[MonoItem],
+    reported: &mut HashSet<rustc_span::Span>,
+) {
+    for item in items {
+        let MonoItem::Fn(instance) = item else { continue };
+        let def_id = rustc_internal::internal(tcx, instance.def.def_id());
+        if !def_id.is_local() {
+            continue;
+        }
+        let Some(body) = instance.body() else { continue };
+        for bb in &body.blocks {
+            let span = rustc_internal::internal(tcx, bb.terminator.span);
+            let Some((name, call_site)) = std_assert_call_site(tcx, span) else { continue };
+            if reported.insert(call_site) {
+                tcx.dcx()
+                    .struct_span_note(
+                        call_site,
+                        format!("`{name}!` from the standard library is checked as a panic"),
+                    )
+                    .with_help("use `kani::assert` instead if you want to name the property")
+                    .emit();
+            }
+        }
+    }
+}
+
+/// The name and the call site of the outermost `assert!` or `debug_assert!` from the standard
+/// library that `span` was expanded from, if any.
+fn std_assert_call_site(tcx: TyCtxt, span: rustc_span::Span) -> Option<(Symbol, rustc_span::Span)> {
+    span.macro_backtrace()
+        .filter_map(|expn| {
+            // The name of the expansion is the path used by the call, e.g., `core::assert`.
+            let def_id = expn.macro_def_id?;
+            let name = tcx.item_name(def_id);
+            (matches!(expn.kind, ExpnKind::Macro(MacroKind::Bang, _))
+                && matches!(name.as_str(), "assert" | "debug_assert")
+                && matches!(tcx.crate_name(def_id.krate).as_str(), "std" | "core"))
+            .then_some((name, expn.call_site))
+        })
+        .last()
+}
+
 /// Structure that represents the source location of a definition.
 /// TODO: Use `InternedString` once we move it out of the cprover_bindings.
 /// <https://github.com/model-checking/kani/issues/2435>
@@ -577,6 +577,116 @@ fn add_edges(&mut self, from: MonoItem, to: &[CollectedItem]) {
         }
     }
 
+    /// Return the cycles of recursive functions in this graph.
+    ///
+    /// We only consider direct calls, and we return one cycle per set of mutually recursive
+    /// functions. Each cycle lists the functions in the order they call each other, starting from
+    /// the function with the smallest name.
+    pub fn recursive_cycles(&self) -> Vec<Vec<Instance>> {
+        let successors = |node: &Node| -> Vec<Node> {
+            self.edges[node]
+                .iter()
+                .filter(|succ| succ.0.reason == CollectionReason::DirectCall)
+                .map(|succ| Node::from(succ.clone()))
+                .collect()
+        };
+        let predecessors = |node: &Node| -> Vec<Node> {
+            self.back_edges[node]
+                .iter()
+                .filter(|pred| pred.0.reason == CollectionReason::DirectCall)
+                .map(|pred| Node::from(pred.clone()))
+                .collect()
+        };
+
+        // Compute the strongly connected components using Kosaraju's algorithm.
+        // First, compute the post-order of the nodes.
+        let mut visited: HashSet<&Node> = HashSet::default();
+        let mut post_order: Vec<Node> = vec![];
+        for start in &self.nodes {
+            if !visited.insert(start) {
+                continue;
+            }
+            let mut stack = vec![(start.clone(), successors(start))];
+            while let Some((node, succs)) = stack.last_mut() {
+                if let Some(next) = succs.pop() {
+                    if let Some(next) = self.nodes.get(&next) {
+                        if visited.insert(next) {
+                            stack.push((next.clone(), successors(next)));
+                        }
+                    }
+                } else {
+                    post_order.push(node.clone());
+                    stack.pop();
+                }
+            }
+        }
+
+        // Then, collect the components by traversing the back edges in reverse post-order.
+        let mut assigned: HashSet<Node> = HashSet::default();
+        let mut cycles = vec![];
+        for root in post_order.into_iter().rev() {
+            if assigned.contains(&root) {
+                continue;
+            }
+            assigned.insert(root.clone());
+            let mut component: HashSet<Node> = HashSet::from([root.clone()]);
+            let mut queue = vec![root];
+            while let Some(node) = queue.pop() {
+                for pred in predecessors(&node) {
+                    if assigned.insert(pred.clone()) {
+                        component.insert(pred.clone());
+                        queue.push(pred);
+                    }
+                }
+            }
+            if let Some(cycle) = self.cycle_in(&component, successors) {
+                cycles.push(cycle);
+            }
+        }
+        cycles.sort_by_cached_key(|cycle| cycle.iter().map(Instance::name).collect::<Vec<_>>());
+        cycles
+    }
+
+    /// Find the shortest cycle that starts with the function with the smallest name in the given
+    /// strongly connected component. Return `None` if the component is not recursive.
+    ///
+    /// Note that the cycle may not include every function in the component, since there may not be
+    /// a simple cycle that covers the entire component.
+    fn cycle_in<F>(&self, component: &HashSet<Node>, successors: F) -> Option<Vec<Instance>>
+    where
+        F: Fn(&Node) -> Vec<Node>,
+    {
+        let start = component.iter().min_by_key(|node| node.to_string())?;
+        let MonoItem::Fn(_) = start.0 else { return None };
+        // Breadth-first search for the shortest path back to `start`.
+        let mut parents: HashMap<Node, Node> = HashMap::default();
+        let mut queue = std::collections::VecDeque::from([start.clone()]);
+        while let Some(node) = queue.pop_front() {
+            for succ in successors(&node) {
+                if succ == *start {
+                    let mut path = vec![node.clone()];
+                    while let Some(parent) = parents.get(path.last().unwrap()) {
+                        path.push(parent.clone());
+                    }
+                    path.reverse();
+                    return Some(
+                        path.into_iter()
+                            .filter_map(|node| match node.0 {
+                                MonoItem::Fn(instance) => Some(instance),
+                                _ => None,
+                            })
+                            .collect(),
+                    );
+                }
+                if component.contains(&succ) && !parents.contains_key(&succ) && succ != *start {
+                    parents.insert(succ.clone(), node.clone());
+                    queue.push_back(succ);
+                }
+            }
+        }
+        None
+    }
+
     /// Print the graph in DOT format to a file.
     /// See <https://graphviz.org/doc/info/lang.html> for more information.
     fn dump_dot(&self, tcx: TyCtxt, initial: Option<MonoItem>) -> std::io::Result<()> {
@@ -441,6 +441,12 @@ fn resolve_relative(tcx: TyCtxt, current_module: LocalModDefId, name: &str) -> R
                 ),
                 _ => Some(item.owner_id.def_id.to_def_id()),
             }
+        } else if let ItemKind::ForeignMod { items, .. } = item.kind {
+            // The items of an `extern` block are declared in the enclosing module.
+            items
+                .iter()
+                .find(|foreign_item| foreign_item.ident.as_str() == name)
+                .map(|foreign_item| foreign_item.id.owner_id.def_id.to_def_id())
         } else {
             if let ItemKind::Use(use_path, UseKind::Glob) = item.kind {
                 // Do not immediately try to resolve the path using this glob,
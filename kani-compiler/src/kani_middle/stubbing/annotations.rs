@@ -11,13 +11,17 @@
 use crate::kani_middle::resolve::resolve_fn;
 
 /// Given a `kani::stub` attribute, tries to extract a pair of paths (the
-/// original function/method, and its stub). Returns `None` and errors if the
-/// attribute's arguments are not two paths.
-fn stub_def_ids(tcx: TyCtxt, harness: LocalDefId, stub: &Stub) -> Option<(DefId, DefId)> {
+/// original function/method, and its stub), resolved from `module`. Returns `None`
+/// and errors if the attribute's arguments are not two paths.
+fn stub_def_ids(
+    tcx: TyCtxt,
+    harness: LocalDefId,
+    module: LocalDefId,
+    stub: &Stub,
+) -> Option<(DefId, DefId)> {
     // Resolve the attribute arguments to `DefId`s
-    let current_module = tcx.parent_module_from_def_id(harness);
     let resolve = |name: &str| -> Option<DefId> {
-        let maybe_resolved = resolve_fn(tcx, current_module.to_local_def_id(), name);
+        let maybe_resolved = resolve_fn(tcx, module, name);
         match maybe_resolved {
             Ok(def_id) => {
                 tracing::debug!(?def_id, "Resolved {name} to {}", tcx.def_path_str(def_id));
@@ -36,14 +40,16 @@ fn stub_def_ids(tcx: TyCtxt, harness: LocalDefId, stub: &Stub) -> Option<(DefId,
 }
 
 /// Updates the running map `stub_pairs` that maps a function/method to its
-/// stub. Errors if a function/method is mapped more than once.
+/// stub, where the paths of `stub` are relative to `module`. Errors if a
+/// function/method is mapped more than once.
 pub fn update_stub_mapping(
     tcx: TyCtxt,
     harness: LocalDefId,
+    module: LocalDefId,
     stub: &Stub,
     stub_pairs: &mut HashMap<DefId, DefId>,
 ) {
-    if let Some((orig_id, stub_id)) = stub_def_ids(tcx, harness, stub) {
+    if let Some((orig_id, stub_id)) = stub_def_ids(tcx, harness, module, stub) {
         let other_opt = stub_pairs.insert(orig_id, stub_id);
         if let Some(other) = other_opt {
             if other != stub_id {
@@ -10,7 +10,7 @@
 use tracing::{debug, trace};
 
 use kani_metadata::HarnessMetadata;
-use rustc_hir::def_id::DefId;
+use rustc_hir::def_id::{CRATE_DEF_ID, DefId};
 use rustc_middle::mir::Const;
 use rustc_middle::ty::{self, EarlyBinder, TyCtxt, TypeFoldable, TypingEnv};
 use rustc_smir::rustc_internal;
@@ -21,18 +21,25 @@
 use stable_mir::{CrateDef, CrateItem};
 
 use self::annotations::update_stub_mapping;
+use crate::kani_middle::codegen_units::contract_harness_target;
 
-/// Collects the stubs from the harnesses in a crate.
+/// Collects the stubs of a harness, including the default stubs of the crate that apply to it.
 pub fn harness_stub_map(
     tcx: TyCtxt,
     harness: Instance,
     metadata: &HarnessMetadata,
 ) -> HashMap<DefId, DefId> {
-    let def_id = rustc_internal::internal(tcx, harness.def.def_id());
+    // Harnesses generated for `--check-contract` resolve stubs from the function they verify.
+    let harness_def = contract_harness_target(harness).map_or(harness.def.def_id(), |f| f.def_id());
+    let def_id = rustc_internal::internal(tcx, harness_def).expect_local();
+    let module = tcx.parent_module_from_def_id(def_id).to_local_def_id();
     let attrs = &metadata.attributes;
     let mut stub_pairs = HashMap::default();
     for stubs in &attrs.stubs {
-        update_stub_mapping(tcx, def_id.expect_local(), stubs, &mut stub_pairs);
+        update_stub_mapping(tcx, def_id, module, stubs, &mut stub_pairs);
+    }
+    for stubs in &attrs.default_stubs {
+        update_stub_mapping(tcx, def_id, CRATE_DEF_ID, stubs, &mut stub_pairs);
     }
     stub_pairs
 }
@@ -42,11 +49,9 @@ pub fn harness_stub_map(
 /// does **NOT** check whether the type variables are constrained to implement
 /// the same traits; trait mismatches are checked during monomorphization.
 pub fn check_compatibility(tcx: TyCtxt, old_def: FnDef, new_def: FnDef) -> Result<(), String> {
-    // TODO: Validate stubs that do not have body.
-    // We could potentially look at the function signature to see if they match.
-    // However, they will include region information which can make types different.
-    let Some(old_body) = old_def.body() else { return Ok(()) };
-    let Some(new_body) = new_def.body() else { return Ok(()) };
+    let (Some(old_body), Some(new_body)) = (old_def.body(), new_def.body()) else {
+        return check_signature_compatibility(tcx, old_def, new_def);
+    };
     // Check whether the arities match.
     if old_body.arg_locals().len() != new_body.arg_locals().len() {
         let msg = format!(
@@ -106,6 +111,75 @@ pub fn check_compatibility(tcx: TyCtxt, old_def: FnDef, new_def: FnDef) -> Resul
             ));
         }
     }
+    mismatch_result(old_def, new_def, diff)
+}
+
+/// Checks whether the signature of a stub matches the one of the original function when one of
+/// them doesn't have a body, e.g., a foreign function declared in an `extern` block.
+///
+/// Besides the types, the ABIs must match, since the stub may be used as a function pointer of
+/// the original type. Variadic functions are rejected, since the stub cannot receive the extra
+/// arguments.
+fn check_signature_compatibility(
+    tcx: TyCtxt,
+    old_def: FnDef,
+    new_def: FnDef,
+) -> Result<(), String> {
+    let fn_sig = |def: FnDef| {
+        let def_id = rustc_internal::internal(tcx, def.def_id());
+        let sig =
+            tcx.instantiate_bound_regions_with_erased(tcx.fn_sig(def_id).instantiate_identity());
+        tcx.erase_regions(sig)
+    };
+    let old_sig = fn_sig(old_def);
+    let new_sig = fn_sig(new_def);
+    let variadic =
+        [(old_def, old_sig), (new_def, new_sig)].into_iter().find(|(_, sig)| sig.c_variadic);
+    if let Some((variadic, _)) = variadic {
+        return Err(format!(
+            "Cannot stub `{}` by `{}`. Stubbing variadic function `{}` is not supported.",
+            old_def.name(),
+            new_def.name(),
+            variadic.name(),
+        ));
+    }
+    if old_sig.inputs().len() != new_sig.inputs().len() {
+        return Err(format!(
+            "arity mismatch: original function/method `{}` takes {} argument(s), stub `{}` takes {}",
+            old_def.name(),
+            old_sig.inputs().len(),
+            new_def.name(),
+            new_sig.inputs().len(),
+        ));
+    }
+    let mut diff = vec![];
+    if old_sig.abi != new_sig.abi {
+        diff.push(format!(
+            "Expected ABI `{}`, but found `{}`",
+            old_sig.abi.name(),
+            new_sig.abi.name()
+        ));
+    }
+    if old_sig.output() != new_sig.output() {
+        diff.push(format!(
+            "Expected return type `{}`, but found `{}`",
+            old_sig.output(),
+            new_sig.output()
+        ));
+    }
+    for (i, (old_arg, new_arg)) in old_sig.inputs().iter().zip(new_sig.inputs()).enumerate() {
+        if old_arg != new_arg {
+            diff.push(format!(
+                "Expected type `{old_arg}` for parameter {}, but found `{new_arg}`",
+                i + 1
+            ));
+        }
+    }
+    mismatch_result(old_def, new_def, diff)
+}
+
+/// Build the result of a compatibility check from the list of mismatches found.
+fn mismatch_result(old_def: FnDef, new_def: FnDef, diff: Vec<String>) -> Result<(), String> {
     if !diff.is_empty() {
         Err(format!(
             "Cannot stub `{}` by `{}`.\n - {}",
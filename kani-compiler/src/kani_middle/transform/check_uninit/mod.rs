@@ -20,7 +20,7 @@
 use crate::kani_middle::kani_functions::{KaniFunction, KaniModel};
 pub use delayed_ub::DelayedUbPass;
 pub use ptr_uninit::UninitPass;
-pub use ty_layout::{PointeeInfo, PointeeLayout};
+pub use ty_layout::{PointeeInfo, PointeeLayout, transmute_reads_padding};
 
 mod delayed_ub;
 mod ptr_uninit;
@@ -405,6 +405,28 @@ fn data_bytes_for_ty(
     }
 }
 
+/// Returns true if some data byte of `to_ty` overlaps with a padding byte of `from_ty`, i.e., if
+/// transmuting a value of `from_ty` into `to_ty` reads uninitialized memory.
+///
+/// This is conservative and returns false if the layout of either type cannot be computed.
+pub fn transmute_reads_padding(from_ty: &Ty, to_ty: &Ty) -> bool {
+    let (Ok(from_ty_info), Ok(to_ty_info)) =
+        (PointeeInfo::from_ty(*from_ty), PointeeInfo::from_ty(*to_ty))
+    else {
+        return false;
+    };
+    match (from_ty_info.layout(), to_ty_info.layout()) {
+        (
+            PointeeLayout::Sized { layout: from_layout },
+            PointeeLayout::Sized { layout: to_layout },
+        ) => from_layout
+            .iter()
+            .zip(to_layout.iter())
+            .any(|(from_byte, to_byte)| !from_byte && *to_byte),
+        _ => false,
+    }
+}
+
 /// Returns true if `to_ty` has a smaller or equal size and padding bytes in `from_ty` are padding
 /// bytes in `to_ty`.
 pub fn tys_layout_compatible_to_size(from_ty: &Ty, to_ty: &Ty) -> bool {
@@ -165,6 +165,9 @@ pub struct ValidValueReq {
     size: MachineSize,
     /// The range restriction is represented by a Scalar.
     valid_range: WrappingRange,
+    /// The values of the range that are valid, if only some of them are, e.g., the discriminants
+    /// of an enumeration with explicit discriminants such as `enum Level { Low = 1, High = 5 }`.
+    valid_values: Option<Vec<u128>>,
 }
 
 // TODO: Optimize checks by merging requirements whenever possible.
@@ -184,7 +187,12 @@ pub fn try_from_ty(machine_info: &MachineInfo, ty: Ty) -> Option<ValidValueReq>
         match shape.abi {
             ValueAbi::Scalar(Scalar::Initialized { value, valid_range })
             | ValueAbi::ScalarPair(Scalar::Initialized { value, valid_range }, _) => {
-                Some(ValidValueReq { offset: 0, size: value.size(machine_info), valid_range })
+                Some(ValidValueReq {
+                    offset: 0,
+                    size: value.size(machine_info),
+                    valid_range,
+                    valid_values: None,
+                })
             }
             ValueAbi::Scalar(_)
             | ValueAbi::ScalarPair(_, _)
@@ -196,7 +204,7 @@ pub fn try_from_ty(machine_info: &MachineInfo, ty: Ty) -> Option<ValidValueReq>
 
     /// Check if range is full.
     pub fn is_full(&self) -> bool {
-        self.valid_range.is_full(self.size).unwrap()
+        self.valid_values.is_none() && self.valid_range.is_full(self.size).unwrap()
     }
 
     /// Check if this range contains `other` range.
@@ -204,6 +212,10 @@ pub fn is_full(&self) -> bool {
     /// I.e., `scalar_2` ⊆ `scalar_1`
     pub fn contains(&self, other: &ValidValueReq) -> bool {
         assert_eq!(self.size, other.size);
+        if self.valid_values.is_some() {
+            // Be conservative, since this is only used to avoid redundant checks.
+            return false;
+        }
         match (self.valid_range.wraps_around(), other.valid_range.wraps_around()) {
             (true, true) | (false, false) => {
                 self.valid_range.start <= other.valid_range.start
@@ -399,7 +411,8 @@ fn visit_terminator(&mut self, term: &Terminator, location: Location) {
                             else {
                                 unreachable!()
                             };
-                            let validity = ty_validity_per_offset(&self.machine, target_ty, 0);
+                            let validity =
+                                ty_validity_per_offset(self.tcx, &self.machine, target_ty, 0);
                             match validity {
                                 Ok(ranges) if ranges.is_empty() => {}
                                 Ok(ranges) => {
@@ -446,7 +459,8 @@ fn visit_place(&mut self, place: &Place, ptx: PlaceContext, location: Location)
                     let ptr_ty = place_ref.ty(self.locals).unwrap();
                     if ptr_ty.kind().is_raw_ptr() {
                         let target_ty = elem.ty(ptr_ty).unwrap();
-                        let validity = ty_validity_per_offset(&self.machine, target_ty, 0);
+                        let validity =
+                            ty_validity_per_offset(self.tcx, &self.machine, target_ty, 0);
                         match validity {
                             Ok(ranges) if !ranges.is_empty() => {
                                 self.push_target(SourceOp::DerefValidity {
@@ -473,7 +487,8 @@ fn visit_place(&mut self, place: &Place, ptx: PlaceContext, location: Location)
                     if target_ty.kind().is_union()
                         && (!ptx.is_mutating() || place.projection.len() > idx + 1)
                     {
-                        let validity = ty_validity_per_offset(&self.machine, *target_ty, 0);
+                        let validity =
+                            ty_validity_per_offset(self.tcx, &self.machine, *target_ty, 0);
                         match validity {
                             Ok(ranges) if !ranges.is_empty() => {
                                 self.push_target(SourceOp::BytesValidity {
@@ -539,11 +554,11 @@ fn visit_rvalue(&mut self, rvalue: &Rvalue, location: Location) {
                     }
 
                     if let Ok(src_validity) =
-                        ty_validity_per_offset(&self.machine, src_pointee_ty, 0)
+                        ty_validity_per_offset(self.tcx, &self.machine, src_pointee_ty, 0)
                     {
                         if !src_validity.is_empty() {
                             if let Ok(dest_validity) =
-                                ty_validity_per_offset(&self.machine, dest_pointee_ty, 0)
+                                ty_validity_per_offset(self.tcx, &self.machine, dest_pointee_ty, 0)
                             {
                                 if dest_validity != src_validity {
                                     self.push_target(SourceOp::UnsupportedCheck {
@@ -570,7 +585,9 @@ fn visit_rvalue(&mut self, rvalue: &Rvalue, location: Location) {
                     // For transmute, we care about the destination type only.
                     // This could be optimized to only add a check if the requirements of the
                     // destination type are stricter than the source.
-                    if let Ok(dest_validity) = ty_validity_per_offset(&self.machine, *dest_ty, 0) {
+                    if let Ok(dest_validity) =
+                        ty_validity_per_offset(self.tcx, &self.machine, *dest_ty, 0)
+                    {
                         trace!(?dest_validity, "transmute");
                         if !dest_validity.is_empty() {
                             self.push_target(SourceOp::BytesValidity {
@@ -799,6 +816,31 @@ pub fn build_limits(
         InsertPosition::Before,
     );
     let value = Operand::Copy(Place { local: value_ptr, projection: vec![ProjectionElem::Deref] });
+    if let Some(valid_values) = &req.valid_values {
+        // valid == values[0] || valid == values[1] || ...
+        let mut result = None;
+        for valid in valid_values {
+            let valid_const = body.new_uint_operand(*valid, primitive_ty, span);
+            let eq = body.insert_binary_op(
+                BinOp::Eq,
+                value.clone(),
+                valid_const,
+                source,
+                InsertPosition::Before,
+            );
+            result = Some(match result {
+                None => eq,
+                Some(prev) => body.insert_binary_op(
+                    BinOp::BitOr,
+                    move_local(prev),
+                    move_local(eq),
+                    source,
+                    InsertPosition::Before,
+                ),
+            });
+        }
+        return result.expect("an enumeration with a tag has variants");
+    }
     let start_result = body.insert_binary_op(
         BinOp::Ge,
         value.clone(),
@@ -833,6 +875,7 @@ pub fn build_limits(
 ///
 /// Not all values are currently supported. For those not supported, we return Error.
 pub fn ty_validity_per_offset(
+    tcx: TyCtxt,
     machine_info: &MachineInfo,
     ty: Ty,
     current_offset: usize,
@@ -852,7 +895,7 @@ pub fn ty_validity_per_offset(
         FieldsShape::Primitive => Ok(ty_req()),
         FieldsShape::Array { stride, count } if count > 0 => {
             let TyKind::RigidTy(RigidTy::Array(elem_ty, _)) = ty.kind() else { unreachable!() };
-            let elem_validity = ty_validity_per_offset(machine_info, elem_ty, current_offset)?;
+            let elem_validity = ty_validity_per_offset(tcx, machine_info, elem_ty, current_offset)?;
             let mut result = vec![];
             if !elem_validity.is_empty() {
                 for idx in 0..count {
@@ -888,6 +931,7 @@ pub fn ty_validity_per_offset(
                                         let field_offset = offsets[idx].bytes();
                                         let field_ty = fields[idx].ty_with_args(&args);
                                         fields_validity.append(&mut ty_validity_per_offset(
+                                            tcx,
                                             machine_info,
                                             field_ty,
                                             field_offset + current_offset,
@@ -902,7 +946,6 @@ pub fn ty_validity_per_offset(
                                     Err(format!("Unsupported Enum `{}` check", def.trimmed_name()))?
                                 }
                                 VariantsShape::Multiple { variants, .. } => {
-                                    let enum_validity = ty_req();
                                     let mut fields_validity = vec![];
                                     for (index, variant) in variants.iter().enumerate() {
                                         let fields = ty_variants[index].fields();
@@ -910,6 +953,7 @@ pub fn ty_validity_per_offset(
                                             let field_offset = offsets[field_idx].bytes();
                                             let field_ty = fields[field_idx].ty_with_args(&args);
                                             fields_validity.append(&mut ty_validity_per_offset(
+                                                tcx,
                                                 machine_info,
                                                 field_ty,
                                                 field_offset + current_offset,
@@ -917,7 +961,7 @@ pub fn ty_validity_per_offset(
                                         }
                                     }
                                     if fields_validity.is_empty() {
-                                        Ok(enum_validity)
+                                        Ok(enum_tag_validity(tcx, machine_info, ty, current_offset))
                                     } else {
                                         Err(format!(
                                             "Unsupported Enum `{}` check",
@@ -936,6 +980,7 @@ pub fn ty_validity_per_offset(
                                 let field_offset = offsets[idx].bytes();
                                 let field_ty = fields[idx].ty_with_args(&args);
                                 struct_validity.append(&mut ty_validity_per_offset(
+                                    tcx,
                                     machine_info,
                                     field_ty,
                                     field_offset + current_offset,
@@ -948,7 +993,12 @@ pub fn ty_validity_per_offset(
                 RigidTy::Pat(base_ty, ..) => {
                     // This is similar to a structure with one field and with niche defined.
                     let mut pat_validity = ty_req();
-                    pat_validity.append(&mut ty_validity_per_offset(machine_info, *base_ty, 0)?);
+                    pat_validity.append(&mut ty_validity_per_offset(
+                        tcx,
+                        machine_info,
+                        *base_ty,
+                        0,
+                    )?);
                     Ok(pat_validity)
                 }
                 RigidTy::Tuple(tys) => {
@@ -957,6 +1007,7 @@ pub fn ty_validity_per_offset(
                         let field_offset = offsets[idx].bytes();
                         let field_ty = tys[idx];
                         tuple_validity.append(&mut ty_validity_per_offset(
+                            tcx,
                             machine_info,
                             field_ty,
                             field_offset + current_offset,
@@ -995,3 +1046,33 @@ pub fn ty_validity_per_offset(
         }
     }
 }
+
+/// The requirement of the tag of an enumeration whose variants have no fields, which must be
+/// the discriminant of one of the variants. The discriminants may not be contiguous, e.g., for
+/// `enum Level { Low = 1, High = 5 }`, so the valid values are listed explicitly in that case.
+fn enum_tag_validity(
+    tcx: TyCtxt,
+    machine_info: &MachineInfo,
+    ty: Ty,
+    current_offset: usize,
+) -> Vec<ValidValueReq> {
+    let Some(mut req) = ValidValueReq::try_from_ty(machine_info, ty) else { return vec![] };
+    req.offset = current_offset;
+    let rustc_middle::ty::Adt(def, _) = rustc_internal::internal(tcx, ty).kind() else {
+        unreachable!("Expected enumeration, but found `{ty}`")
+    };
+    let bits = req.size.bits();
+    let mask = if bits >= 128 { u128::MAX } else { (1 << bits) - 1 };
+    let mut discriminants: Vec<u128> = def
+        .discriminants(tcx)
+        .map(|(_, discr)| discr.val & mask)
+        .filter(|val| req.valid_range.contains(*val))
+        .collect();
+    discriminants.sort_unstable();
+    discriminants.dedup();
+    let range_len = (req.valid_range.end.wrapping_sub(req.valid_range.start) & mask).checked_add(1);
+    if range_len != Some(discriminants.len() as u128) {
+        req.valid_values = Some(discriminants);
+    }
+    if req.is_full() { vec![] } else { vec![req] }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 //! This module contains code related to the MIR-to-MIR pass to enable contracts.
 use crate::kani_middle::attributes::KaniAttributes;
-use crate::kani_middle::codegen_units::CodegenUnit;
+use crate::kani_middle::codegen_units::{CodegenUnit, contract_harness_target};
 use crate::kani_middle::kani_functions::{KaniIntrinsic, KaniModel};
 use crate::kani_middle::transform::body::{InsertPosition, MutableBody, SourceInstruction};
 use crate::kani_middle::transform::{TransformPass, TransformationType};
@@ -86,10 +86,12 @@ pub fn new(tcx: TyCtxt, queries: &QueryDb, unit: &CodegenUnit) -> AnyModifiesPas
         let kani_write_any_slice = kani_fns.get(&KaniModel::WriteAnySlice.into()).copied();
         let kani_write_any_str = kani_fns.get(&KaniModel::WriteAnyStr.into()).copied();
         let target_fn = if let Some(harness) = unit.harnesses.first() {
-            let attributes = KaniAttributes::for_instance(tcx, *harness);
-            let target_fn =
-                attributes.proof_for_contract().map(|symbol| symbol.unwrap().as_str().intern());
-            target_fn
+            if let Some(target) = contract_harness_target(*harness) {
+                Some(target.name().intern())
+            } else {
+                let attributes = KaniAttributes::for_instance(tcx, *harness);
+                attributes.proof_for_contract().map(|symbol| symbol.unwrap().as_str().intern())
+            }
         } else {
             None
         };
@@ -342,7 +344,11 @@ impl FunctionWithContractPass {
     pub fn new(tcx: TyCtxt, queries: &QueryDb, unit: &CodegenUnit) -> FunctionWithContractPass {
         if let Some(harness) = unit.harnesses.first() {
             let attrs = KaniAttributes::for_instance(tcx, *harness);
-            let check_fn = attrs.interpret_for_contract_attribute().map(|(_, def_id, _)| def_id);
+            let check_fn = if let Some(target) = contract_harness_target(*harness) {
+                Some(rustc_internal::internal(tcx, target.def_id()))
+            } else {
+                attrs.interpret_for_contract_attribute().map(|(_, def_id, _)| def_id)
+            };
             let replace_fns: HashSet<_> = attrs
                 .interpret_stub_verified_attribute()
                 .iter()
@@ -17,20 +17,42 @@
 use super::BodyTransformation;
 
 /// Dump all MIR bodies.
+///
+/// This is enabled for every codegen unit when the user passes `--emit mir`, or only for the
+/// harnesses that match the name given with `--dump-mir-for-harness`.
 #[derive(Debug)]
 pub struct DumpMirPass {
-    enabled: bool,
+    emit_mir: bool,
+    harness_filter: Option<String>,
 }
 
 impl DumpMirPass {
-    pub fn new(tcx: TyCtxt) -> Self {
-        Self { enabled: tcx.sess.opts.output_types.contains_key(&OutputType::Mir) }
+    pub fn new(tcx: TyCtxt, queries: &QueryDb) -> Self {
+        Self {
+            emit_mir: tcx.sess.opts.output_types.contains_key(&OutputType::Mir),
+            harness_filter: queries.args().dump_mir_for_harness.clone(),
+        }
+    }
+
+    /// Whether the MIR should be dumped for a codegen unit with the given entry points.
+    fn should_dump(&self, starting_items: &[MonoItem]) -> bool {
+        if self.emit_mir {
+            return true;
+        }
+        let Some(filter) = &self.harness_filter else { return false };
+        starting_items.iter().any(|item| {
+            let MonoItem::Fn(instance) = item else { return false };
+            // Keep this in sync with how `kani-driver` matches harness names.
+            let name = instance.name();
+            name == *filter
+                || name.strip_suffix(filter.as_str()).is_some_and(|prefix| prefix.ends_with("::"))
+        })
     }
 }
 
 impl GlobalPass for DumpMirPass {
     fn is_enabled(&self, _query_db: &QueryDb) -> bool {
-        self.enabled
+        self.emit_mir || self.harness_filter.is_some()
     }
 
     fn transform(
@@ -41,6 +63,9 @@ fn transform(
         instances: Vec<Instance>,
         transformer: &mut BodyTransformation,
     ) {
+        if !self.should_dump(starting_items) {
+            return;
+        }
         // Create output buffer.
         let file_path = {
             let base_path = tcx.output_filenames(()).path(OutputType::Object);
@@ -60,10 +85,13 @@ fn transform(
         let out_file = File::create(file_path.with_extension("kani.mir")).unwrap();
         let mut writer = BufWriter::new(out_file);
 
-        // For each def_id, dump their MIR.
+        // For each def_id, dump their MIR. Sort them by name so the output is deterministic.
+        let mut instances = instances;
+        instances.sort_by_cached_key(|instance| (instance.name(), instance.mangled_name()));
         for instance in instances.iter() {
             writeln!(writer, "// Item: {} ({})", instance.name(), instance.mangled_name()).unwrap();
             let _ = transformer.body(tcx, *instance).dump(&mut writer, &instance.name());
+            writeln!(writer).unwrap();
         }
     }
 }
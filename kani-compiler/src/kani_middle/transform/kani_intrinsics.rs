@@ -10,7 +10,7 @@
 use crate::args::ExtraChecks;
 use crate::kani_middle::abi::LayoutOf;
 use crate::kani_middle::attributes::KaniAttributes;
-use crate::kani_middle::kani_functions::{KaniFunction, KaniIntrinsic, KaniModel};
+use crate::kani_middle::kani_functions::{KaniFunction, KaniHook, KaniIntrinsic, KaniModel};
 use crate::kani_middle::transform::body::{
     CheckType, InsertPosition, MutableBody, SourceInstruction,
 };
@@ -73,7 +73,10 @@ fn transform(&mut self, tcx: TyCtxt, body: Body, instance: Instance) -> (bool, B
                 KaniIntrinsic::CheckedAlignOf => (true, self.checked_align_of(body, instance)),
                 KaniIntrinsic::CheckedSizeOf => (true, self.checked_size_of(body, instance)),
                 KaniIntrinsic::IsInitialized => (true, self.is_initialized_body(body)),
-                KaniIntrinsic::ValidValue => (true, self.valid_value_body(body)),
+                KaniIntrinsic::ValidValue => (true, self.valid_value_body(tcx, body)),
+                KaniIntrinsic::ContractHarness => {
+                    (true, self.contract_harness_body(body, instance))
+                }
                 // This is handled in contracts pass for now.
                 KaniIntrinsic::WriteAny | KaniIntrinsic::AnyModifies => (false, body),
             }
@@ -103,7 +106,7 @@ pub fn new(check_type: CheckType, queries: &QueryDb) -> Self {
     ///     ret
     /// }
     /// ```
-    fn valid_value_body(&self, body: Body) -> Body {
+    fn valid_value_body(&self, tcx: TyCtxt, body: Body) -> Body {
         let mut new_body = MutableBody::from(body);
         new_body.clear_body(TerminatorKind::Return);
 
@@ -126,7 +129,7 @@ fn valid_value_body(&self, body: Body) -> Body {
         // The first and only argument type.
         let arg_ty = new_body.locals()[1].ty;
         let TyKind::RigidTy(RigidTy::RawPtr(target_ty, _)) = arg_ty.kind() else { unreachable!() };
-        let validity = ty_validity_per_offset(&machine_info, target_ty, 0);
+        let validity = ty_validity_per_offset(tcx, &machine_info, target_ty, 0);
         match validity {
             Ok(ranges) if ranges.is_empty() => {
                 // Nothing to check
@@ -588,6 +591,58 @@ fn checked_align_of(&mut self, body: Body, instance: Instance) -> Body {
         new_body.into()
     }
 
+    /// Generate the body of the harness that checks the contract of the function `F`, which
+    /// should be something like:
+    ///
+    /// ```
+    /// fn contract_harness<F>() {
+    ///     kani::internal::init_contracts();
+    ///     let arg1 = kani::any();
+    ///     ...
+    ///     let argN = kani::any();
+    ///     F(arg1, ..., argN);
+    /// }
+    /// ```
+    ///
+    /// The arguments of `F` were already checked to implement `kani::Arbitrary`.
+    fn contract_harness_body(&mut self, body: Body, instance: Instance) -> Body {
+        let fn_ty = *instance.args().0[0].expect_ty();
+        let TyKind::RigidTy(RigidTy::FnDef(target_def, target_args)) = fn_ty.kind() else {
+            unreachable!("Expected a function definition, but found `{fn_ty}`")
+        };
+        let target = Instance::resolve(target_def, &target_args).unwrap();
+        let fn_abi = target.fn_abi().unwrap();
+
+        let mut new_body = MutableBody::from(body);
+        new_body.clear_body(TerminatorKind::Return);
+        let mut source = SourceInstruction::Terminator { bb: 0 };
+        let span = source.span(new_body.blocks());
+
+        let init_def = self.kani_defs[&KaniHook::InitContracts.into()];
+        let init = Instance::resolve(init_def, &GenericArgs(vec![])).unwrap();
+        let unit = new_body.new_local(Ty::new_tuple(&[]), span, Mutability::Not);
+        new_body.insert_call(&init, &mut source, InsertPosition::Before, vec![], unit.into());
+
+        let any_def = self.kani_defs[&KaniModel::Any.into()];
+        let operands = fn_abi
+            .args
+            .iter()
+            .map(|arg| {
+                let input = arg.ty;
+                let any =
+                    Instance::resolve(any_def, &GenericArgs(vec![GenericArgKind::Type(input)]))
+                        .unwrap();
+                let arg = new_body.new_local(input, span, Mutability::Not);
+                new_body.insert_call(&any, &mut source, InsertPosition::Before, vec![], arg.into());
+                Operand::Move(arg.into())
+            })
+            .collect();
+
+        let ret = new_body.new_local(fn_abi.ret.ty, span, Mutability::Not);
+        new_body.insert_call(&target, &mut source, InsertPosition::Before, operands, ret.into());
+        new_body.into()
+    }
+
     fn return_model(
         &mut self,
         new_body: &mut MutableBody,
@@ -0,0 +1,312 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Module responsible for removing `kani::assume` calls that only constrain local values that no
+//! property depends on (i.e. `--enable-slicing`).
+//!
+//! CBMC's formula slicing (`--slice-formula`) removes the assignments that no property or
+//! assumption depends on, but it always keeps the assumptions, and with them, the computation of
+//! their conditions and the non-deterministic values they read. Once this pass removes such an
+//! assumption, all of these become irrelevant, so CBMC can slice them away, which reduces the size
+//! of the formula given to the solver.
+//!
+//! A local is "relevant" if its value may flow into a property, into the control flow, into
+//! memory, or out of the function. The analysis is flow-insensitive and conservative: a local is
+//! relevant if it is an argument or the return value, if it is borrowed, assigned or read through
+//! a projection other than a field or a downcast, if it is read by any terminator other than an
+//! assumption, if it is defined by a call other than `kani::any`, or if it is read by the
+//! definition of a relevant local. The condition of an assumption that reads a relevant local, even
+//! indirectly, is also relevant, since it may constrain the relevant local.
+//!
+//! An assumption is removed if its condition is not relevant and it reads at least one value
+//! created by `kani::any`. Since the values it reads are independent of every relevant value,
+//! removing it either doesn't change the relevant values that the harness may reach, or it makes
+//! the harness reach values that the assumption ruled out if the condition could never hold.
+//! Hence, a property that is violated with the assumption is still violated without it.
+//!
+//! Note that this pass must run after the instrumentation passes, since the checks they add may
+//! read values that were only read by assumptions before.
+
+use crate::kani_middle::kani_functions::{KaniFunction, KaniHook, KaniModel};
+use crate::kani_middle::transform::{TransformPass, TransformationType};
+use crate::kani_queries::QueryDb;
+use rustc_middle::ty::TyCtxt;
+use stable_mir::mir::mono::Instance;
+use stable_mir::mir::{
+    BasicBlockIdx, Body, Local, NonDivergingIntrinsic, Operand, Place, ProjectionElem, Rvalue,
+    StatementKind, TerminatorKind,
+};
+use stable_mir::ty::{FnDef, RigidTy, TyKind};
+use tracing::debug;
+
+/// Remove calls to `kani::assume` whose condition no property depends on.
+#[derive(Debug)]
+pub struct LocalAssumePass {
+    enabled: bool,
+    assume: Option<FnDef>,
+    any: Option<FnDef>,
+}
+
+impl TransformPass for LocalAssumePass {
+    fn transformation_type() -> TransformationType
+    where
+        Self: Sized,
+    {
+        TransformationType::Instrumentation
+    }
+
+    fn is_enabled(&self, _query_db: &QueryDb) -> bool
+    where
+        Self: Sized,
+    {
+        self.enabled && self.assume.is_some() && self.any.is_some()
+    }
+
+    fn transform(&mut self, _tcx: TyCtxt, mut body: Body, instance: Instance) -> (bool, Body) {
+        debug!(function=?instance.name(), "transform");
+        let Some(mut deps) = self.dependencies(&body) else { return (false, body) };
+
+        // Keep the assumptions that constrain a relevant local until we reach a fixed point,
+        // since their conditions become relevant and they may in turn constrain other locals.
+        let mut removable = std::mem::take(&mut deps.assumptions);
+        loop {
+            let (kept, rest): (Vec<_>, Vec<_>) = removable
+                .into_iter()
+                .partition(|(_, cond)| deps.slice(*cond).iter().any(|local| deps.relevant[*local]));
+            removable = rest;
+            if kept.is_empty() {
+                break;
+            }
+            for (_, cond) in kept {
+                deps.mark_relevant(cond);
+            }
+        }
+
+        let mut modified = false;
+        for (bb, cond) in removable {
+            if !deps.slice(cond).iter().any(|local| deps.any_value[*local]) {
+                continue;
+            }
+            debug!(function=?instance.name(), ?bb, ?cond, "remove local assumption");
+            let terminator = &mut body.blocks[bb].terminator;
+            let TerminatorKind::Call { target: Some(target), .. } = terminator.kind else {
+                unreachable!()
+            };
+            terminator.kind = TerminatorKind::Goto { target };
+            modified = true;
+        }
+        (modified, body)
+    }
+}
+
+impl LocalAssumePass {
+    pub fn new(queries: &QueryDb) -> Self {
+        let functions = queries.kani_functions();
+        LocalAssumePass {
+            enabled: queries.args().enable_slicing,
+            assume: functions.get(&KaniFunction::Hook(KaniHook::Assume)).copied(),
+            any: functions.get(&KaniFunction::Model(KaniModel::Any)).copied(),
+        }
+    }
+
+    /// Return the function called through `func` if it is `kani::assume` or `kani::any`.
+    fn callee(&self, body: &Body, func: &Operand) -> Option<FnDef> {
+        let TyKind::RigidTy(RigidTy::FnDef(def, _)) = func.ty(body.locals()).ok()?.kind() else {
+            return None;
+        };
+        (Some(def) == self.assume || Some(def) == self.any).then_some(def)
+    }
+
+    /// Collect the dependencies between the locals of the body, and the locals that are relevant
+    /// regardless of the assumptions.
+    ///
+    /// Return `None` if the body has constructs that we don't analyze, such as inline assembly.
+    fn dependencies(&self, body: &Body) -> Option<Dependencies> {
+        let num_locals = body.locals().len();
+        let mut deps = Dependencies {
+            reads: vec![vec![]; num_locals],
+            relevant: vec![false; num_locals],
+            any_value: vec![false; num_locals],
+            assumptions: vec![],
+        };
+        let mut roots: Vec<Local> = (0..=body.arg_locals().len()).collect();
+        for (bb, block) in body.blocks.iter().enumerate() {
+            for stmt in &block.statements {
+                match &stmt.kind {
+                    StatementKind::Assign(place, rvalue) => {
+                        let mut reads = vec![];
+                        rvalue_reads(rvalue, &mut reads, &mut roots);
+                        if place.projection.is_empty() {
+                            deps.reads[place.local].extend(reads);
+                        } else {
+                            mark_place(place, &mut roots);
+                            roots.extend(reads);
+                        }
+                    }
+                    StatementKind::Intrinsic(NonDivergingIntrinsic::Assume(op)) => {
+                        mark_operand(op, &mut roots);
+                    }
+                    StatementKind::Intrinsic(NonDivergingIntrinsic::CopyNonOverlapping(copy)) => {
+                        mark_operand(&copy.src, &mut roots);
+                        mark_operand(&copy.dst, &mut roots);
+                        mark_operand(&copy.count, &mut roots);
+                    }
+                    StatementKind::FakeRead(_, place)
+                    | StatementKind::SetDiscriminant { place, .. }
+                    | StatementKind::Deinit(place)
+                    | StatementKind::Retag(_, place)
+                    | StatementKind::PlaceMention(place) => {
+                        mark_place(place, &mut roots);
+                    }
+                    StatementKind::StorageLive(_)
+                    | StatementKind::StorageDead(_)
+                    | StatementKind::AscribeUserType { .. }
+                    | StatementKind::Coverage(_)
+                    | StatementKind::ConstEvalCounter
+                    | StatementKind::Nop => {}
+                }
+            }
+            match &block.terminator.kind {
+                TerminatorKind::Call { func, args, destination, target, .. } => {
+                    let callee = self.callee(body, func);
+                    match args.as_slice() {
+                        [Operand::Copy(place) | Operand::Move(place)]
+                            if callee == self.assume
+                                && target.is_some()
+                                && place.projection.is_empty() =>
+                        {
+                            deps.assumptions.push((bb, place.local));
+                        }
+                        [] if callee == self.any && destination.projection.is_empty() => {
+                            deps.any_value[destination.local] = true;
+                        }
+                        _ => {
+                            mark_operand(func, &mut roots);
+                            for arg in args {
+                                mark_operand(arg, &mut roots);
+                            }
+                            mark_place(destination, &mut roots);
+                        }
+                    }
+                }
+                TerminatorKind::SwitchInt { discr: op, .. }
+                | TerminatorKind::Assert { cond: op, .. } => {
+                    mark_operand(op, &mut roots);
+                }
+                TerminatorKind::Drop { place, .. } => {
+                    mark_place(place, &mut roots);
+                }
+                TerminatorKind::InlineAsm { .. } => return None,
+                TerminatorKind::Goto { .. }
+                | TerminatorKind::Resume
+                | TerminatorKind::Abort
+                | TerminatorKind::Return
+                | TerminatorKind::Unreachable => {}
+            }
+        }
+        for local in roots {
+            deps.mark_relevant(local);
+        }
+        Some(deps)
+    }
+}
+
+/// The dependencies between the locals of a body.
+struct Dependencies {
+    /// The locals read by the definitions of each local.
+    reads: Vec<Vec<Local>>,
+    /// Whether each local is relevant.
+    relevant: Vec<bool>,
+    /// Whether each local is defined by a call to `kani::any`.
+    any_value: Vec<bool>,
+    /// The blocks that end with a call to `kani::assume`, and the local with their condition.
+    assumptions: Vec<(BasicBlockIdx, Local)>,
+}
+
+impl Dependencies {
+    /// Mark the given local and every local that its definitions read as relevant.
+    fn mark_relevant(&mut self, local: Local) {
+        let mut worklist = vec![local];
+        while let Some(local) = worklist.pop() {
+            if !self.relevant[local] {
+                self.relevant[local] = true;
+                worklist.extend(self.reads[local].iter().copied());
+            }
+        }
+    }
+
+    /// Return the given local and every local that its definitions read, directly or not.
+    fn slice(&self, local: Local) -> Vec<Local> {
+        let mut visited = vec![false; self.reads.len()];
+        let mut worklist = vec![local];
+        let mut slice = vec![];
+        while let Some(local) = worklist.pop() {
+            if !visited[local] {
+                visited[local] = true;
+                slice.push(local);
+                worklist.extend(self.reads[local].iter().copied());
+            }
+        }
+        slice
+    }
+}
+
+/// Add the locals read by the given rvalue to `reads`, and the ones that become relevant to
+/// `roots`.
+fn rvalue_reads(rvalue: &Rvalue, reads: &mut Vec<Local>, roots: &mut Vec<Local>) {
+    match rvalue {
+        Rvalue::Use(op)
+        | Rvalue::UnaryOp(_, op)
+        | Rvalue::Cast(_, op, _)
+        | Rvalue::Repeat(op, _)
+        | Rvalue::ShallowInitBox(op, _) => operand_reads(op, reads, roots),
+        Rvalue::BinaryOp(_, lhs, rhs) | Rvalue::CheckedBinaryOp(_, lhs, rhs) => {
+            operand_reads(lhs, reads, roots);
+            operand_reads(rhs, reads, roots);
+        }
+        Rvalue::Aggregate(_, ops) => {
+            for op in ops {
+                operand_reads(op, reads, roots);
+            }
+        }
+        Rvalue::CopyForDeref(place) | Rvalue::Discriminant(place) | Rvalue::Len(place) => {
+            place_reads(place, reads, roots)
+        }
+        Rvalue::Ref(_, _, place) | Rvalue::AddressOf(_, place) => mark_place(place, roots),
+        Rvalue::ThreadLocalRef(_) | Rvalue::NullaryOp(..) => {}
+    }
+}
+
+fn operand_reads(op: &Operand, reads: &mut Vec<Local>, roots: &mut Vec<Local>) {
+    match op {
+        Operand::Copy(place) | Operand::Move(place) => place_reads(place, reads, roots),
+        Operand::Constant(_) => {}
+    }
+}
+
+/// Add the local of the given place to `reads`, unless the place reads memory through a
+/// projection, in which case its locals become relevant.
+fn place_reads(place: &Place, reads: &mut Vec<Local>, roots: &mut Vec<Local>) {
+    let is_value = place
+        .projection
+        .iter()
+        .all(|elem| matches!(elem, ProjectionElem::Field(..) | ProjectionElem::Downcast(_)));
+    if is_value { reads.push(place.local) } else { mark_place(place, roots) }
+}
+
+/// Mark the locals read by the given operand as relevant.
+fn mark_operand(op: &Operand, roots: &mut Vec<Local>) {
+    match op {
+        Operand::Copy(place) | Operand::Move(place) => mark_place(place, roots),
+        Operand::Constant(_) => {}
+    }
+}
+
+/// Mark the local of the given place, and the locals used to index it, as relevant.
+fn mark_place(place: &Place, roots: &mut Vec<Local>) {
+    roots.push(place.local);
+    roots.extend(place.projection.iter().filter_map(|elem| match elem {
+        ProjectionElem::Index(local) => Some(*local),
+        _ => None,
+    }));
+}
@@ -23,7 +23,9 @@
 use crate::kani_middle::transform::check_values::ValidValuePass;
 use crate::kani_middle::transform::contracts::{AnyModifiesPass, FunctionWithContractPass};
 use crate::kani_middle::transform::kani_intrinsics::IntrinsicGeneratorPass;
+use crate::kani_middle::transform::local_assumes::LocalAssumePass;
 use crate::kani_middle::transform::loop_contracts::LoopContractPass;
+use crate::kani_middle::transform::redundant_assumes::RedundantAssumePass;
 use crate::kani_middle::transform::stubs::{ExternFnStubPass, FnStubPass};
 use crate::kani_queries::QueryDb;
 use dump_mir_pass::DumpMirPass;
@@ -34,6 +36,8 @@
 use std::fmt::Debug;
 
 use crate::kani_middle::transform::rustc_intrinsics::RustcIntrinsicsPass;
+use crate::kani_middle::transform::std_abstractions::StdAbstractionsPass;
+pub use check_uninit::transmute_reads_padding;
 pub use internal_mir::RustcInternalMir;
 
 pub(crate) mod body;
@@ -43,8 +47,11 @@
 mod dump_mir_pass;
 mod internal_mir;
 mod kani_intrinsics;
+mod local_assumes;
 mod loop_contracts;
+mod redundant_assumes;
 mod rustc_intrinsics;
+mod std_abstractions;
 mod stubs;
 
 /// Object used to retrieve a transformed instance body.
@@ -73,6 +80,7 @@ pub fn new(queries: &QueryDb, tcx: TyCtxt, unit: &CodegenUnit) -> Self {
         let check_type = CheckType::new_assert_assume(queries);
         transformer.add_pass(queries, FnStubPass::new(&unit.stubs));
         transformer.add_pass(queries, ExternFnStubPass::new(&unit.stubs));
+        transformer.add_pass(queries, StdAbstractionsPass::new(queries));
         transformer.add_pass(queries, FunctionWithContractPass::new(tcx, queries, &unit));
         // This has to come after the contract pass since we want this to only replace the closure
         // body that is relevant for this harness.
@@ -91,6 +99,12 @@ pub fn new(queries: &QueryDb, tcx: TyCtxt, unit: &CodegenUnit) -> Self {
         transformer.add_pass(queries, IntrinsicGeneratorPass::new(check_type, &queries));
         transformer.add_pass(queries, LoopContractPass::new(tcx, queries, &unit));
         transformer.add_pass(queries, RustcIntrinsicsPass::new(&queries));
+        // Run after the other stubbing passes, so it sees every user assumption, but before
+        // instrumentation, so it never removes the assumptions that Kani adds for its checks.
+        transformer.add_pass(queries, RedundantAssumePass::new(&queries));
+        // This has to come after every instrumentation pass, since the checks they add may
+        // depend on values that were only constrained by user assumptions.
+        transformer.add_pass(queries, LocalAssumePass::new(&queries));
         transformer
     }
 
@@ -193,7 +207,7 @@ pub fn new(queries: &QueryDb, tcx: TyCtxt) -> Self {
         let mut global_passes = GlobalPasses { global_passes: vec![] };
         global_passes
             .add_global_pass(queries, DelayedUbPass::new(CheckType::new_assert(queries), queries));
-        global_passes.add_global_pass(queries, DumpMirPass::new(tcx));
+        global_passes.add_global_pass(queries, DumpMirPass::new(tcx, queries));
         global_passes
     }
 
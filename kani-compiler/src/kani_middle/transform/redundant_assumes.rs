@@ -0,0 +1,334 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Module responsible for removing `kani::assume` calls that are redundant.
+//!
+//! A call `kani::assume(cond)` is redundant if it is dominated by another call to `kani::assume`
+//! whose condition is syntactically identical and is guaranteed to have the same value. Since the
+//! dominating assumption was already added to every path that reaches the redundant one, removing
+//! the latter doesn't change the semantics of the program, but it saves CBMC from adding the same
+//! constraint again, e.g., once per loop iteration.
+//!
+//! The analysis is intentionally conservative. A condition is only considered to have the same
+//! value if every local it reads is "stable": it is assigned exactly once in the whole body (or it
+//! is an argument that is never assigned), only as a whole, and it is never borrowed. Since a
+//! local with a single definition must be defined before any use, any path between the dominating
+//! and the redundant call cannot redefine such a local, so both conditions have the same value.
+
+use crate::kani_middle::kani_functions::{KaniFunction, KaniHook};
+use crate::kani_middle::transform::{TransformPass, TransformationType};
+use crate::kani_queries::QueryDb;
+use rustc_middle::ty::TyCtxt;
+use stable_mir::mir::mono::Instance;
+use stable_mir::mir::{
+    BasicBlock, BasicBlockIdx, BinOp, Body, CastKind, Local, Operand, Place, Rvalue, StatementKind,
+    TerminatorKind, UnOp,
+};
+use stable_mir::ty::{ConstantKind, FnDef, RigidTy, Ty, TyKind};
+use tracing::debug;
+
+/// Remove calls to `kani::assume` that are dominated by an identical assumption.
+#[derive(Debug)]
+pub struct RedundantAssumePass {
+    assume: Option<FnDef>,
+}
+
+impl TransformPass for RedundantAssumePass {
+    fn transformation_type() -> TransformationType
+    where
+        Self: Sized,
+    {
+        TransformationType::Stubbing
+    }
+
+    fn is_enabled(&self, _query_db: &QueryDb) -> bool
+    where
+        Self: Sized,
+    {
+        self.assume.is_some()
+    }
+
+    fn transform(&mut self, _tcx: TyCtxt, mut body: Body, instance: Instance) -> (bool, Body) {
+        debug!(function=?instance.name(), "transform");
+        let Some(stable) = stable_locals(&body) else { return (false, body) };
+        let assumptions: Vec<(BasicBlockIdx, Condition)> = body
+            .blocks
+            .iter()
+            .enumerate()
+            .filter_map(|(bb, block)| {
+                self.assume_condition(&body, block, &stable).map(|cond| (bb, cond))
+            })
+            .collect();
+        if assumptions.len() < 2 {
+            return (false, body);
+        }
+
+        let dominators = Dominators::new(&body.blocks);
+        let mut changed = false;
+        for (bb, cond) in &assumptions {
+            let is_redundant = assumptions.iter().any(|(other_bb, other_cond)| {
+                other_bb != bb && other_cond == cond && dominators.dominates(*other_bb, *bb)
+            });
+            if is_redundant {
+                debug!(function=?instance.name(), ?bb, ?cond, "remove redundant assumption");
+                let terminator = &mut body.blocks[*bb].terminator;
+                let TerminatorKind::Call { target: Some(target), .. } = terminator.kind else {
+                    unreachable!()
+                };
+                terminator.kind = TerminatorKind::Goto { target };
+                changed = true;
+            }
+        }
+        (changed, body)
+    }
+}
+
+impl RedundantAssumePass {
+    pub fn new(queries: &QueryDb) -> Self {
+        let assume = queries.kani_functions().get(&KaniFunction::Hook(KaniHook::Assume)).copied();
+        RedundantAssumePass { assume }
+    }
+
+    /// If the given block ends with a call to `kani::assume` whose condition only reads stable
+    /// locals, return the condition.
+    fn assume_condition(
+        &self,
+        body: &Body,
+        block: &BasicBlock,
+        stable: &[bool],
+    ) -> Option<Condition> {
+        let TerminatorKind::Call { func, args, target: Some(_), .. } = &block.terminator.kind
+        else {
+            return None;
+        };
+        let TyKind::RigidTy(RigidTy::FnDef(def, _)) = func.ty(body.locals()).ok()?.kind() else {
+            return None;
+        };
+        if Some(def) != self.assume {
+            return None;
+        }
+        let [Operand::Copy(place) | Operand::Move(place)] = args.as_slice() else { return None };
+        let local = stable_local(place, stable)?;
+        // Look for the definition of the condition in the same block, so we can compare the
+        // expressions instead of the temporaries that hold their result.
+        let definition = block.statements.iter().find_map(|stmt| match &stmt.kind {
+            StatementKind::Assign(lhs, rvalue) if lhs.local == local => Some(rvalue),
+            _ => None,
+        });
+        definition
+            .and_then(|rvalue| Condition::from_rvalue(rvalue, stable))
+            .or(Some(Condition::Local(local)))
+    }
+}
+
+/// A condition of an assumption, which only reads stable locals.
+#[derive(Clone, Debug, PartialEq)]
+enum Condition {
+    Local(Local),
+    BinaryOp(BinOp, Value, Value),
+    UnaryOp(UnOp, Value),
+    Cast(CastKind, Value, Ty),
+}
+
+/// An operand of a condition. Constants are compared by value and type, ignoring their span.
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    Local(Local),
+    Const(ConstantKind, Ty),
+}
+
+impl Condition {
+    fn from_rvalue(rvalue: &Rvalue, stable: &[bool]) -> Option<Condition> {
+        match rvalue {
+            Rvalue::Use(op) => match Value::from_operand(op, stable)? {
+                Value::Local(local) => Some(Condition::Local(local)),
+                Value::Const(..) => None,
+            },
+            Rvalue::BinaryOp(bin_op, lhs, rhs) => Some(Condition::BinaryOp(
+                *bin_op,
+                Value::from_operand(lhs, stable)?,
+                Value::from_operand(rhs, stable)?,
+            )),
+            Rvalue::UnaryOp(un_op, op) => {
+                Some(Condition::UnaryOp(*un_op, Value::from_operand(op, stable)?))
+            }
+            Rvalue::Cast(kind, op, ty) => {
+                Some(Condition::Cast(*kind, Value::from_operand(op, stable)?, *ty))
+            }
+            Rvalue::AddressOf(..)
+            | Rvalue::Aggregate(..)
+            | Rvalue::CheckedBinaryOp(..)
+            | Rvalue::CopyForDeref(..)
+            | Rvalue::Discriminant(..)
+            | Rvalue::Len(..)
+            | Rvalue::Ref(..)
+            | Rvalue::Repeat(..)
+            | Rvalue::ShallowInitBox(..)
+            | Rvalue::ThreadLocalRef(..)
+            | Rvalue::NullaryOp(..) => None,
+        }
+    }
+}
+
+impl Value {
+    fn from_operand(op: &Operand, stable: &[bool]) -> Option<Value> {
+        match op {
+            Operand::Copy(place) | Operand::Move(place) => {
+                stable_local(place, stable).map(Value::Local)
+            }
+            Operand::Constant(constant) => {
+                Some(Value::Const(constant.const_.kind().clone(), constant.const_.ty()))
+            }
+        }
+    }
+}
+
+/// Return the local of `place` if it is the whole local and the local is stable.
+fn stable_local(place: &Place, stable: &[bool]) -> Option<Local> {
+    (place.projection.is_empty() && stable[place.local]).then_some(place.local)
+}
+
+/// Compute which locals are stable, i.e., assigned exactly once as a whole and never borrowed.
+/// Arguments count as assigned on entry.
+///
+/// Return `None` if the body has constructs that we don't analyze, such as inline assembly.
+fn stable_locals(body: &Body) -> Option<Vec<bool>> {
+    let mut definitions = vec![0usize; body.locals().len()];
+    let mut unstable = vec![false; body.locals().len()];
+    definitions[1..=body.arg_locals().len()].fill(1);
+    let mut define = |place: &Place, unstable: &mut Vec<bool>| {
+        if place.projection.is_empty() {
+            definitions[place.local] += 1;
+        } else {
+            unstable[place.local] = true;
+        }
+    };
+    for block in &body.blocks {
+        for stmt in &block.statements {
+            match &stmt.kind {
+                StatementKind::Assign(place, rvalue) => {
+                    define(place, &mut unstable);
+                    if let Rvalue::Ref(_, _, borrowed) | Rvalue::AddressOf(_, borrowed) = rvalue {
+                        unstable[borrowed.local] = true;
+                    }
+                }
+                StatementKind::SetDiscriminant { place, .. } | StatementKind::Deinit(place) => {
+                    unstable[place.local] = true;
+                }
+                StatementKind::FakeRead(..)
+                | StatementKind::StorageLive(_)
+                | StatementKind::StorageDead(_)
+                | StatementKind::Retag(..)
+                | StatementKind::PlaceMention(_)
+                | StatementKind::AscribeUserType { .. }
+                | StatementKind::Coverage(_)
+                | StatementKind::Intrinsic(_)
+                | StatementKind::ConstEvalCounter
+                | StatementKind::Nop => {}
+            }
+        }
+        match &block.terminator.kind {
+            TerminatorKind::Call { destination, .. } => {
+                define(destination, &mut unstable);
+            }
+            TerminatorKind::Drop { place, .. } => {
+                unstable[place.local] = true;
+            }
+            TerminatorKind::InlineAsm { .. } => return None,
+            TerminatorKind::Goto { .. }
+            | TerminatorKind::SwitchInt { .. }
+            | TerminatorKind::Resume
+            | TerminatorKind::Abort
+            | TerminatorKind::Return
+            | TerminatorKind::Unreachable
+            | TerminatorKind::Assert { .. } => {}
+        }
+    }
+    Some(definitions.iter().zip(unstable).map(|(defs, unstable)| *defs == 1 && !unstable).collect())
+}
+
+/// The dominator tree of a body, computed with the algorithm from "A Simple, Fast Dominance
+/// Algorithm" by Cooper, Harvey and Kennedy.
+struct Dominators {
+    /// The immediate dominator of each block, or `None` for the entry block and for unreachable
+    /// blocks.
+    idom: Vec<Option<BasicBlockIdx>>,
+}
+
+impl Dominators {
+    fn new(blocks: &[BasicBlock]) -> Self {
+        // Compute the reverse post-order of the reachable blocks.
+        let mut post_order = Vec::with_capacity(blocks.len());
+        let mut visited = vec![false; blocks.len()];
+        let mut stack = vec![(0, blocks[0].terminator.successors())];
+        visited[0] = true;
+        while let Some((bb, successors)) = stack.last_mut() {
+            if let Some(succ) = successors.pop() {
+                if !visited[succ] {
+                    visited[succ] = true;
+                    stack.push((succ, blocks[succ].terminator.successors()));
+                }
+            } else {
+                post_order.push(*bb);
+                stack.pop();
+            }
+        }
+        let mut order = vec![usize::MAX; blocks.len()];
+        for (idx, bb) in post_order.iter().enumerate() {
+            order[*bb] = idx;
+        }
+        let mut predecessors = vec![vec![]; blocks.len()];
+        for bb in &post_order {
+            for succ in blocks[*bb].terminator.successors() {
+                predecessors[succ].push(*bb);
+            }
+        }
+
+        // The entry block is temporarily its own dominator to simplify the intersection.
+        let mut idom: Vec<Option<BasicBlockIdx>> = vec![None; blocks.len()];
+        idom[0] = Some(0);
+        let intersect =
+            |idom: &[Option<BasicBlockIdx>], mut a: BasicBlockIdx, mut b: BasicBlockIdx| {
+                while a != b {
+                    while order[a] < order[b] {
+                        a = idom[a].unwrap();
+                    }
+                    while order[b] < order[a] {
+                        b = idom[b].unwrap();
+                    }
+                }
+                a
+            };
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for bb in post_order.iter().rev().skip(1) {
+                let new_idom = predecessors[*bb]
+                    .iter()
+                    .filter(|pred| idom[**pred].is_some())
+                    .copied()
+                    .reduce(|a, b| intersect(&idom, a, b));
+                if new_idom.is_some() && idom[*bb] != new_idom {
+                    idom[*bb] = new_idom;
+                    changed = true;
+                }
+            }
+        }
+        idom[0] = None;
+        Dominators { idom }
+    }
+
+    /// Whether block `a` dominates block `b`. Unreachable blocks don't dominate and aren't
+    /// dominated by any block.
+    fn dominates(&self, a: BasicBlockIdx, mut b: BasicBlockIdx) -> bool {
+        loop {
+            if a == b {
+                return true;
+            }
+            match self.idom[b] {
+                Some(parent) => b = parent,
+                None => return false,
+            }
+        }
+    }
+}
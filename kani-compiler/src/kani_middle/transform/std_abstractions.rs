@@ -0,0 +1,183 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Module responsible for replacing a few standard library functions by abstractions that only
+//! model their postconditions.
+//!
+//! These abstractions are only enabled with `--std-abstractions`, since they may rely on
+//! assumptions that the user has to uphold. See each model documentation for more details.
+//!
+//! This module also implements `--hashmap-capacity`, which models `HashMap` as a bounded
+//! association list.
+
+use crate::kani_middle::kani_functions::{KaniFunction, KaniModel};
+use crate::kani_middle::transform::body::{MutMirVisitor, MutableBody};
+use crate::kani_middle::transform::{TransformPass, TransformationType};
+use crate::kani_queries::QueryDb;
+use rustc_middle::ty::TyCtxt;
+use stable_mir::CrateDef;
+use stable_mir::mir::mono::Instance;
+use stable_mir::mir::{Body, ConstOperand, LocalDecl, Operand, Terminator, TerminatorKind};
+use stable_mir::ty::{FnDef, GenericArgs, MirConst, RigidTy, TyKind, UintTy};
+use std::collections::HashMap;
+use tracing::debug;
+
+/// Replace calls to standard library functions by their abstraction.
+#[derive(Debug)]
+pub struct StdAbstractionsPass {
+    /// Map the name of the standard library function to its model.
+    models: HashMap<&'static str, Model>,
+}
+
+/// A model that replaces a standard library function.
+#[derive(Debug, Copy, Clone)]
+struct Model {
+    def: FnDef,
+    /// Extra argument appended to the call, used to pass the `HashMap` capacity.
+    capacity: Option<usize>,
+}
+
+/// The standard library functions that we currently abstract, and their respective models.
+///
+/// The stable and unstable sorts share the same model, since the model doesn't keep the order of
+/// equal elements.
+const ABSTRACTIONS: &[(&str, KaniModel)] = &[
+    ("core::slice::<impl [T]>::binary_search", KaniModel::BinarySearch),
+    ("alloc::slice::<impl [T]>::sort", KaniModel::Sort),
+    ("alloc::slice::<impl [T]>::sort_by", KaniModel::SortBy),
+    ("alloc::slice::<impl [T]>::sort_by_key", KaniModel::SortByKey),
+    ("core::slice::<impl [T]>::sort_unstable", KaniModel::Sort),
+    ("core::slice::<impl [T]>::sort_unstable_by", KaniModel::SortBy),
+    ("core::slice::<impl [T]>::sort_unstable_by_key", KaniModel::SortByKey),
+];
+
+/// The `HashMap` functions that we abstract with `--hashmap-capacity`, and their models.
+///
+/// Replacing `make_hash` by a constant hash makes every entry collide, so the map behaves like an
+/// association list that compares keys with `PartialEq`. The other models check that the map
+/// does not grow past the capacity before calling the real function. `from_iter` and `from` are
+/// covered by the `extend` models, since they call `extend`.
+///
+/// Trait methods are identified by the name of their `HashMap` implementation.
+const HASHMAP_ABSTRACTIONS: &[(&str, KaniModel)] = &[
+    ("hashbrown::map::make_hash", KaniModel::HashMapMakeHash),
+    ("std::collections::HashMap::<K, V, S>::insert", KaniModel::HashMapInsert),
+    ("std::collections::HashMap::<K, V, S>::entry", KaniModel::HashMapEntry),
+    (
+        "<std::collections::HashMap<K, V, S> as std::iter::Extend<(K, V)>>::extend",
+        KaniModel::HashMapExtend,
+    ),
+    (
+        "<std::collections::HashMap<K, V, S> as std::iter::Extend<(&'a K, &'a V)>>::extend",
+        KaniModel::HashMapExtendCopied,
+    ),
+];
+
+impl TransformPass for StdAbstractionsPass {
+    fn transformation_type() -> TransformationType
+    where
+        Self: Sized,
+    {
+        TransformationType::Stubbing
+    }
+
+    fn is_enabled(&self, query_db: &QueryDb) -> bool
+    where
+        Self: Sized,
+    {
+        query_db.args().std_abstractions || query_db.args().hashmap_capacity.is_some()
+    }
+
+    fn transform(&mut self, _tcx: TyCtxt, body: Body, instance: Instance) -> (bool, Body) {
+        debug!(function=?instance.name(), "transform");
+        // Models may call the function they replace, e.g., the `HashMap::insert` model.
+        if self.models.values().any(|model| model.def.def_id() == instance.def.def_id()) {
+            return (false, body);
+        }
+        let mut new_body = MutableBody::from(body);
+        let mut visitor = ReplaceStdCallVisitor {
+            models: &self.models,
+            locals: new_body.locals().to_vec(),
+            changed: false,
+        };
+        visitor.visit_body(&mut new_body);
+        (visitor.changed, new_body.into())
+    }
+}
+
+impl StdAbstractionsPass {
+    pub fn new(queries: &QueryDb) -> Self {
+        let kani_functions = queries.kani_functions();
+        let args = queries.args();
+        let std_models = ABSTRACTIONS.iter().filter(|_| args.std_abstractions);
+        let hashmap_models =
+            HASHMAP_ABSTRACTIONS.iter().filter(|_| args.hashmap_capacity.is_some());
+        let models = std_models
+            .chain(hashmap_models)
+            .filter_map(|(name, model)| {
+                let capacity = (*model != KaniModel::HashMapMakeHash)
+                    .then_some(args.hashmap_capacity)
+                    .flatten();
+                kani_functions
+                    .get(&KaniFunction::Model(*model))
+                    .map(|def| (*name, Model { def: *def, capacity }))
+            })
+            .collect();
+        debug!(?models, "StdAbstractionsPass::new");
+        StdAbstractionsPass { models }
+    }
+}
+
+struct ReplaceStdCallVisitor<'a> {
+    models: &'a HashMap<&'static str, Model>,
+    locals: Vec<LocalDecl>,
+    changed: bool,
+}
+
+impl ReplaceStdCallVisitor<'_> {
+    /// Find the model of the function called, and the generic arguments to instantiate it with.
+    ///
+    /// Calls to trait methods are resolved first, so the model gets the generic arguments of the
+    /// implementation instead of the trait ones.
+    fn find_model(&self, def: FnDef, args: &GenericArgs) -> Option<(Model, GenericArgs)> {
+        if let Some(model) = self.models.get(def.name().as_str()) {
+            return Some((*model, args.clone()));
+        }
+        let instance = Instance::resolve(def, args).ok()?;
+        self.models.get(instance.def.name().as_str()).map(|model| (*model, instance.args()))
+    }
+}
+
+impl MutMirVisitor for ReplaceStdCallVisitor<'_> {
+    /// Replace direct calls to an abstracted function by a call to its model.
+    ///
+    /// The models have the same generic parameters as the function they replace.
+    /// Models that take the `HashMap` capacity get it as an extra trailing argument.
+    fn visit_terminator(&mut self, term: &mut Terminator) {
+        if let TerminatorKind::Call { func, args: call_args, .. } = &mut term.kind {
+            if let TyKind::RigidTy(RigidTy::FnDef(def, args)) =
+                func.ty(&self.locals).unwrap().kind()
+            {
+                if let Some((model, args)) = self.find_model(def, &args) {
+                    debug!(function=?def.name(), ?model, "replace call");
+                    let new_instance = Instance::resolve(model.def, &args).unwrap();
+                    let literal = MirConst::try_new_zero_sized(new_instance.ty()).unwrap();
+                    let span = term.span;
+                    let new_func = ConstOperand { span, user_ty: None, const_: literal };
+                    *func = Operand::Constant(new_func);
+                    if let Some(capacity) = model.capacity {
+                        let const_ =
+                            MirConst::try_from_uint(capacity as u128, UintTy::Usize).unwrap();
+                        call_args.push(Operand::Constant(ConstOperand {
+                            span,
+                            user_ty: None,
+                            const_,
+                        }));
+                    }
+                    self.changed = true;
+                }
+            }
+        }
+        self.super_terminator(term);
+    }
+}
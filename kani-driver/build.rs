@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use std::env::var;
+use std::process::Command;
 
 fn main() {
     // We want to know what target triple we were built with, but this isn't normally provided to us.
@@ -10,4 +11,31 @@ fn main() {
     // https://doc.rust-lang.org/cargo/reference/environment-variables.html#environment-variables-cargo-sets-for-build-scripts
     // So "repeat" the info from build script (here) to our crate's build environment.
     println!("cargo:rustc-env=TARGET={}", var("TARGET").unwrap());
+
+    // Record the commit Kani was built from, if we are building from a git checkout. This is
+    // reported in verbose mode and in the JSON results to help users file reproducible bug reports.
+    let commit = git(&["rev-parse", "--short=12", "HEAD"]);
+    if let Some(commit) = commit {
+        println!("cargo:rustc-env=KANI_GIT_COMMIT={commit}");
+    }
+
+    // Rebuild when the checked out commit changes: `HEAD` changes when switching branches, and
+    // the branch it points to changes with every commit.
+    println!("cargo:rerun-if-changed=build.rs");
+    for file in ["HEAD", "refs/heads"] {
+        if let Some(path) = git(&["rev-parse", "--git-path", file]) {
+            println!("cargo:rerun-if-changed={path}");
+        }
+    }
+}
+
+/// Run `git` with the given arguments and return its trimmed output, if it succeeds.
+fn git(args: &[&str]) -> Option<String> {
+    Command::new("git")
+        .args(args)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|output| output.trim().to_string())
 }
@@ -17,6 +17,16 @@ pub struct CargoListArgs {
     /// Output format
     #[clap(long, default_value = "pretty")]
     pub format: Format,
+
+    /// Compare the harnesses against an inventory written by `list --format json`, and report
+    /// the harnesses that were added, removed, renamed or moved. Fails if any harness was removed.
+    #[arg(long, value_name = "OLD_JSON")]
+    pub compare_harnesses: Option<PathBuf>,
+
+    /// Also list the harnesses that were excluded by their `#[cfg(...)]` attribute, marked as
+    /// inactive.
+    #[arg(long)]
+    pub include_inactive: bool,
 }
 
 /// List information relevant to verification
@@ -36,6 +46,16 @@ pub struct StandaloneListArgs {
     #[clap(long, default_value = "pretty")]
     pub format: Format,
 
+    /// Compare the harnesses against an inventory written by `list --format json`, and report
+    /// the harnesses that were added, removed, renamed or moved. Fails if any harness was removed.
+    #[arg(long, value_name = "OLD_JSON")]
+    pub compare_harnesses: Option<PathBuf>,
+
+    /// Also list the harnesses that were excluded by their `#[cfg(...)]` attribute, marked as
+    /// inactive.
+    #[arg(long)]
+    pub include_inactive: bool,
+
     /// Pass this flag to run the `list` command on the standard library.
     /// Ensure that the provided `path` is the `library` folder.
     #[arg(long)]
@@ -54,6 +74,20 @@ pub enum Format {
     Json,
 }
 
+/// Check that the inventory given to `--compare-harnesses` exists.
+fn validate_compare_harnesses(path: &Option<PathBuf>) -> Result<(), Error> {
+    match path {
+        Some(path) if !path.is_file() => Err(Error::raw(
+            ErrorKind::InvalidValue,
+            format!(
+                "Invalid argument: `--compare-harnesses` argument `{}` is not a file",
+                path.display()
+            ),
+        )),
+        _ => Ok(()),
+    }
+}
+
 impl ValidateArgs for CargoListArgs {
     fn validate(&self) -> Result<(), Error> {
         self.common_args.validate()?;
@@ -63,6 +97,7 @@ fn validate(&self) -> Result<(), Error> {
                 "The `list` subcommand is unstable and requires -Z list",
             ));
         }
+        validate_compare_harnesses(&self.compare_harnesses)?;
 
         Ok(())
     }
@@ -77,6 +112,7 @@ fn validate(&self) -> Result<(), Error> {
                 "The `list` subcommand is unstable and requires -Z list",
             ));
         }
+        validate_compare_harnesses(&self.compare_harnesses)?;
 
         if self.std {
             if !self.input.exists() {
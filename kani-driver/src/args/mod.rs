@@ -17,7 +17,8 @@
 use cargo::CargoCommonArgs;
 use clap::builder::{PossibleValue, TypedValueParser};
 use clap::{ValueEnum, error::ContextKind, error::ContextValue, error::Error, error::ErrorKind};
-use kani_metadata::CbmcSolver;
+use kani_metadata::{CbmcSolver, DiagnosticCategory};
+use std::collections::HashMap;
 use std::ffi::OsString;
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -63,6 +64,9 @@ pub fn print_deprecated(verbosity: &CommonArgs, option: &str, alternative: &str)
 // By default we configure CBMC to use 16 bits to represent the object bits in pointers.
 const DEFAULT_OBJECT_BITS: u32 = 16;
 
+// By default, maps modeled by `--hashmap-abstraction` can hold up to 8 entries.
+const DEFAULT_HASHMAP_CAPACITY: usize = 8;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::EnumString)]
 enum TimeUnit {
     #[strum(serialize = "s")]
@@ -110,6 +114,40 @@ fn from(timeout: Timeout) -> Self {
     }
 }
 
+/// A memory size with an optional suffix ('K': kibibytes, 'M': mebibytes, 'G': gibibytes).
+/// Default is bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MemoryLimit {
+    bytes: u64,
+}
+
+impl MemoryLimit {
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+}
+
+impl FromStr for MemoryLimit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value_str, multiplier) = match s.chars().last() {
+            Some('K' | 'k') => (&s[..s.len() - 1], 1 << 10),
+            Some('M' | 'm') => (&s[..s.len() - 1], 1 << 20),
+            Some('G' | 'g') => (&s[..s.len() - 1], 1 << 30),
+            _ => (s, 1),
+        };
+        let value = value_str.parse::<u64>().map_err(|_| {
+            "Invalid memory limit. Use a number of bytes with an optional suffix: 'K', 'M' or 'G'"
+        })?;
+        let bytes = value.checked_mul(multiplier).ok_or("Memory limit is too large")?;
+        if bytes == 0 {
+            return Err("Memory limit must be greater than zero".to_string());
+        }
+        Ok(MemoryLimit { bytes })
+    }
+}
+
 #[derive(Debug, clap::Parser)]
 #[command(
     version,
@@ -122,7 +160,7 @@ fn from(timeout: Timeout) -> Self {
 )]
 pub struct StandaloneArgs {
     /// Rust file to verify
-    #[arg(required = true)]
+    #[arg(required_unless_present = "replay_trace")]
     pub input: Option<PathBuf>,
 
     #[command(flatten)]
@@ -195,6 +233,14 @@ pub struct VerificationArgs {
     #[arg(long, hide_short_help = true)]
     pub keep_temps: bool,
 
+    /// Path to the `cbmc` binary to use instead of the one found in `PATH`.
+    #[arg(long, hide_short_help = true, env = "KANI_CBMC_PATH", value_name = "PATH")]
+    pub cbmc_path: Option<PathBuf>,
+
+    /// Path to the `goto-cc` binary to use instead of the one found in `PATH`.
+    #[arg(long, hide_short_help = true, env = "KANI_GOTO_CC_PATH", value_name = "PATH")]
+    pub goto_cc_path: Option<PathBuf>,
+
     /// Generate C file equivalent to inputted program.
     /// This feature is unstable and it requires `--enable-unstable` to be used
     #[arg(long, hide_short_help = true, requires("enable_unstable"))]
@@ -212,6 +258,11 @@ pub struct VerificationArgs {
     #[arg(long, default_value = "regular", ignore_case = true, value_enum)]
     pub output_format: OutputFormat,
 
+    /// The format of the messages printed by Kani. With `json`, the human-readable output is
+    /// replaced by one JSON object per line for each event, e.g., for editor integrations.
+    #[arg(long, default_value = "human", value_enum)]
+    pub message_format: MessageFormat,
+
     #[command(flatten)]
     pub checks: CheckArgs,
 
@@ -221,10 +272,25 @@ pub struct VerificationArgs {
     #[arg(long = "harness", num_args(1), value_name = "HARNESS_FILTER")]
     pub harnesses: Vec<String>,
 
+    /// Skip the harnesses that match this filter. This option can be provided multiple times,
+    /// and it uses the same matching rules as `--harness`. Note that `--exact` can only be used
+    /// together with `--harness`, in which case exclusions must also match the exact fully
+    /// qualified name of a harness.
+    /// Exclusions are applied after `--harness`, so a harness that matches both is skipped.
+    #[arg(long = "exclude-harness", num_args(1), value_name = "HARNESS_FILTER")]
+    pub exclude_harnesses: Vec<String>,
+
     /// When specified, the harness filter will only match the exact fully qualified name of a harness
     #[arg(long, requires("harnesses"))]
     pub exact: bool,
 
+    /// Verify a single harness and, if verification fails, replay its counterexample by running
+    /// the concrete playback unit test generated for it. The harness is matched like `--harness`,
+    /// but the filter must select exactly one harness. The source code is restored after the test
+    /// runs. This option is unstable and requires `-Z concrete-playback`.
+    #[arg(long, value_name = "HARNESS")]
+    pub run: Option<String>,
+
     /// Link external C files referenced by Rust code.
     /// This is an experimental feature and requires `-Z c-ffi` to be used
     #[arg(long, hide = true, num_args(1..))]
@@ -236,17 +302,42 @@ pub struct VerificationArgs {
     #[arg(long, hide_short_help = true)]
     pub only_codegen: bool,
 
+    /// Print the unsupported features that are reachable from each harness, and stop without
+    /// verifying the harnesses.
+    #[arg(long, hide_short_help = true, conflicts_with("only_codegen"))]
+    pub print_unsupported: bool,
+
+    /// Consider harnesses that have no properties to check as failures, instead of
+    /// trivially passing.
+    #[arg(long, hide_short_help = true)]
+    pub strict_no_properties: bool,
+
+    /// Consider harnesses that have a cover property that isn't satisfied as failures.
+    #[arg(long, hide_short_help = true)]
+    pub strict_cover: bool,
+
     /// Specify the value used for loop unwinding in CBMC
     #[arg(long)]
     pub default_unwind: Option<u32>,
     /// Specify the value used for loop unwinding for the specified harness in CBMC
     #[arg(long, requires("harnesses"))]
     pub unwind: Option<u32>,
+    /// Report the warnings of every diagnostic category as errors, i.e., deny all of them.
+    /// Warnings that don't belong to a category are not affected.
+    #[arg(long, hide_short_help = true, alias = "deny-all-categories")]
+    pub deny_warnings: bool,
+    /// Report the warnings of the given diagnostic category as errors.
+    /// This option can be provided multiple times.
+    #[arg(long, hide_short_help = true, value_name = "CATEGORY")]
+    pub deny: Vec<DiagnosticCategory>,
     /// Specify the CBMC solver to use. Overrides the harness `solver` attribute.
     /// If no solver is specified (with --solver or harness attribute), Kani will use CaDiCaL.
     #[arg(long, value_parser = CbmcSolverValueParser::new(CbmcSolver::VARIANTS))]
     pub solver: Option<CbmcSolver>,
     /// Pass through directly to CBMC; must be the last flag.
+    /// Every argument after it is forwarded verbatim to CBMC, after the flags generated by Kani.
+    /// Flags that control the format of CBMC's output are ignored unless `--output-format old`
+    /// is used.
     /// This feature is unstable and it requires `--enable_unstable` to be used
     #[arg(
         long,
@@ -264,6 +355,23 @@ pub struct VerificationArgs {
     #[arg(short, long, hide_short_help = true, requires("enable_unstable"))]
     pub jobs: Option<Option<usize>>,
 
+    /// Stop the verification as soon as one harness fails. Harnesses that have not started are
+    /// not verified, and harnesses that are being verified in parallel are allowed to finish
+    /// unless `--fail-fast-kill` is given.
+    #[arg(long)]
+    pub fail_fast: bool,
+
+    /// With `--fail-fast`, stop the harnesses that are still being verified when one fails
+    /// instead of waiting for them to finish.
+    #[arg(long, hide_short_help = true, requires("fail_fast"))]
+    pub fail_fast_kill: bool,
+
+    /// Keep verifying the other harnesses when Kani fails to generate the model of a harness,
+    /// e.g., because the compiler panicked. The harness is reported as an error with the message
+    /// of the failure, and the run fails.
+    #[arg(long, hide_short_help = true)]
+    pub keep_going: bool,
+
     /// Enable extra pointer checks such as invalid pointers in relation operations and pointer
     /// arithmetic overflow.
     /// This feature is unstable and it may yield false counter examples. It requires
@@ -278,18 +386,108 @@ pub struct VerificationArgs {
     /// Disable restricting the targets of virtual table function pointer calls
     #[arg(long, hide_short_help = true)]
     pub no_restrict_vtable: bool,
-    /// Turn off assertion reachability checks
+    /// Turn off assertion reachability checks. Cover properties are not affected.
     #[arg(long)]
     pub no_assertion_reach_checks: bool,
+    /// Turn off the satisfiability checks of `kani::assume_satisfiable`, which then behaves like
+    /// `kani::assume`.
+    #[arg(long, hide_short_help = true)]
+    pub no_assume_satisfiable_checks: bool,
+    /// Turn off the notes about `assert!` and `debug_assert!` from the standard library in the
+    /// verified crate, which are checked as panics. Verification is not affected.
+    #[arg(long, hide_short_help = true)]
+    pub no_std_assert_notes: bool,
 
     /// Do not error out for crates containing `global_asm!`.
-    /// This option may impact the soundness of the analysis and may cause false proofs and/or counterexamples
+    /// This option may impact the soundness of the analysis and may cause false proofs and/or counterexamples.
+    /// Calls to functions defined by the ignored assembly fail as unsupported constructs if reached.
     #[arg(long, hide_short_help = true, requires("enable_unstable"))]
     pub ignore_global_asm: bool,
 
-    /// Write the GotoC symbol table to a file in JSON format instead of goto binary format.
+    /// Write the GotoC symbol table of each selected harness in JSON format to the given
+    /// directory. Each harness gets its own `<crate>__<harness>.symtab.json` file.
+    #[arg(long, hide_short_help = true, value_name = "DIR")]
+    pub write_json_symtab: Option<PathBuf>,
+
+    /// Write the GOTO binary that is verified for the selected harness to the given path, and the
+    /// CBMC command used to verify it to a script with the same name and the `.sh` extension.
+    /// This allows reproducing the result with CBMC directly, e.g., to report a CBMC bug.
+    /// Requires exactly one harness to be selected.
+    #[arg(long, hide_short_help = true, value_name = "PATH")]
+    pub export_goto: Option<PathBuf>,
+
+    /// Write a C rendition of the GOTO program that is verified for the selected harness to the
+    /// given path, as produced by `goto-instrument --dump-c`. This is a debugging aid: the C code
+    /// is not guaranteed to compile or to have the same semantics as the verified program.
+    /// Requires exactly one harness to be selected.
+    #[arg(long, hide_short_help = true, value_name = "PATH")]
+    pub dump_c: Option<PathBuf>,
+
+    /// Render the verification results saved with `--output-format json` in the given file again,
+    /// instead of verifying a crate. This neither compiles the crate nor runs CBMC, so results can
+    /// be inspected with the current output format, e.g., after they were attached to an issue.
+    #[arg(long, hide_short_help = true, value_name = "FILE")]
+    pub replay_trace: Option<PathBuf>,
+
+    /// Run the given command once all the harnesses are verified, with their results on its
+    /// standard input, one line per harness in the format of `--output-format json`. A nonzero
+    /// exit status of the command is reported as a warning, unless `--reporter-fail-on-error` is
+    /// given.
+    #[arg(long, hide_short_help = true, value_name = "PATH")]
+    pub reporter: Option<PathBuf>,
+
+    /// Run the `--reporter` command once for each harness, as soon as it is verified, with only
+    /// the result of that harness, instead of once for the whole run.
+    #[arg(long, hide_short_help = true, requires("reporter"))]
+    pub reporter_per_harness: bool,
+
+    /// Fail the run if the `--reporter` command exits with a nonzero status.
+    #[arg(long, hide_short_help = true, requires("reporter"))]
+    pub reporter_fail_on_error: bool,
+
+    /// Print the MIR that Kani generates code for, for every item reachable from the given
+    /// harness. The harness can be given by its fully qualified name or by a suffix of it.
+    #[arg(long, hide_short_help = true, value_name = "HARNESS")]
+    pub dump_mir_for_harness: Option<String>,
+
+    /// Replace some standard library functions, such as `<[T]>::binary_search`, by abstractions
+    /// that only model their postconditions. These abstractions are only sound if the
+    /// preconditions documented by the standard library hold, e.g., the slice is sorted.
     #[arg(long, hide_short_help = true)]
-    pub write_json_symtab: bool,
+    pub std_abstractions: bool,
+
+    /// Model `std::collections::HashMap` as an association list: every key gets the same hash, so
+    /// lookups compare keys with `PartialEq` instead of running the hasher. Inserting a new key
+    /// into a map that already has `--hashmap-capacity` entries fails verification.
+    #[arg(long, hide_short_help = true, requires("enable_unstable"))]
+    pub hashmap_abstraction: bool,
+
+    /// The maximum number of entries of a map modeled by `--hashmap-abstraction`.
+    /// [default: 8]
+    #[arg(long, hide_short_help = true, requires("hashmap_abstraction"), value_name = "N")]
+    pub hashmap_capacity: Option<usize>,
+
+    /// Pin the symbolic value created by `kani::concrete::value(default, id)` for the given id.
+    /// Use `ID` to pin it to its default value, or `ID=VALUE` to pin it to the given value.
+    /// Calls to `kani::any()` can also be pinned with `ID=VALUE`, where `ID` is the identifier
+    /// of the call site reported by `--output-format json`, e.g., `check::any#0`.
+    /// This option can be provided multiple times.
+    #[arg(long, hide_short_help = true, value_name = "ID[=VALUE]")]
+    pub fix: Vec<String>,
+
+    /// Assume that the functions from the given crate never panic, and don't report their panics,
+    /// including failed assertions, overflows and out of bounds accesses. This is unsound if the
+    /// crate can panic, so only use it to focus a proof on the code that uses the crate. Panics in
+    /// the crate that contains the harness are always checked. This option can be provided
+    /// multiple times.
+    #[arg(long, hide_short_help = true, value_name = "CRATE")]
+    pub assume_panic_free: Vec<String>,
+
+    /// Assume that memory allocations never fail, and prune the paths that handle allocation
+    /// failures, e.g., the abort in `Vec::push`. By default, these paths are checked, and the
+    /// allocation failure checks are listed in a separate section of the results.
+    #[arg(long, hide_short_help = true)]
+    pub assume_alloc_never_fails: bool,
 
     /// Execute CBMC's sanity checks to ensure the goto-program we generate is correct.
     #[arg(long, hide_short_help = true, requires("enable_unstable"))]
@@ -299,6 +497,13 @@ pub struct VerificationArgs {
     #[arg(long, hide_short_help = true, requires("enable_unstable"))]
     pub no_slice_formula: bool,
 
+    /// Remove the `kani::assume` calls that only constrain values that no property depends on,
+    /// so CBMC's formula slicing can drop them together with the computation of their conditions.
+    /// This reduces the solver time of proofs with many local assumptions. This option is
+    /// unstable and requires `-Z unstable-options`.
+    #[arg(long, hide_short_help = true, conflicts_with("no_slice_formula"))]
+    pub enable_slicing: bool,
+
     /// Synthesize loop contracts for all loops.
     #[arg(
         long,
@@ -313,10 +518,32 @@ pub struct VerificationArgs {
     #[arg(long, hide_short_help = true)]
     pub no_assert_contracts: bool,
 
+    /// Only verify the contract of the given function, by running the harnesses annotated with
+    /// `#[kani::proof_for_contract(FUNCTION)]`. If there is no such harness, Kani generates one
+    /// that calls the function with arbitrary arguments. Requires -Z function-contracts.
+    #[arg(long, hide_short_help = true, value_name = "FUNCTION", conflicts_with = "harnesses")]
+    pub check_contract: Option<String>,
+
     //Harness Output into individual files
     #[arg(long, hide_short_help = true)]
     pub output_into_files: bool,
 
+    /// Write the verification output of each harness to `<DIR>/<mangled_name>.txt`, and only
+    /// print the verification result of each harness to the standard output.
+    /// This option does not work with `--output-format old`.
+    #[arg(long, hide_short_help = true, value_name = "DIR")]
+    pub split_output: Option<PathBuf>,
+
+    /// Only print the status line of each harness and how many of its properties failed, without
+    /// listing the individual properties. This applies to every `--output-format`.
+    #[arg(long, hide_short_help = true)]
+    pub summary_only: bool,
+
+    /// Write the `kani-compiler` invocations used to build the project to the given path, in the
+    /// JSON compilation database format (`compile_commands.json`).
+    #[arg(long, hide_short_help = true, value_name = "PATH")]
+    pub write_compile_commands: Option<PathBuf>,
+
     /// Randomize the layout of structures. This option can help catching code that relies on
     /// a specific layout chosen by the compiler that is not guaranteed to be stable in the future.
     /// If a value is given, it will be used as the seed for randomization
@@ -336,6 +563,27 @@ pub struct VerificationArgs {
     #[arg(long)]
     pub harness_timeout: Option<Timeout>,
 
+    /// Maximum size of the address space of CBMC for each harness, with optional suffix ('K', 'M'
+    /// or 'G'). Default is bytes. This is a hard limit enforced by the operating system, so CBMC
+    /// fails with an out of memory error when an allocation goes over it. It is only supported on
+    /// Linux. See `--max-memory` to have Kani stop CBMC instead. This option is experimental and
+    /// requires `-Z unstable-options` to be used.
+    #[arg(long, hide_short_help = true, value_name = "SIZE")]
+    pub memory_limit: Option<MemoryLimit>,
+
+    /// Maximum resident memory in megabytes that CBMC may use for each harness. Kani monitors
+    /// CBMC and stops it when it goes over the limit, reporting the harness as
+    /// `MEMORY LIMIT EXCEEDED`. This option is experimental and requires `-Z unstable-options` to
+    /// be used.
+    #[arg(long, hide_short_help = true, value_name = "MB", value_parser = clap::value_parser!(u64).range(1..))]
+    pub max_memory: Option<u64>,
+
+    /// Seed for the SAT solver, to reproduce its behavior across runs. Only solvers that accept a
+    /// seed use it, i.e., `kissat`. This option is experimental and requires
+    /// `-Z unstable-options` to be used.
+    #[arg(long, hide_short_help = true, value_name = "SEED")]
+    pub cbmc_seed: Option<u64>,
+
     /// Arguments to pass down to Cargo
     #[command(flatten)]
     pub cargo: CargoCommonArgs,
@@ -356,7 +604,7 @@ pub fn restrict_vtable(&self) -> bool {
 
     /// Assertion reachability checks should be disabled
     pub fn assertion_reach_checks(&self) -> bool {
-        !self.no_assertion_reach_checks
+        !self.no_assertion_reach_checks && !self.checks.cover_only
     }
 
     /// Suppress our default value, if the user has supplied it explicitly in --cbmc-args
@@ -368,6 +616,24 @@ pub fn cbmc_object_bits(&self) -> Option<u32> {
         }
     }
 
+    /// The capacity of the maps modeled by `--hashmap-abstraction`, or `None` if the abstraction
+    /// is disabled.
+    pub fn hashmap_capacity(&self) -> Option<usize> {
+        self.hashmap_abstraction.then(|| self.hashmap_capacity.unwrap_or(DEFAULT_HASHMAP_CAPACITY))
+    }
+
+    /// Whether CBMC prints its output directly to the terminal, instead of Kani processing it.
+    /// This is the case for `--output-format old`, unless `--summary-only` is given, since Kani
+    /// needs to process the output to summarize it.
+    pub fn raw_cbmc_output(&self) -> bool {
+        self.output_format == OutputFormat::Old && !self.summary_only
+    }
+
+    /// The limit given to `--max-memory` in bytes, if any.
+    pub fn max_memory_bytes(&self) -> Option<u64> {
+        self.max_memory.map(|megabytes| megabytes.saturating_mul(1 << 20))
+    }
+
     /// Computes how many threads should be used to verify harnesses.
     pub fn jobs(&self) -> Option<usize> {
         match self.jobs {
@@ -387,6 +653,20 @@ pub fn is_stubbing_enabled(&self) -> bool {
         self.common_args.unstable_features.contains(UnstableFeature::Stubbing)
             || self.is_function_contracts_enabled()
     }
+
+    /// The option that turns the warnings of `category` into errors, if any was given.
+    pub fn denied_by(&self, category: DiagnosticCategory) -> Option<String> {
+        if self.deny_warnings {
+            Some("--deny-warnings".to_string())
+        } else {
+            self.deny.contains(&category).then(|| format!("--deny {category}"))
+        }
+    }
+
+    /// Whether harnesses that don't check any property are reported as failures.
+    pub fn is_strict_no_properties(&self) -> bool {
+        self.strict_no_properties || self.denied_by(DiagnosticCategory::Vacuous).is_some()
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
@@ -402,6 +682,20 @@ pub enum OutputFormat {
     Regular,
     Terse,
     Old,
+    /// Print the result of each harness, including the traces of failed properties, as a single
+    /// line of JSON.
+    Json,
+    /// Print the regular output, followed by a GitHub Actions `::error` workflow command for each
+    /// failed property, which GitHub shows as an annotation on the pull request.
+    Github,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum MessageFormat {
+    Human,
+    /// Newline-delimited JSON messages, which are documented in
+    /// `docs/src/reference/json-messages.md`.
+    Json,
 }
 
 #[derive(Debug, clap::Args)]
@@ -442,21 +736,28 @@ pub struct CheckArgs {
     /// Turn off default unwinding checks
     #[arg(long)]
     pub no_unwinding_checks: bool,
+
+    /// Only check the cover properties, e.g., `kani::cover!`, to find out which of them are
+    /// satisfiable. This turns off all default checks, as well as user assertions.
+    #[arg(long, conflicts_with_all(["default_checks", "memory_safety_checks", "overflow_checks", "undefined_function_checks", "unwinding_checks"]))]
+    pub cover_only: bool,
 }
 
 impl CheckArgs {
     pub fn memory_safety_on(&self) -> bool {
-        !self.no_default_checks && !self.no_memory_safety_checks || self.memory_safety_checks
+        self.defaults_on() && !self.no_memory_safety_checks || self.memory_safety_checks
     }
     pub fn overflow_on(&self) -> bool {
-        !self.no_default_checks && !self.no_overflow_checks || self.overflow_checks
+        self.defaults_on() && !self.no_overflow_checks || self.overflow_checks
     }
     pub fn undefined_function_on(&self) -> bool {
-        !self.no_default_checks && !self.no_undefined_function_checks
-            || self.undefined_function_checks
+        self.defaults_on() && !self.no_undefined_function_checks || self.undefined_function_checks
     }
     pub fn unwinding_on(&self) -> bool {
-        !self.no_default_checks && !self.no_unwinding_checks || self.unwinding_checks
+        self.defaults_on() && !self.no_unwinding_checks || self.unwinding_checks
+    }
+    fn defaults_on(&self) -> bool {
+        !self.no_default_checks && !self.cover_only
     }
 }
 
@@ -572,10 +873,6 @@ fn validate(&self) -> Result<(), Error> {
             );
         }
 
-        if self.write_json_symtab {
-            print_obsolete(&self.common_args, "--write-json-symtab");
-        }
-
         // TODO: these conflicting flags reflect what's necessary to pass current tests unmodified.
         // We should consider improving the error messages slightly in a later pull request.
         if natives_unwind && extra_unwind {
@@ -603,6 +900,63 @@ fn validate(&self) -> Result<(), Error> {
                 --output-format=old.",
             ));
         }
+        if self.run.is_some() {
+            let conflict = if !self.harnesses.is_empty() {
+                Some("--harness")
+            } else if self.concrete_playback.is_some() {
+                Some("--concrete-playback")
+            } else if self.jobs() != Some(1) {
+                Some("--jobs")
+            } else if self.output_format == OutputFormat::Old {
+                Some("--output-format=old")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                return Err(Error::raw(
+                    ErrorKind::ArgumentConflict,
+                    format!("Conflicting options: --run isn't compatible with {conflict}."),
+                ));
+            }
+            if !self.common_args.unstable_features.contains(UnstableFeature::ConcretePlayback) {
+                return Err(Error::raw(
+                    ErrorKind::MissingRequiredArgument,
+                    "The `--run` argument is unstable and requires `-Z concrete-playback` to be \
+                    used.",
+                ));
+            }
+        }
+        if self.message_format == MessageFormat::Json && self.output_format == OutputFormat::Old {
+            return Err(Error::raw(
+                ErrorKind::ArgumentConflict,
+                "Conflicting options: --message-format=json isn't compatible with \
+                --output-format=old.",
+            ));
+        }
+        if self.replay_trace.is_some() && self.output_format == OutputFormat::Old {
+            return Err(Error::raw(
+                ErrorKind::ArgumentConflict,
+                "Conflicting options: --replay-trace isn't compatible with --output-format=old.",
+            ));
+        }
+        if self.reporter.is_some() && self.output_format == OutputFormat::Old {
+            return Err(Error::raw(
+                ErrorKind::ArgumentConflict,
+                "Conflicting options: --reporter isn't compatible with --output-format=old.",
+            ));
+        }
+        if self.split_output.is_some() && self.output_format == OutputFormat::Old {
+            return Err(Error::raw(
+                ErrorKind::ArgumentConflict,
+                "Conflicting options: --split-output isn't compatible with --output-format=old.",
+            ));
+        }
+        if self.summary_only && self.output_format == OutputFormat::Json {
+            return Err(Error::raw(
+                ErrorKind::ArgumentConflict,
+                "Conflicting options: --summary-only isn't compatible with --output-format=json.",
+            ));
+        }
         if self.concrete_playback.is_some() && self.jobs() != Some(1) {
             // Concrete playback currently embeds a lot of assumptions about the order in which harnesses get called.
             return Err(Error::raw(
@@ -695,6 +1049,152 @@ fn validate(&self) -> Result<(), Error> {
             ));
         }
 
+        if self.std_abstractions
+            && !self.common_args.unstable_features.contains(UnstableFeature::UnstableOptions)
+        {
+            return Err(Error::raw(
+                ErrorKind::MissingRequiredArgument,
+                format!(
+                    "The `--std-abstractions` argument is unstable and requires `-Z {}` to be used.",
+                    UnstableFeature::UnstableOptions
+                ),
+            ));
+        }
+
+        if self.hashmap_capacity == Some(0) {
+            return Err(Error::raw(
+                ErrorKind::InvalidValue,
+                "The `--hashmap-capacity` argument must be greater than zero.",
+            ));
+        }
+
+        if !self.fix.is_empty() {
+            if !self.common_args.unstable_features.contains(UnstableFeature::UnstableOptions) {
+                return Err(Error::raw(
+                    ErrorKind::MissingRequiredArgument,
+                    format!(
+                        "The `--fix` argument is unstable and requires `-Z {}` to be used.",
+                        UnstableFeature::UnstableOptions
+                    ),
+                ));
+            }
+            let mut pins = HashMap::new();
+            for fix in &self.fix {
+                let (id, value) = match fix.split_once('=') {
+                    Some((id, value)) => (id, Some(value)),
+                    None => (fix.as_str(), None),
+                };
+                if id.is_empty() {
+                    return Err(Error::raw(
+                        ErrorKind::InvalidValue,
+                        format!("Invalid value `{fix}` for `--fix`: expected `ID` or `ID=VALUE`."),
+                    ));
+                }
+                if let Some(previous) = pins.insert(id, value) {
+                    if previous != value {
+                        return Err(Error::raw(
+                            ErrorKind::ArgumentConflict,
+                            format!("The value of `{id}` was pinned more than once with `--fix`."),
+                        ));
+                    }
+                }
+            }
+        }
+
+        if !self.assume_panic_free.is_empty()
+            && !self.common_args.unstable_features.contains(UnstableFeature::UnstableOptions)
+        {
+            return Err(Error::raw(
+                ErrorKind::MissingRequiredArgument,
+                format!(
+                    "The `--assume-panic-free` argument is unstable and requires `-Z {}` to be used.",
+                    UnstableFeature::UnstableOptions
+                ),
+            ));
+        }
+
+        if self.assume_alloc_never_fails
+            && !self.common_args.unstable_features.contains(UnstableFeature::UnstableOptions)
+        {
+            return Err(Error::raw(
+                ErrorKind::MissingRequiredArgument,
+                format!(
+                    "The `--assume-alloc-never-fails` argument is unstable and requires `-Z {}` to \
+                    be used.",
+                    UnstableFeature::UnstableOptions
+                ),
+            ));
+        }
+
+        if self.enable_slicing
+            && !self.common_args.unstable_features.contains(UnstableFeature::UnstableOptions)
+        {
+            return Err(Error::raw(
+                ErrorKind::MissingRequiredArgument,
+                format!(
+                    "The `--enable-slicing` argument is unstable and requires `-Z {}` to be used.",
+                    UnstableFeature::UnstableOptions
+                ),
+            ));
+        }
+
+        if self.memory_limit.is_some() {
+            if !self.common_args.unstable_features.contains(UnstableFeature::UnstableOptions) {
+                return Err(Error::raw(
+                    ErrorKind::MissingRequiredArgument,
+                    format!(
+                        "The `--memory-limit` argument is unstable and requires `-Z {}` to be used.",
+                        UnstableFeature::UnstableOptions
+                    ),
+                ));
+            }
+            // Other platforms, e.g., macOS, accept a limit on the address space but don't enforce
+            // it.
+            if !cfg!(target_os = "linux") {
+                return Err(Error::raw(
+                    ErrorKind::InvalidValue,
+                    "The `--memory-limit` argument is only supported on Linux. Use `--max-memory` \
+                    instead.",
+                ));
+            }
+        }
+
+        if self.cbmc_seed.is_some()
+            && !self.common_args.unstable_features.contains(UnstableFeature::UnstableOptions)
+        {
+            return Err(Error::raw(
+                ErrorKind::MissingRequiredArgument,
+                format!(
+                    "The `--cbmc-seed` argument is unstable and requires `-Z {}` to be used.",
+                    UnstableFeature::UnstableOptions
+                ),
+            ));
+        }
+
+        if self.max_memory.is_some() {
+            if !self.common_args.unstable_features.contains(UnstableFeature::UnstableOptions) {
+                return Err(Error::raw(
+                    ErrorKind::MissingRequiredArgument,
+                    format!(
+                        "The `--max-memory` argument is unstable and requires `-Z {}` to be used.",
+                        UnstableFeature::UnstableOptions
+                    ),
+                ));
+            }
+            if !cfg!(any(target_os = "linux", target_os = "macos")) {
+                return Err(Error::raw(
+                    ErrorKind::InvalidValue,
+                    "The `--max-memory` argument is only supported on Linux and macOS.",
+                ));
+            }
+            if self.raw_cbmc_output() {
+                return Err(Error::raw(
+                    ErrorKind::ArgumentConflict,
+                    "Conflicting options: --max-memory isn't compatible with --output-format=old.",
+                ));
+            }
+        }
+
         if self.harness_timeout.is_some()
             && !self.common_args.unstable_features.contains(UnstableFeature::UnstableOptions)
         {
@@ -717,6 +1217,16 @@ fn validate(&self) -> Result<(), Error> {
             ));
         }
 
+        if !self.is_function_contracts_enabled() && self.check_contract.is_some() {
+            return Err(Error::raw(
+                ErrorKind::MissingRequiredArgument,
+                format!(
+                    "The `--check-contract` option requires `-Z {}`.",
+                    UnstableFeature::FunctionContracts
+                ),
+            ));
+        }
+
         Ok(())
     }
 }
@@ -1022,4 +1532,299 @@ fn check_no_assert_contracts() {
         let err = StandaloneArgs::try_parse_from(args).unwrap().validate().unwrap_err();
         assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
     }
+
+    #[test]
+    fn check_check_contract() {
+        let args = "kani input.rs --check-contract foo".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+
+        let args = "kani input.rs -Z function-contracts --check-contract foo --harness bar"
+            .split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+
+        let args = "kani input.rs -Z function-contracts --check-contract foo --unwind 3"
+            .split_whitespace();
+        let parsed = StandaloneArgs::try_parse_from(args).unwrap();
+        assert_eq!(parsed.verify_opts.check_contract.as_deref(), Some("foo"));
+    }
+
+    #[test]
+    fn check_std_abstractions() {
+        let args = "kani input.rs --std-abstractions".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+
+        let args = "kani input.rs -Z unstable-options --std-abstractions".split_whitespace();
+        let parsed = StandaloneArgs::try_parse_from(args).unwrap();
+        assert!(parsed.verify_opts.std_abstractions);
+    }
+
+    #[test]
+    fn check_hashmap_abstraction() {
+        let args = "kani input.rs --hashmap-abstraction".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+
+        let args = "kani input.rs --enable-unstable --hashmap-capacity 4".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+
+        let args = "kani input.rs --enable-unstable --hashmap-abstraction".split_whitespace();
+        let parsed = StandaloneArgs::try_parse_from(args).unwrap();
+        assert_eq!(parsed.verify_opts.hashmap_capacity(), Some(DEFAULT_HASHMAP_CAPACITY));
+
+        let args = "kani input.rs --enable-unstable --hashmap-abstraction --hashmap-capacity 2"
+            .split_whitespace();
+        let parsed = StandaloneArgs::try_parse_from(args).unwrap();
+        assert_eq!(parsed.verify_opts.hashmap_capacity(), Some(2));
+
+        let args = "kani input.rs --enable-unstable --hashmap-abstraction --hashmap-capacity 0"
+            .split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().verify_opts.validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn check_assume_panic_free() {
+        let args = "kani input.rs --assume-panic-free my_dep".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+
+        let args =
+            "kani input.rs -Z unstable-options --assume-panic-free my-dep --assume-panic-free other"
+                .split_whitespace();
+        let parsed = StandaloneArgs::try_parse_from(args).unwrap();
+        assert!(parsed.verify_opts.validate().is_ok());
+        assert_eq!(parsed.verify_opts.assume_panic_free, ["my-dep", "other"]);
+    }
+
+    #[test]
+    fn check_memory_limit() {
+        assert_eq!(MemoryLimit::from_str("100").unwrap().bytes(), 100);
+        assert_eq!(MemoryLimit::from_str("4K").unwrap().bytes(), 4096);
+        assert_eq!(MemoryLimit::from_str("8G").unwrap().bytes(), 8 << 30);
+        assert!(MemoryLimit::from_str("0M").is_err());
+        assert!(MemoryLimit::from_str("8T").is_err());
+        assert!(MemoryLimit::from_str("").is_err());
+
+        let args = "kani input.rs --memory-limit 8G".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+
+        // The last value wins, which gives precedence to the command line over `Cargo.toml`.
+        let args = "kani input.rs -Z unstable-options --memory-limit 8G --memory-limit 512M"
+            .split_whitespace();
+        let parsed = StandaloneArgs::try_parse_from(args).unwrap();
+        assert_eq!(parsed.verify_opts.memory_limit.unwrap().bytes(), 512 << 20);
+        // The limit isn't enforced on other platforms.
+        assert_eq!(parsed.verify_opts.validate().is_ok(), cfg!(target_os = "linux"));
+    }
+
+    #[test]
+    fn check_max_memory() {
+        let args = "kani input.rs --max-memory 512".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+
+        let args = "kani input.rs -Z unstable-options --max-memory 0".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+
+        let args = "kani input.rs -Z unstable-options --max-memory 512 --output-format old"
+            .split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+
+        let args = "kani input.rs -Z unstable-options --max-memory 512".split_whitespace();
+        let parsed = StandaloneArgs::try_parse_from(args).unwrap();
+        assert!(parsed.verify_opts.validate().is_ok());
+        assert_eq!(parsed.verify_opts.max_memory_bytes(), Some(512 << 20));
+    }
+
+    #[test]
+    fn check_cbmc_seed() {
+        let args = "kani input.rs --cbmc-seed 42".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+
+        let args = "kani input.rs -Z unstable-options --cbmc-seed 42".split_whitespace();
+        let parsed = StandaloneArgs::try_parse_from(args).unwrap();
+        assert!(parsed.verify_opts.validate().is_ok());
+        assert_eq!(parsed.verify_opts.cbmc_seed, Some(42));
+    }
+
+    #[test]
+    fn check_json_output_format() {
+        let args = "kani input.rs --output-format json".split_whitespace();
+        let parsed = StandaloneArgs::try_parse_from(args).unwrap();
+        assert_eq!(parsed.verify_opts.output_format, OutputFormat::Json);
+        assert!(parsed.verify_opts.validate().is_ok());
+
+        let args = "kani input.rs --output-format json --summary-only".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn check_replay_trace() {
+        let args = "kani --replay-trace results.json".split_whitespace();
+        let parsed = StandaloneArgs::try_parse_from(args).unwrap();
+        assert_eq!(parsed.input, None);
+        assert!(parsed.verify_opts.validate().is_ok());
+
+        let args = "kani --replay-trace results.json --output-format old".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().verify_opts.validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+
+        assert!(
+            StandaloneArgs::try_parse_from("kani --output-format json".split_whitespace()).is_err()
+        );
+    }
+
+    #[test]
+    fn check_reporter() {
+        let args = "kani input.rs --reporter ./report.sh --reporter-per-harness".split_whitespace();
+        let parsed = StandaloneArgs::try_parse_from(args).unwrap();
+        assert!(parsed.verify_opts.validate().is_ok());
+        assert!(parsed.verify_opts.reporter_per_harness);
+        assert!(!parsed.verify_opts.reporter_fail_on_error);
+
+        let args = "kani input.rs --reporter-fail-on-error".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+
+        let args = "kani input.rs --reporter ./report.sh --output-format old".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().verify_opts.validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn check_split_output() {
+        let args = "kani input.rs --split-output out".split_whitespace();
+        let parsed = StandaloneArgs::try_parse_from(args).unwrap();
+        assert!(parsed.verify_opts.validate().is_ok());
+
+        let args = "kani input.rs --split-output out --output-format old".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().verify_opts.validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn check_run() {
+        let args = "kani input.rs --run check".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+
+        let args = "kani input.rs -Z concrete-playback --run check".split_whitespace();
+        let parsed = StandaloneArgs::try_parse_from(args).unwrap();
+        assert!(parsed.verify_opts.validate().is_ok());
+        assert_eq!(parsed.verify_opts.run.as_deref(), Some("check"));
+
+        for conflict in ["--harness other", "--concrete-playback print", "--output-format old"] {
+            let args = format!("kani input.rs -Z concrete-playback --run check {conflict}");
+            let err = StandaloneArgs::try_parse_from(args.split_whitespace())
+                .unwrap()
+                .validate()
+                .unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+        }
+    }
+
+    #[test]
+    fn check_assume_alloc_never_fails() {
+        let args = "kani input.rs --assume-alloc-never-fails".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+
+        let args =
+            "kani input.rs -Z unstable-options --assume-alloc-never-fails".split_whitespace();
+        let parsed = StandaloneArgs::try_parse_from(args).unwrap();
+        assert!(parsed.verify_opts.validate().is_ok());
+        assert!(parsed.verify_opts.assume_alloc_never_fails);
+    }
+
+    #[test]
+    fn check_deny() {
+        let args = "kani input.rs --deny recursion --deny unsupported-soft".split_whitespace();
+        let parsed = StandaloneArgs::try_parse_from(args).unwrap().verify_opts;
+        assert_eq!(
+            parsed.denied_by(DiagnosticCategory::Recursion).as_deref(),
+            Some("--deny recursion")
+        );
+        assert!(parsed.denied_by(DiagnosticCategory::UnsupportedSoft).is_some());
+        assert!(parsed.denied_by(DiagnosticCategory::Vacuous).is_none());
+        assert!(!parsed.is_strict_no_properties());
+
+        // `--deny-warnings` denies every category.
+        let args = "kani input.rs --deny-warnings".split_whitespace();
+        let parsed = StandaloneArgs::try_parse_from(args).unwrap().verify_opts;
+        assert_eq!(
+            parsed.denied_by(DiagnosticCategory::Concurrency).as_deref(),
+            Some("--deny-warnings")
+        );
+        assert!(parsed.is_strict_no_properties());
+
+        // `--deny-all-categories` is an alias of `--deny-warnings`.
+        let args = "kani input.rs --deny-all-categories".split_whitespace();
+        let parsed = StandaloneArgs::try_parse_from(args).unwrap().verify_opts;
+        assert!(parsed.deny_warnings);
+
+        let args = "kani input.rs --deny unknown".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn check_fix() {
+        let args = "kani input.rs --fix len".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+
+        let args = "kani input.rs -Z unstable-options --fix len --fix idx=3 --fix len";
+        let parsed = StandaloneArgs::try_parse_from(args.split_whitespace()).unwrap();
+        assert!(parsed.verify_opts.validate().is_ok());
+        assert_eq!(parsed.verify_opts.fix, ["len", "idx=3", "len"]);
+
+        let args = "kani input.rs -Z unstable-options --fix idx=3 --fix idx=4".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+
+        let args = "kani input.rs -Z unstable-options --fix =3".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn check_enable_slicing() {
+        let args = "kani input.rs --enable-slicing".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap().validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+
+        let args = "kani input.rs -Z unstable-options --enable-slicing".split_whitespace();
+        let parsed = StandaloneArgs::try_parse_from(args).unwrap();
+        assert!(parsed.verify_opts.validate().is_ok());
+        assert!(parsed.verify_opts.enable_slicing);
+
+        let args = "kani input.rs -Z unstable-options --enable-slicing --no-slice-formula"
+            .split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn check_fail_fast() {
+        let parsed = StandaloneArgs::try_parse_from("kani input.rs".split_whitespace()).unwrap();
+        assert!(!parsed.verify_opts.fail_fast);
+
+        let args = "kani input.rs --fail-fast --fail-fast-kill".split_whitespace();
+        let parsed = StandaloneArgs::try_parse_from(args).unwrap();
+        assert!(parsed.verify_opts.validate().is_ok());
+        assert!(parsed.verify_opts.fail_fast && parsed.verify_opts.fail_fast_kill);
+
+        let args = "kani input.rs --fail-fast-kill".split_whitespace();
+        let err = StandaloneArgs::try_parse_from(args).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+    }
 }
@@ -1,8 +1,10 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use crate::args::common::Verbosity;
+use crate::util::warning;
 use anyhow::{Result, bail};
-use clap::Parser;
+use clap::{CommandFactory, Parser};
 use std::collections::BTreeMap;
 use std::ffi::OsString;
 use std::path::PathBuf;
@@ -19,9 +21,25 @@ pub fn join_args(input_args: Vec<OsString>) -> Result<Vec<OsString>> {
         // We're not inside a Cargo project. Don't error... yet.
         return Ok(input_args);
     }
-    let file = std::fs::read_to_string(toml_path?)?;
+    let toml_path = toml_path?;
+    let file = std::fs::read_to_string(&toml_path)?;
     let (kani_args, cbmc_args) = toml_to_args(&file)?;
-    merge_args(input_args, kani_args, cbmc_args)
+    let config_args = [kani_args.as_slice(), cbmc_args.as_slice()].concat();
+    let merged_args = merge_args(input_args, kani_args, cbmc_args)?;
+    if !config_args.is_empty() && is_verbose(&merged_args) {
+        let config_args: Vec<_> = config_args.iter().map(|arg| arg.to_string_lossy()).collect();
+        println!("[Kani] Flags from `{}`: {}", toml_path.display(), config_args.join(" "));
+    }
+    Ok(merged_args)
+}
+
+/// Whether the final list of arguments enables verbose output.
+///
+/// If the arguments are invalid, we just return `false` and let the argument parsing report the
+/// error later.
+fn is_verbose(args: &[OsString]) -> bool {
+    crate::args::CargoKaniArgs::try_parse_from(args)
+        .is_ok_and(|args| args.verify_opts.common_args.verbose())
 }
 
 /// Join the arguments passed via command line with the ones found in the Cargo.toml.
@@ -88,7 +106,8 @@ fn cargo_locate_project(input_args: &[OsString]) -> Result<PathBuf> {
 /// Parse a config toml string and extract the cargo-kani arguments we should try injecting.
 /// This returns two different vectors since all cbmc-args have to be at the end.
 /// We currently support the following entries:
-/// - flags: Flags that get directly passed to Kani.
+/// - flags: Flags that get directly passed to Kani. `cbmc-args` and its alias `extra-cbmc-args`
+///   are passed to CBMC instead. Unknown flags are ignored with a warning.
 /// - unstable: Unstable features (it will be passed using `-Z` flag).
 ///
 /// The tables supported are:
@@ -127,12 +146,16 @@ fn toml_to_args(tomldata: &str) -> Result<(Vec<OsString>, Vec<OsString>)> {
     let mut cbmc_args = Vec::new();
 
     for (flag, value) in map {
-        if flag == "cbmc-args" {
+        if flag == "cbmc-args" || flag == "extra-cbmc-args" {
             // --cbmc-args has to come last because it eats all remaining arguments
-            cbmc_args.push("--cbmc-args".into());
+            if cbmc_args.is_empty() {
+                cbmc_args.push("--cbmc-args".into());
+            }
             cbmc_args.append(&mut cbmc_arg_from_toml(&value)?);
-        } else {
+        } else if is_known_flag(&flag, &value) {
             insert_arg_from_toml(&flag, &value, &mut args)?;
+        } else {
+            warning(&format!("Ignoring unknown flag `{flag}` found in the Kani configuration"));
         }
     }
 
@@ -148,6 +171,20 @@ fn unstable_entry(name: &String, value: &Value) -> Result<Option<OsString>> {
     }
 }
 
+/// Check whether the flag generated for the given toml entry is a known `cargo kani` argument.
+///
+/// Note that `no-<flag> = false` is checked as `no-<flag>`, so it is reported as an error instead of
+/// being ignored.
+fn is_known_flag(flag: &str, value: &Value) -> bool {
+    let flag = match value {
+        Value::Boolean(false) if !flag.starts_with("no-") => format!("no-{flag}"),
+        _ => flag.to_string(),
+    };
+    crate::args::CargoKaniArgs::command()
+        .get_arguments()
+        .any(|arg| arg.get_long() == Some(flag.as_str()))
+}
+
 /// Translates one toml entry (flag, value) into arguments and inserts it into `args`
 fn insert_arg_from_toml(flag: &str, value: &Value, args: &mut Vec<OsString>) -> Result<()> {
     match value {
@@ -209,6 +246,13 @@ fn cbmc_arg_from_toml(value: &Value) -> Result<Vec<OsString>> {
     Ok(args)
 }
 
+/// Return the panic strategy set by the `dev` profile of a manifest, which Kani builds with, if
+/// any.
+pub fn profile_panic_strategy(tomldata: &str) -> Option<String> {
+    let config = tomldata.parse::<Value>().ok()?;
+    get_table(&config, "profile.dev")?.get("panic")?.as_str().map(str::to_string)
+}
+
 /// Take 'a.b.c' and turn it into 'start['a']['b']['c']' reliably, and interpret the result as a table
 fn get_table<'a>(start: &'a Value, table: &str) -> Option<&'a Table> {
     let mut current = start;
@@ -278,13 +322,41 @@ fn check_multiple_table_works() {
                          disabled-feature=false
                          enabled-feature=true
                          [workspace.metadata.kani.flags]
-                         kani-arg=\"value\"
+                         harness=\"value\"
                          cbmc-args=[\"--dummy\"]";
         let (kani_args, cbmc_args) = toml_to_args(data).unwrap();
-        assert_eq!(kani_args, vec!["-Zenabled-feature", "--kani-arg", "value"]);
+        assert_eq!(kani_args, vec!["-Zenabled-feature", "--harness", "value"]);
         assert_eq!(cbmc_args, vec!["--cbmc-args", "--dummy"]);
     }
 
+    #[test]
+    fn check_cbmc_limits_and_unknown_flags() {
+        let data = "[package.metadata.kani.flags]
+                         memory-limit=\"8G\"
+                         unknown-flag=true
+                         extra-cbmc-args=[\"--slice-formula\"]
+                         cbmc-args=[\"--dummy\"]";
+        let (kani_args, cbmc_args) = toml_to_args(data).unwrap();
+        assert_eq!(kani_args, vec!["--memory-limit", "8G"]);
+        assert_eq!(cbmc_args, vec!["--cbmc-args", "--dummy", "--slice-formula"]);
+    }
+
+    #[test]
+    fn check_known_flags() {
+        assert!(is_known_flag("default-unwind", &Value::String("2".into())));
+        assert!(is_known_flag("default-checks", &Value::Boolean(false)));
+        assert!(!is_known_flag("fake-flag", &Value::Boolean(true)));
+        assert!(!is_known_flag("output-format", &Value::Boolean(false)));
+        assert!(is_known_flag("no-default-checks", &Value::Boolean(false)));
+    }
+
+    #[test]
+    fn check_disabled_disabling_flag() {
+        let data = "[package.metadata.kani.flags]
+                         no-default-checks=false";
+        assert!(toml_to_args(data).is_err());
+    }
+
     #[test]
     fn check_unstable_table_works() {
         let data = "[workspace.metadata.kani.unstable]
@@ -295,6 +367,14 @@ fn check_unstable_table_works() {
         assert!(cbmc_args.is_empty());
     }
 
+    #[test]
+    fn check_profile_panic_strategy() {
+        let abort = "[profile.dev]\npanic = \"abort\"\n[profile.release]\npanic = \"unwind\"";
+        assert_eq!(profile_panic_strategy(abort).as_deref(), Some("abort"));
+        assert_eq!(profile_panic_strategy("[profile.release]\npanic = \"abort\""), None);
+        assert_eq!(profile_panic_strategy("[package]\nname = \"foo\""), None);
+    }
+
     #[test]
     fn check_unstable_entry_enabled() -> Result<()> {
         let name = String::from("feature");
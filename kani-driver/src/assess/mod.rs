@@ -6,6 +6,7 @@
 use kani_metadata::KaniMetadata;
 
 use crate::assess::table_builder::TableBuilder;
+use crate::call_cargo::KeepGoing;
 use crate::metadata::merge_kani_metadata;
 use crate::project;
 use crate::session::KaniSession;
@@ -53,7 +54,7 @@ fn assess_project(mut session: KaniSession) -> Result<AssessMetadata> {
         session.args.jobs = Some(None); // -j, num_cpu
     }
 
-    let project = project::cargo_project(&session, true)?;
+    let project = project::cargo_project(&session, KeepGoing::Yes)?;
     let cargo_metadata = project.cargo_metadata.as_ref().expect("built with cargo");
 
     let packages_metadata =
@@ -35,11 +35,13 @@ pub(crate) fn build(results: &[HarnessResult]) -> TableBuilder<FailureReasonsTab
     let mut builder = TableBuilder::new();
 
     for r in results {
-        let classification = if let Err(exit_status) = r.result.results {
+        let classification = if let Err(exit_status) = &r.result.results {
             match exit_status {
                 ExitStatus::Timeout => String::from("CBMC timed out"),
                 ExitStatus::OutOfMemory => String::from("CBMC ran out of memory"),
+                ExitStatus::MemoryLimitExceeded => String::from("CBMC went over the memory limit"),
                 ExitStatus::Other(exit_code) => format!("CBMC failed with status {exit_code}"),
+                ExitStatus::CodegenError(_) => String::from("code generation failed"),
             }
         } else {
             let failures = r.result.failed_properties();
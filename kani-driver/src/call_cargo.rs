@@ -3,6 +3,7 @@
 
 use crate::args::VerificationArgs;
 use crate::call_single_file::{LibConfig, to_rustc_arg};
+use crate::compile_commands::CompileCommand;
 use crate::project::Artifact;
 use crate::session::{KaniSession, lib_folder, lib_no_core_folder, setup_cargo_command};
 use crate::util;
@@ -30,12 +31,26 @@ pub struct CargoOutputs {
     pub outdir: PathBuf,
     /// The kani-metadata.json files written by kani-compiler.
     pub metadata: Vec<Artifact>,
+    /// The subset of `metadata` that was written for integration test targets, with `--tests`.
+    pub integration_tests: Vec<Artifact>,
     /// Recording the cargo metadata from the build
     pub cargo_metadata: Metadata,
     /// For build `keep_going` mode, we collect the targets that we failed to compile.
     pub failed_targets: Option<Vec<String>>,
 }
 
+/// What `cargo_build` does when a target fails to compile.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum KeepGoing {
+    /// Stop at the first target that fails to compile.
+    No,
+    /// Build as many targets as possible, and collect the ones that failed to compile.
+    Yes,
+    /// Like [KeepGoing::Yes] if more than one package is built, e.g., when verifying a workspace,
+    /// so a package that fails to compile doesn't prevent the verification of the others.
+    IfMultiplePackages,
+}
+
 impl KaniSession {
     /// Create a new cargo library in the given path.
     ///
@@ -126,7 +141,7 @@ pub fn cargo_build_std(&self, std_path: &Path, krate_path: &Path) -> Result<Vec<
     }
 
     /// Calls `cargo_build` to generate `*.symtab.json` files in `target_dir`
-    pub fn cargo_build(&self, keep_going: bool) -> Result<CargoOutputs> {
+    pub fn cargo_build(&self, keep_going: KeepGoing) -> Result<CargoOutputs> {
         let build_target = env!("TARGET"); // see build.rs
         let metadata = self.cargo_metadata(build_target)?;
         let target_dir = self
@@ -194,7 +209,13 @@ pub fn cargo_build(&self, keep_going: bool) -> Result<CargoOutputs> {
 
         let mut found_target = false;
         let packages = self.packages_to_verify(&self.args, &metadata)?;
+        let keep_going = match keep_going {
+            KeepGoing::No => false,
+            KeepGoing::Yes => true,
+            KeepGoing::IfMultiplePackages => packages.len() > 1,
+        };
         let mut artifacts = vec![];
+        let mut integration_tests = vec![];
         let mut failed_targets = vec![];
         for package in packages {
             for verification_target in package_targets(&self.args, package) {
@@ -209,6 +230,15 @@ pub fn cargo_build(&self, keep_going: bool) -> Result<CargoOutputs> {
                     .env("CARGO_ENCODED_RUSTFLAGS", rustc_args.join(OsStr::new("\x1f")))
                     .env("CARGO_TERM_PROGRESS_WHEN", "never");
 
+                self.record_compile_command(compile_command(
+                    &self.kani_compiler,
+                    package,
+                    &verification_target,
+                    &rustc_args,
+                    &pkg_args,
+                    &outdir,
+                ));
+
                 match self.run_build_target(cmd, verification_target.target()) {
                     Err(err) => {
                         if keep_going {
@@ -219,7 +249,12 @@ pub fn cargo_build(&self, keep_going: bool) -> Result<CargoOutputs> {
                             return Err(err);
                         }
                     }
-                    Ok(Some(artifact)) => artifacts.push(artifact),
+                    Ok(Some(artifact)) => {
+                        if matches!(verification_target, VerificationTarget::Test(_)) {
+                            integration_tests.push(artifact.clone());
+                        }
+                        artifacts.push(artifact)
+                    }
                     Ok(None) => {}
                 }
                 found_target = true;
@@ -233,6 +268,7 @@ pub fn cargo_build(&self, keep_going: bool) -> Result<CargoOutputs> {
         Ok(CargoOutputs {
             outdir,
             metadata: artifacts,
+            integration_tests,
             cargo_metadata: metadata,
             failed_targets: keep_going.then_some(failed_targets),
         })
@@ -276,7 +312,22 @@ fn run_build(&self, cargo_cmd: Command) -> Result<Vec<RustcArtifact>> {
         let mut error_count = 0;
         for message in Message::parse_stream(reader) {
             let message = message.unwrap();
+            if self.json_messages() && !matches!(message, Message::TextLine(_)) {
+                // Forward cargo's own messages, which can be consumed alongside Kani's.
+                println!("{}", serde_json::to_string(&message)?);
+            }
             match message {
+                Message::CompilerMessage(msg) if self.json_messages() => {
+                    if msg.message.level == DiagnosticLevel::Error {
+                        error_count += 1;
+                    } else if msg.message.level == DiagnosticLevel::Ice {
+                        let _ = cargo_process.wait();
+                        return Err(anyhow::Error::msg(msg.message).context(format!(
+                            "Failed to compile `{}` due to an internal compiler error.",
+                            msg.target.name
+                        )));
+                    }
+                }
                 Message::CompilerMessage(msg) => match msg.message.level {
                     DiagnosticLevel::FailureNote => {
                         print_msg(&msg.message, support_color)?;
@@ -466,6 +517,37 @@ pub fn cargo_config_args() -> Vec<OsString> {
     .to_vec()
 }
 
+/// Build the compile command entry that represents the `kani-compiler` invocation performed by
+/// cargo for the given target.
+///
+/// Note that the `--extern` flags that cargo passes for each dependency are not included, since
+/// they are only known once cargo has resolved the build plan.
+fn compile_command(
+    kani_compiler: &Path,
+    package: &Package,
+    verification_target: &VerificationTarget,
+    rustc_args: &[OsString],
+    pkg_args: &[String],
+    outdir: &Path,
+) -> CompileCommand {
+    let target = verification_target.target();
+    let mut args = rustc_args.to_vec();
+    args.push("--crate-name".into());
+    args.push(target.name.replace('-', "_").into());
+    args.push(format!("--edition={}", target.edition).into());
+    args.extend(verification_target.to_rustc_args().into_iter().map(OsString::from));
+    args.extend(pkg_args.iter().filter(|arg| *arg != "--").map(OsString::from));
+    args.push("-L".into());
+    args.push(format!("dependency={}", outdir.display()).into());
+    args.push(target.src_path.as_os_str().into());
+    CompileCommand::new(
+        package.manifest_path.parent().unwrap().as_std_path(),
+        kani_compiler,
+        args,
+        target.src_path.as_std_path(),
+    )
+}
+
 /// Print the compiler message following the coloring schema.
 fn print_msg(diagnostic: &Diagnostic, use_rendered: bool) -> Result<()> {
     if use_rendered {
@@ -534,6 +616,15 @@ fn to_args(&self) -> Vec<String> {
         }
     }
 
+    /// Convert to the rustc arguments that cargo uses to compile the specific target.
+    fn to_rustc_args(&self) -> Vec<String> {
+        match self {
+            VerificationTarget::Test(_) => vec![String::from("--test")],
+            VerificationTarget::Bin(_) => vec![String::from("--crate-type"), String::from("bin")],
+            VerificationTarget::Lib(_) => vec![String::from("--crate-type"), String::from("lib")],
+        }
+    }
+
     fn target(&self) -> &Target {
         match self {
             VerificationTarget::Test(target)
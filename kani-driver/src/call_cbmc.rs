@@ -1,29 +1,33 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use anyhow::{Result, bail};
-use kani_metadata::{CbmcSolver, HarnessMetadata};
+use anyhow::{Context, Result, bail};
+use kani_metadata::{CbmcSolver, ExpectedOutcome, HarnessMetadata};
 use regex::Regex;
 use rustc_demangle::demangle;
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::collections::btree_map::Entry;
 use std::ffi::OsString;
 use std::fmt::Write;
-use std::path::Path;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 use std::time::{Duration, Instant};
 use tokio::process::Command as TokioCommand;
 
 use crate::args::common::Verbosity;
-use crate::args::{OutputFormat, VerificationArgs};
+use crate::args::{MemoryLimit, OutputFormat, VerificationArgs};
 use crate::cbmc_output_parser::{
     CheckStatus, Property, VerificationOutput, extract_results, process_cbmc_output,
 };
-use crate::cbmc_property_renderer::{format_coverage, format_result, kani_cbmc_output_filter};
+use crate::cbmc_property_renderer::{
+    format_coverage, format_result, format_verification_status, kani_cbmc_output_filter,
+};
 use crate::coverage::cov_results::{CoverageCheck, CoverageResults};
 use crate::coverage::cov_results::{CoverageRegion, CoverageTerm};
 use crate::session::KaniSession;
-use crate::util::render_command;
+use crate::util::{render_args, render_command, warning};
 
 /// We will use Cadical by default since it performed better than MiniSAT in our analysis.
 /// Note: Kissat was marginally better, but it is an external solver which could be more unstable.
@@ -33,6 +37,13 @@
 pub enum VerificationStatus {
     Success,
     Failure,
+    /// The only failures are unwinding assertions, so the unwind bound was not large enough to
+    /// reach a conclusion.
+    UnwindBoundInsufficient,
+    /// CBMC did not report any property, so the harness passes trivially.
+    NoProperties,
+    /// CBMC was stopped because it used more memory than allowed by `--max-memory`.
+    MemoryLimitExceeded,
 }
 
 /// Represents failed properties in three different categories.
@@ -45,15 +56,22 @@ pub enum FailedProperties {
     PanicsOnly,
     // One or more failures that aren't panic-related
     Other,
+    // One or more unwinding assertion failures, and no other failures
+    UnwindOnly,
 }
 
-/// The possible CBMC exit statuses
-#[derive(Clone, Copy, Debug)]
+/// The possible CBMC exit statuses, or the reason why CBMC wasn't run.
+#[derive(Clone, Debug)]
 pub enum ExitStatus {
     Timeout,
     OutOfMemory,
+    /// Kani stopped CBMC because it went over `--max-memory`.
+    MemoryLimitExceeded,
     /// the integer is the process exit status
     Other(i32),
+    /// Kani failed to generate the model of the harness, so it wasn't verified. This is only
+    /// reported with `--keep-going`, which records the error message.
+    CodegenError(String),
 }
 
 /// Our (kani-driver) notions of CBMC results.
@@ -74,18 +92,117 @@ pub struct VerificationResult {
     pub generated_concrete_test: bool,
     /// The coverage results
     pub coverage_results: Option<CoverageResults>,
+    /// The seed given to the SAT solver with `--cbmc-seed`, if the solver accepts one.
+    pub solver_seed: Option<u64>,
+    /// How CBMC was invoked. Only recorded for `--output-format json`.
+    pub invocation: Option<CbmcInvocation>,
+    /// Whether the harness was expected to fail, so `status` is the result of comparing the
+    /// verdict with that expectation.
+    pub expected_failure: bool,
+}
+
+/// How CBMC was invoked to verify a harness, reported by `--output-format json` so the run can
+/// be reproduced.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CbmcInvocation {
+    /// The version of Kani, followed by the commit it was built from, if known.
+    pub kani_version: String,
+    /// The output of `cbmc --version`.
+    pub cbmc_version: String,
+    /// The full command line, starting with the path to the `cbmc` binary.
+    pub cbmc_command: Vec<String>,
+}
+
+/// Limit the address space of the process spawned by `cmd` to the given size.
+fn limit_memory(cmd: &mut TokioCommand, limit: MemoryLimit) {
+    let bytes = limit.bytes() as libc::rlim_t;
+    // SAFETY: `setrlimit` is async-signal-safe, so it can be called in the child process before
+    // `exec`.
+    unsafe {
+        cmd.pre_exec(move || {
+            let rlimit = libc::rlimit { rlim_cur: bytes, rlim_max: bytes };
+            if libc::setrlimit(libc::RLIMIT_AS, &rlimit) == 0 {
+                Ok(())
+            } else {
+                Err(std::io::Error::last_os_error())
+            }
+        });
+    }
+}
+
+/// How often a running harness checks whether it should be stopped for `--fail-fast-kill`.
+const FAIL_FAST_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How often the memory usage of CBMC is sampled for `--max-memory`.
+const MEMORY_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Wait until the resident memory of process `pid` goes over `limit` bytes.
+///
+/// This never returns if the memory usage can't be read, e.g., if the process has exited.
+async fn wait_for_memory_limit(pid: u32, limit: u64) {
+    let mut interval = tokio::time::interval(MEMORY_POLL_INTERVAL);
+    loop {
+        interval.tick().await;
+        if resident_memory(pid).is_some_and(|bytes| bytes > limit) {
+            return;
+        }
+    }
+}
+
+/// The resident memory of process `pid` in bytes, read from `/proc/<pid>/statm`.
+#[cfg(target_os = "linux")]
+fn resident_memory(pid: u32) -> Option<u64> {
+    // The second field is the resident set size in pages.
+    let statm = std::fs::read_to_string(format!("/proc/{pid}/statm")).ok()?;
+    let pages: u64 = statm.split_whitespace().nth(1)?.parse().ok()?;
+    // SAFETY: `sysconf` has no preconditions.
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    Some(pages * page_size as u64)
+}
+
+/// The resident memory of process `pid` in bytes, as reported by `proc_pidinfo`.
+#[cfg(target_os = "macos")]
+fn resident_memory(pid: u32) -> Option<u64> {
+    let mut info = std::mem::MaybeUninit::<libc::proc_taskinfo>::zeroed();
+    let size = std::mem::size_of::<libc::proc_taskinfo>() as libc::c_int;
+    // SAFETY: `info` is valid for writes of `size` bytes.
+    let written = unsafe {
+        libc::proc_pidinfo(
+            pid as libc::c_int,
+            libc::PROC_PIDTASKINFO,
+            0,
+            info.as_mut_ptr().cast(),
+            size,
+        )
+    };
+    // SAFETY: `proc_pidinfo` initialized `info` if it wrote the whole struct.
+    (written == size).then(|| unsafe { info.assume_init() }.pti_resident_size)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn resident_memory(_pid: u32) -> Option<u64> {
+    None
 }
 
 impl KaniSession {
     /// Verify a goto binary that's been prepared with goto-instrument
-    pub fn run_cbmc(&self, file: &Path, harness: &HarnessMetadata) -> Result<VerificationResult> {
+    ///
+    /// Returns `None` if CBMC was stopped because another harness failed with `--fail-fast-kill`.
+    pub fn run_cbmc(
+        &self,
+        file: &Path,
+        harness: &HarnessMetadata,
+    ) -> Result<Option<VerificationResult>> {
         let args: Vec<OsString> = self.cbmc_flags(file, harness)?;
 
-        // TODO get cbmc path from self
-        let mut cmd = TokioCommand::new("cbmc");
+        let mut cmd = TokioCommand::new(&self.cbmc);
         cmd.args(args);
+        if let Some(limit) = self.args.memory_limit {
+            limit_memory(&mut cmd, limit);
+        }
 
-        let verification_results = if self.args.output_format == crate::args::OutputFormat::Old {
+        let mut invocation = None;
+        let verification_results = if self.args.raw_cbmc_output() {
             if self.run_terminal_timeout(cmd).is_err() {
                 VerificationResult::mock_failure()
             } else {
@@ -96,18 +213,29 @@ pub fn run_cbmc(&self, file: &Path, harness: &HarnessMetadata) -> Result<Verific
             // Done here because now removed `--visualize` used the XML format instead.
             // TODO: move this now that we don't use --visualize
             cmd.arg("--json-ui");
+            if self.args.output_format == OutputFormat::Json {
+                invocation = Some(self.cbmc_invocation(cmd.as_std())?);
+            }
 
-            self.runtime.block_on(self.run_cbmc_piped(cmd, harness))?
+            let Some(results) = self.runtime.block_on(self.run_cbmc_piped(cmd, harness))? else {
+                return Ok(None);
+            };
+            results
+        };
+        let verification_results = VerificationResult {
+            solver_seed: self.solver_seed(harness),
+            invocation,
+            ..verification_results
         };
 
-        Ok(verification_results)
+        Ok(Some(verification_results))
     }
 
     async fn run_cbmc_piped(
         &self,
         mut cmd: TokioCommand,
         harness: &HarnessMetadata,
-    ) -> Result<VerificationResult> {
+    ) -> Result<Option<VerificationResult>> {
         if self.args.common_args.verbose() {
             println!("[Kani] Running: `{}`", render_command(cmd.as_std()).to_string_lossy());
         }
@@ -119,52 +247,95 @@ async fn run_cbmc_piped(
 
         let start_time = Instant::now();
 
-        let res = if let Some(timeout) = self.args.harness_timeout {
-            tokio::time::timeout(
-                timeout.into(),
-                process_cbmc_output(&mut cbmc_process, |i| {
-                    kani_cbmc_output_filter(
-                        i,
-                        self.args.extra_pointer_checks,
-                        self.args.common_args.quiet,
-                        &self.args.output_format,
-                    )
-                }),
+        let pid = cbmc_process.id();
+        let output = process_cbmc_output(&mut cbmc_process, |i| {
+            kani_cbmc_output_filter(
+                i,
+                self.args.extra_pointer_checks,
+                self.args.common_args.quiet || self.args.summary_only,
+                &self.args.output_format,
             )
-            .await
-        } else {
-            Ok(process_cbmc_output(&mut cbmc_process, |i| {
-                kani_cbmc_output_filter(
-                    i,
-                    self.args.extra_pointer_checks,
-                    self.args.common_args.quiet,
-                    &self.args.output_format,
-                )
-            })
-            .await)
+        });
+        // Stop processing the output if CBMC goes over `--max-memory`.
+        let output = async {
+            match (self.args.max_memory_bytes(), pid) {
+                (Some(limit), Some(pid)) => tokio::select! {
+                    output = output => Ok(output),
+                    _ = wait_for_memory_limit(pid, limit) => Err(ExitStatus::MemoryLimitExceeded),
+                },
+                _ => Ok(output.await),
+            }
+        };
+        let res = async {
+            if let Some(timeout) = self.args.harness_timeout {
+                tokio::time::timeout(timeout.into(), output)
+                    .await
+                    .unwrap_or(Err(ExitStatus::Timeout))
+            } else {
+                output.await
+            }
+        };
+        // Stop CBMC if another harness fails with `--fail-fast-kill`.
+        let res = tokio::select! {
+            res = res => res,
+            _ = self.wait_for_fail_fast_kill() => {
+                cbmc_process.kill().await?;
+                return Ok(None);
+            }
         };
 
-        let verification_results = if res.is_err() {
-            // An error occurs if the timeout was reached
-
-            // Kill the process
-            cbmc_process.kill().await?;
+        let verification_results = match res {
+            Err(exit_status) => {
+                // The timeout or the memory limit was reached, so kill the process
+                cbmc_process.kill().await?;
 
-            VerificationResult {
-                status: VerificationStatus::Failure,
-                failed_properties: FailedProperties::None,
-                results: Err(ExitStatus::Timeout),
-                runtime: start_time.elapsed(),
-                generated_concrete_test: false,
-                coverage_results: None,
+                let status = if matches!(exit_status, ExitStatus::MemoryLimitExceeded) {
+                    VerificationStatus::MemoryLimitExceeded
+                } else {
+                    VerificationStatus::Failure
+                };
+                VerificationResult {
+                    status,
+                    failed_properties: FailedProperties::None,
+                    results: Err(exit_status),
+                    runtime: start_time.elapsed(),
+                    generated_concrete_test: false,
+                    coverage_results: None,
+                    solver_seed: None,
+                    invocation: None,
+                    expected_failure: false,
+                }
+            }
+            Ok(output) => {
+                let mut result =
+                    VerificationResult::from(output?, harness.attributes.should_panic, start_time);
+                if self.args.strict_cover {
+                    result.fail_on_unsatisfied_covers();
+                }
+                if harness.attributes.expected == ExpectedOutcome::Fail {
+                    result.expect_failure();
+                }
+                result
             }
-        } else {
-            // The timeout wasn't reached
-            let output = res.unwrap()?;
-            VerificationResult::from(output, harness.attributes.should_panic, start_time)
         };
 
-        Ok(verification_results)
+        Ok(Some(verification_results))
+    }
+
+    /// Wait until a harness fails with `--fail-fast-kill`.
+    ///
+    /// This never returns if `--fail-fast-kill` wasn't given.
+    async fn wait_for_fail_fast_kill(&self) {
+        if !self.args.fail_fast_kill {
+            return std::future::pending().await;
+        }
+        let mut interval = tokio::time::interval(FAIL_FAST_POLL_INTERVAL);
+        loop {
+            interval.tick().await;
+            if self.is_fail_fast_stopped() {
+                return;
+            }
+        }
     }
 
     /// "Internal," but also used by call_cbmc_viewer
@@ -185,22 +356,29 @@ pub fn cbmc_flags(
             args.push(unwind_value.to_string().into());
         }
 
-        self.handle_solver_args(&harness_metadata.attributes.solver, &mut args)?;
+        if let Some(seed) = self.solver_seed(harness_metadata) {
+            args.push("--external-sat-solver".into());
+            args.push(self.write_seeded_solver(file, seed)?.into());
+        } else {
+            self.warn_unseeded_solver(harness_metadata);
+            self.handle_solver_args(&harness_metadata.attributes.solver, &mut args)?;
+        }
 
         if self.args.run_sanity_checks {
             args.push("--validate-goto-model".into());
             args.push("--validate-ssa-equation".into());
         }
 
-        if self.args.concrete_playback.is_none() && !self.args.no_slice_formula {
+        let replays = self.args.concrete_playback.is_some() || self.args.run.is_some();
+        if !replays && !self.args.no_slice_formula {
             args.push("--slice-formula".into());
         }
 
-        if self.args.concrete_playback.is_some() {
+        if replays || self.args.output_format == OutputFormat::Json {
             args.push("--trace".into());
         }
 
-        args.extend(self.args.cbmc_args.iter().cloned());
+        args.extend(self.passthrough_cbmc_args().0);
 
         args.push(file.to_owned().into_os_string());
 
@@ -212,6 +390,44 @@ pub fn cbmc_flags(
         Ok(args)
     }
 
+    /// Log the arguments given via `--cbmc-args` and warn about the ones that conflict with the
+    /// flags Kani injects. This is done once per session rather than once per harness.
+    pub fn check_cbmc_args(&self) {
+        if self.args.cbmc_args.is_empty() || self.args.common_args.quiet {
+            return;
+        }
+        let (passthrough, dropped) = self.passthrough_cbmc_args();
+        for flag in dropped {
+            warning(&format!(
+                "Ignoring `{flag}` from `--cbmc-args`, since Kani relies on it to process the \
+                output of CBMC. Use `--output-format old` to pass it to CBMC."
+            ));
+        }
+        for flag in passthrough.iter().filter_map(|arg| cbmc_flag_name(arg)) {
+            if OVERRIDDEN_CBMC_FLAGS.contains(&flag) {
+                warning(&format!(
+                    "`{flag}` from `--cbmc-args` overrides the value that Kani would otherwise \
+                    pass to CBMC."
+                ));
+            }
+        }
+        println!("[Kani] Extra CBMC arguments: {}", render_args(&passthrough).to_string_lossy());
+    }
+
+    /// The arguments given via `--cbmc-args` that should be forwarded to CBMC, followed by the
+    /// flags that were removed because they would break the processing of CBMC's output.
+    ///
+    /// Arguments are forwarded verbatim, without going through a shell, so they don't need any
+    /// extra quoting or escaping.
+    fn passthrough_cbmc_args(&self) -> (Vec<OsString>, Vec<String>) {
+        if self.args.raw_cbmc_output() {
+            // Kani doesn't parse the output of CBMC in this mode.
+            (self.args.cbmc_args.clone(), vec![])
+        } else {
+            filter_reserved_cbmc_args(&self.args.cbmc_args)
+        }
+    }
+
     /// Just the flags to CBMC that enable property checking of any sort.
     pub fn cbmc_check_flags(&self) -> Vec<OsString> {
         let mut args = Vec::new();
@@ -264,21 +480,62 @@ pub fn cbmc_check_flags(&self) -> Vec<OsString> {
         args
     }
 
-    pub fn handle_solver_args(
-        &self,
-        harness_solver: &Option<CbmcSolver>,
-        args: &mut Vec<OsString>,
-    ) -> Result<()> {
-        let solver = if let Some(solver) = &self.args.solver {
+    /// Return the solver used for a harness with the given `solver` attribute.
+    fn resolve_solver<'a>(&'a self, harness_solver: &'a Option<CbmcSolver>) -> &'a CbmcSolver {
+        if let Some(solver) = &self.args.solver {
             // `--solver` option takes precedence over attributes
             solver
         } else if let Some(solver) = harness_solver {
             solver
         } else {
             &DEFAULT_SOLVER
+        }
+    }
+
+    /// Return the seed given with `--cbmc-seed` if the solver of `harness` accepts a seed.
+    ///
+    /// CBMC doesn't expose the seed of its built-in solvers, so only Kissat, which is an external
+    /// solver, is seeded.
+    fn solver_seed(&self, harness: &HarnessMetadata) -> Option<u64> {
+        let seed = self.args.cbmc_seed?;
+        matches!(self.resolve_solver(&harness.attributes.solver), CbmcSolver::Kissat)
+            .then_some(seed)
+    }
+
+    /// Warn that `--cbmc-seed` is ignored for `harness`, since its solver doesn't accept a seed.
+    fn warn_unseeded_solver(&self, harness: &HarnessMetadata) {
+        if self.args.cbmc_seed.is_none() || self.args.common_args.quiet {
+            return;
+        }
+        let solver = match self.resolve_solver(&harness.attributes.solver) {
+            CbmcSolver::Binary(binary) => binary.as_str(),
+            solver => solver.as_ref(),
         };
+        warning(&format!(
+            "The `{solver}` solver used by harness `{}` doesn't accept a seed, so `--cbmc-seed` \
+            is ignored for it. Use `--solver kissat` to seed the solver.",
+            harness.pretty_name
+        ));
+    }
+
+    /// Write a script next to the goto binary `file` that runs Kissat with the given seed, and
+    /// return its path. CBMC doesn't forward options to external solvers, so the script is used as
+    /// the solver instead. It's kept with `--keep-temps` to reproduce the solver invocation.
+    fn write_seeded_solver(&self, file: &Path, seed: u64) -> Result<PathBuf> {
+        let script = file.with_extension(format!("kissat_seed_{seed}.sh"));
+        std::fs::write(&script, format!("#!/bin/sh\nexec kissat --seed={seed} \"$@\"\n"))
+            .with_context(|| format!("failed to write `{}`", script.display()))?;
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))?;
+        self.record_temporary_file(&script);
+        Ok(script)
+    }
 
-        match solver {
+    pub fn handle_solver_args(
+        &self,
+        harness_solver: &Option<CbmcSolver>,
+        args: &mut Vec<OsString>,
+    ) -> Result<()> {
+        match self.resolve_solver(harness_solver) {
             CbmcSolver::Cadical => {
                 args.push("--sat-solver".into());
                 args.push("cadical".into());
@@ -332,6 +589,9 @@ fn from(
                 runtime,
                 generated_concrete_test: false,
                 coverage_results,
+                solver_seed: None,
+                invocation: None,
+                expected_failure: false,
             }
         } else {
             // We never got results from CBMC - something went wrong (e.g. crash) so it's failure
@@ -347,6 +607,9 @@ fn from(
                 runtime,
                 generated_concrete_test: false,
                 coverage_results: None,
+                solver_seed: None,
+                invocation: None,
+                expected_failure: false,
             }
         }
     }
@@ -359,6 +622,41 @@ pub fn mock_success() -> VerificationResult {
             runtime: Duration::from_secs(0),
             generated_concrete_test: false,
             coverage_results: None,
+            solver_seed: None,
+            invocation: None,
+            expected_failure: false,
+        }
+    }
+
+    /// The result of a harness that was not verified because one of the contracts it relies on
+    /// could not be verified.
+    pub fn dependency_failure() -> VerificationResult {
+        VerificationResult {
+            status: VerificationStatus::Failure,
+            failed_properties: FailedProperties::Other,
+            results: Ok(vec![]),
+            runtime: Duration::from_secs(0),
+            generated_concrete_test: false,
+            coverage_results: None,
+            solver_seed: None,
+            invocation: None,
+            expected_failure: false,
+        }
+    }
+
+    /// The result of a harness that was not verified because Kani failed to generate its model,
+    /// which is only reported with `--keep-going`.
+    pub fn codegen_error(error: &str) -> VerificationResult {
+        VerificationResult {
+            status: VerificationStatus::Failure,
+            failed_properties: FailedProperties::Other,
+            results: Err(ExitStatus::CodegenError(error.to_string())),
+            runtime: Duration::from_secs(0),
+            generated_concrete_test: false,
+            coverage_results: None,
+            solver_seed: None,
+            invocation: None,
+            expected_failure: false,
         }
     }
 
@@ -373,6 +671,9 @@ fn mock_failure() -> VerificationResult {
             runtime: Duration::from_secs(0),
             generated_concrete_test: false,
             coverage_results: None,
+            solver_seed: None,
+            invocation: None,
+            expected_failure: false,
         }
     }
 
@@ -381,7 +682,8 @@ pub fn render(&self, output_format: &OutputFormat, should_panic: bool) -> String
             Ok(results) => {
                 let status = self.status;
                 let failed_properties = self.failed_properties;
-                let show_checks = matches!(output_format, OutputFormat::Regular);
+                let show_checks =
+                    matches!(output_format, OutputFormat::Regular | OutputFormat::Github);
 
                 let mut result = if let Some(cov_results) = &self.coverage_results {
                     format_coverage(
@@ -389,17 +691,25 @@ pub fn render(&self, output_format: &OutputFormat, should_panic: bool) -> String
                         cov_results,
                         status,
                         should_panic,
+                        self.expected_failure,
                         failed_properties,
                         show_checks,
                     )
                 } else {
-                    format_result(results, status, should_panic, failed_properties, show_checks)
+                    format_result(
+                        results,
+                        status,
+                        should_panic,
+                        self.expected_failure,
+                        failed_properties,
+                        show_checks,
+                    )
                 };
                 writeln!(result, "Verification Time: {}s", self.runtime.as_secs_f32()).unwrap();
                 result
             }
             Err(exit_status) => {
-                let verification_result = console::style("FAILED").red();
+                let verification_result = format_verification_status(self.status);
                 let (header, explanation) = match exit_status {
                     ExitStatus::OutOfMemory => (
                         String::from("CBMC failed"),
@@ -412,9 +722,18 @@ pub fn render(&self, output_format: &OutputFormat, should_panic: bool) -> String
                         "CBMC timed out. You may want to rerun your proof with a larger timeout \
                     or use stubbing to reduce the size of the code the verifier reasons about.\n",
                     ),
+                    ExitStatus::MemoryLimitExceeded => (
+                        String::from("CBMC stopped"),
+                        "CBMC used more memory than allowed by `--max-memory`. You may want to \
+                    rerun your proof with a larger limit or use stubbing to reduce the size of the \
+                    code the verifier reasons about.\n",
+                    ),
                     ExitStatus::Other(exit_status) => {
                         (format!("CBMC failed with status {exit_status}"), "")
                     }
+                    ExitStatus::CodegenError(error) => {
+                        (format!("ERROR: Failed to generate the model of the harness: {error}"), "")
+                    }
                 };
                 format!(
                     "\n{header}\n\
@@ -425,6 +744,87 @@ pub fn render(&self, output_format: &OutputFormat, should_panic: bool) -> String
         }
     }
 
+    /// Render only the overall verification status and the number of failed properties, which is
+    /// what `--summary-only` prints for each harness.
+    pub fn render_summary(&self) -> String {
+        let status = format_verification_status(self.status);
+        match &self.results {
+            Ok(results) => {
+                let checks = results.iter().filter(|prop| !prop.is_cover_property());
+                let total = checks.clone().count();
+                let failed = checks.filter(|prop| prop.status == CheckStatus::Failure).count();
+                format!("VERIFICATION:- {status} ({failed} of {total} failed)")
+            }
+            Err(ExitStatus::OutOfMemory) => {
+                format!("VERIFICATION:- {status} (CBMC ran out of memory)")
+            }
+            Err(ExitStatus::Timeout) => format!("VERIFICATION:- {status} (CBMC timed out)"),
+            Err(ExitStatus::MemoryLimitExceeded) => {
+                format!("VERIFICATION:- {status} (CBMC went over `--max-memory`)")
+            }
+            Err(ExitStatus::Other(exit_status)) => {
+                format!("VERIFICATION:- {status} (CBMC failed with status {exit_status})")
+            }
+            Err(ExitStatus::CodegenError(_)) => {
+                format!("VERIFICATION:- {status} (code generation failed)")
+            }
+        }
+    }
+
+    /// Render the line that is printed as soon as the verification of `harness_name` completes,
+    /// e.g., `PASS check_foo (0.52s)`.
+    pub fn render_status_line(&self, harness_name: &str, strict_no_properties: bool) -> String {
+        let status = if self.is_success(strict_no_properties) { "PASS" } else { "FAIL" };
+        format!("{status} {harness_name} ({:.2}s)", self.runtime.as_secs_f64())
+    }
+
+    /// Whether this result counts as a successful verification.
+    ///
+    /// Harnesses without properties only count as successful if `strict_no_properties` is unset.
+    pub fn is_success(&self, strict_no_properties: bool) -> bool {
+        match self.status {
+            VerificationStatus::Success => true,
+            VerificationStatus::NoProperties => !strict_no_properties,
+            VerificationStatus::Failure
+            | VerificationStatus::UnwindBoundInsufficient
+            | VerificationStatus::MemoryLimitExceeded => false,
+        }
+    }
+
+    /// Report a successful verification as a failure if one of its cover properties wasn't
+    /// satisfied, which is what `--strict-cover` requires.
+    fn fail_on_unsatisfied_covers(&mut self) {
+        let Ok(properties) = &self.results else { return };
+        let unsatisfied = properties
+            .iter()
+            .any(|prop| prop.is_cover_property() && prop.status != CheckStatus::Satisfied);
+        if unsatisfied && self.status == VerificationStatus::Success {
+            self.status = VerificationStatus::Failure;
+            self.failed_properties = FailedProperties::Other;
+        }
+    }
+
+    /// Compare the verdict with the one of a harness that is expected to fail, so a failure is
+    /// reported as a success, and a success as an (unexpected) failure.
+    ///
+    /// Inconclusive verdicts, e.g., an insufficient unwind bound, are kept as they are. So are the
+    /// failures of CBMC itself, e.g., a timeout or a crash, since they say nothing about the
+    /// properties of the harness.
+    fn expect_failure(&mut self) {
+        if self.results.is_err() {
+            return;
+        }
+        self.expected_failure = true;
+        self.status = match self.status {
+            VerificationStatus::Failure => VerificationStatus::Success,
+            VerificationStatus::Success | VerificationStatus::NoProperties => {
+                VerificationStatus::Failure
+            }
+            status @ (VerificationStatus::UnwindBoundInsufficient
+            | VerificationStatus::MemoryLimitExceeded) => status,
+        };
+    }
+
     /// Find the failed properties from this verification run
     pub fn failed_properties(&self) -> Vec<&Property> {
         if let Ok(properties) = &self.results {
@@ -446,18 +846,21 @@ fn verification_outcome_from_properties(
         match failed_properties {
             FailedProperties::None | FailedProperties::Other => VerificationStatus::Failure,
             FailedProperties::PanicsOnly => VerificationStatus::Success,
+            FailedProperties::UnwindOnly => VerificationStatus::UnwindBoundInsufficient,
         }
     } else {
         match failed_properties {
+            FailedProperties::None if properties.is_empty() => VerificationStatus::NoProperties,
             FailedProperties::None => VerificationStatus::Success,
             FailedProperties::PanicsOnly | FailedProperties::Other => VerificationStatus::Failure,
+            FailedProperties::UnwindOnly => VerificationStatus::UnwindBoundInsufficient,
         }
     };
     (status, failed_properties)
 }
 
 /// Determines the `FailedProperties` variant that corresponds to an array of properties
-fn determine_failed_properties(properties: &[Property]) -> FailedProperties {
+pub(crate) fn determine_failed_properties(properties: &[Property]) -> FailedProperties {
     let failed_properties: Vec<&Property> =
         properties.iter().filter(|prop| prop.status == CheckStatus::Failure).collect();
     // Return `FAILURE` if there isn't at least one failed property
@@ -468,7 +871,13 @@ fn determine_failed_properties(properties: &[Property]) -> FailedProperties {
         // Note: Panics caused by `panic!` and `assert!` fall into this class.
         let all_failed_checks_are_panics =
             failed_properties.iter().all(|prop| prop.property_class() == "assertion");
-        if all_failed_checks_are_panics {
+        // Insufficient unwinding is not a bug in the code under verification, so we only
+        // report it separately if there were no other failures.
+        let all_failed_checks_are_unwinding =
+            failed_properties.iter().all(|prop| prop.is_unwinding_assertion());
+        if all_failed_checks_are_unwinding {
+            FailedProperties::UnwindOnly
+        } else if all_failed_checks_are_panics {
             FailedProperties::PanicsOnly
         } else {
             FailedProperties::Other
@@ -544,19 +953,68 @@ fn coverage_results_from_properties(properties: &[Property]) -> Option<CoverageR
 
     Some(CoverageResults::new(coverage_results))
 }
+
+/// CBMC flags that control the format of CBMC's output, which Kani relies on to process the
+/// verification results. The value is the number of arguments the flag takes.
+const RESERVED_CBMC_FLAGS: &[(&str, usize)] = &[
+    ("--json-ui", 0),
+    ("--xml-ui", 0),
+    ("--json-interface", 0),
+    ("--xml-interface", 0),
+    ("--verbosity", 1),
+];
+
+/// CBMC flags that Kani may also set. Since the arguments from `--cbmc-args` come after the ones
+/// generated by Kani, the user provided value takes precedence.
+const OVERRIDDEN_CBMC_FLAGS: &[&str] =
+    &["--object-bits", "--unwind", "--sat-solver", "--external-sat-solver"];
+
+/// Return the name of the flag represented by `arg`, if any. E.g.: `--flag=value` -> `--flag`.
+fn cbmc_flag_name(arg: &OsString) -> Option<&str> {
+    let arg = arg.to_str()?;
+    if arg.starts_with("--") { arg.split('=').next() } else { None }
+}
+
+/// Split the user provided CBMC arguments into the arguments that can be forwarded to CBMC and
+/// the reserved flags that were removed.
+fn filter_reserved_cbmc_args(args: &[OsString]) -> (Vec<OsString>, Vec<String>) {
+    let mut passthrough = Vec::with_capacity(args.len());
+    let mut dropped = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let reserved = cbmc_flag_name(arg)
+            .and_then(|name| RESERVED_CBMC_FLAGS.iter().find(|(flag, _)| *flag == name));
+        match reserved {
+            Some((flag, num_values)) => {
+                // The value is part of the argument when using the `--flag=value` syntax.
+                if !arg.to_string_lossy().contains('=') {
+                    for _ in 0..*num_values {
+                        iter.next();
+                    }
+                }
+                dropped.push(flag.to_string());
+            }
+            None => passthrough.push(arg.clone()),
+        }
+    }
+    (passthrough, dropped)
+}
+
 /// Solve Unwind Value from conflicting inputs of unwind values. (--default-unwind, annotation-unwind, --unwind)
 pub fn resolve_unwind_value(
     args: &VerificationArgs,
     harness_metadata: &HarnessMetadata,
 ) -> Option<u32> {
     // Check for which flag is being passed and prioritize extracting unwind from the
-    // respective flag/annotation.
+    // respective flag/annotation. Note that the harness value already falls back to the
+    // crate-level `#![kanitool::unwind]` attribute.
     args.unwind.or(harness_metadata.attributes.unwind_value).or(args.default_unwind)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::args;
+    use crate::cbmc_output_parser::{PropertyId, SourceLocation};
     use crate::metadata::tests::mock_proof_harness;
     use clap::Parser;
 
@@ -593,4 +1051,178 @@ fn resolve(args: &[&str], harness: &HarnessMetadata) -> Option<u32> {
         assert_eq!(resolve(&args_only_harness, &harness_some), Some(1));
         assert_eq!(resolve(&args_both, &harness_some), Some(1));
     }
+
+    #[test]
+    fn check_filter_reserved_cbmc_args() {
+        let to_args = |args: &[&str]| args.iter().map(OsString::from).collect::<Vec<_>>();
+        let args = to_args(&["--unwindset", "f.0:2", "--verbosity", "3", "--xml-ui", "--trace"]);
+        let (passthrough, dropped) = filter_reserved_cbmc_args(&args);
+        assert_eq!(passthrough, to_args(&["--unwindset", "f.0:2", "--trace"]));
+        assert_eq!(dropped, ["--verbosity", "--xml-ui"]);
+
+        let args = to_args(&["--verbosity=3", "--object-bits", "8"]);
+        let (passthrough, dropped) = filter_reserved_cbmc_args(&args);
+        assert_eq!(passthrough, to_args(&["--object-bits", "8"]));
+        assert_eq!(dropped, ["--verbosity"]);
+    }
+
+    fn mock_property(class: &str, status: CheckStatus) -> Property {
+        Property {
+            description: String::new(),
+            property_id: PropertyId { fn_name: None, class: class.to_string(), id: 0 },
+            source_location: SourceLocation {
+                column: None,
+                file: None,
+                function: None,
+                line: None,
+            },
+            status,
+            reach: None,
+            trace: None,
+        }
+    }
+
+    #[test]
+    fn check_unwind_bound_insufficient_status() {
+        let unwind_failure = mock_property("unwind", CheckStatus::Failure);
+        let recursion_failure = mock_property("recursion", CheckStatus::Failure);
+        let assert_failure = mock_property("assertion", CheckStatus::Failure);
+        let undetermined = mock_property("assertion", CheckStatus::Undetermined);
+
+        let only_unwind = [unwind_failure.clone(), recursion_failure, undetermined];
+        assert_eq!(
+            verification_outcome_from_properties(&only_unwind, false).0,
+            VerificationStatus::UnwindBoundInsufficient
+        );
+        assert_eq!(
+            verification_outcome_from_properties(&only_unwind, true).0,
+            VerificationStatus::UnwindBoundInsufficient
+        );
+
+        // Genuine failures must still be reported as such.
+        let mixed = [unwind_failure, assert_failure];
+        assert_eq!(
+            verification_outcome_from_properties(&mixed, false).0,
+            VerificationStatus::Failure
+        );
+    }
+
+    #[test]
+    fn check_no_properties_status() {
+        assert_eq!(
+            verification_outcome_from_properties(&[], false).0,
+            VerificationStatus::NoProperties
+        );
+        // A harness that should panic but has no properties cannot have panicked.
+        assert_eq!(verification_outcome_from_properties(&[], true).0, VerificationStatus::Failure);
+
+        let success = [mock_property("assertion", CheckStatus::Success)];
+        assert_eq!(
+            verification_outcome_from_properties(&success, false).0,
+            VerificationStatus::Success
+        );
+
+        let mut result = VerificationResult::mock_success();
+        result.status = VerificationStatus::NoProperties;
+        assert!(result.is_success(false));
+        assert!(!result.is_success(true));
+    }
+
+    #[test]
+    fn check_strict_cover() {
+        let mut result = VerificationResult::mock_success();
+        result.results = Ok(vec![
+            mock_property("cover", CheckStatus::Satisfied),
+            mock_property("assertion", CheckStatus::Success),
+        ]);
+        result.fail_on_unsatisfied_covers();
+        assert_eq!(result.status, VerificationStatus::Success);
+
+        for status in [CheckStatus::Unsatisfiable, CheckStatus::Unreachable] {
+            let mut result = VerificationResult::mock_success();
+            result.results = Ok(vec![
+                mock_property("cover", CheckStatus::Satisfied),
+                mock_property("cover", status),
+            ]);
+            result.fail_on_unsatisfied_covers();
+            assert_eq!(result.status, VerificationStatus::Failure);
+        }
+    }
+
+    #[test]
+    fn check_expected_failure() {
+        let mut result = VerificationResult::mock_success();
+        result.status = VerificationStatus::Failure;
+        result.expect_failure();
+        assert_eq!(result.status, VerificationStatus::Success);
+        assert!(result.expected_failure);
+
+        for status in [VerificationStatus::Success, VerificationStatus::NoProperties] {
+            let mut result = VerificationResult::mock_success();
+            result.status = status;
+            result.expect_failure();
+            assert_eq!(result.status, VerificationStatus::Failure);
+        }
+
+        let mut result = VerificationResult::mock_success();
+        result.status = VerificationStatus::UnwindBoundInsufficient;
+        result.expect_failure();
+        assert_eq!(result.status, VerificationStatus::UnwindBoundInsufficient);
+
+        // CBMC being killed, e.g., by the OOM killer, is not the expected failure.
+        let mut result = VerificationResult::mock_success();
+        result.status = VerificationStatus::Failure;
+        result.results = Err(ExitStatus::Other(137));
+        result.expect_failure();
+        assert_eq!(result.status, VerificationStatus::Failure);
+        assert!(!result.expected_failure);
+    }
+
+    #[test]
+    fn check_render_summary() {
+        let mut result = VerificationResult::mock_success();
+        result.status = VerificationStatus::Failure;
+        result.results = Ok(vec![
+            mock_property("assertion", CheckStatus::Failure),
+            mock_property("assertion", CheckStatus::Success),
+            mock_property("overflow", CheckStatus::Failure),
+            mock_property("cover", CheckStatus::Satisfied),
+        ]);
+        assert!(result.render_summary().ends_with("(2 of 3 failed)"));
+
+        let result = VerificationResult::mock_failure();
+        assert!(result.render_summary().ends_with("(CBMC failed with status 42)"));
+
+        let result = VerificationResult::codegen_error("not implemented: foo");
+        assert!(!result.is_success(false));
+        assert!(result.render_summary().ends_with("FAILED (code generation failed)"));
+        assert!(
+            result.render(&OutputFormat::Regular, false).contains(
+                "ERROR: Failed to generate the model of the harness: not implemented: foo"
+            )
+        );
+    }
+
+    #[test]
+    fn check_render_status_line() {
+        let mut result = VerificationResult::mock_success();
+        result.runtime = Duration::from_millis(1520);
+        assert_eq!(result.render_status_line("check_foo", false), "PASS check_foo (1.52s)");
+
+        result.status = VerificationStatus::NoProperties;
+        assert_eq!(result.render_status_line("check_foo", true), "FAIL check_foo (1.52s)");
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    #[test]
+    fn check_memory_limit_monitor() {
+        let pid = std::process::id();
+        assert!(resident_memory(pid).is_some_and(|bytes| bytes > 0));
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+        // The test process certainly uses more than one byte.
+        let reached = runtime.block_on(async {
+            tokio::time::timeout(Duration::from_secs(5), wait_for_memory_limit(pid, 1)).await
+        });
+        assert!(reached.is_ok());
+    }
 }
@@ -23,8 +23,7 @@ pub fn link_goto_binary(&self, inputs: &[PathBuf], output: &Path) -> Result<()>
         args.push("-o".into());
         args.push(output.to_owned().into_os_string());
 
-        // TODO get goto-cc path from self
-        let mut cmd = Command::new("goto-cc");
+        let mut cmd = Command::new(&self.goto_cc);
         cmd.args(args);
 
         self.run_suppress(cmd)?;
@@ -39,7 +38,7 @@ pub fn specialize_to_proof_harness(
         output: &Path,
         function: &str,
     ) -> Result<()> {
-        let mut cmd = Command::new("goto-cc");
+        let mut cmd = Command::new(&self.goto_cc);
         cmd.arg(input).args(["--function", function, "-o"]).arg(output);
 
         self.run_suppress(cmd)?;
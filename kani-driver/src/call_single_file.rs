@@ -2,12 +2,17 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use anyhow::Result;
-use kani_metadata::UnstableFeature;
+use clap::ValueEnum;
+use kani_metadata::{DiagnosticCategory, UnstableFeature};
 use std::ffi::OsString;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use crate::args_toml::profile_panic_strategy;
+use crate::compile_commands::CompileCommand;
+use crate::project::Project;
 use crate::session::{KaniSession, lib_folder};
+use crate::util::warning;
 
 pub struct LibConfig {
     args: Vec<OsString>,
@@ -83,6 +88,12 @@ pub fn compile_single_rust_file(
         // rustc ones.
         let mut cmd = Command::new(&self.kani_compiler);
         let kani_compiler_args = to_rustc_arg(kani_args);
+        self.record_compile_command(CompileCommand::new(
+            &std::env::current_dir()?,
+            &self.kani_compiler,
+            std::iter::once(OsString::from(&kani_compiler_args)).chain(rustc_args.iter().cloned()),
+            file,
+        ));
         cmd.arg(kani_compiler_args).args(rustc_args);
 
         if self.args.common_args.quiet {
@@ -125,9 +136,54 @@ pub fn kani_compiler_flags(&self) -> Vec<String> {
         if self.args.assertion_reach_checks() {
             flags.push("--assertion-reach-checks".into());
         }
+        if self.args.checks.cover_only {
+            flags.push("--cover-only".into());
+        }
+        if self.args.no_assume_satisfiable_checks {
+            flags.push("--no-assume-satisfiable-checks".into());
+        }
+        if self.args.enable_slicing {
+            flags.push("--enable-slicing".into());
+        }
         if self.args.ignore_global_asm {
             flags.push("--ignore-global-asm".into());
         }
+        if self.args.std_abstractions {
+            flags.push("--std-abstractions".into());
+        }
+        if let Some(capacity) = self.args.hashmap_capacity() {
+            flags.push(format!("--hashmap-capacity={capacity}"));
+        }
+        for krate in &self.args.assume_panic_free {
+            // Cargo allows `-` in package names, but the crate name uses `_` instead.
+            flags.push(format!("--assume-panic-free={}", krate.replace('-', "_")));
+        }
+        if self.args.assume_alloc_never_fails {
+            flags.push("--assume-alloc-never-fails".into());
+        }
+        if self.args.keep_going {
+            flags.push("--keep-going".into());
+        }
+        if self.args.no_std_assert_notes {
+            flags.push("--no-std-assert-notes".into());
+        }
+        for fix in &self.args.fix {
+            flags.push(format!("--fix={fix}"));
+        }
+        for category in DiagnosticCategory::value_variants() {
+            if category.is_compiler_diagnostic() && self.args.denied_by(*category).is_some() {
+                flags.push(format!("--deny={category}"));
+            }
+        }
+        if self.args.write_json_symtab.is_some() {
+            flags.push("--write-json-symtab".into());
+        }
+        if let Some(harness) = &self.args.dump_mir_for_harness {
+            flags.push(format!("--dump-mir-for-harness={harness}"));
+        }
+        if let Some(function) = &self.args.check_contract {
+            flags.push(format!("--check-contract={function}"));
+        }
 
         if self.args.is_stubbing_enabled() {
             flags.push("--enable-stubbing".into());
@@ -161,6 +217,55 @@ pub fn kani_compiler_flags(&self) -> Vec<String> {
         flags
     }
 
+    /// Warn that the panics from the crates given to `--assume-panic-free` are not checked.
+    pub fn warn_assume_panic_free(&self) {
+        if self.args.assume_panic_free.is_empty() || self.args.common_args.quiet {
+            return;
+        }
+        let crates = self.args.assume_panic_free.iter().map(|krate| format!("`{krate}`"));
+        warning(&format!(
+            "Assuming that functions from {} never panic. Their panics are not reported, which is \
+            unsound if they can happen.",
+            crates.collect::<Vec<_>>().join(", ")
+        ));
+    }
+
+    /// Log that the paths where a memory allocation fails are pruned.
+    pub fn log_assume_alloc_never_fails(&self) {
+        if self.args.assume_alloc_never_fails && !self.args.common_args.quiet {
+            println!(
+                "INFO: Assuming that memory allocations never fail. The paths that handle \
+                allocation failures are pruned, and their checks are not reported."
+            );
+        }
+    }
+
+    /// Report the panic strategy used for verification. Kani always verifies with the `abort`
+    /// strategy, which may differ from the one in the `dev` profile of the project. Cargo
+    /// ignores the profile strategy for tests, which always unwind.
+    pub fn report_panic_strategy(&self, project: &Project) {
+        if self.args.common_args.quiet {
+            return;
+        }
+        let configured =
+            project.cargo_metadata.as_ref().filter(|_| !self.args.tests).and_then(|metadata| {
+                let manifest = metadata.workspace_root.join("Cargo.toml");
+                profile_panic_strategy(&std::fs::read_to_string(manifest).ok()?)
+            });
+        if configured.as_deref() == Some("abort") {
+            println!(
+                "[Kani] Panic strategy: abort, as configured by the crate. A panic stops the \
+                harness without running any cleanup code, such as `Drop` implementations."
+            );
+        } else {
+            println!(
+                "[Kani] Panic strategy: abort. Kani doesn't support unwinding, so a panic stops \
+                the harness without running the cleanup code that unwinding would run, such as \
+                `Drop` implementations."
+            );
+        }
+    }
+
     /// This function generates all rustc configurations required by our goto-c codegen.
     pub fn kani_rustc_flags(&self, lib_config: LibConfig) -> Vec<OsString> {
         let mut flags: Vec<_> = base_rustc_flags(lib_config);
@@ -96,7 +96,10 @@ pub struct PropertyId {
 
 impl Property {
     const COVER_PROPERTY_CLASS: &'static str = "cover";
+    const ASSUME_SATISFIABLE_PROPERTY_CLASS: &'static str = "assume_satisfiable";
     const COVERAGE_PROPERTY_CLASS: &'static str = "code_coverage";
+    const UNWIND_PROPERTY_CLASS: &'static str = "unwind";
+    const RECURSION_PROPERTY_CLASS: &'static str = "recursion";
 
     pub fn property_class(&self) -> String {
         self.property_id.class.clone()
@@ -112,6 +115,17 @@ pub fn is_cover_property(&self) -> bool {
         self.property_id.class == Self::COVER_PROPERTY_CLASS
     }
 
+    /// Returns true if this is a satisfiability check of `kani::assume_satisfiable`
+    pub fn is_assume_satisfiable_property(&self) -> bool {
+        self.property_id.class == Self::ASSUME_SATISFIABLE_PROPERTY_CLASS
+    }
+
+    /// Returns true if this is an unwinding assertion for a loop or a recursive call
+    pub fn is_unwinding_assertion(&self) -> bool {
+        self.property_id.class == Self::UNWIND_PROPERTY_CLASS
+            || self.property_id.class == Self::RECURSION_PROPERTY_CLASS
+    }
+
     pub fn property_name(&self) -> String {
         let class = &self.property_id.class;
         let id = self.property_id.id;
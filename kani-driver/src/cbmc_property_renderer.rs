@@ -5,7 +5,7 @@
 use crate::call_cbmc::{FailedProperties, VerificationStatus};
 use crate::cbmc_output_parser::{CheckStatus, ParserItem, Property, TraceItem};
 use crate::coverage::cov_results::CoverageResults;
-use console::style;
+use console::{StyledObject, style};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rustc_demangle::demangle;
@@ -123,6 +123,7 @@
 const UNWINDING_ASSERT_DESC: &str = "unwinding assertion loop";
 const UNWINDING_ASSERT_REC_DESC: &str = "recursion unwinding assertion";
 const UNDEFINED_FUNCTION_DESC: &str = "undefined function should be unreachable";
+const ALLOCATION_FAILURE_CLASS: &str = "allocation_failure";
 
 impl ParserItem {
     /// Determines if an item must be skipped or not.
@@ -182,8 +183,8 @@ fn process_item(item: ParserItem, extra_ptr_checks: bool) -> ParserItem {
 fn format_item(item: &ParserItem, output_format: &OutputFormat) -> Option<String> {
     match output_format {
         OutputFormat::Old => todo!(),
-        OutputFormat::Regular => format_item_regular(item),
-        OutputFormat::Terse => format_item_terse(item),
+        OutputFormat::Regular | OutputFormat::Github => format_item_regular(item),
+        OutputFormat::Terse | OutputFormat::Json => format_item_terse(item),
     }
 }
 
@@ -216,6 +217,7 @@ pub fn format_result(
     properties: &Vec<Property>,
     status: VerificationStatus,
     should_panic: bool,
+    expected_failure: bool,
     failed_properties: FailedProperties,
     show_checks: bool,
 ) -> String {
@@ -230,6 +232,7 @@ pub fn format_result(
     let mut number_covers_undetermined = 0;
     let mut number_covers_unreachable = 0;
     let mut number_covers_unsatisfiable = 0;
+    let mut cover_properties: Vec<&Property> = vec![];
 
     let mut index = 1;
 
@@ -243,6 +246,10 @@ pub fn format_result(
         let description = &prop.description;
         let location = &prop.source_location;
 
+        if prop.is_cover_property() {
+            cover_properties.push(prop);
+        }
+
         match status {
             CheckStatus::Failure => {
                 number_checks_failed += 1;
@@ -305,8 +312,12 @@ pub fn format_result(
 
     let number_properties = properties.len() - number_cover_properties;
 
-    let summary = format!("\n ** {number_checks_failed} of {number_properties} failed");
-    result_str.push_str(&summary);
+    // Only show the cover summary if there's nothing else to check, e.g., with `--cover-only`.
+    let only_covers = number_properties == 0 && number_cover_properties > 0;
+    if !only_covers {
+        let summary = format!("\n ** {number_checks_failed} of {number_properties} failed");
+        result_str.push_str(&summary);
+    }
 
     let mut other_status = Vec::<String>::new();
     if number_checks_undetermined > 0 {
@@ -322,7 +333,9 @@ pub fn format_result(
         result_str.push_str(&other_status.join(","));
         result_str.push(')');
     }
-    result_str.push('\n');
+    if !only_covers {
+        result_str.push('\n');
+    }
 
     if number_cover_properties > 0 {
         // Print a summary line for cover properties
@@ -345,31 +358,51 @@ pub fn format_result(
             result_str.push(')');
         }
         result_str.push('\n');
+        if !show_checks {
+            // The checks were not listed, so list the cover properties with their message.
+            for prop in cover_properties {
+                let status = &prop.status;
+                let description = &prop.description;
+                let location = &prop.source_location;
+                result_str.push_str(&format!(" - {status}: \"{description}\""));
+                if !location.is_missing() {
+                    result_str.push_str(&format!(" ({location})"));
+                }
+                result_str.push('\n');
+            }
+        }
         result_str.push('\n');
     }
 
+    result_str.push_str(&format_allocation_failure_checks(properties));
+
     for prop in failed_tests {
         let failure_message = build_failure_message(prop.description.clone(), &prop.trace.clone());
         result_str.push_str(&failure_message);
     }
 
-    let verification_result = if status == VerificationStatus::Success {
-        style("SUCCESSFUL").green()
-    } else {
-        style("FAILED").red()
-    };
-    let should_panic_info = if should_panic {
+    let verification_result = format_verification_status(status);
+    let outcome_info = if expected_failure {
+        match status {
+            VerificationStatus::Success => " (failed as expected)",
+            VerificationStatus::Failure => " (unexpected pass: the harness was expected to fail)",
+            _ => "",
+        }
+    } else if should_panic {
         match failed_properties {
             FailedProperties::None => " (encountered no panics, but at least one was expected)",
             FailedProperties::PanicsOnly => " (encountered one or more panics as expected)",
             FailedProperties::Other => {
                 " (encountered failures other than panics, which were unexpected)"
             }
+            FailedProperties::UnwindOnly => {
+                " (the unwind bound is too small to determine whether the harness panics)"
+            }
         }
     } else {
         ""
     };
-    let overall_result = format!("\nVERIFICATION:- {verification_result}{should_panic_info}\n");
+    let overall_result = format!("\nVERIFICATION:- {verification_result}{outcome_info}\n");
     result_str.push_str(&overall_result);
 
     // Ideally, we should generate two `ParserItem::Message` and push them
@@ -385,12 +418,26 @@ pub fn format_result(
     }
     if has_unwinding_assertion_failures(properties) {
         result_str.push_str("[Kani] info: Verification output shows one or more unwinding failures.\n\
-        [Kani] tip: Consider increasing the unwinding value or disabling `--unwinding-assertions`.\n");
+        [Kani] tip: Consider increasing the unwinding value (e.g. with `--unwind <n>`) or disabling `--unwinding-assertions`.\n");
     }
 
     result_str
 }
 
+/// Returns the (styled) text that describes the given verification status, as in
+/// `VERIFICATION:- <status>`.
+pub fn format_verification_status(status: VerificationStatus) -> StyledObject<&'static str> {
+    match status {
+        VerificationStatus::Success => style("SUCCESSFUL").green(),
+        VerificationStatus::Failure => style("FAILED").red(),
+        VerificationStatus::UnwindBoundInsufficient => style("UNWIND BOUND INSUFFICIENT").yellow(),
+        VerificationStatus::MemoryLimitExceeded => style("MEMORY LIMIT EXCEEDED").red(),
+        VerificationStatus::NoProperties => {
+            style("NO PROPERTIES CHECKED (trivially passing)").yellow()
+        }
+    }
+}
+
 /// Separate checks into coverage and non-coverage based on property class and
 /// format them separately for `--coverage`. Then we report both verification
 /// and processed coverage results.
@@ -402,20 +449,53 @@ pub fn format_coverage(
     cov_results: &CoverageResults,
     status: VerificationStatus,
     should_panic: bool,
+    expected_failure: bool,
     failed_properties: FailedProperties,
     show_checks: bool,
 ) -> String {
     let (_coverage_checks, non_coverage_checks): (Vec<Property>, Vec<Property>) =
         properties.iter().cloned().partition(|x| x.property_class() == "code_coverage");
 
-    let verification_output =
-        format_result(&non_coverage_checks, status, should_panic, failed_properties, show_checks);
+    let verification_output = format_result(
+        &non_coverage_checks,
+        status,
+        should_panic,
+        expected_failure,
+        failed_properties,
+        show_checks,
+    );
     let cov_results_intro = "Source-based code coverage results:";
     let result = format!("{}\n{}\n\n{}", verification_output, cov_results_intro, cov_results);
 
     result
 }
 
+/// List the checks that fail if a memory allocation fails. These checks are not generated with
+/// `--assume-alloc-never-fails`, so this returns an empty string in that case.
+fn format_allocation_failure_checks(properties: &[Property]) -> String {
+    let checks: Vec<_> = properties
+        .iter()
+        .filter(|prop| prop.property_class() == ALLOCATION_FAILURE_CLASS)
+        .collect();
+    if checks.is_empty() {
+        return String::new();
+    }
+    let failed = checks.iter().filter(|prop| prop.status == CheckStatus::Failure).count();
+    let mut result = format!(
+        "\nAllocation failure checks: {failed} of {} failed (use `--assume-alloc-never-fails` to \
+        assume that allocations succeed)\n",
+        checks.len()
+    );
+    for prop in checks {
+        result.push_str(&format!(" - {}: {}", prop.property_name(), prop.status));
+        if !prop.source_location.is_missing() {
+            result.push_str(&format!(" ({})", prop.source_location));
+        }
+        result.push('\n');
+    }
+    result
+}
+
 /// Attempts to build a message for a failed property with as much detailed
 /// information on the source location as possible.
 fn build_failure_message(description: String, trace: &Option<Vec<TraceItem>>) -> String {
@@ -512,7 +592,8 @@ pub fn postprocess_result(properties: Vec<Property>, extra_ptr_checks: bool) ->
     let updated_properties =
         update_properties_with_reach_status(properties_filtered, has_fundamental_failures);
     let results_after_code_coverage = update_results_of_code_covererage_checks(updated_properties);
-    update_results_of_cover_checks(results_after_code_coverage)
+    let results_after_cover = update_results_of_cover_checks(results_after_code_coverage);
+    update_results_of_assume_satisfiable_checks(results_after_cover)
 }
 
 /// Determines if there is property with status `FAILURE` and the given description
@@ -658,6 +739,28 @@ fn update_results_of_cover_checks(mut properties: Vec<Property>) -> Vec<Property
     }
     properties
 }
+
+/// Update the results of `kani::assume_satisfiable` checks.
+/// We encode them as an assert(false) after the assumption, so if the
+/// assertion fails, then the assumption is satisfiable and vice versa:
+/// - SUCCESS -> FAILURE
+/// - FAILURE -> SUCCESS
+///
+/// As with cover properties, the check is `CheckStatus::Unreachable` at this
+/// point if the assumption itself is unreachable, which is not a failure.
+fn update_results_of_assume_satisfiable_checks(mut properties: Vec<Property>) -> Vec<Property> {
+    for prop in properties.iter_mut() {
+        if prop.is_assume_satisfiable_property() {
+            if prop.status == CheckStatus::Success {
+                prop.status = CheckStatus::Failure;
+            } else if prop.status == CheckStatus::Failure {
+                prop.status = CheckStatus::Success;
+            }
+        }
+    }
+    properties
+}
+
 /// Some Kani-generated asserts have a unique ID in their description of the form:
 /// ```text
 /// [KANI_CHECK_ID_<crate-fn-name>_<index>]
@@ -0,0 +1,93 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This module implements `--write-compile-commands`, which records the `kani-compiler`
+//! invocations made by the driver in the JSON compilation database format used by clang
+//! (`compile_commands.json`).
+//!
+//! See <https://clang.llvm.org/docs/JSONCompilationDatabase.html> for the format specification.
+
+use std::ffi::OsString;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::session::KaniSession;
+
+/// A single entry of the compilation database, describing one compilation unit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CompileCommand {
+    /// The working directory of the compilation.
+    pub directory: PathBuf,
+    /// The compiler invocation, starting with the path to `kani-compiler`.
+    pub arguments: Vec<String>,
+    /// The main source file of the compilation unit.
+    pub file: PathBuf,
+}
+
+impl CompileCommand {
+    pub fn new(
+        directory: &Path,
+        compiler: &Path,
+        args: impl IntoIterator<Item = OsString>,
+        file: &Path,
+    ) -> Self {
+        let arguments = std::iter::once(compiler.as_os_str().to_owned())
+            .chain(args)
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect();
+        CompileCommand { directory: directory.to_path_buf(), arguments, file: file.to_path_buf() }
+    }
+}
+
+impl KaniSession {
+    /// Record a compiler invocation so it can be written out with `--write-compile-commands`.
+    pub fn record_compile_command(&self, command: CompileCommand) {
+        self.compile_commands.lock().unwrap().push(command);
+    }
+
+    /// Write the compiler invocations recorded so far to the path given by
+    /// `--write-compile-commands`. This is a no-op if the option was not provided.
+    pub fn write_compile_commands(&self) -> Result<()> {
+        let Some(path) = &self.args.write_compile_commands else { return Ok(()) };
+        let commands = self.compile_commands.lock().unwrap();
+        let file =
+            File::create(path).with_context(|| format!("failed to create `{}`", path.display()))?;
+        serde_json::to_writer_pretty(BufWriter::new(file), &*commands)?;
+        if !self.args.common_args.quiet {
+            println!("[info] Compile commands written to {}", path.display());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_compile_command_format() {
+        let command = CompileCommand::new(
+            Path::new("/ws/member"),
+            Path::new("/kani/bin/kani-compiler"),
+            ["--kani-compiler", "-Cllvm-args=--reachability=harnesses"].map(OsString::from),
+            Path::new("/ws/member/src/lib.rs"),
+        );
+        let json = serde_json::to_value(&command).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "directory": "/ws/member",
+                "arguments": [
+                    "/kani/bin/kani-compiler",
+                    "--kani-compiler",
+                    "-Cllvm-args=--reachability=harnesses"
+                ],
+                "file": "/ws/member/src/lib.rs",
+            })
+        );
+    }
+}
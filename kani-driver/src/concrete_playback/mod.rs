@@ -3,4 +3,5 @@
 //! Implements the logic related to concrete playback
 
 pub mod playback;
+pub mod replay;
 pub mod test_generator;
@@ -22,7 +22,12 @@ pub fn playback_cargo(args: CargoPlaybackArgs) -> Result<()> {
 
 pub fn playback_standalone(args: KaniPlaybackArgs) -> Result<()> {
     let install = InstallType::new()?;
-    let artifact = build_test(&install, &args)?;
+    let artifact = build_test(
+        &install,
+        &args.input,
+        &args.playback.common_opts,
+        args.playback.message_format,
+    )?;
     debug!(?artifact, "playback_standalone");
 
     if !args.playback.common_opts.quiet() {
@@ -63,40 +68,42 @@ fn run_test(exe: &Path, args: &KaniPlaybackArgs) -> Result<()> {
     Ok(())
 }
 
-fn build_test(install: &InstallType, args: &KaniPlaybackArgs) -> Result<PathBuf> {
+/// Build the tests of the standalone crate `input`, and return the path to the test executable.
+pub(crate) fn build_test(
+    install: &InstallType,
+    input: &Path,
+    verbosity: &impl Verbosity,
+    message_format: MessageFormat,
+) -> Result<PathBuf> {
     const TEST_BIN_NAME: &str = "kani_concrete_playback";
 
-    if !args.playback.common_opts.quiet() {
-        util::info_operation("Building", args.input.to_string_lossy().deref());
+    if !verbosity.quiet() {
+        util::info_operation("Building", input.to_string_lossy().deref());
     }
 
     let mut rustc_args = base_rustc_flags(LibConfig::new(lib_playback_folder()?));
     rustc_args.push("--test".into());
-    rustc_args.push(OsString::from(&args.input));
+    rustc_args.push(OsString::from(input));
     rustc_args.push(format!("--crate-name={TEST_BIN_NAME}").into());
 
-    if args.playback.common_opts.verbose() {
+    if verbosity.verbose() {
         rustc_args.push("--verbose".into());
     }
 
-    if args.playback.message_format == MessageFormat::Json {
+    if message_format == MessageFormat::Json {
         rustc_args.push("--error-format=json".into());
     }
 
     let mut cmd = Command::new(install.kani_compiler()?);
     cmd.args(rustc_args);
 
-    session::run_terminal(&args.playback.common_opts, cmd)?;
+    session::run_terminal(verbosity, cmd)?;
 
     Ok(PathBuf::from(TEST_BIN_NAME).canonicalize()?)
 }
 
 /// Invokes cargo test using Kani compiler and the provided arguments.
 fn cargo_test(args: CargoPlaybackArgs) -> Result<()> {
-    let install = InstallType::new()?;
-    let mut cmd = setup_cargo_command()?;
-
-    let rustc_args = base_rustc_flags(LibConfig::new(lib_playback_folder()?));
     let mut cargo_args: Vec<OsString> = vec!["test".into()];
 
     if args.playback.common_opts.verbose() {
@@ -114,12 +121,27 @@ fn cargo_test(args: CargoPlaybackArgs) -> Result<()> {
     }
 
     cargo_args.append(&mut args.cargo.to_cargo_args());
+    let cmd = cargo_test_cmd(cargo_args, &args.playback.test_args)?;
+    session::run_terminal(&args.playback.common_opts, cmd)?;
+    Ok(())
+}
+
+/// Create the command that invokes cargo with the Kani compiler, where `cargo_args` start with
+/// the cargo subcommand, and `test_args` are passed to the test executables.
+pub(crate) fn cargo_test_cmd(
+    mut cargo_args: Vec<OsString>,
+    test_args: &[String],
+) -> Result<Command> {
+    let install = InstallType::new()?;
+    let mut cmd = setup_cargo_command()?;
+
+    let rustc_args = base_rustc_flags(LibConfig::new(lib_playback_folder()?));
     cargo_args.append(&mut cargo_config_args());
 
     // These have to be the last arguments to cargo test.
-    if !args.playback.test_args.is_empty() {
+    if !test_args.is_empty() {
         cargo_args.push("--".into());
-        cargo_args.extend(args.playback.test_args.iter().map(|arg| arg.into()));
+        cargo_args.extend(test_args.iter().map(|arg| arg.into()));
     }
 
     // Arguments that will only be passed to the target package.
@@ -129,7 +151,5 @@ fn cargo_test(args: CargoPlaybackArgs) -> Result<()> {
         // https://doc.rust-lang.org/cargo/reference/environment-variables.html
         .env("CARGO_ENCODED_RUSTFLAGS", rustc_args.join(&OsString::from("\x1f")))
         .env("CARGO_TERM_PROGRESS_WHEN", "never");
-
-    session::run_terminal(&args.playback.common_opts, cmd)?;
-    Ok(())
+    Ok(cmd)
 }
@@ -0,0 +1,169 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Implements `--run`, which replays the counterexample of a failed harness right after
+//! verification, by temporarily adding the concrete playback unit test to the source code and
+//! running it.
+
+use crate::args::playback_args::MessageFormat;
+use crate::call_cbmc::{VerificationResult, VerificationStatus};
+use crate::concrete_playback::playback::{build_test, cargo_test_cmd};
+use crate::concrete_playback::test_generator::{UnitTest, gen_unit_tests};
+use crate::harness_runner::HarnessResult;
+use crate::project::Project;
+use crate::session::{InstallType, KaniSession};
+use anyhow::{Context, Result, bail};
+use kani_metadata::HarnessMetadata;
+use std::ffi::OsString;
+use std::process::{Command, Output};
+
+/// The message printed by the playback library when the test runs out of concrete values, i.e.,
+/// when the counterexample could not be fully reconstructed.
+const MISSING_VALUES_MSG: &str = "Not enough det vals found";
+
+impl KaniSession {
+    /// Replay the counterexample of each failed harness in `results`.
+    pub fn replay_counterexamples(
+        &self,
+        project: &Project,
+        results: &[HarnessResult],
+    ) -> Result<()> {
+        for HarnessResult { harness, result } in results {
+            self.replay_counterexample(project, harness, result)?;
+        }
+        Ok(())
+    }
+
+    fn replay_counterexample(
+        &self,
+        project: &Project,
+        harness: &HarnessMetadata,
+        result: &VerificationResult,
+    ) -> Result<()> {
+        if result.status != VerificationStatus::Failure {
+            println!(
+                "INFO: Harness `{}` did not fail, so there is no counterexample to replay.",
+                harness.pretty_name
+            );
+            return Ok(());
+        }
+        let Ok(properties) = &result.results else {
+            println!(
+                "WARNING: Kani cannot replay `{}` because CBMC did not produce a counterexample.",
+                harness.pretty_name
+            );
+            return Ok(());
+        };
+        let Some(unit_test) = gen_unit_tests(harness, properties).into_iter().next() else {
+            println!(
+                "WARNING: Kani cannot replay `{}` because there were no failing panic checks to \
+                reconstruct a counterexample from.",
+                harness.pretty_name
+            );
+            return Ok(());
+        };
+
+        let output = self.run_replay_test(project, harness, unit_test)?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let panic = extract_panic(&stdout);
+        println!("\nReplay of harness `{}`:", harness.pretty_name);
+        println!(" - CBMC verdict: {}", result.render_summary());
+        match panic {
+            Some(panic) if panic.contains(MISSING_VALUES_MSG) => println!(
+                " - Replayed test ran out of concrete values.\n\
+                WARNING: Kani could not reconstruct every value of the counterexample, so the \
+                replay diverged from it. This may happen when the harness uses stubs, contracts \
+                or values that concrete playback doesn't support."
+            ),
+            Some(panic) => println!(" - Replayed panic:\n{panic}"),
+            None if output.status.success() => println!(
+                " - Replayed test passed.\n\
+                WARNING: The concrete playback test did not reproduce the failure, e.g., because \
+                the harness uses stubs or contracts that are not applied to the test."
+            ),
+            None => {
+                print!("{stdout}{}", String::from_utf8_lossy(&output.stderr));
+                bail!("failed to replay the counterexample of `{}`", harness.pretty_name);
+            }
+        }
+        Ok(())
+    }
+
+    /// Add `unit_test` to the source code of `harness`, build and run it, and restore the source
+    /// code. Returns the output of the test.
+    fn run_replay_test(
+        &self,
+        project: &Project,
+        harness: &HarnessMetadata,
+        unit_test: UnitTest,
+    ) -> Result<Output> {
+        let src_path = &harness.original_file;
+        let original = std::fs::read_to_string(src_path)
+            .with_context(|| format!("failed to read `{src_path}`"))?;
+        let test_name = unit_test.name.clone();
+        let result = self
+            .add_tests_inplace(src_path, harness.original_end_line, vec![unit_test])
+            .and_then(|_| self.replay_test_cmd(project, &test_name))
+            .and_then(|mut cmd| {
+                cmd.output().with_context(|| {
+                    format!("failed to invoke {}", cmd.get_program().to_string_lossy())
+                })
+            });
+        std::fs::write(src_path, original)
+            .with_context(|| format!("failed to restore `{src_path}`"))?;
+        result
+    }
+
+    /// Build the command that runs the concrete playback test `test_name`. For standalone
+    /// projects the test executable is built first.
+    fn replay_test_cmd(&self, project: &Project, test_name: &str) -> Result<Command> {
+        // The test is generated next to the harness, which may be inside a module, so its name
+        // is used as a filter instead of an exact path.
+        let test_args = [test_name.to_string()];
+        if let Some(input) = &project.input {
+            let install = InstallType::new()?;
+            let verbosity = &self.args.common_args;
+            let exe = build_test(&install, input, verbosity, MessageFormat::Human)?;
+            self.record_temporary_file(&exe);
+            let mut cmd = Command::new(exe);
+            cmd.args(test_args);
+            Ok(cmd)
+        } else {
+            let mut cargo_args: Vec<OsString> = vec!["test".into(), "--quiet".into()];
+            cargo_args.append(&mut self.args.cargo.to_cargo_args());
+            cargo_args.append(&mut self.args.target.to_cargo_args());
+            cargo_test_cmd(cargo_args, &test_args)
+        }
+    }
+}
+
+/// Extract the panic message of a failed test from the output of the test executable, i.e., the
+/// lines from `panicked at` up to the next empty line or note.
+fn extract_panic(output: &str) -> Option<String> {
+    let mut lines = output.lines().skip_while(|line| !line.contains("panicked at"));
+    let first = lines.next()?;
+    let rest = lines.take_while(|line| !line.is_empty() && !line.starts_with("note:"));
+    Some(std::iter::once(first).chain(rest).collect::<Vec<_>>().join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_extract_panic() {
+        let output = "running 1 test\n\
+            test kani_concrete_playback_check_123 ... FAILED\n\n\
+            failures:\n\n\
+            ---- kani_concrete_playback_check_123 stdout ----\n\
+            thread 'kani_concrete_playback_check_123' panicked at src/main.rs:5:5:\n\
+            assertion failed: x < 5\n\
+            note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace\n\n\
+            failures:\n";
+        assert_eq!(
+            extract_panic(output).unwrap(),
+            "thread 'kani_concrete_playback_check_123' panicked at src/main.rs:5:5:\n\
+            assertion failed: x < 5"
+        );
+        assert_eq!(extract_panic("running 1 test\ntest check ... ok\n"), None);
+    }
+}
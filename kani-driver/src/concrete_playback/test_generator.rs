@@ -33,23 +33,15 @@ pub fn gen_and_add_concrete_playback(
         };
 
         if let Ok(result_items) = &verification_result.results {
-            let harness_values = extract_harness_values(result_items);
+            let unit_tests = gen_unit_tests(harness, result_items);
 
-            if harness_values.is_empty() {
+            if unit_tests.is_empty() {
                 println!(
                     "WARNING: Kani could not produce a concrete playback for `{}` because there \
                     were no failing panic checks or satisfiable cover statements.",
                     harness.pretty_name
                 )
             } else {
-                let mut unit_tests: Vec<UnitTest> = harness_values
-                    .iter()
-                    .map(|(prop, concrete_vals)| {
-                        let pretty_name = harness.get_harness_name_unqualified();
-                        format_unit_test(&pretty_name, &concrete_vals, gen_test_doc(harness, prop))
-                    })
-                    .collect();
-                unit_tests.dedup_by(|a, b| a.name == b.name);
                 match playback_mode {
                     ConcretePlaybackMode::Print => {
                         for generated_unit_test in unit_tests.iter() {
@@ -130,7 +122,7 @@ fn modify_src_code(
 
     /// Writes the new source code to a user's source file using a tempfile as the means.
     /// Returns whether new unit test was injected.
-    fn add_tests_inplace(
+    pub(super) fn add_tests_inplace(
         &self,
         source_path: &str,
         proof_harness_end_line: usize,
@@ -235,6 +227,23 @@ fn run_rustfmt(
     }
 }
 
+/// Generate one concrete playback unit test for each failing panic check or satisfiable cover
+/// statement of `harness`. Returns an empty vector if there are none.
+pub(super) fn gen_unit_tests(
+    harness: &HarnessMetadata,
+    result_items: &[Property],
+) -> Vec<UnitTest> {
+    let mut unit_tests: Vec<UnitTest> = extract_harness_values(result_items)
+        .iter()
+        .map(|(prop, concrete_vals)| {
+            let pretty_name = harness.get_harness_name_unqualified();
+            format_unit_test(&pretty_name, concrete_vals, gen_test_doc(harness, prop))
+        })
+        .collect();
+    unit_tests.dedup_by(|a, b| a.name == b.name);
+    unit_tests
+}
+
 fn gen_test_doc(harness: &HarnessMetadata, property: &Property) -> String {
     let mut doc_str = match &harness.attributes.kind {
         HarnessKind::Proof => {
@@ -256,7 +265,7 @@ fn gen_test_doc(harness: &HarnessMetadata, property: &Property) -> String {
         property.property_class(),
         property.description
     ));
-    if !harness.attributes.stubs.is_empty() {
+    if harness.attributes.all_stubs().next().is_some() {
         doc_str.push_str(
             r#"///
 /// # Warning
@@ -317,14 +326,18 @@ fn format_concrete_vals(concrete_vals: &[ConcreteVal]) -> impl Iterator<Item = S
     Given a number of byte vectors, format them as:
     // interp_concrete_val_1
     vec![concrete_val_1],
-    // interp_concrete_val_2
+    // interp_concrete_val_2 (`kani::concrete::value` with id `id`)
     vec![concrete_val_2], ...
+    where the id names the `kani::concrete::value` site that created the value, if any.
     */
     concrete_vals.iter().flat_map(|concrete_val| {
-        [
-            format!("{:<8}// {}", " ", concrete_val.interp_val),
-            format!("{:<8}vec!{:?},", " ", concrete_val.byte_arr),
-        ]
+        let comment = match &concrete_val.site {
+            Some(id) => {
+                format!("{} (`kani::concrete::value` with id `{id}`)", concrete_val.interp_val)
+            }
+            None => concrete_val.interp_val.clone(),
+        };
+        [format!("{:<8}// {comment}", " "), format!("{:<8}vec!{:?},", " ", concrete_val.byte_arr)]
     })
 }
 
@@ -342,9 +355,9 @@ struct FileLineRange {
     line_range: Option<(usize, usize)>,
 }
 
-struct UnitTest {
+pub(super) struct UnitTest {
     code: Vec<String>,
-    name: String,
+    pub(super) name: String,
 }
 
 /// Extract concrete values from the CBMC output processed items.
@@ -364,10 +377,15 @@ struct UnitTest {
 mod concrete_vals_extractor {
     use crate::cbmc_output_parser::{CheckStatus, Property, TraceItem};
 
+    /// The prefix of the variable that holds the value of a `kani::concrete::value` site.
+    const CONCRETE_VALUE_VAR_PREFIX: &str = "kani_concrete_value$";
+
     #[derive(Hash)]
     pub struct ConcreteVal {
         pub byte_arr: Vec<u8>,
         pub interp_val: String,
+        /// The id of the `kani::concrete::value` site that created this value, if any.
+        pub site: Option<String>,
     }
 
     /// Extract a set of concrete values that trigger one assertion
@@ -377,7 +395,11 @@ pub fn extract_harness_values(result_items: &[Property]) -> Vec<(&Property, Vec<
         result_items
             .iter()
             .filter(|prop| {
-                (prop.property_class() != "unwind" && prop.status == CheckStatus::Failure)
+                // A failed `kani::assume_satisfiable` check has no trace, since the
+                // assumption was unsatisfiable.
+                (!prop.is_assume_satisfiable_property()
+                    && prop.property_class() != "unwind"
+                    && prop.status == CheckStatus::Failure)
                     || (prop.property_class() == "cover" && prop.status == CheckStatus::Satisfied)
             })
             .map(|property| {
@@ -386,8 +408,19 @@ pub fn extract_harness_values(result_items: &[Property]) -> Vec<(&Property, Vec<
                     .trace
                     .as_ref()
                     .expect(&format!("Missing trace for {}", property.property_name()));
-                let concrete_vals: Vec<ConcreteVal> =
-                    trace.iter().filter_map(&extract_from_trace_item).collect();
+                let mut concrete_vals: Vec<ConcreteVal> = vec![];
+                for trace_item in trace {
+                    if let Some(concrete_val) = extract_from_trace_item(trace_item) {
+                        concrete_vals.push(concrete_val);
+                    } else if let Some(id) = extract_concrete_value_site(trace_item) {
+                        // The value of the site is the last one created before it's stored.
+                        if let Some(last) =
+                            concrete_vals.last_mut().filter(|val| val.site.is_none())
+                        {
+                            last.site = Some(id.to_string());
+                        }
+                    }
+                }
 
                 (property, concrete_vals)
             })
@@ -447,12 +480,22 @@ fn extract_from_trace_item(trace_item: &TraceItem) -> Option<ConcreteVal> {
                         }
                     };
 
-                    return Some(ConcreteVal { byte_arr: next_num, interp_val });
+                    return Some(ConcreteVal { byte_arr: next_num, interp_val, site: None });
                 }
             }
         }
         None
     }
+
+    /// Extracts the id of the `kani::concrete::value` site whose variable is assigned by
+    /// `trace_item`, if any.
+    fn extract_concrete_value_site(trace_item: &TraceItem) -> Option<&str> {
+        if trace_item.step_type == "assignment" {
+            trace_item.lhs.as_deref()?.strip_prefix(CONCRETE_VALUE_VAR_PREFIX)
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg(test)]
@@ -500,16 +543,19 @@ fn format_zero_concrete_vals() {
     /// Check that the generated unit tests have the right formatting and indentation
     #[test]
     fn format_two_concrete_vals() {
-        let concrete_vals =
-            [ConcreteVal { byte_arr: vec![0, 0], interp_val: "0".to_string() }, ConcreteVal {
+        let concrete_vals = [
+            ConcreteVal { byte_arr: vec![0, 0], interp_val: "0".to_string(), site: None },
+            ConcreteVal {
                 byte_arr: vec![0, 0, 0, 0, 0, 0, 0, 0],
                 interp_val: "0l".to_string(),
-            }];
+                site: Some("len".to_string()),
+            },
+        ];
         let actual: Vec<_> = format_concrete_vals(&concrete_vals).collect();
         let expected = vec![
             format!("{:<8}// 0", " "),
             format!("{:<8}vec![0, 0],", " "),
-            format!("{:<8}// 0l", " "),
+            format!("{:<8}// 0l (`kani::concrete::value` with id `len`)", " "),
             format!("{:<8}vec![0, 0, 0, 0, 0, 0, 0, 0],", " "),
         ];
         assert_eq!(actual, expected);
@@ -536,7 +582,8 @@ fn split_unit_test_name(unit_test_name: &str) -> SplitUnitTestName {
     fn format_unit_test_full_func() {
         let doc_str = "/// Test documentation";
         let harness_name = "test_proof_harness";
-        let concrete_vals = [ConcreteVal { byte_arr: vec![0, 0], interp_val: "0".to_string() }];
+        let concrete_vals =
+            [ConcreteVal { byte_arr: vec![0, 0], interp_val: "0".to_string(), site: None }];
         let unit_test = format_unit_test(harness_name, &concrete_vals, doc_str.to_string());
         let full_func = unit_test.code;
         let split_unit_test_name = split_unit_test_name(&unit_test.name);
@@ -574,9 +621,12 @@ fn extract_hash_from_unit_test(harness_name: &str, concrete_vals: &[ConcreteVal]
     fn check_hashes_are_unique() {
         let harness_name_1 = "test_proof_harness1";
         let harness_name_2 = "test_proof_harness2";
-        let concrete_vals_1 = [ConcreteVal { byte_arr: vec![0, 0], interp_val: "0".to_string() }];
-        let concrete_vals_2 = [ConcreteVal { byte_arr: vec![1, 0], interp_val: "0".to_string() }];
-        let concrete_vals_3 = [ConcreteVal { byte_arr: vec![0, 0], interp_val: "1".to_string() }];
+        let concrete_vals_1 =
+            [ConcreteVal { byte_arr: vec![0, 0], interp_val: "0".to_string(), site: None }];
+        let concrete_vals_2 =
+            [ConcreteVal { byte_arr: vec![1, 0], interp_val: "0".to_string(), site: None }];
+        let concrete_vals_3 =
+            [ConcreteVal { byte_arr: vec![0, 0], interp_val: "1".to_string(), site: None }];
 
         let hash_base = extract_hash_from_unit_test(harness_name_1, &concrete_vals_1);
         let hash_diff_harness_name = extract_hash_from_unit_test(harness_name_2, &concrete_vals_1);
@@ -666,4 +716,56 @@ fn check_concrete_vals_extractor() {
         assert_eq!(concrete_val.byte_arr, vec![1, 3]);
         assert_eq!(concrete_val.interp_val, "385");
     }
+
+    /// Check that a value is labeled with the `kani::concrete::value` site that stores it.
+    #[test]
+    fn check_concrete_value_site_extractor() {
+        let any_raw_item = |binary: &str, data: &str| TraceItem {
+            step_type: "assignment".to_string(),
+            lhs: Some("goto_symex$$return_value".to_string()),
+            source_location: Some(SourceLocation {
+                column: None,
+                file: None,
+                function: Some("kani::any_raw_internal::<u8>".to_string()),
+                line: None,
+            }),
+            value: Some(TraceValue {
+                binary: Some(binary.to_string()),
+                data: Some(TraceData::NonBool(data.to_string())),
+                width: Some(8),
+            }),
+        };
+        let site_item = TraceItem {
+            step_type: "assignment".to_string(),
+            lhs: Some("kani_concrete_value$len".to_string()),
+            source_location: None,
+            value: None,
+        };
+        let processed_items = [Property {
+            description: "".to_string(),
+            property_id: PropertyId {
+                fn_name: Some("".to_string()),
+                class: "assertion".to_string(),
+                id: 1,
+            },
+            status: CheckStatus::Failure,
+            reach: None,
+            source_location: SourceLocation {
+                column: None,
+                file: None,
+                function: None,
+                line: None,
+            },
+            trace: Some(vec![
+                any_raw_item("00000100", "4"),
+                site_item,
+                any_raw_item("00000001", "1"),
+            ]),
+        }];
+        let (_, concrete_vals) = extract_harness_values(&processed_items).pop().unwrap();
+
+        assert_eq!(concrete_vals.len(), 2);
+        assert_eq!(concrete_vals[0].site.as_deref(), Some("len"));
+        assert_eq!(concrete_vals[1].site, None);
+    }
 }
@@ -0,0 +1,312 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This module implements the generation of HTML coverage reports.
+//!
+//! The report consists of an `index.html` page which summarizes line coverage
+//! for every source file that has coverage checks, plus one annotated page per
+//! source file. Coverage results are aggregated across all harnesses in the
+//! run: a region is considered covered if any harness covers it.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::KaniSession;
+use crate::cbmc_output_parser::CheckStatus;
+use crate::coverage::cov_results::{CoverageRegion, CoverageResults};
+use crate::harness_runner::HarnessResult;
+use crate::project::Project;
+
+/// The coverage status of a single source line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LineStatus {
+    /// All the regions which include this line are covered.
+    Covered,
+    /// Some (but not all) of the regions which include this line are covered.
+    Partial,
+    /// None of the regions which include this line are covered.
+    Uncovered,
+}
+
+impl LineStatus {
+    fn css_class(&self) -> &'static str {
+        match self {
+            LineStatus::Covered => "covered",
+            LineStatus::Partial => "partial",
+            LineStatus::Uncovered => "uncovered",
+        }
+    }
+}
+
+/// Aggregated coverage results, mapping each file to its coverage regions and
+/// whether they were covered by at least one harness.
+type AggregatedResults = BTreeMap<String, BTreeMap<CoverageRegion, bool>>;
+
+const REPORT_STYLE: &str = "\
+body { font-family: sans-serif; }
+table { border-collapse: collapse; }
+td, th { padding: 0 0.5em; text-align: left; }
+.source td { font-family: monospace; white-space: pre; }
+.line-number { color: #888; text-align: right; }
+.covered { background-color: #c8f0c8; }
+.partial { background-color: #f8ecb0; }
+.uncovered { background-color: #f4c0c0; }
+";
+
+impl KaniSession {
+    /// Generates an HTML coverage report for the coverage results in `results`
+    /// and saves it to the `html` folder of the coverage directory.
+    ///
+    /// Report generation is skipped if no coverage data was collected.
+    pub fn save_coverage_html_report(
+        &self,
+        project: &Project,
+        results: &[HarnessResult],
+        stamp: &str,
+    ) -> Result<()> {
+        let aggregated = aggregate_coverage_results(
+            results.iter().filter_map(|r| r.result.coverage_results.as_ref()),
+        );
+        if aggregated.is_empty() {
+            println!("[info] No coverage data was collected; skipping HTML coverage report");
+            return Ok(());
+        }
+
+        let outdir = self.coverage_outdir(project, stamp)?;
+        let source_root = if project.input.is_some() {
+            std::env::current_dir()?
+        } else {
+            let metadata = project
+                .cargo_metadata
+                .as_ref()
+                .context("could not find project metadata required for the coverage report")?;
+            metadata.workspace_root.clone().into()
+        };
+
+        let html_dir = outdir.join("html");
+        fs::create_dir_all(&html_dir)?;
+
+        let mut index_rows = Vec::new();
+        let mut page_names = HashSet::new();
+        for (file, regions) in &aggregated {
+            let source_path = resolve_source_path(&source_root, file);
+            let source = fs::read_to_string(&source_path).with_context(|| {
+                format!("failed to read source file `{}`", source_path.display())
+            })?;
+            let line_status = line_status_from_regions(regions);
+            let page_name = html_page_name(file, &mut page_names);
+            fs::write(html_dir.join(&page_name), render_file_page(file, &source, &line_status))?;
+            index_rows.push((file.clone(), page_name, line_status));
+        }
+        fs::write(html_dir.join("index.html"), render_index_page(&index_rows))?;
+
+        println!("[info] Coverage HTML report saved to {}", html_dir.join("index.html").display());
+        Ok(())
+    }
+}
+
+/// Merges the coverage results of multiple harnesses.
+///
+/// A region is marked as covered if at least one harness covers it.
+fn aggregate_coverage_results<'a>(
+    results: impl Iterator<Item = &'a CoverageResults>,
+) -> AggregatedResults {
+    let mut aggregated = AggregatedResults::new();
+    for cov_results in results {
+        for (file, checks) in &cov_results.data {
+            let regions = aggregated.entry(file.clone()).or_default();
+            for check in checks {
+                let covered = check.status == CheckStatus::Covered;
+                *regions.entry(check.region.clone()).or_default() |= covered;
+            }
+        }
+    }
+    aggregated.retain(|_, regions| !regions.is_empty());
+    aggregated
+}
+
+/// Computes the coverage status of each line which appears in at least one
+/// coverage region.
+fn line_status_from_regions(regions: &BTreeMap<CoverageRegion, bool>) -> BTreeMap<u32, LineStatus> {
+    let mut lines: BTreeMap<u32, (bool, bool)> = BTreeMap::new();
+    for (region, covered) in regions {
+        for line in region.start.0..=region.end.0 {
+            let (any_covered, any_uncovered) = lines.entry(line).or_default();
+            *any_covered |= *covered;
+            *any_uncovered |= !*covered;
+        }
+    }
+    lines
+        .into_iter()
+        .map(|(line, flags)| {
+            let status = match flags {
+                (true, false) => LineStatus::Covered,
+                (true, true) => LineStatus::Partial,
+                _ => LineStatus::Uncovered,
+            };
+            (line, status)
+        })
+        .collect()
+}
+
+/// Resolve the path of a source file referenced by a coverage region.
+/// Relative paths are relative to the directory where the compiler was invoked.
+fn resolve_source_path(source_root: &Path, file: &str) -> PathBuf {
+    let path = Path::new(file);
+    if path.is_absolute() { path.to_path_buf() } else { source_root.join(path) }
+}
+
+/// Returns a flat file name for the page associated to `file`, which is different from the names
+/// in `used`, e.g., for `src/a_b.rs` and `src/a/b.rs`. The new name is added to `used`.
+fn html_page_name(file: &str, used: &mut HashSet<String>) -> String {
+    let name: String =
+        file.chars().map(|c| if c.is_ascii_alphanumeric() || c == '.' { c } else { '_' }).collect();
+    let mut page_name = format!("{name}.html");
+    let mut suffix = 1;
+    while !used.insert(page_name.clone()) {
+        suffix += 1;
+        page_name = format!("{name}_{suffix}.html");
+    }
+    page_name
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn render_page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+        <style>\n{REPORT_STYLE}</style>\n</head>\n<body>\n{body}</body>\n</html>\n",
+        escape_html(title)
+    )
+}
+
+fn render_file_page(file: &str, source: &str, line_status: &BTreeMap<u32, LineStatus>) -> String {
+    let mut body = String::new();
+    writeln!(body, "<h1>{}</h1>", escape_html(file)).unwrap();
+    writeln!(body, "<p><a href=\"index.html\">Back to index</a></p>").unwrap();
+    writeln!(body, "<table class=\"source\">").unwrap();
+    for (idx, line) in source.lines().enumerate() {
+        let line_num = idx as u32 + 1;
+        let class = line_status.get(&line_num).map_or("", LineStatus::css_class);
+        writeln!(
+            body,
+            "<tr class=\"{class}\"><td class=\"line-number\">{line_num}</td><td>{}</td></tr>",
+            escape_html(line)
+        )
+        .unwrap();
+    }
+    writeln!(body, "</table>").unwrap();
+    render_page(file, &body)
+}
+
+fn render_index_page(rows: &[(String, String, BTreeMap<u32, LineStatus>)]) -> String {
+    let mut body = String::new();
+    writeln!(body, "<h1>Kani coverage report</h1>").unwrap();
+    writeln!(body, "<table>").unwrap();
+    writeln!(
+        body,
+        "<tr><th>File</th><th>Covered lines</th><th>Partially covered lines</th>\
+        <th>Uncovered lines</th></tr>"
+    )
+    .unwrap();
+    for (file, page_name, line_status) in rows {
+        let count = |status| line_status.values().filter(|s| **s == status).count();
+        writeln!(
+            body,
+            "<tr><td><a href=\"{}\">{}</a></td><td class=\"covered\">{}</td>\
+            <td class=\"partial\">{}</td><td class=\"uncovered\">{}</td></tr>",
+            escape_html(page_name),
+            escape_html(file),
+            count(LineStatus::Covered),
+            count(LineStatus::Partial),
+            count(LineStatus::Uncovered),
+        )
+        .unwrap();
+    }
+    writeln!(body, "</table>").unwrap();
+    render_page("Kani coverage report", &body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coverage::cov_results::{CoverageCheck, CoverageTerm};
+
+    fn check(file: &str, start: (u32, u32), end: (u32, u32), status: CheckStatus) -> CoverageCheck {
+        let region = CoverageRegion { file: file.to_string(), start, end };
+        CoverageCheck::new("f".to_string(), CoverageTerm::Counter(0), region, status)
+    }
+
+    fn results(checks: Vec<CoverageCheck>) -> CoverageResults {
+        let mut data: BTreeMap<String, Vec<CoverageCheck>> = BTreeMap::new();
+        for check in checks {
+            data.entry(check.region.file.clone()).or_default().push(check);
+        }
+        CoverageResults::new(data)
+    }
+
+    #[test]
+    fn check_aggregation_across_harnesses() {
+        let first = results(vec![
+            check("main.rs", (1, 1), (1, 10), CheckStatus::Covered),
+            check("main.rs", (2, 1), (2, 10), CheckStatus::Uncovered),
+        ]);
+        let second = results(vec![check("main.rs", (2, 1), (2, 10), CheckStatus::Covered)]);
+        let aggregated = aggregate_coverage_results([&first, &second].into_iter());
+        assert!(aggregated["main.rs"].values().all(|covered| *covered));
+    }
+
+    #[test]
+    fn check_line_status() {
+        let cov = results(vec![
+            check("main.rs", (1, 1), (3, 2), CheckStatus::Covered),
+            check("main.rs", (2, 5), (2, 10), CheckStatus::Uncovered),
+            check("main.rs", (5, 1), (5, 10), CheckStatus::Uncovered),
+        ]);
+        let aggregated = aggregate_coverage_results([&cov].into_iter());
+        let line_status = line_status_from_regions(&aggregated["main.rs"]);
+        assert_eq!(line_status.get(&1), Some(&LineStatus::Covered));
+        assert_eq!(line_status.get(&2), Some(&LineStatus::Partial));
+        assert_eq!(line_status.get(&3), Some(&LineStatus::Covered));
+        assert_eq!(line_status.get(&4), None);
+        assert_eq!(line_status.get(&5), Some(&LineStatus::Uncovered));
+    }
+
+    #[test]
+    fn check_empty_results() {
+        let cov = results(vec![]);
+        assert!(aggregate_coverage_results([&cov].into_iter()).is_empty());
+    }
+
+    #[test]
+    fn check_escape_html() {
+        assert_eq!(escape_html("if a < b && c > d"), "if a &lt; b &amp;&amp; c &gt; d");
+    }
+
+    #[test]
+    fn check_html_page_name() {
+        let mut used = HashSet::new();
+        assert_eq!(html_page_name("src/lib.rs", &mut used), "src_lib.rs.html");
+        // Files whose names only differ by the characters that are replaced get distinct pages.
+        assert_eq!(html_page_name("src/a_b.rs", &mut used), "src_a_b.rs.html");
+        assert_eq!(html_page_name("src/a/b.rs", &mut used), "src_a_b.rs_2.html");
+        assert_eq!(html_page_name("src/a-b.rs", &mut used), "src_a_b.rs_3.html");
+    }
+}
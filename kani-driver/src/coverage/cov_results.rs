@@ -47,7 +47,7 @@ pub struct CoverageCheck {
     pub function: String,
     term: CoverageTerm,
     pub region: CoverageRegion,
-    status: CheckStatus,
+    pub status: CheckStatus,
 }
 
 impl CoverageCheck {
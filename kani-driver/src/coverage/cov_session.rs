@@ -4,6 +4,7 @@
 use std::fs;
 use std::fs::File;
 use std::io::Write;
+use std::path::PathBuf;
 
 use crate::KaniSession;
 use crate::harness_runner::HarnessResult;
@@ -26,17 +27,7 @@ pub fn save_coverage_metadata(&self, project: &Project, stamp: &String) -> Resul
     }
 
     fn save_coverage_metadata_cargo(&self, project: &Project, stamp: &String) -> Result<()> {
-        let build_target = env!("TARGET");
-        let metadata = self.cargo_metadata(build_target)?;
-        let target_dir = self
-            .args
-            .target_dir
-            .as_ref()
-            .unwrap_or(&metadata.target_directory.clone().into())
-            .clone()
-            .join("kani");
-
-        let outdir = target_dir.join(build_target).join(format!("kanicov_{stamp}"));
+        let outdir = self.coverage_outdir(project, stamp)?;
 
         // Generally we don't expect this directory to exist, but there's no
         // reason to delete it if it does.
@@ -68,8 +59,7 @@ fn save_coverage_metadata_cargo(&self, project: &Project, stamp: &String) -> Res
 
     fn save_coverage_metadata_standalone(&self, project: &Project, stamp: &String) -> Result<()> {
         let input = project.input.clone().unwrap().canonicalize().unwrap();
-        let input_dir = input.parent().unwrap().to_path_buf();
-        let outdir = input_dir.join(format!("kanicov_{stamp}"));
+        let outdir = self.coverage_outdir(project, stamp)?;
 
         // Generally we don't expect this directory to exist, but there's no
         // reason to delete it if it does.
@@ -95,31 +85,9 @@ pub fn save_coverage_results(
         &self,
         project: &Project,
         results: &Vec<HarnessResult>,
-        stamp: &String,
+        stamp: &str,
     ) -> Result<()> {
-        if project.input.is_none() {
-            self.save_coverage_results_cargo(results, stamp)
-        } else {
-            self.save_coverage_results_standalone(project, results, stamp)
-        }
-    }
-
-    pub fn save_coverage_results_cargo(
-        &self,
-        results: &Vec<HarnessResult>,
-        stamp: &String,
-    ) -> Result<()> {
-        let build_target = env!("TARGET");
-        let metadata = self.cargo_metadata(build_target)?;
-        let target_dir = self
-            .args
-            .target_dir
-            .as_ref()
-            .unwrap_or(&metadata.target_directory.clone().into())
-            .clone()
-            .join("kani");
-
-        let outdir = target_dir.join(build_target).join(format!("kanicov_{stamp}"));
+        let outdir = self.coverage_outdir(project, stamp)?;
 
         // This directory should have been created by `save_coverage_metadata`,
         // so now we expect it to exist.
@@ -142,35 +110,24 @@ pub fn save_coverage_results_cargo(
         Ok(())
     }
 
-    pub fn save_coverage_results_standalone(
-        &self,
-        project: &Project,
-        results: &Vec<HarnessResult>,
-        stamp: &String,
-    ) -> Result<()> {
-        let input = project.input.clone().unwrap().canonicalize().unwrap();
-        let input_dir = input.parent().unwrap().to_path_buf();
-        let outdir = input_dir.join(format!("kanicov_{stamp}"));
-
-        // This directory should have been created by `save_coverage_metadata`,
-        // so now we expect it to exist.
-        if !outdir.exists() {
-            bail!("directory associated to coverage run does not exist")
-        }
-
-        for harness_res in results {
-            let harness_name = harness_res.harness.mangled_name.clone();
-            let kaniraw_name = format!("{harness_name}_kaniraw");
-            let file_name = outdir.join(kaniraw_name).with_extension("json");
-            let mut cov_file = File::create(file_name)?;
-
-            let cov_results = &harness_res.result.coverage_results.clone().unwrap();
-            let serialized_data = serde_json::to_string(&cov_results)?;
-            cov_file.write_all(serialized_data.as_bytes())?;
+    /// The directory where the coverage metadata, results and report of the run identified by
+    /// `stamp` are saved, i.e., `kanicov_<stamp>` next to the input file for standalone Kani,
+    /// or in `target/kani/<target triple>/` for `cargo kani`.
+    pub fn coverage_outdir(&self, project: &Project, stamp: &str) -> Result<PathBuf> {
+        if let Some(input) = &project.input {
+            let input = input.canonicalize()?;
+            Ok(input.parent().unwrap().join(format!("kanicov_{stamp}")))
+        } else {
+            let build_target = env!("TARGET");
+            let metadata = self.cargo_metadata(build_target)?;
+            let target_dir = self
+                .args
+                .target_dir
+                .as_ref()
+                .unwrap_or(&metadata.target_directory.clone().into())
+                .clone()
+                .join("kani");
+            Ok(target_dir.join(build_target).join(format!("kanicov_{stamp}")))
         }
-
-        println!("[info] Coverage results saved to {}", &outdir.display());
-
-        Ok(())
     }
 }
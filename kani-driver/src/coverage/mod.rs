@@ -1,5 +1,6 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+pub mod cov_html;
 pub mod cov_results;
 pub mod cov_session;
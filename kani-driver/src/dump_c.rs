@@ -0,0 +1,65 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This module implements `--dump-c`, which writes a C rendition of the GOTO program of a harness
+//! using `goto-instrument --dump-c`. This is meant for debugging: CBMC doesn't guarantee that the
+//! C code compiles, or that it has the same semantics as the GOTO program.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use kani_metadata::HarnessMetadata;
+
+use crate::session::KaniSession;
+
+impl KaniSession {
+    /// Ensure that `--dump-c` selects a single harness, since the C file is tied to one harness.
+    /// This is a no-op if the option was not provided.
+    pub fn check_dump_c(&self, harnesses: &[&HarnessMetadata]) -> Result<()> {
+        if self.args.dump_c.is_some() && harnesses.len() != 1 {
+            bail!(
+                "`--dump-c` requires exactly one harness, but {} were selected. Use \
+                `--harness <NAME> --exact` to select a single harness.",
+                harnesses.len()
+            );
+        }
+        Ok(())
+    }
+
+    /// Write the C rendition of the GOTO binary `file` that is about to be verified for `harness`
+    /// to the path given by `--dump-c`. This is a no-op if the option was not provided.
+    ///
+    /// The C code is kept as CBMC produces it, except for a header that marks it as a debugging
+    /// aid. In particular, names are not demangled, since demangled names are not valid C.
+    pub fn dump_c(&self, file: &Path, harness: &HarnessMetadata) -> Result<()> {
+        let Some(output) = &self.args.dump_c else { return Ok(()) };
+        self.gen_c(file, output)?;
+
+        let c_code = fs::read_to_string(output)
+            .with_context(|| format!("failed to read `{}`", output.display()))?;
+        let content = format!("{}{c_code}", header(&harness.pretty_name));
+        fs::write(output, content)
+            .with_context(|| format!("failed to write `{}`", output.display()))?;
+
+        if !self.args.common_args.quiet {
+            println!(
+                "[info] C code for harness `{}` written to {} (for debugging only, it may not \
+                compile or match the verified program)",
+                harness.pretty_name,
+                output.display()
+            );
+        }
+        Ok(())
+    }
+}
+
+/// The comment added at the top of the C file.
+fn header(harness_name: &str) -> String {
+    format!(
+        "// C rendition of the GOTO program verified by Kani for harness `{harness_name}`,\n\
+        // generated by `goto-instrument --dump-c`.\n\
+        // This is a debugging aid only: it may not compile, and it is not guaranteed to have the\n\
+        // same semantics as the program that Kani verifies.\n\n"
+    )
+}
@@ -0,0 +1,44 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This module implements `--dump-mir-for-harness`, which prints the MIR that was used to
+//! generate the code of a harness, i.e., the MIR of every item reachable from the harness after
+//! Kani's transformations.
+
+use std::fs;
+
+use anyhow::{Context, Result, bail};
+use kani_metadata::HarnessMetadata;
+
+use crate::metadata::matches_qualified_name;
+use crate::project::Project;
+use crate::session::KaniSession;
+
+impl KaniSession {
+    /// Print the MIR dumped by the compiler for the harnesses that match
+    /// `--dump-mir-for-harness`. This is a no-op if the option was not provided.
+    pub fn print_harness_mir(&self, project: &Project) -> Result<()> {
+        let Some(name) = &self.args.dump_mir_for_harness else { return Ok(()) };
+        let harnesses: Vec<&HarnessMetadata> = project
+            .get_all_harnesses()
+            .into_iter()
+            .filter(|harness| matches_qualified_name(&harness.pretty_name, name))
+            .collect();
+        if harnesses.is_empty() {
+            bail!("Failed to find a harness named `{name}` to dump its MIR.");
+        }
+        for harness in harnesses {
+            let goto_file = harness.goto_file.as_ref().expect("Expected a model file");
+            let mir_file = goto_file.with_extension("kani.mir");
+            // The dump is only printed, so remove it at the end unless `--keep-temps` is given.
+            self.record_temporary_file(&mir_file);
+            let mir = fs::read_to_string(&mir_file).with_context(|| {
+                format!("failed to read the MIR of harness `{}`", harness.pretty_name)
+            })?;
+            println!("// ===== Start of MIR for harness `{}` =====", harness.pretty_name);
+            print!("{mir}");
+            println!("// ===== End of MIR for harness `{}` =====", harness.pretty_name);
+        }
+        Ok(())
+    }
+}
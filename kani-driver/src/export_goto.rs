@@ -0,0 +1,152 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This module implements `--export-goto`, which saves the GOTO binary of a harness together with
+//! the CBMC command that Kani uses to verify it. This allows reproducing the verification result
+//! with CBMC directly, e.g., when reporting a CBMC bug, without running Kani's codegen again.
+
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use kani_metadata::HarnessMetadata;
+
+use crate::session::KaniSession;
+use crate::util::render_args;
+
+impl KaniSession {
+    /// Ensure that `--export-goto` selects a single harness, since the exported files are tied to
+    /// one harness. This is a no-op if the option was not provided.
+    pub fn check_export_goto(&self, harnesses: &[&HarnessMetadata]) -> Result<()> {
+        let Some(output) = &self.args.export_goto else { return Ok(()) };
+        if script_path(output) == *output {
+            bail!(
+                "the path given to `--export-goto` cannot have the `.sh` extension, since it is \
+                used for the script with the CBMC command."
+            );
+        }
+        if harnesses.len() != 1 {
+            bail!(
+                "`--export-goto` requires exactly one harness, but {} were selected. Use \
+                `--harness <NAME> --exact` to select a single harness.",
+                harnesses.len()
+            );
+        }
+        Ok(())
+    }
+
+    /// Copy the GOTO binary `file` that is about to be verified for `harness` to the path given
+    /// by `--export-goto`, and write a script with the CBMC command next to it. This is a no-op if
+    /// the option was not provided.
+    pub fn export_goto(&self, file: &Path, harness: &HarnessMetadata) -> Result<()> {
+        let Some(output) = &self.args.export_goto else { return Ok(()) };
+        fs::copy(file, output)
+            .with_context(|| format!("failed to write `{}`", output.display()))?;
+
+        // The script runs from its own directory, so it refers to the binary by its file name
+        // and works wherever the two files are moved to.
+        let goto_name =
+            output.file_name().context("the path given to `--export-goto` has no file name")?;
+        // A relative path to CBMC would be resolved from the wrong directory, unlike a binary
+        // found in `PATH`.
+        let cbmc = if self.cbmc.components().count() > 1 {
+            self.cbmc.canonicalize()?
+        } else {
+            self.cbmc.clone()
+        };
+        let mut script_args: Vec<OsString> = vec![cbmc.into()];
+        script_args.extend(self.cbmc_flags(Path::new(goto_name), harness)?);
+        let json_ui_note = if self.args.raw_cbmc_output() {
+            ""
+        } else {
+            "# Kani also passes `--json-ui` to read CBMC's output as JSON. Run this script with\n\
+            # `--json-ui` to get the exact output that Kani processed.\n"
+        };
+        let script = script_path(output);
+        let content = format!(
+            "#!/usr/bin/env sh\n\
+            # CBMC command used by Kani to verify harness `{}`.\n\
+            # Any argument given to this script is forwarded to CBMC.\n\
+            {json_ui_note}\
+            cd \"$(dirname \"$0\")\" || exit 1\n\
+            exec {} \"$@\"\n",
+            harness.pretty_name,
+            script_args
+                .iter()
+                .map(|arg| shell_quote(&arg.to_string_lossy()))
+                .collect::<Vec<_>>()
+                .join(" ")
+        );
+        fs::write(&script, content)
+            .with_context(|| format!("failed to write `{}`", script.display()))?;
+        make_executable(&script)?;
+
+        if !self.args.common_args.quiet {
+            // Log the exact command, with the absolute path of the binary so it can be run from
+            // any directory.
+            let mut args: Vec<OsString> = vec![self.cbmc.clone().into()];
+            args.extend(self.cbmc_flags(&output.canonicalize()?, harness)?);
+            if !self.args.raw_cbmc_output() {
+                args.push("--json-ui".into());
+            }
+            println!(
+                "[info] GOTO binary for harness `{}` written to {}",
+                harness.pretty_name,
+                output.display()
+            );
+            println!("[info] Reproduce the result with `{}`", render_args(&args).to_string_lossy());
+            println!("[info] or with the script {}", script.display());
+        }
+        Ok(())
+    }
+}
+
+/// Quote `arg` for a POSIX shell, unless it only contains characters that are never interpreted
+/// by the shell.
+fn shell_quote(arg: &str) -> String {
+    let is_safe = |c: char| c.is_ascii_alphanumeric() || "_-+=/.,:@%".contains(c);
+    if !arg.is_empty() && arg.chars().all(is_safe) {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
+
+/// Returns the path of the script with the CBMC command, which sits next to the GOTO binary.
+fn script_path(goto_path: &Path) -> PathBuf {
+    goto_path.with_extension("sh")
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+        .with_context(|| format!("failed to make `{}` executable", path.display()))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_shell_quote() {
+        assert_eq!(shell_quote("--unwind"), "--unwind");
+        assert_eq!(shell_quote("/path/to/repro.goto"), "/path/to/repro.goto");
+        assert_eq!(shell_quote("a b"), "'a b'");
+        assert_eq!(shell_quote("name$0"), "'name$0'");
+        assert_eq!(shell_quote("it's"), "'it'\\''s'");
+        assert_eq!(shell_quote(""), "''");
+    }
+
+    #[test]
+    fn check_script_path() {
+        assert_eq!(script_path(Path::new("out/repro.goto")), Path::new("out/repro.sh"));
+        assert_eq!(script_path(Path::new("repro")), Path::new("repro.sh"));
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This module implements `--output-format github`, which prints the regular output followed by
+//! one GitHub Actions workflow command for each failed property, e.g.,
+//! `::error file=src/lib.rs,line=10,col=5,title=Kani: check_foo::assertion failed: x > 0`.
+//! GitHub shows these as annotations on the lines of the pull request diff.
+//!
+//! See <https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions>.
+
+use std::path::Path;
+
+use crate::call_cbmc::{VerificationResult, VerificationStatus};
+use crate::cbmc_output_parser::{CheckStatus, Property};
+use crate::json_output::exit_status_message;
+
+impl VerificationResult {
+    /// Render one `::error` workflow command per failed property of `harness`, separated by new
+    /// lines. This is empty if the verification was successful, including harnesses that failed
+    /// as expected.
+    pub fn render_github_annotations(&self, harness: &str) -> String {
+        if matches!(self.status, VerificationStatus::Success | VerificationStatus::NoProperties) {
+            return String::new();
+        }
+        let title = format!("Kani: {harness}");
+        match &self.results {
+            Ok(results) => results
+                .iter()
+                .filter(|prop| prop.status == CheckStatus::Failure)
+                .map(|prop| property_annotation(prop, &title))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Err(exit_status) => {
+                let message = exit_status_message(exit_status);
+                format!("::error title={}::{}", escape_property(&title), escape_data(&message))
+            }
+        }
+    }
+}
+
+/// The workflow command for a failed property, with its location if CBMC reported one.
+fn property_annotation(prop: &Property, title: &str) -> String {
+    let location = &prop.source_location;
+    let mut params = vec![];
+    if let Some(file) = &location.file {
+        params.push(format!("file={}", escape_property(&relative_path(file))));
+    }
+    if let Some(line) = &location.line {
+        params.push(format!("line={}", escape_property(line)));
+    }
+    if let Some(column) = &location.column {
+        params.push(format!("col={}", escape_property(column)));
+    }
+    params.push(format!("title={}", escape_property(title)));
+    format!("::error {}::{}", params.join(","), escape_data(&prop.description))
+}
+
+/// GitHub resolves the files of annotations relative to the root of the repository, so make the
+/// paths under the current directory relative to it.
+fn relative_path(file: &str) -> String {
+    std::env::current_dir()
+        .ok()
+        .and_then(|dir| Path::new(file).strip_prefix(dir).ok().map(|path| path.to_path_buf()))
+        .map_or_else(|| file.to_string(), |path| path.to_string_lossy().into_owned())
+}
+
+/// Escape the message of a workflow command, which ends at the end of the line.
+fn escape_data(data: &str) -> String {
+    data.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Escape the value of a workflow command parameter, which also ends at `,` and `:`.
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::call_cbmc::{ExitStatus, FailedProperties};
+    use crate::cbmc_output_parser::{PropertyId, SourceLocation};
+    use std::time::Duration;
+
+    fn property(description: &str, status: CheckStatus, line: u32) -> Property {
+        Property {
+            description: description.into(),
+            property_id: PropertyId { fn_name: None, class: "assertion".into(), id: line },
+            source_location: SourceLocation {
+                column: Some("5".into()),
+                file: Some("src/lib.rs".into()),
+                function: Some("check".into()),
+                line: Some(line.to_string()),
+            },
+            status,
+            reach: None,
+            trace: None,
+        }
+    }
+
+    fn result(
+        status: VerificationStatus,
+        results: Result<Vec<Property>, ExitStatus>,
+    ) -> VerificationResult {
+        VerificationResult {
+            status,
+            failed_properties: FailedProperties::Other,
+            results,
+            runtime: Duration::ZERO,
+            generated_concrete_test: false,
+            coverage_results: None,
+            solver_seed: None,
+            invocation: None,
+            expected_failure: false,
+        }
+    }
+
+    #[test]
+    fn check_failed_properties_annotations() {
+        let properties = vec![
+            property("assertion failed: x > 0", CheckStatus::Failure, 10),
+            property("assertion failed: y > 0", CheckStatus::Success, 11),
+            property("index out of bounds: 100%", CheckStatus::Failure, 12),
+        ];
+        let failed = result(VerificationStatus::Failure, Ok(properties));
+        assert_eq!(
+            failed.render_github_annotations("mod::check"),
+            "::error file=src/lib.rs,line=10,col=5,title=Kani%3A mod%3A%3Acheck::\
+            assertion failed: x > 0\n\
+            ::error file=src/lib.rs,line=12,col=5,title=Kani%3A mod%3A%3Acheck::\
+            index out of bounds: 100%25"
+        );
+    }
+
+    #[test]
+    fn check_no_annotations_on_success() {
+        let properties = vec![property("assertion failed: x > 0", CheckStatus::Failure, 10)];
+        // E.g., a `#[kani::should_panic]` harness.
+        let expected = result(VerificationStatus::Success, Ok(properties));
+        assert_eq!(expected.render_github_annotations("check"), "");
+    }
+
+    #[test]
+    fn check_exit_status_annotation() {
+        let timeout = result(VerificationStatus::Failure, Err(ExitStatus::Timeout));
+        assert_eq!(
+            timeout.render_github_annotations("check"),
+            "::error title=Kani%3A check::CBMC timed out"
+        );
+    }
+}
@@ -1,21 +1,28 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use anyhow::{Result, bail};
-use kani_metadata::{ArtifactType, HarnessMetadata};
+use anyhow::{Context, Result, bail};
+use kani_metadata::{ArtifactType, HarnessMetadata, KaniMetadata, UnstableFeature};
+use rayon::ThreadPool;
 use rayon::prelude::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 
 use crate::args::OutputFormat;
 use crate::call_cbmc::{VerificationResult, VerificationStatus};
-use crate::project::Project;
+use crate::cbmc_property_renderer::format_verification_status;
+use crate::project::{Project, harness_target};
 use crate::session::KaniSession;
 
 use std::env::current_dir;
 use std::path::PathBuf;
 
+/// The exit code used when no harness failed, but at least one harness hit its unwind bound.
+/// This is distinct from the failure (1) and invalid argument (2) exit codes.
+const UNWIND_BOUND_INSUFFICIENT_EXIT_CODE: i32 = 3;
+
 /// A HarnessRunner is responsible for checking all proof harnesses. The data in this structure represents
 /// "background information" that the controlling driver (e.g. cargo-kani or kani) computed.
 ///
@@ -52,25 +59,141 @@ pub(crate) fn check_all_harnesses(
             builder.build()?
         };
 
-        let results = pool.install(|| -> Result<Vec<HarnessResult<'pr>>> {
-            sorted_harnesses
-                .par_iter()
-                .map(|harness| -> Result<HarnessResult<'pr>> {
-                    let goto_file =
-                        self.project.get_harness_artifact(&harness, ArtifactType::Goto).unwrap();
+        let results = if self
+            .sess
+            .args
+            .common_args
+            .unstable_features
+            .contains(UnstableFeature::VerifiedStubDependencies)
+        {
+            self.check_harnesses_in_dependency_order(&pool, &sorted_harnesses)?
+        } else {
+            self.check_harnesses(&pool, &sorted_harnesses)?
+        };
 
-                    self.sess.instrument_model(goto_file, goto_file, &self.project, &harness)?;
+        if self.sess.is_fail_fast_stopped() && !self.sess.args.common_args.quiet {
+            println!(
+                "Stopped verification after a harness failed (`--fail-fast`): {} of {} harnesses \
+                were not verified.",
+                harnesses.len() - results.len(),
+                harnesses.len()
+            );
+        }
+        Ok(results)
+    }
 
-                    if self.sess.args.synthesize_loop_contracts {
-                        self.sess.synthesize_loop_contracts(goto_file, &goto_file, &harness)?;
+    /// Check the given harnesses in parallel.
+    fn check_harnesses(
+        &self,
+        pool: &ThreadPool,
+        harnesses: &[&'pr HarnessMetadata],
+    ) -> Result<Vec<HarnessResult<'pr>>> {
+        let results = pool.install(|| -> Result<Vec<Option<HarnessResult<'pr>>>> {
+            harnesses
+                .par_iter()
+                .map(|harness| -> Result<Option<HarnessResult<'pr>>> {
+                    // With `--fail-fast`, don't start any harness after one has failed.
+                    if self.sess.is_fail_fast_stopped() {
+                        return Ok(None);
                     }
-
-                    let result = self.sess.check_harness(goto_file, harness)?;
-                    Ok(HarnessResult { harness, result })
+                    // With `--keep-going`, the harnesses whose model couldn't be generated are
+                    // reported as errors.
+                    let result = if let Some(error) = &harness.codegen_error {
+                        self.sess.report_codegen_error(harness, error)?
+                    } else {
+                        let goto_file = self
+                            .project
+                            .get_harness_artifact(&harness, ArtifactType::Goto)
+                            .unwrap();
+
+                        self.sess.instrument_model(
+                            goto_file,
+                            goto_file,
+                            &self.project,
+                            &harness,
+                        )?;
+
+                        if self.sess.args.synthesize_loop_contracts {
+                            self.sess.synthesize_loop_contracts(goto_file, &goto_file, &harness)?;
+                        }
+
+                        self.sess.export_goto(goto_file, harness)?;
+                        self.sess.dump_c(goto_file, harness)?;
+                        let Some(result) = self.sess.check_harness(goto_file, harness)? else {
+                            // The harness was stopped by `--fail-fast-kill`.
+                            return Ok(None);
+                        };
+                        result
+                    };
+                    if self.sess.args.fail_fast
+                        && !result.is_success(self.sess.args.is_strict_no_properties())
+                    {
+                        self.sess.stop_fail_fast();
+                    }
+                    Ok(Some(HarnessResult { harness, result }))
                 })
                 .collect::<Result<Vec<_>>>()
         })?;
 
+        Ok(results.into_iter().flatten().collect())
+    }
+
+    /// Check the harnesses such that every contract harness is verified before the harnesses
+    /// that use its contract as a verified stub (`#[kani::stub_verified]`).
+    ///
+    /// Harnesses that depend on a contract harness that did not succeed are not verified, and
+    /// are reported as failures.
+    fn check_harnesses_in_dependency_order(
+        &self,
+        pool: &ThreadPool,
+        harnesses: &[&'pr HarnessMetadata],
+    ) -> Result<Vec<HarnessResult<'pr>>> {
+        let dependencies = verified_stub_dependencies(&self.project.metadata, harnesses)?;
+        if self.sess.args.common_args.verbose {
+            for harness in harnesses {
+                for (stub, contract_harness) in &dependencies[harness.mangled_name.as_str()] {
+                    println!(
+                        "Harness `{}` uses the contract of `{stub}` verified by `{}`",
+                        harness.pretty_name, contract_harness.pretty_name
+                    );
+                }
+            }
+        }
+
+        let mut results = vec![];
+        let mut failed: HashSet<&str> = HashSet::new();
+        for level in dependency_levels(harnesses, &dependencies)? {
+            if self.sess.is_fail_fast_stopped() {
+                break;
+            }
+            let (blocked, ready): (Vec<_>, Vec<_>) = level.into_iter().partition(|harness| {
+                dependencies[harness.mangled_name.as_str()]
+                    .iter()
+                    .any(|(_, dep)| failed.contains(dep.mangled_name.as_str()))
+            });
+            for harness in blocked {
+                let (stub, dep) = dependencies[harness.mangled_name.as_str()]
+                    .iter()
+                    .find(|(_, dep)| failed.contains(dep.mangled_name.as_str()))
+                    .unwrap();
+                println!(
+                    "Skipping harness {}: the contract of `{stub}` is used as a verified stub, \
+                    but contract harness `{}` failed.",
+                    harness.pretty_name, dep.pretty_name
+                );
+                failed.insert(&harness.mangled_name);
+                results.push(HarnessResult {
+                    harness,
+                    result: VerificationResult::dependency_failure(),
+                });
+            }
+            for result in self.check_harnesses(pool, &ready)? {
+                if !result.result.is_success(self.sess.args.is_strict_no_properties()) {
+                    failed.insert(&result.harness.mangled_name);
+                }
+                results.push(result);
+            }
+        }
         Ok(results)
     }
 
@@ -81,7 +204,12 @@ fn check_stubbing(&self, harnesses: &[&HarnessMetadata]) -> Result<()> {
             let with_stubs: Vec<_> = harnesses
                 .iter()
                 .filter_map(|harness| {
-                    (!harness.attributes.stubs.is_empty()).then_some(harness.pretty_name.as_str())
+                    harness
+                        .attributes
+                        .all_stubs()
+                        .next()
+                        .is_some()
+                        .then_some(harness.pretty_name.as_str())
                 })
                 .collect();
             match with_stubs.as_slice() {
@@ -102,29 +230,212 @@ fn check_stubbing(&self, harnesses: &[&HarnessMetadata]) -> Result<()> {
     }
 }
 
+/// The contract harnesses that a harness depends on, together with the name of the verified
+/// stub that requires them.
+type Dependencies<'pr> = HashMap<&'pr str, Vec<(&'pr str, &'pr HarnessMetadata)>>;
+
+/// Find the contract harnesses that each harness depends on, indexed by the harness mangled name.
+///
+/// A harness that uses `#[kani::stub_verified(foo)]` depends on every selected harness annotated
+/// with `#[kani::proof_for_contract(foo)]` in the same crate. It is an error if there is none.
+fn verified_stub_dependencies<'pr>(
+    metadata: &'pr [KaniMetadata],
+    harnesses: &[&'pr HarnessMetadata],
+) -> Result<Dependencies<'pr>> {
+    let contract_harnesses: HashMap<(&str, &str), &Vec<String>> = metadata
+        .iter()
+        .flat_map(|md| {
+            md.contracted_functions
+                .iter()
+                .map(|cf| ((md.crate_name.as_str(), cf.function.as_str()), &cf.harnesses))
+        })
+        .collect();
+
+    let mut dependencies = Dependencies::new();
+    for harness in harnesses {
+        let attributes = &harness.attributes;
+        let mut harness_deps = vec![];
+        for (stub, path) in attributes.verified_stubs.iter().zip(&attributes.verified_stub_paths) {
+            let deps: Vec<_> = contract_harnesses
+                .get(&(harness.crate_name.as_str(), path.as_str()))
+                .map(|names| {
+                    harnesses
+                        .iter()
+                        .filter(|candidate| {
+                            candidate.crate_name == harness.crate_name
+                                && names.contains(&candidate.pretty_name)
+                        })
+                        .map(|dep| (stub.as_str(), *dep))
+                        .collect()
+                })
+                .unwrap_or_default();
+            if deps.is_empty() {
+                bail!(
+                    "Harness `{}` uses `#[kani::stub_verified({stub})]`, but no harness with \
+                    `#[kani::proof_for_contract({stub})]` was selected for verification. \
+                    The contract of `{stub}` must be verified before it can be used as a stub.",
+                    harness.pretty_name
+                );
+            }
+            harness_deps.extend(deps);
+        }
+        dependencies.insert(harness.mangled_name.as_str(), harness_deps);
+    }
+    Ok(dependencies)
+}
+
+/// Split the harnesses into levels, such that every harness only depends on harnesses of
+/// previous levels. Return an error if there is a circular dependency.
+fn dependency_levels<'pr>(
+    harnesses: &[&'pr HarnessMetadata],
+    dependencies: &Dependencies<'pr>,
+) -> Result<Vec<Vec<&'pr HarnessMetadata>>> {
+    let mut levels: Vec<Vec<&HarnessMetadata>> = vec![];
+    let mut done: HashSet<&str> = HashSet::new();
+    let mut pending: Vec<&HarnessMetadata> = harnesses.to_vec();
+    while !pending.is_empty() {
+        let (ready, blocked): (Vec<_>, Vec<_>) = pending.into_iter().partition(|harness| {
+            dependencies[harness.mangled_name.as_str()]
+                .iter()
+                .all(|(_, dep)| done.contains(dep.mangled_name.as_str()))
+        });
+        if ready.is_empty() {
+            bail!(
+                "Found a circular dependency between contract harnesses: {}",
+                find_cycle(&blocked, dependencies)
+            );
+        }
+        done.extend(ready.iter().map(|harness| harness.mangled_name.as_str()));
+        levels.push(ready);
+        pending = blocked;
+    }
+    Ok(levels)
+}
+
+/// Render a dependency cycle among the given harnesses, which must all be part of or lead to a
+/// cycle.
+fn find_cycle(blocked: &[&HarnessMetadata], dependencies: &Dependencies) -> String {
+    let blocked_names: HashSet<&str> =
+        blocked.iter().map(|harness| harness.mangled_name.as_str()).collect();
+    let mut path: Vec<&HarnessMetadata> = vec![blocked[0]];
+    loop {
+        let current = path.last().unwrap();
+        let (_, next) = dependencies[current.mangled_name.as_str()]
+            .iter()
+            .find(|(_, dep)| blocked_names.contains(dep.mangled_name.as_str()))
+            .unwrap();
+        if let Some(start) = path.iter().position(|h| h.mangled_name == next.mangled_name) {
+            let mut cycle: Vec<_> =
+                path[start..].iter().map(|h| format!("`{}`", h.pretty_name)).collect();
+            cycle.push(format!("`{}`", next.pretty_name));
+            return cycle.join(" -> ");
+        }
+        path.push(next);
+    }
+}
+
 impl KaniSession {
     fn process_output(
         &self,
         result: &VerificationResult,
         harness: &HarnessMetadata,
         thread_index: usize,
-    ) {
-        if self.should_print_output() {
+    ) -> Result<()> {
+        if let Some(dir) = &self.args.split_output
+            && !self.args.raw_cbmc_output()
+        {
+            let file_name = self.write_split_output(dir, result, harness)?;
+            if !self.args.common_args.quiet {
+                let summary = if self.args.summary_only {
+                    result.render_summary()
+                } else {
+                    format!("VERIFICATION:- {}", format_verification_status(result.status))
+                };
+                let output = format!(
+                    "Harness {}: {summary} (output written to {})",
+                    harness.pretty_name,
+                    file_name.display()
+                );
+                if rayon::current_num_threads() > 1 {
+                    println!("Thread {thread_index}: {output}");
+                } else {
+                    println!("{output}");
+                }
+            }
+        } else if self.args.summary_only {
+            if !self.args.common_args.quiet {
+                let status_line = self.status_line(result, harness);
+                let output = format!("{status_line}: {}", result.render_summary());
+                if rayon::current_num_threads() > 1 {
+                    println!("Thread {thread_index}: {output}");
+                } else {
+                    println!("{output}");
+                }
+            }
+        } else if self.should_print_output() {
             if self.args.output_into_files {
                 self.write_output_to_file(result, harness, thread_index);
             }
 
-            let output = result.render(&self.args.output_format, harness.attributes.should_panic);
-            if rayon::current_num_threads() > 1 {
-                println!("Thread {thread_index}: {output}");
+            let output = if self.args.output_format == OutputFormat::Json {
+                // Keep each line a valid JSON document.
+                result.render_json(&harness.pretty_name)
+            } else {
+                result.render(&self.args.output_format, harness.attributes.should_panic)
+            };
+            // The status line is printed with the detailed output, so the output of harnesses
+            // verified in parallel is not interleaved.
+            let status_line = self.status_line(result, harness);
+            if self.args.output_format == OutputFormat::Json {
+                println!("{output}\n{status_line}");
+            } else if rayon::current_num_threads() > 1 {
+                println!("Thread {thread_index}: {output}\nThread {thread_index}: {status_line}");
             } else {
-                println!("{output}");
+                println!("{output}\n{status_line}");
+            }
+        }
+        if self.args.output_format == OutputFormat::Github && !self.args.common_args.quiet {
+            // Workflow commands must start at the beginning of a line, so they are printed
+            // separately from the output above.
+            let annotations = result.render_github_annotations(&harness.pretty_name);
+            if !annotations.is_empty() {
+                println!("{annotations}");
             }
         }
+        Ok(())
+    }
+
+    /// The line printed as soon as the verification of `harness` completes, e.g.,
+    /// `PASS check_foo (0.52s)`.
+    fn status_line(&self, result: &VerificationResult, harness: &HarnessMetadata) -> String {
+        result.render_status_line(&harness.pretty_name, self.args.is_strict_no_properties())
+    }
+
+    /// Write the full verification output of the given harness to `<dir>/<mangled_name>.txt`
+    /// for `--split-output`, and return the path of the file.
+    ///
+    /// Each file is written in one go by the thread that verified the harness, so the output of
+    /// different harnesses is never interleaved.
+    fn write_split_output(
+        &self,
+        dir: &Path,
+        result: &VerificationResult,
+        harness: &HarnessMetadata,
+    ) -> Result<PathBuf> {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create directory `{}`", dir.display()))?;
+        let file_name = dir.join(format!("{}.txt", harness.mangled_name));
+        let output = result.render(&OutputFormat::Regular, harness.attributes.should_panic);
+        std::fs::write(
+            &file_name,
+            format!("Checking harness {}...\n{output}\n", harness.pretty_name),
+        )
+        .with_context(|| format!("failed to write `{}`", file_name.display()))?;
+        Ok(file_name)
     }
 
     fn should_print_output(&self) -> bool {
-        !self.args.common_args.quiet && self.args.output_format != OutputFormat::Old
+        !self.args.common_args.quiet && !self.args.raw_cbmc_output()
     }
 
     fn write_output_to_file(
@@ -161,39 +472,90 @@ fn result_output_dir(&self) -> Result<PathBuf> {
     }
 
     /// Run the verification process for a single harness
+    ///
+    /// Returns `None` if the verification was stopped because another harness failed with
+    /// `--fail-fast-kill`.
     pub(crate) fn check_harness(
         &self,
         binary: &Path,
         harness: &HarnessMetadata,
+    ) -> Result<Option<VerificationResult>> {
+        let thread_index = rayon::current_thread_index().unwrap_or_default();
+        self.print_checking_harness(harness, thread_index);
+
+        self.emit_harness_started(harness);
+        let Some(mut result) = self.with_timer(|| self.run_cbmc(binary, harness), "run_cbmc")?
+        else {
+            return Ok(None);
+        };
+
+        self.process_output(&result, harness, thread_index)?;
+        self.emit_harness_finished(harness, &result);
+        self.report_harness(harness, &result)?;
+        self.gen_and_add_concrete_playback(harness, &mut result)?;
+        Ok(Some(result))
+    }
+
+    /// Report a harness whose model could not be generated with `--keep-going` as an error with
+    /// the message of the failure, without running CBMC.
+    pub(crate) fn report_codegen_error(
+        &self,
+        harness: &HarnessMetadata,
+        error: &str,
     ) -> Result<VerificationResult> {
         let thread_index = rayon::current_thread_index().unwrap_or_default();
-        if !self.args.common_args.quiet {
+        self.print_checking_harness(harness, thread_index);
+
+        self.emit_harness_started(harness);
+        let result = VerificationResult::codegen_error(error);
+        self.process_output(&result, harness, thread_index)?;
+        self.emit_harness_finished(harness, &result);
+        self.report_harness(harness, &result)?;
+        Ok(result)
+    }
+
+    fn print_checking_harness(&self, harness: &HarnessMetadata, thread_index: usize) {
+        // With `--summary-only`, the result line already names the harness.
+        if !self.args.common_args.quiet && !self.args.summary_only {
             if rayon::current_num_threads() > 1 {
                 println!("Thread {thread_index}: Checking harness {}...", harness.pretty_name);
             } else {
                 println!("Checking harness {}...", harness.pretty_name);
             }
         }
-
-        let mut result = self.with_timer(|| self.run_cbmc(binary, harness), "run_cbmc")?;
-
-        self.process_output(&result, harness, thread_index);
-        self.gen_and_add_concrete_playback(harness, &mut result)?;
-        Ok(result)
     }
 
     /// Concludes a session by printing a summary report and exiting the process with an
     /// error code (if applicable).
     ///
+    /// The `failed_targets` are the targets that failed to compile when verifying multiple
+    /// packages, which also make the session fail.
+    ///
     /// Note: Takes `self` "by ownership". This function wants to be able to drop before
     /// exiting with an error code, if needed.
-    pub(crate) fn print_final_summary(self, results: &[HarnessResult<'_>]) -> Result<()> {
+    pub(crate) fn print_final_summary(
+        self,
+        results: &[HarnessResult<'_>],
+        failed_targets: &[String],
+    ) -> Result<()> {
         let (successes, failures): (Vec<_>, Vec<_>) =
-            results.iter().partition(|r| r.result.status == VerificationStatus::Success);
+            results.iter().partition(|r| r.result.is_success(self.args.is_strict_no_properties()));
+        let (inconclusive, failures): (Vec<_>, Vec<_>) = failures
+            .into_iter()
+            .partition(|r| r.result.status == VerificationStatus::UnwindBoundInsufficient);
+        let (memory_exceeded, failures): (Vec<_>, Vec<_>) = failures
+            .into_iter()
+            .partition(|r| r.result.status == VerificationStatus::MemoryLimitExceeded);
+        let trivial: Vec<_> = successes
+            .iter()
+            .filter(|r| r.result.status == VerificationStatus::NoProperties)
+            .collect();
 
         let succeeding = successes.len();
         let failing = failures.len();
-        let total = succeeding + failing;
+        let insufficient = inconclusive.len();
+        let out_of_memory = memory_exceeded.len();
+        let total = succeeding + failing + insufficient + out_of_memory;
 
         if self.args.concrete_playback.is_some()
             && !self.args.common_args.quiet
@@ -206,17 +568,57 @@ pub(crate) fn print_final_summary(self, results: &[HarnessResult<'_>]) -> Result
 
         // We currently omit a summary if there was just 1 harness
         if !self.args.common_args.quiet {
-            if failing > 0 {
+            if failing > 0
+                || insufficient > 0
+                || out_of_memory > 0
+                || !trivial.is_empty()
+                || !failed_targets.is_empty()
+            {
                 println!("Summary:");
             }
             for failure in failures.iter() {
-                println!("Verification failed for - {}", failure.harness.pretty_name);
+                if failure.result.expected_failure {
+                    println!("Unexpected pass for - {}", failure.harness.pretty_name);
+                } else {
+                    println!("Verification failed for - {}", failure.harness.pretty_name);
+                }
+            }
+            for result in trivial.iter() {
+                println!(
+                    "No properties checked (trivially passing) for - {}",
+                    result.harness.pretty_name
+                );
+            }
+            for result in inconclusive.iter() {
+                println!(
+                    "Verification inconclusive (unwind bound insufficient) for - {}",
+                    result.harness.pretty_name
+                );
+            }
+            for result in memory_exceeded.iter() {
+                println!("Memory limit exceeded for - {}", result.harness.pretty_name);
+            }
+            for target in failed_targets {
+                println!("Failed to compile - {target}");
+            }
+
+            let crates = crate_summaries(results, self.args.is_strict_no_properties());
+            if crates.len() > 1 {
+                println!("Results by crate:");
+                for (krate, summary) in &crates {
+                    println!(" - {krate}: {summary}");
+                }
             }
 
             if total > 0 {
+                let summary = ResultSummary { succeeding, failing, insufficient, out_of_memory };
+                println!("Complete - {summary}");
+            } else if !failed_targets.is_empty() {
                 println!(
-                    "Complete - {succeeding} successfully verified harnesses, {failing} failures, {total} total."
+                    "No proof harnesses (functions with #[kani::proof]) were found in the targets that compiled."
                 );
+            } else if !self.args.exclude_harnesses.is_empty() {
+                println!("All the selected proof harnesses were skipped by `--exclude-harness`.");
             } else {
                 match self.args.harnesses.as_slice() {
                     [] =>
@@ -238,16 +640,24 @@ pub(crate) fn print_final_summary(self, results: &[HarnessResult<'_>]) -> Result
             }
         }
 
+        self.emit_summary(succeeding, failing + out_of_memory, insufficient);
+
         if self.args.coverage {
             self.show_coverage_summary()?;
         }
 
-        if failing > 0 {
+        if failing > 0 || out_of_memory > 0 || !failed_targets.is_empty() {
             // Failure exit code without additional error message
             drop(self);
             std::process::exit(1);
         }
 
+        if insufficient > 0 {
+            // The results are inconclusive, which we report with a dedicated exit code.
+            drop(self);
+            std::process::exit(UNWIND_BOUND_INSUFFICIENT_EXIT_CODE);
+        }
+
         Ok(())
     }
 
@@ -258,3 +668,171 @@ fn show_coverage_summary(&self) -> Result<()> {
         Ok(())
     }
 }
+
+/// The number of harnesses with each kind of result, as reported by the final summary.
+#[derive(Debug, Default, PartialEq)]
+struct ResultSummary {
+    succeeding: usize,
+    failing: usize,
+    /// The harnesses that were inconclusive because of an insufficient unwind bound.
+    insufficient: usize,
+    out_of_memory: usize,
+}
+
+impl ResultSummary {
+    /// Count one more result, classified like in `print_final_summary`.
+    fn add(&mut self, result: &VerificationResult, strict_no_properties: bool) {
+        if result.is_success(strict_no_properties) {
+            self.succeeding += 1;
+        } else if result.status == VerificationStatus::UnwindBoundInsufficient {
+            self.insufficient += 1;
+        } else if result.status == VerificationStatus::MemoryLimitExceeded {
+            self.out_of_memory += 1;
+        } else {
+            self.failing += 1;
+        }
+    }
+
+    fn total(&self) -> usize {
+        self.succeeding + self.failing + self.insufficient + self.out_of_memory
+    }
+}
+
+impl std::fmt::Display for ResultSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} successfully verified harnesses, {} failures,",
+            self.succeeding, self.failing
+        )?;
+        if self.insufficient > 0 {
+            write!(f, " {} with insufficient unwind bound,", self.insufficient)?;
+        }
+        if self.out_of_memory > 0 {
+            write!(f, " {} exceeded the memory limit,", self.out_of_memory)?;
+        }
+        write!(f, " {} total.", self.total())
+    }
+}
+
+/// Group the results by the crate of their harness. The harnesses of integration tests are
+/// grouped by test target, e.g., `tests/my_crate`, apart from the library.
+fn crate_summaries<'a>(
+    results: &'a [HarnessResult<'_>],
+    strict_no_properties: bool,
+) -> BTreeMap<&'a str, ResultSummary> {
+    let mut crates: BTreeMap<&str, ResultSummary> = BTreeMap::new();
+    for result in results {
+        crates
+            .entry(harness_target(result.harness))
+            .or_default()
+            .add(&result.result, strict_no_properties);
+    }
+    crates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::tests::mock_proof_harness;
+    use kani_metadata::{ContractedFunction, HarnessKind};
+
+    /// Create a harness for the contract of `target` which uses the contracts of `stubs`.
+    fn contract_harness(name: &str, target: &str, stubs: &[&str]) -> HarnessMetadata {
+        let mut harness = mock_proof_harness(name, None, Some("krate"), None);
+        harness.attributes.kind = HarnessKind::ProofForContract { target_fn: target.into() };
+        harness.attributes.verified_stubs = stubs.iter().map(|s| s.to_string()).collect();
+        harness.attributes.verified_stub_paths = harness.attributes.verified_stubs.clone();
+        harness
+    }
+
+    fn mock_metadata(harnesses: &[HarnessMetadata]) -> KaniMetadata {
+        let contracted_functions = harnesses
+            .iter()
+            .filter_map(|harness| match &harness.attributes.kind {
+                HarnessKind::ProofForContract { target_fn } => Some(ContractedFunction {
+                    function: target_fn.clone(),
+                    file: "<unknown>".into(),
+                    harnesses: vec![harness.pretty_name.clone()],
+                }),
+                _ => None,
+            })
+            .collect();
+        KaniMetadata {
+            crate_name: "krate".into(),
+            proof_harnesses: harnesses.to_vec(),
+            unsupported_features: vec![],
+            test_harnesses: vec![],
+            contracted_functions,
+            inactive_harnesses: vec![],
+        }
+    }
+
+    #[test]
+    fn check_crate_summaries() {
+        let first = mock_proof_harness("check_first", None, Some("first"), None);
+        let second = mock_proof_harness("check_second", None, Some("second"), None);
+        let with_status = |harness, status| HarnessResult {
+            harness,
+            result: VerificationResult { status, ..VerificationResult::mock_success() },
+        };
+        let results = [
+            with_status(&first, VerificationStatus::Success),
+            with_status(&first, VerificationStatus::UnwindBoundInsufficient),
+            with_status(&second, VerificationStatus::Failure),
+            with_status(&second, VerificationStatus::MemoryLimitExceeded),
+        ];
+        let crates = crate_summaries(&results, false);
+        assert_eq!(
+            crates["first"].to_string(),
+            "1 successfully verified harnesses, 0 failures, 1 with insufficient unwind bound, 2 total."
+        );
+        assert_eq!(
+            crates["second"].to_string(),
+            "0 successfully verified harnesses, 1 failures, 1 exceeded the memory limit, 2 total."
+        );
+    }
+
+    fn names(levels: &[Vec<&HarnessMetadata>]) -> Vec<Vec<String>> {
+        levels.iter().map(|l| l.iter().map(|h| h.pretty_name.clone()).collect()).collect()
+    }
+
+    #[test]
+    fn check_dependency_order() {
+        let harnesses = [
+            contract_harness("check_outer", "outer", &["inner"]),
+            contract_harness("check_inner", "inner", &[]),
+        ];
+        let metadata = [mock_metadata(&harnesses)];
+        let refs: Vec<_> = harnesses.iter().collect();
+        let dependencies = verified_stub_dependencies(&metadata, &refs).unwrap();
+        let levels = dependency_levels(&refs, &dependencies).unwrap();
+        assert_eq!(names(&levels), vec![vec!["check_inner"], vec!["check_outer"]]);
+    }
+
+    #[test]
+    fn check_missing_contract_harness() {
+        let harnesses = [contract_harness("check_outer", "outer", &["inner"])];
+        let metadata = [mock_metadata(&harnesses)];
+        let refs: Vec<_> = harnesses.iter().collect();
+        let error = verified_stub_dependencies(&metadata, &refs).unwrap_err();
+        assert!(error.to_string().contains("no harness with `#[kani::proof_for_contract(inner)]`"));
+    }
+
+    #[test]
+    fn check_circular_dependency() {
+        let harnesses = [
+            contract_harness("check_first", "first", &["second"]),
+            contract_harness("check_second", "second", &["first"]),
+        ];
+        let metadata = [mock_metadata(&harnesses)];
+        let refs: Vec<_> = harnesses.iter().collect();
+        let dependencies = verified_stub_dependencies(&metadata, &refs).unwrap();
+        let error = dependency_levels(&refs, &dependencies).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Found a circular dependency between contract harnesses: \
+            `check_first` -> `check_second` -> `check_first`"
+        );
+    }
+}
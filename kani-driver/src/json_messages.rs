@@ -0,0 +1,166 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This module implements `--message-format json`, which replaces the human-readable output with
+//! a stream of JSON messages, one per line, for editor integrations.
+//!
+//! Each message has a `reason` field, like the JSON messages of cargo, which are forwarded as is
+//! by `cargo kani --message-format json`. Kani's messages use reasons prefixed with `kani-`, so
+//! both can be consumed from the same stream. As for `--output-format json`, removing a field or
+//! changing its meaning must increment [`MESSAGE_SCHEMA_VERSION`]. The schema is documented in
+//! `docs/src/reference/json-messages.md`.
+
+use kani_metadata::HarnessMetadata;
+use serde::Serialize;
+
+use crate::args::MessageFormat;
+use crate::call_cbmc::{VerificationResult, VerificationStatus};
+use crate::json_output::{PropertyOutput, exit_status_message, property_output, status_name};
+use crate::session::KaniSession;
+
+/// The version of the schema of the messages emitted by `--message-format json`.
+pub const MESSAGE_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize)]
+struct Message<'a> {
+    #[serde(flatten)]
+    event: Event<'a>,
+    schema_version: u32,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "reason")]
+enum Event<'a> {
+    /// The verification of a harness started.
+    #[serde(rename = "kani-harness-started")]
+    HarnessStarted { harness: &'a str, crate_name: &'a str },
+    /// The result of one property of a harness.
+    #[serde(rename = "kani-property")]
+    Property {
+        harness: &'a str,
+        #[serde(flatten)]
+        property: PropertyOutput<'a>,
+    },
+    /// The verification of a harness completed.
+    #[serde(rename = "kani-harness-finished")]
+    HarnessFinished {
+        harness: &'a str,
+        status: &'static str,
+        /// Why CBMC didn't produce any result, e.g., because it timed out.
+        error: Option<String>,
+        runtime_secs: f64,
+    },
+    /// All the selected harnesses were verified.
+    #[serde(rename = "kani-summary")]
+    Summary { succeeded: usize, failed: usize, inconclusive: usize, total: usize },
+}
+
+/// Print a single message as a line of JSON. Each message is printed with a single call, so the
+/// messages of harnesses verified in parallel are not interleaved.
+fn emit(event: Event) {
+    println!("{}", render_message(event));
+}
+
+fn render_message(event: Event) -> String {
+    let message = Message { event, schema_version: MESSAGE_SCHEMA_VERSION };
+    serde_json::to_string(&message).unwrap()
+}
+
+impl KaniSession {
+    /// Whether Kani prints JSON messages instead of its human-readable output.
+    pub fn json_messages(&self) -> bool {
+        self.args.message_format == MessageFormat::Json
+    }
+
+    /// Emit the `kani-harness-started` message if `--message-format json` is enabled.
+    pub fn emit_harness_started(&self, harness: &HarnessMetadata) {
+        if self.json_messages() {
+            emit(Event::HarnessStarted {
+                harness: &harness.pretty_name,
+                crate_name: &harness.crate_name,
+            });
+        }
+    }
+
+    /// Emit one `kani-property` message per property of the harness, followed by the
+    /// `kani-harness-finished` message, if `--message-format json` is enabled.
+    pub fn emit_harness_finished(&self, harness: &HarnessMetadata, result: &VerificationResult) {
+        if !self.json_messages() {
+            return;
+        }
+        let harness_name = harness.pretty_name.as_str();
+        let failed = !matches!(
+            result.status,
+            VerificationStatus::Success | VerificationStatus::NoProperties
+        );
+        if let Ok(properties) = &result.results {
+            for prop in properties {
+                // These are internal to Kani and are not shown in the report.
+                if prop.is_code_coverage_property()
+                    || matches!(
+                        prop.property_id.class.as_str(),
+                        "reachability_check" | "sanity_check"
+                    )
+                {
+                    continue;
+                }
+                emit(Event::Property {
+                    harness: harness_name,
+                    property: property_output(prop, failed),
+                });
+            }
+        }
+        emit(Event::HarnessFinished {
+            harness: harness_name,
+            status: status_name(result.status),
+            error: result.results.as_ref().err().map(exit_status_message),
+            runtime_secs: result.runtime.as_secs_f64(),
+        });
+    }
+
+    /// Emit the `kani-summary` message if `--message-format json` is enabled.
+    pub fn emit_summary(&self, succeeded: usize, failed: usize, inconclusive: usize) {
+        if self.json_messages() {
+            emit(Event::Summary {
+                succeeded,
+                failed,
+                inconclusive,
+                total: succeeded + failed + inconclusive,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_message_reason() {
+        let json: serde_json::Value =
+            serde_json::from_str(&render_message(Event::HarnessStarted {
+                harness: "check",
+                crate_name: "krate",
+            }))
+            .unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "reason": "kani-harness-started",
+                "harness": "check",
+                "crate_name": "krate",
+                "schema_version": MESSAGE_SCHEMA_VERSION,
+            })
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&render_message(Event::Summary {
+            succeeded: 2,
+            failed: 1,
+            inconclusive: 0,
+            total: 3,
+        }))
+        .unwrap();
+        assert_eq!(json["reason"], "kani-summary");
+        assert_eq!(json["total"], 3);
+    }
+}
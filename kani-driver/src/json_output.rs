@@ -0,0 +1,639 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This module implements `--output-format json`, which prints the result of each harness as a
+//! single line of JSON, including the counterexample traces of failed properties.
+//!
+//! The structures below define the schema of the output. Any change that removes or changes the
+//! meaning of a field must increment [`JSON_SCHEMA_VERSION`]. Adding new fields is allowed without
+//! a version change, so consumers should ignore fields they don't know about. The schema is
+//! documented in `docs/src/reference/json-output.md`.
+
+use anyhow::{Result, bail};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::call_cbmc::{
+    CbmcInvocation, ExitStatus, FailedProperties, VerificationResult, VerificationStatus,
+    determine_failed_properties,
+};
+use crate::cbmc_output_parser::{
+    CheckStatus, Property, PropertyId, SourceLocation, TraceData, TraceItem, TraceValue,
+};
+
+/// The version of the JSON schema emitted by `--output-format json`.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// The prefix of the variables that hold the values of `kani::any()` call sites, followed by the
+/// identifier of the site. This must match the names used by the compiler.
+const ANY_SITE_VAR_PREFIX: &str = "kani_any_site$";
+
+/// The result of verifying one harness.
+#[derive(Debug, Serialize)]
+struct HarnessOutput<'a> {
+    schema_version: u32,
+    harness: &'a str,
+    status: &'static str,
+    /// Why CBMC didn't produce any result, e.g., because it timed out.
+    error: Option<String>,
+    runtime_secs: f64,
+    /// The seed given to the SAT solver with `--cbmc-seed`, to reproduce this run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    solver_seed: Option<u64>,
+    /// The versions of Kani and CBMC, and the full CBMC command line, to reproduce this run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invocation: Option<&'a CbmcInvocation>,
+    checks: CheckCounts,
+    properties: Vec<PropertyOutput<'a>>,
+}
+
+/// The number of properties of a harness in each group of check classes. Every group is always
+/// present, so a class of checks that was disabled, e.g., with `--no-overflow-checks`, shows zero.
+#[derive(Debug, Default, PartialEq, Serialize)]
+struct CheckCounts {
+    /// Assertions and panics, including `assert!`, `kani::assert` and `unwrap`, and the
+    /// satisfiability checks of `kani::assume_satisfiable`.
+    assertion: usize,
+    cover: usize,
+    /// Arithmetic checks, e.g., overflow, division by zero and invalid shifts.
+    overflow: usize,
+    /// Memory safety checks, e.g., pointer dereference and out of bounds accesses.
+    pointer: usize,
+    /// Undefined behavior checks added by Kani, and reachable unsupported constructs.
+    safety: usize,
+    /// Unwinding assertions of loops and recursive calls.
+    unwind: usize,
+    /// Checks that an allocation didn't fail, e.g., because its size is too large.
+    allocation_failure: usize,
+    other: usize,
+}
+
+impl CheckCounts {
+    fn new(properties: &[Property]) -> CheckCounts {
+        let mut counts = CheckCounts::default();
+        for prop in properties {
+            let count = match prop.property_id.class.as_str() {
+                // These are internal to Kani and are not shown in the report.
+                "reachability_check" | "code_coverage" | "sanity_check" => continue,
+                "assertion" | "assume_satisfiable" => &mut counts.assertion,
+                "cover" => &mut counts.cover,
+                "overflow"
+                | "arithmetic_overflow"
+                | "NaN"
+                | "division-by-zero"
+                | "undefined-shift"
+                | "exact_div"
+                | "finite_check"
+                | "bit_count" => &mut counts.overflow,
+                "pointer"
+                | "pointer_dereference"
+                | "pointer_arithmetic"
+                | "pointer_primitives"
+                | "array_bounds"
+                | "memory-leak" => &mut counts.pointer,
+                "safety_check"
+                | "assume"
+                | "unreachable"
+                | "unsupported_construct"
+                | "enum-range-check" => &mut counts.safety,
+                "unwind" | "recursion" => &mut counts.unwind,
+                "allocation_failure" => &mut counts.allocation_failure,
+                _ => &mut counts.other,
+            };
+            *count += 1;
+        }
+        counts
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct PropertyOutput<'a> {
+    name: String,
+    class: &'a str,
+    description: &'a str,
+    status: CheckStatus,
+    location: LocationOutput<'a>,
+    /// Only present for failed properties of failed harnesses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trace: Option<Vec<TraceStepOutput<'a>>>,
+}
+
+#[derive(Debug, Serialize)]
+struct LocationOutput<'a> {
+    file: Option<&'a str>,
+    function: Option<&'a str>,
+    line: Option<&'a str>,
+    column: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize)]
+struct TraceStepOutput<'a> {
+    step_type: &'a str,
+    lhs: Option<&'a str>,
+    /// The identifier of the `kani::any()` call site that created the assigned value.
+    any_id: Option<&'a str>,
+    location: Option<LocationOutput<'a>>,
+    value: Option<ValueOutput>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct ValueOutput {
+    /// The value as rendered by CBMC, which depends on its type, e.g., `-1`, `1.5` or `true`.
+    data: Option<String>,
+    /// The bits of the value, most significant first.
+    binary: Option<String>,
+    width: Option<u32>,
+    /// The bits of the value interpreted as an unsigned integer, in decimal. Only present for
+    /// values of up to 128 bits.
+    decimal: Option<String>,
+    /// The bits of the value in hexadecimal, prefixed with `0x`.
+    hex: Option<String>,
+}
+
+impl VerificationResult {
+    /// Render the result of `harness` as a single line of JSON for `--output-format json`.
+    pub fn render_json(&self, harness: &str) -> String {
+        let failed =
+            !matches!(self.status, VerificationStatus::Success | VerificationStatus::NoProperties);
+        let (checks, properties, error) = match &self.results {
+            Ok(results) => (
+                CheckCounts::new(results),
+                results.iter().map(|prop| property_output(prop, failed)).collect(),
+                None,
+            ),
+            Err(exit_status) => {
+                (CheckCounts::default(), vec![], Some(exit_status_message(exit_status)))
+            }
+        };
+        let output = HarnessOutput {
+            schema_version: JSON_SCHEMA_VERSION,
+            harness,
+            status: status_name(self.status),
+            error,
+            runtime_secs: self.runtime.as_secs_f64(),
+            solver_seed: self.solver_seed,
+            invocation: self.invocation.as_ref(),
+            checks,
+            properties,
+        };
+        serde_json::to_string(&output).unwrap()
+    }
+}
+
+/// The result of one harness as printed by `--output-format json`, which is read back by
+/// `--replay-trace`. Fields that can be recomputed from the properties, e.g., `checks`, are ignored.
+#[derive(Debug, Deserialize)]
+struct SavedHarness {
+    schema_version: u32,
+    harness: String,
+    status: String,
+    error: Option<String>,
+    runtime_secs: f64,
+    solver_seed: Option<u64>,
+    invocation: Option<CbmcInvocation>,
+    properties: Vec<SavedProperty>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SavedProperty {
+    name: String,
+    class: String,
+    description: String,
+    status: CheckStatus,
+    location: SourceLocation,
+    trace: Option<Vec<SavedTraceStep>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SavedTraceStep {
+    step_type: String,
+    lhs: Option<String>,
+    location: Option<SourceLocation>,
+    value: Option<SavedValue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SavedValue {
+    data: Option<String>,
+    binary: Option<String>,
+    width: Option<u32>,
+}
+
+/// Parse a line printed by `--output-format json` back into the name of the harness and its
+/// verification result.
+pub fn parse_json_result(line: &str) -> Result<(String, VerificationResult)> {
+    let saved: SavedHarness = serde_json::from_str(line)?;
+    if saved.schema_version > JSON_SCHEMA_VERSION {
+        bail!(
+            "the result was written with version {} of the JSON schema, but this version of Kani \
+            only supports up to version {JSON_SCHEMA_VERSION}",
+            saved.schema_version
+        );
+    }
+    let Some(status) = status_from_name(&saved.status) else {
+        bail!("unknown verification status `{}`", saved.status)
+    };
+    let (results, failed_properties) = match saved.error {
+        Some(error) => (Err(exit_status_from_message(&error)), FailedProperties::Other),
+        None => {
+            let properties: Vec<_> =
+                saved.properties.into_iter().map(saved_property).collect::<Result<_>>()?;
+            let failed_properties = determine_failed_properties(&properties);
+            (Ok(properties), failed_properties)
+        }
+    };
+    let result = VerificationResult {
+        status,
+        failed_properties,
+        results,
+        runtime: Duration::try_from_secs_f64(saved.runtime_secs)?,
+        generated_concrete_test: false,
+        coverage_results: None,
+        solver_seed: saved.solver_seed,
+        invocation: saved.invocation,
+        expected_failure: false,
+    };
+    Ok((saved.harness, result))
+}
+
+/// Rebuild a property, whose name has the form `<function>.<class>.<id>` or `<class>.<id>`.
+fn saved_property(saved: SavedProperty) -> Result<Property> {
+    let Some((prefix, id)) = saved.name.rsplit_once('.') else {
+        bail!("invalid property name `{}`", saved.name)
+    };
+    let fn_name = if prefix == saved.class {
+        None
+    } else if let Some(fn_name) = prefix.strip_suffix(&format!(".{}", saved.class)) {
+        Some(fn_name.to_string())
+    } else {
+        bail!("property name `{}` doesn't match its class `{}`", saved.name, saved.class)
+    };
+    let trace = saved.trace.map(|trace| {
+        trace
+            .into_iter()
+            .map(|step| TraceItem {
+                step_type: step.step_type,
+                lhs: step.lhs,
+                source_location: step.location,
+                value: step.value.map(|value| TraceValue {
+                    binary: value.binary,
+                    data: value.data.map(|data| match data.as_str() {
+                        "true" => TraceData::Bool(true),
+                        "false" => TraceData::Bool(false),
+                        _ => TraceData::NonBool(data),
+                    }),
+                    width: value.width,
+                }),
+            })
+            .collect()
+    });
+    Ok(Property {
+        description: saved.description,
+        property_id: PropertyId { fn_name, class: saved.class, id: id.parse()? },
+        source_location: saved.location,
+        status: saved.status,
+        reach: None,
+        trace,
+    })
+}
+
+pub(crate) fn status_name(status: VerificationStatus) -> &'static str {
+    match status {
+        VerificationStatus::Success => "SUCCESS",
+        VerificationStatus::Failure => "FAILURE",
+        VerificationStatus::UnwindBoundInsufficient => "UNWIND_BOUND_INSUFFICIENT",
+        VerificationStatus::NoProperties => "NO_PROPERTIES",
+        VerificationStatus::MemoryLimitExceeded => "MEMORY_LIMIT_EXCEEDED",
+    }
+}
+
+fn status_from_name(name: &str) -> Option<VerificationStatus> {
+    [
+        VerificationStatus::Success,
+        VerificationStatus::Failure,
+        VerificationStatus::UnwindBoundInsufficient,
+        VerificationStatus::NoProperties,
+        VerificationStatus::MemoryLimitExceeded,
+    ]
+    .into_iter()
+    .find(|status| status_name(*status) == name)
+}
+
+/// The prefix of the message of a harness whose model couldn't be generated.
+const CODEGEN_ERROR_PREFIX: &str = "Failed to generate the model of the harness: ";
+
+pub(crate) fn exit_status_message(exit_status: &ExitStatus) -> String {
+    match exit_status {
+        ExitStatus::OutOfMemory => "CBMC ran out of memory".to_string(),
+        ExitStatus::Timeout => "CBMC timed out".to_string(),
+        ExitStatus::MemoryLimitExceeded => "CBMC went over `--max-memory`".to_string(),
+        ExitStatus::Other(status) => format!("CBMC failed with status {status}"),
+        ExitStatus::CodegenError(error) => format!("{CODEGEN_ERROR_PREFIX}{error}"),
+    }
+}
+
+/// The inverse of [`exit_status_message`]. Unknown messages are mapped to a generic failure.
+fn exit_status_from_message(message: &str) -> ExitStatus {
+    match message {
+        "CBMC ran out of memory" => ExitStatus::OutOfMemory,
+        "CBMC timed out" => ExitStatus::Timeout,
+        "CBMC went over `--max-memory`" => ExitStatus::MemoryLimitExceeded,
+        _ if message.starts_with(CODEGEN_ERROR_PREFIX) => {
+            ExitStatus::CodegenError(message.replacen(CODEGEN_ERROR_PREFIX, "", 1))
+        }
+        _ => ExitStatus::Other(
+            message
+                .strip_prefix("CBMC failed with status ")
+                .and_then(|status| status.parse().ok())
+                .unwrap_or(1),
+        ),
+    }
+}
+
+/// Convert a property. Its trace is only included if both the property and the harness failed.
+pub(crate) fn property_output(prop: &Property, harness_failed: bool) -> PropertyOutput<'_> {
+    let trace = prop
+        .trace
+        .as_ref()
+        .filter(|_| harness_failed && prop.status == CheckStatus::Failure)
+        .map(|trace| trace.iter().map(trace_step_output).collect());
+    PropertyOutput {
+        name: prop.property_name(),
+        class: &prop.property_id.class,
+        description: &prop.description,
+        status: prop.status,
+        location: location_output(&prop.source_location),
+        trace,
+    }
+}
+
+fn location_output(location: &SourceLocation) -> LocationOutput<'_> {
+    LocationOutput {
+        file: location.file.as_deref(),
+        function: location.function.as_deref(),
+        line: location.line.as_deref(),
+        column: location.column.as_deref(),
+    }
+}
+
+fn trace_step_output(step: &TraceItem) -> TraceStepOutput<'_> {
+    TraceStepOutput {
+        step_type: &step.step_type,
+        lhs: step.lhs.as_deref(),
+        any_id: step.lhs.as_deref().and_then(any_site_id),
+        location: step.source_location.as_ref().map(location_output),
+        value: step.value.as_ref().map(value_output),
+    }
+}
+
+/// The identifier of the `kani::any()` call site whose variable is assigned by `lhs`, e.g.,
+/// `check::any#0` for `kani_any_site$check::any#0` or for one of its fields.
+fn any_site_id(lhs: &str) -> Option<&str> {
+    let var = lhs.strip_prefix(ANY_SITE_VAR_PREFIX)?;
+    let ordinal = var.rfind("::any#")? + "::any#".len();
+    let digits = var[ordinal..].bytes().take_while(u8::is_ascii_digit).count();
+    (digits > 0).then(|| &var[..ordinal + digits])
+}
+
+fn value_output(value: &TraceValue) -> ValueOutput {
+    let bits = value
+        .binary
+        .as_deref()
+        .filter(|bits| !bits.is_empty() && bits.chars().all(|bit| bit == '0' || bit == '1'));
+    ValueOutput {
+        data: value.data.as_ref().map(ToString::to_string),
+        binary: value.binary.clone(),
+        width: value.width,
+        decimal: bits.and_then(|bits| u128::from_str_radix(bits, 2).ok()).map(|n| n.to_string()),
+        hex: bits.map(bits_to_hex),
+    }
+}
+
+/// Convert a string of bits, most significant first, to hexadecimal. Values that don't have a
+/// whole number of nibbles are padded with zeros on the left.
+fn bits_to_hex(bits: &str) -> String {
+    let padding = (4 - bits.len() % 4) % 4;
+    let padded: Vec<u8> = "0".repeat(padding).bytes().chain(bits.bytes()).collect();
+    let digits: String = padded
+        .chunks(4)
+        .map(|nibble| {
+            let value = nibble.iter().fold(0, |acc, bit| (acc << 1) | u32::from(bit - b'0'));
+            char::from_digit(value, 16).unwrap()
+        })
+        .collect();
+    format!("0x{digits}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::call_cbmc::FailedProperties;
+    use crate::cbmc_output_parser::{PropertyId, TraceData};
+    use std::time::Duration;
+
+    fn mock_property(status: CheckStatus) -> Property {
+        mock_property_with_class("assertion", status)
+    }
+
+    fn mock_property_with_class(class: &str, status: CheckStatus) -> Property {
+        let step = TraceItem {
+            step_type: "assignment".to_string(),
+            lhs: Some("x".to_string()),
+            source_location: None,
+            value: Some(TraceValue {
+                binary: Some("00000101".to_string()),
+                data: Some(TraceData::NonBool("5".to_string())),
+                width: Some(8),
+            }),
+        };
+        Property {
+            description: "assertion failed: x < 5".to_string(),
+            property_id: PropertyId {
+                fn_name: Some("check".to_string()),
+                class: class.to_string(),
+                id: 1,
+            },
+            source_location: SourceLocation {
+                column: None,
+                file: None,
+                function: None,
+                line: None,
+            },
+            status,
+            reach: None,
+            trace: Some(vec![step]),
+        }
+    }
+
+    fn mock_result(status: VerificationStatus, properties: Vec<Property>) -> VerificationResult {
+        VerificationResult {
+            status,
+            failed_properties: FailedProperties::None,
+            results: Ok(properties),
+            runtime: Duration::from_secs(1),
+            generated_concrete_test: false,
+            coverage_results: None,
+            solver_seed: None,
+            invocation: None,
+            expected_failure: false,
+        }
+    }
+
+    #[test]
+    fn check_render_json_traces() {
+        let failed = mock_result(VerificationStatus::Failure, vec![
+            mock_property(CheckStatus::Failure),
+            mock_property(CheckStatus::Success),
+        ]);
+        let json: serde_json::Value = serde_json::from_str(&failed.render_json("check")).unwrap();
+        assert_eq!(json["schema_version"], JSON_SCHEMA_VERSION);
+        assert_eq!(json["harness"], "check");
+        assert_eq!(json["status"], "FAILURE");
+        assert_eq!(json["properties"][0]["name"], "check.assertion.1");
+        assert_eq!(json["properties"][0]["trace"][0]["lhs"], "x");
+        assert_eq!(json["properties"][0]["trace"][0]["value"]["hex"], "0x05");
+        assert!(json["properties"][0]["trace"][0]["any_id"].is_null());
+        assert!(json["properties"][1].get("trace").is_none());
+        assert!(json.get("solver_seed").is_none());
+
+        // Traces are omitted for harnesses that pass, e.g., `should_panic` harnesses.
+        let passed =
+            mock_result(VerificationStatus::Success, vec![mock_property(CheckStatus::Failure)]);
+        let passed = VerificationResult { solver_seed: Some(42), ..passed };
+        let json: serde_json::Value = serde_json::from_str(&passed.render_json("check")).unwrap();
+        assert!(json["properties"][0].get("trace").is_none());
+        assert_eq!(json["solver_seed"], 42);
+        assert!(json.get("invocation").is_none());
+    }
+
+    #[test]
+    fn check_render_json_invocation() {
+        let invocation = CbmcInvocation {
+            kani_version: "0.1.0 (commit 0123456789ab)".to_string(),
+            cbmc_version: "6.4.1 (cbmc-6.4.1)".to_string(),
+            cbmc_command: vec!["/opt/cbmc/bin/cbmc".to_string(), "--json-ui".to_string()],
+        };
+        let passed = mock_result(VerificationStatus::Success, vec![]);
+        let passed = VerificationResult { invocation: Some(invocation.clone()), ..passed };
+        let json: serde_json::Value = serde_json::from_str(&passed.render_json("check")).unwrap();
+        assert_eq!(json["invocation"]["kani_version"], "0.1.0 (commit 0123456789ab)");
+        assert_eq!(json["invocation"]["cbmc_version"], "6.4.1 (cbmc-6.4.1)");
+        assert_eq!(
+            json["invocation"]["cbmc_command"],
+            serde_json::json!(["/opt/cbmc/bin/cbmc", "--json-ui"])
+        );
+        // The invocation is kept when the result is read back, e.g., by `--replay-trace`.
+        let (_, parsed) = parse_json_result(&passed.render_json("check")).unwrap();
+        assert_eq!(parsed.invocation, Some(invocation));
+    }
+
+    #[test]
+    fn check_any_site_id() {
+        assert_eq!(any_site_id("kani_any_site$check::any#0"), Some("check::any#0"));
+        assert_eq!(
+            any_site_id("kani_any_site$verify::check::any#12.x"),
+            Some("verify::check::any#12")
+        );
+        assert_eq!(
+            any_site_id("kani_any_site$check::{closure#0}::any#1[0]"),
+            Some("check::{closure#0}::any#1")
+        );
+        assert_eq!(any_site_id("x"), None);
+        assert_eq!(any_site_id("kani_any_site$check"), None);
+    }
+
+    #[test]
+    fn check_parse_json_result() {
+        let failed = mock_result(VerificationStatus::Failure, vec![
+            mock_property(CheckStatus::Failure),
+            mock_property_with_class("cover", CheckStatus::Satisfied),
+        ]);
+        let (harness, parsed) = parse_json_result(&failed.render_json("check")).unwrap();
+        assert_eq!(harness, "check");
+        assert_eq!(parsed.status, VerificationStatus::Failure);
+        assert!(matches!(parsed.failed_properties, FailedProperties::PanicsOnly));
+        let properties = parsed.results.as_ref().unwrap();
+        assert_eq!(properties[0].property_name(), "check.assertion.1");
+        assert_eq!(properties[1].property_name(), "check.cover.1");
+        let trace = properties[0].trace.as_ref().unwrap();
+        assert_eq!(trace[0].lhs.as_deref(), Some("x"));
+        assert_eq!(trace[0].value.as_ref().unwrap().binary.as_deref(), Some("00000101"));
+        // Rendering the parsed result again gives the same output.
+        assert_eq!(parsed.render_json("check"), failed.render_json("check"));
+
+        let timeout = VerificationResult { results: Err(ExitStatus::Timeout), ..failed };
+        let (_, parsed) = parse_json_result(&timeout.render_json("check")).unwrap();
+        assert!(matches!(parsed.results, Err(ExitStatus::Timeout)));
+
+        let codegen_error = VerificationResult::codegen_error("not implemented: foo");
+        let (_, parsed) = parse_json_result(&codegen_error.render_json("check")).unwrap();
+        assert!(
+            matches!(parsed.results, Err(ExitStatus::CodegenError(error)) if error == "not implemented: foo")
+        );
+
+        let newer = r#"{"schema_version":1000,"harness":"check","status":"SUCCESS","error":null,"runtime_secs":1.0,"properties":[]}"#;
+        assert!(parse_json_result(newer).is_err());
+    }
+
+    #[test]
+    fn check_render_json_checks() {
+        let properties = ["assertion", "assertion", "overflow", "pointer_dereference", "cover"]
+            .into_iter()
+            .chain(["reachability_check", "unwind", "safety_check", "memory-leak"])
+            .chain(["allocation_failure", "allocation_failure", "code_coverage", "object_size"])
+            .map(|class| mock_property_with_class(class, CheckStatus::Success))
+            .collect();
+        let result = mock_result(VerificationStatus::Success, properties);
+        let json: serde_json::Value = serde_json::from_str(&result.render_json("check")).unwrap();
+        assert_eq!(
+            json["checks"],
+            serde_json::json!({
+                "assertion": 2,
+                "cover": 1,
+                "overflow": 1,
+                "pointer": 2,
+                "safety": 1,
+                "unwind": 1,
+                "allocation_failure": 2,
+                "other": 1,
+            })
+        );
+    }
+
+    #[test]
+    fn check_bits_to_hex() {
+        assert_eq!(bits_to_hex("0"), "0x0");
+        assert_eq!(bits_to_hex("11111111"), "0xff");
+        assert_eq!(bits_to_hex("100000000"), "0x100");
+        assert_eq!(bits_to_hex(&"1".repeat(256)), format!("0x{}", "f".repeat(64)));
+    }
+
+    #[test]
+    fn check_value_output() {
+        let value = TraceValue {
+            binary: Some("11111111111111111111111111111110".to_string()),
+            data: Some(TraceData::NonBool("-2".to_string())),
+            width: Some(32),
+        };
+        assert_eq!(value_output(&value), ValueOutput {
+            data: Some("-2".to_string()),
+            binary: value.binary.clone(),
+            width: Some(32),
+            decimal: Some("4294967294".to_string()),
+            hex: Some("0xfffffffe".to_string()),
+        });
+
+        // Values that are too wide for a decimal representation only get the hexadecimal one.
+        let wide = TraceValue { binary: Some("1".repeat(130)), data: None, width: Some(130) };
+        let output = value_output(&wide);
+        assert_eq!(output.decimal, None);
+        assert_eq!(output.hex, Some(format!("0x3{}", "f".repeat(32))));
+
+        let no_bits = TraceValue { binary: None, data: Some(TraceData::Bool(true)), width: None };
+        let output = value_output(&no_bits);
+        assert_eq!(
+            (output.data.as_deref(), output.decimal, output.hex),
+            (Some("true"), None, None)
+        );
+    }
+}
@@ -10,7 +10,9 @@
         VerificationArgs,
         list_args::{CargoListArgs, StandaloneListArgs},
     },
+    call_cargo::KeepGoing,
     list::ListMetadata,
+    list::compare::HarnessEntry,
     list::output::output_list_results,
     project::{Project, cargo_project, standalone_project, std_project},
     session::KaniSession,
@@ -20,7 +22,7 @@
 use kani_metadata::{ContractedFunction, HarnessKind, KaniMetadata};
 
 /// Process the KaniMetadata output from kani-compiler and output the list subcommand results
-fn process_metadata(metadata: Vec<KaniMetadata>) -> ListMetadata {
+fn process_metadata(metadata: Vec<KaniMetadata>, include_inactive: bool) -> ListMetadata {
     // We use ordered maps and sets so that the output is in lexicographic order (and consistent across invocations).
 
     // Map each file to a vector of its harnesses.
@@ -29,14 +31,20 @@ fn process_metadata(metadata: Vec<KaniMetadata>) -> ListMetadata {
 
     let mut contracted_functions: BTreeSet<ContractedFunction> = BTreeSet::new();
 
+    let mut standard_harnesses_metadata = vec![];
     let mut standard_harnesses_count = 0;
     let mut contract_harnesses_count = 0;
 
+    let mut inventory = vec![];
+    let mut inactive_harnesses = vec![];
+
     for kani_meta in metadata {
         for harness_meta in kani_meta.proof_harnesses {
+            inventory.extend(HarnessEntry::new(&harness_meta));
             match harness_meta.attributes.kind {
                 HarnessKind::Proof => {
                     standard_harnesses_count += 1;
+                    standard_harnesses_metadata.push(harness_meta.clone());
                     if let Some(harnesses) = standard_harnesses.get_mut(&harness_meta.original_file)
                     {
                         harnesses.insert(harness_meta.pretty_name);
@@ -64,14 +72,31 @@ fn process_metadata(metadata: Vec<KaniMetadata>) -> ListMetadata {
         }
 
         contracted_functions.extend(kani_meta.contracted_functions.into_iter());
+        inactive_harnesses.extend(kani_meta.inactive_harnesses);
     }
 
+    // Sort by name last, so the order is stable even for harnesses with the same location.
+    standard_harnesses_metadata.sort_by(|a, b| {
+        (&a.original_file, a.original_start_line, &a.pretty_name).cmp(&(
+            &b.original_file,
+            b.original_start_line,
+            &b.pretty_name,
+        ))
+    });
+
+    inventory.sort();
+    inactive_harnesses.sort();
+    let inactive_harnesses = include_inactive.then_some(inactive_harnesses);
+
     ListMetadata {
         standard_harnesses,
         standard_harnesses_count,
+        standard_harnesses_metadata,
         contract_harnesses,
         contract_harnesses_count,
         contracted_functions,
+        inventory,
+        inactive_harnesses,
     }
 }
 
@@ -83,10 +108,10 @@ pub fn list_cargo(args: CargoListArgs, mut verify_opts: VerificationArgs) -> Res
         print_kani_version(InvocationType::CargoKani(vec![]));
     }
 
-    let project = cargo_project(&session, false)?;
-    let list_metadata = process_metadata(project.metadata);
+    let project = cargo_project(&session, KeepGoing::No)?;
+    let list_metadata = process_metadata(project.metadata, args.include_inactive);
 
-    output_list_results(list_metadata, args.format, quiet)
+    output_list_results(list_metadata, args.format, args.compare_harnesses.as_deref(), quiet)
 }
 
 pub fn list_standalone(args: StandaloneListArgs, mut verify_opts: VerificationArgs) -> Result<()> {
@@ -103,7 +128,7 @@ pub fn list_standalone(args: StandaloneListArgs, mut verify_opts: VerificationAr
         standalone_project(&args.input, args.crate_name, &session)?
     };
 
-    let list_metadata = process_metadata(project.metadata);
+    let list_metadata = process_metadata(project.metadata, args.include_inactive);
 
-    output_list_results(list_metadata, args.format, quiet)
+    output_list_results(list_metadata, args.format, args.compare_harnesses.as_deref(), quiet)
 }
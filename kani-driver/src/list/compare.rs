@@ -0,0 +1,309 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! This module implements `--compare-harnesses`, which compares the harnesses of the project
+//! against an inventory previously written by `list --format json`.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use kani_metadata::{HarnessKind, HarnessMetadata};
+use serde::{Deserialize, Serialize};
+
+/// One harness in the inventory written to the JSON output of the list subcommand.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct HarnessEntry {
+    pub file: String,
+    pub start_line: usize,
+    pub end_line: usize,
+    pub name: String,
+    pub mangled_name: String,
+    pub crate_name: String,
+    /// Either `proof` or `proof_for_contract`.
+    pub kind: String,
+    /// The function whose contract is checked by a `proof_for_contract` harness.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contract_target: Option<String>,
+}
+
+impl HarnessEntry {
+    /// Create an entry for the given harness, or return `None` for harnesses that are not listed,
+    /// i.e., tests.
+    pub fn new(harness: &HarnessMetadata) -> Option<HarnessEntry> {
+        let (kind, contract_target) = match &harness.attributes.kind {
+            HarnessKind::Proof => ("proof", None),
+            HarnessKind::ProofForContract { target_fn } => {
+                ("proof_for_contract", Some(target_fn.clone()))
+            }
+            HarnessKind::Test => return None,
+        };
+        Some(HarnessEntry {
+            file: harness.original_file.clone(),
+            start_line: harness.original_start_line,
+            end_line: harness.original_end_line,
+            name: harness.pretty_name.clone(),
+            mangled_name: harness.mangled_name.clone(),
+            crate_name: harness.crate_name.clone(),
+            kind: kind.to_string(),
+            contract_target,
+        })
+    }
+
+    fn location(&self) -> String {
+        format!("{}:{}-{}", self.file, self.start_line, self.end_line)
+    }
+
+    fn same_location(&self, other: &HarnessEntry) -> bool {
+        (&self.file, self.start_line, self.end_line)
+            == (&other.file, other.start_line, other.end_line)
+    }
+
+    /// The path of the module that contains the harness, e.g., `verify` for `verify::check_a`.
+    fn module_path(&self) -> &str {
+        self.name.rsplit_once("::").map_or("", |(module, _)| module)
+    }
+
+    /// Whether `other` is this harness under a different name, i.e., whether they check the
+    /// contract of the same function, or they start at the same line of the same module.
+    fn same_harness(&self, other: &HarnessEntry) -> bool {
+        if self.crate_name != other.crate_name {
+            return false;
+        }
+        match (&self.contract_target, &other.contract_target) {
+            (Some(target), Some(other_target)) => target == other_target,
+            (None, None) => {
+                (self.module_path(), &self.file, self.start_line)
+                    == (other.module_path(), &other.file, other.start_line)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The differences between two harness inventories.
+#[derive(Debug, Default, PartialEq)]
+pub struct InventoryDiff {
+    pub added: Vec<HarnessEntry>,
+    pub removed: Vec<HarnessEntry>,
+    /// Harnesses whose name changed, as `(old, new)` pairs.
+    pub renamed: Vec<(HarnessEntry, HarnessEntry)>,
+    /// Harnesses with the same name whose location changed, as `(old, new)` pairs.
+    pub moved: Vec<(HarnessEntry, HarnessEntry)>,
+    pub unchanged: usize,
+}
+
+/// Compare the `old` inventory with the `new` one.
+///
+/// Harnesses are matched in the following order:
+/// 1. By crate and name. If the location changed, the harness was moved.
+/// 2. By a key that doesn't depend on the name, i.e., the function whose contract a
+///    `proof_for_contract` harness checks, or the module, the file and the first line of other
+///    harnesses. These harnesses were renamed. Note that mangled names can't be used, since they
+///    include the name of the harness.
+///
+/// The harnesses that don't match are either added or removed.
+pub fn compare(old: &[HarnessEntry], new: &[HarnessEntry]) -> InventoryDiff {
+    let mut diff = InventoryDiff::default();
+    let mut old_left: Vec<&HarnessEntry> = old.iter().collect();
+    let mut new_left: Vec<&HarnessEntry> = vec![];
+    for entry in new {
+        let same_name = old_left
+            .iter()
+            .position(|old| (&old.crate_name, &old.name) == (&entry.crate_name, &entry.name));
+        match same_name {
+            Some(idx) => {
+                let old_entry = old_left.remove(idx);
+                if old_entry.same_location(entry) {
+                    diff.unchanged += 1;
+                } else {
+                    diff.moved.push((old_entry.clone(), entry.clone()));
+                }
+            }
+            None => new_left.push(entry),
+        }
+    }
+
+    new_left.retain(|entry| match old_left.iter().position(|old| old.same_harness(entry)) {
+        Some(idx) => {
+            diff.renamed.push((old_left.remove(idx).clone(), (*entry).clone()));
+            false
+        }
+        None => true,
+    });
+    diff.added = new_left.into_iter().cloned().collect();
+    diff.removed = old_left.into_iter().cloned().collect();
+    diff
+}
+
+/// Read the harness inventory from a JSON file written by `list --format json`.
+pub fn read_inventory(path: &Path) -> Result<Vec<HarnessEntry>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read `{}`", path.display()))?;
+    let json: serde_json::Value = serde_json::from_str(&content)
+        .with_context(|| format!("`{}` is not a valid JSON file", path.display()))?;
+    let Some(harnesses) = json.get("harnesses") else {
+        bail!(
+            "`{}` doesn't have a harness inventory. Generate it with `list --format json` using \
+            this version of Kani.",
+            path.display()
+        );
+    };
+    serde_json::from_value(harnesses.clone())
+        .with_context(|| format!("invalid harness inventory in `{}`", path.display()))
+}
+
+/// Print the differences between the inventory in `old_path` and the `new` one, and fail if any
+/// harness was removed.
+pub fn compare_harnesses(old_path: &Path, new: &[HarnessEntry]) -> Result<()> {
+    let old = read_inventory(old_path)?;
+    let diff = compare(&old, new);
+    println!("{}", format_diff(&diff, old_path));
+    if !diff.removed.is_empty() {
+        bail!("{} harness(es) from `{}` were removed", diff.removed.len(), old_path.display());
+    }
+    Ok(())
+}
+
+fn format_diff(diff: &InventoryDiff, old_path: &Path) -> String {
+    let mut output = format!("Harnesses compared to `{}`:\n", old_path.display());
+    let mut section = |title: &str, lines: Vec<String>| {
+        if !lines.is_empty() {
+            writeln!(output, "\n{title} ({}):", lines.len()).unwrap();
+            for line in lines {
+                writeln!(output, "  {line}").unwrap();
+            }
+        }
+    };
+    section(
+        "Added",
+        diff.added.iter().map(|entry| format!("+ {} ({})", entry.name, entry.location())).collect(),
+    );
+    section(
+        "Removed",
+        diff.removed
+            .iter()
+            .map(|entry| format!("- {} ({})", entry.name, entry.location()))
+            .collect(),
+    );
+    section(
+        "Renamed",
+        diff.renamed
+            .iter()
+            .map(|(old, new)| format!("{} -> {} ({})", old.name, new.name, new.location()))
+            .collect(),
+    );
+    section(
+        "Moved",
+        diff.moved
+            .iter()
+            .map(|(old, new)| format!("{}: {} -> {}", new.name, old.location(), new.location()))
+            .collect(),
+    );
+    write!(output, "\nUnchanged: {}", diff.unchanged).unwrap();
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Create an entry with the mangled name that the compiler generates for the harness.
+    fn entry(name: &str, file: &str, start_line: usize) -> HarnessEntry {
+        // E.g., `_RNvNtCs4fqI2P2rA04_5krate6verify7check_a` for `verify::check_a`.
+        let mangled_name = match name.rsplit_once("::") {
+            Some((module, fn_name)) => format!(
+                "_RNvNtCs4fqI2P2rA04_5krate{}{module}{}{fn_name}",
+                module.len(),
+                fn_name.len()
+            ),
+            None => format!("_RNvCs4fqI2P2rA04_5krate{}{name}", name.len()),
+        };
+        HarnessEntry {
+            file: file.to_string(),
+            start_line,
+            end_line: start_line + 5,
+            name: name.to_string(),
+            mangled_name,
+            crate_name: "krate".to_string(),
+            kind: "proof".to_string(),
+            contract_target: None,
+        }
+    }
+
+    fn contract_entry(name: &str, target: &str, start_line: usize) -> HarnessEntry {
+        HarnessEntry {
+            kind: "proof_for_contract".to_string(),
+            contract_target: Some(target.to_string()),
+            ..entry(name, "src/lib.rs", start_line)
+        }
+    }
+
+    #[test]
+    fn check_compare_unchanged_and_moved() {
+        let old = [entry("check_a", "src/lib.rs", 10), entry("check_b", "src/lib.rs", 20)];
+        let new = [entry("check_a", "src/lib.rs", 10), entry("check_b", "src/other.rs", 30)];
+        let diff = compare(&old, &new);
+        assert_eq!(diff.unchanged, 1);
+        assert_eq!(diff.moved, vec![(old[1].clone(), new[1].clone())]);
+        assert!(diff.added.is_empty() && diff.removed.is_empty() && diff.renamed.is_empty());
+    }
+
+    #[test]
+    fn check_compare_renamed() {
+        let old =
+            [entry("verify::check_a", "src/lib.rs", 10), contract_entry("check_b", "div", 20)];
+        // `check_a` keeps its module and its first line, even though its body grows, and
+        // `check_b` still checks the contract of `div`, although it moved.
+        let mut renamed_a = entry("verify::check_c", "src/lib.rs", 10);
+        renamed_a.end_line += 3;
+        let renamed_b = contract_entry("check_d", "div", 40);
+        // The mangled names include the name of the harnesses, so they change as well.
+        assert_eq!(old[0].mangled_name, "_RNvNtCs4fqI2P2rA04_5krate6verify7check_a");
+        assert_eq!(renamed_a.mangled_name, "_RNvNtCs4fqI2P2rA04_5krate6verify7check_c");
+        let diff = compare(&old, &[renamed_a.clone(), renamed_b.clone()]);
+        assert_eq!(diff.renamed, vec![(old[0].clone(), renamed_a), (old[1].clone(), renamed_b)]);
+        assert!(diff.added.is_empty() && diff.removed.is_empty() && diff.moved.is_empty());
+    }
+
+    #[test]
+    fn check_compare_not_renamed() {
+        let old =
+            [entry("verify::check_a", "src/lib.rs", 10), contract_entry("check_b", "div", 20)];
+        // A harness in another module, or that checks another contract, is a different harness.
+        let new = [entry("check_c", "src/lib.rs", 10), contract_entry("check_d", "mul", 20)];
+        let diff = compare(&old, &new);
+        assert_eq!(diff.added, new.to_vec());
+        assert_eq!(diff.removed, old.to_vec());
+        assert!(diff.renamed.is_empty());
+    }
+
+    #[test]
+    fn check_compare_added_and_removed() {
+        let old = [entry("check_a", "src/lib.rs", 10)];
+        let new = [entry("check_b", "src/lib.rs", 30)];
+        let diff = compare(&old, &new);
+        assert_eq!(diff.added, new.to_vec());
+        assert_eq!(diff.removed, old.to_vec());
+        assert_eq!(diff.unchanged, 0);
+
+        let formatted = format_diff(&diff, Path::new("old.json"));
+        assert!(formatted.contains("Added (1):\n  + check_b (src/lib.rs:30-35)"));
+        assert!(formatted.contains("Removed (1):\n  - check_a (src/lib.rs:10-15)"));
+        assert!(!formatted.contains("Renamed"));
+    }
+
+    #[test]
+    fn check_read_inventory() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("kani-list.json");
+        let harnesses =
+            vec![entry("check_a", "src/lib.rs", 10), contract_entry("check_b", "div", 20)];
+        let json = serde_json::json!({ "file-version": "0.2", "harnesses": &harnesses });
+        std::fs::write(&path, json.to_string()).unwrap();
+        assert_eq!(read_inventory(&path).unwrap(), harnesses);
+
+        std::fs::write(&path, r#"{ "file-version": "0.1" }"#).unwrap();
+        assert!(read_inventory(&path).is_err());
+    }
+}
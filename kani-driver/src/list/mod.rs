@@ -2,10 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Implements the list subcommand logic
 
-use kani_metadata::ContractedFunction;
+use kani_metadata::{ContractedFunction, HarnessMetadata, InactiveHarness};
 use std::collections::{BTreeMap, BTreeSet};
 
 pub mod collect_metadata;
+mod compare;
 mod output;
 
 struct ListMetadata {
@@ -13,10 +14,16 @@ struct ListMetadata {
     standard_harnesses: BTreeMap<String, BTreeSet<String>>,
     // Total number of #[kani::proof] harnesses
     standard_harnesses_count: usize,
+    // Metadata of the #[kani::proof] harnesses, sorted by file and then by line
+    standard_harnesses_metadata: Vec<HarnessMetadata>,
     // Files mapped to their #[kani::proof_for_contract] harnesses
     contract_harnesses: BTreeMap<String, BTreeSet<String>>,
     // Total number of #[kani:proof_for_contract] harnesses
     contract_harnesses_count: usize,
     // Set of all functions under contract
     contracted_functions: BTreeSet<ContractedFunction>,
+    // All harnesses, sorted by file, then by line and name
+    inventory: Vec<compare::HarnessEntry>,
+    // Harnesses excluded by #[cfg], sorted by name, if `--include-inactive` was given
+    inactive_harnesses: Option<Vec<InactiveHarness>>,
 }
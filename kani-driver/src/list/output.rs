@@ -9,7 +9,11 @@
     path::Path,
 };
 
-use crate::{args::list_args::Format, list::ListMetadata, version::KANI_VERSION};
+use crate::{
+    args::list_args::Format,
+    list::{ListMetadata, compare::compare_harnesses},
+    version::KANI_VERSION,
+};
 use anyhow::Result;
 use comfy_table::Table as PrettyTable;
 use serde_json::json;
@@ -17,15 +21,29 @@
 
 // Represents the version of our JSON file format.
 // Increment this version (according to semantic versioning rules) whenever the JSON output format changes.
-const FILE_VERSION: &str = "0.1";
+const FILE_VERSION: &str = "0.2";
 const OUTPUT_FILENAME: &str = "kani-list";
 
 /// Output the results of the list subcommand.
-pub fn output_list_results(list_metadata: ListMetadata, format: Format, quiet: bool) -> Result<()> {
+///
+/// If `compare_with` is provided, print how the harnesses changed since that inventory instead of
+/// the pretty output. The Markdown and JSON files are still written.
+pub fn output_list_results(
+    list_metadata: ListMetadata,
+    format: Format,
+    compare_with: Option<&Path>,
+    quiet: bool,
+) -> Result<()> {
+    let inventory = list_metadata.inventory.clone();
     match format {
-        Format::Pretty => pretty(list_metadata),
-        Format::Markdown => markdown(list_metadata, quiet),
-        Format::Json => json(list_metadata, quiet),
+        Format::Pretty if compare_with.is_some() => {}
+        Format::Pretty => pretty(list_metadata)?,
+        Format::Markdown => markdown(list_metadata, quiet)?,
+        Format::Json => json(list_metadata, quiet)?,
+    }
+    match compare_with {
+        Some(old_path) => compare_harnesses(old_path, &inventory),
+        None => Ok(()),
     }
 }
 
@@ -39,7 +57,15 @@ fn pretty(list_metadata: ListMetadata) -> Result<()> {
         t.set_header(header).add_rows(rows);
         Some(t)
     };
-    let output = format_results(table, &list_metadata);
+    let harnesses_table = if list_metadata.standard_harnesses_metadata.is_empty() {
+        None
+    } else {
+        let (header, rows) = construct_harnesses_table(&list_metadata);
+        let mut t = PrettyTable::new();
+        t.set_header(header).add_rows(rows);
+        Some(t.to_string())
+    };
+    let output = format_results(table, harnesses_table, &list_metadata);
     println!("{}", output);
 
     Ok(())
@@ -54,7 +80,7 @@ fn markdown(list_metadata: ListMetadata, quiet: bool) -> Result<()> {
         Some(MarkdownTable::new(Some(header), rows)?)
     };
 
-    let output = format_results(table, &list_metadata);
+    let output = format_results(table, None, &list_metadata);
 
     let out_path = Path::new(OUTPUT_FILENAME).with_extension("md");
     let mut out_file = File::create(&out_path).unwrap();
@@ -71,18 +97,23 @@ fn json(list_metadata: ListMetadata, quiet: bool) -> Result<()> {
     let out_file = File::create(&out_path).unwrap();
     let writer = BufWriter::new(out_file);
 
-    let json_obj = json!({
+    let mut json_obj = json!({
         "kani-version": KANI_VERSION,
         "file-version": FILE_VERSION,
         "standard-harnesses": &list_metadata.standard_harnesses,
         "contract-harnesses": &list_metadata.contract_harnesses,
         "contracts": &list_metadata.contracted_functions,
+        "harnesses": &list_metadata.inventory,
         "totals": {
             "standard-harnesses": list_metadata.standard_harnesses_count,
             "contract-harnesses": list_metadata.contract_harnesses_count,
             "functions-under-contract": list_metadata.contracted_functions.len(),
         }
     });
+    if let Some(inactive) = &list_metadata.inactive_harnesses {
+        json_obj["inactive-harnesses"] = json!(inactive);
+        json_obj["totals"]["inactive-harnesses"] = json!(inactive.len());
+    }
 
     serde_json::to_writer_pretty(writer, &json_obj)?;
 
@@ -126,12 +157,56 @@ fn construct_contracts_table(list_metadata: &ListMetadata) -> (Vec<String>, Vec<
     (header, rows)
 }
 
-/// Format results as a String
-fn format_results<T: Display>(table: Option<T>, list_metadata: &ListMetadata) -> String {
+/// Construct the rows for the table of standard harnesses, which includes their location and
+/// unwind bound. The rows follow the order of `standard_harnesses_metadata`, i.e., by file and
+/// then by line.
+/// Returns a tuple of the table header and the rows.
+fn construct_harnesses_table(list_metadata: &ListMetadata) -> (Vec<String>, Vec<Vec<String>>) {
+    const NO_UNWIND_MSG: &str = "NONE";
+    let header = ["#", "Harness", "File", "Lines", "Unwind"].map(String::from).to_vec();
+    let current_dir = std::env::current_dir().ok();
+    let rows = list_metadata
+        .standard_harnesses_metadata
+        .iter()
+        .enumerate()
+        .map(|(idx, harness)| {
+            // Show paths relative to the current directory when possible, which are shorter.
+            let file = Path::new(&harness.original_file);
+            let file = current_dir
+                .as_ref()
+                .and_then(|dir| file.strip_prefix(dir).ok())
+                .unwrap_or(file)
+                .display()
+                .to_string();
+            let unwind = harness
+                .attributes
+                .unwind_value
+                .map_or(NO_UNWIND_MSG.to_string(), |unwind| unwind.to_string());
+            vec![
+                (idx + 1).to_string(),
+                harness.pretty_name.clone(),
+                file,
+                format!("{}-{}", harness.original_start_line, harness.original_end_line),
+                unwind,
+            ]
+        })
+        .collect();
+    (header, rows)
+}
+
+/// Format results as a String. If `harnesses_table` is provided, it is used to display the
+/// standard harnesses instead of a numbered list.
+fn format_results<T: Display>(
+    table: Option<T>,
+    harnesses_table: Option<String>,
+    list_metadata: &ListMetadata,
+) -> String {
     const CONTRACTS_SECTION: &str = "Contracts:";
     const HARNESSES_SECTION: &str = "Standard Harnesses (#[kani::proof]):";
     const NO_CONTRACTS_MSG: &str = "No contracts or contract harnesses found.";
     const NO_HARNESSES_MSG: &str = "No standard harnesses found.";
+    const INACTIVE_SECTION: &str = "Inactive Harnesses (excluded by #[cfg(...)]):";
+    const NO_INACTIVE_MSG: &str = "No inactive harnesses found.";
 
     let mut output: Vec<String> = vec![];
     output.push(format!("\n{CONTRACTS_SECTION}"));
@@ -147,12 +222,29 @@ fn format_results<T: Display>(table: Option<T>, list_metadata: &ListMetadata) ->
         output.push(NO_HARNESSES_MSG.to_string());
     }
 
-    let mut std_harness_index = 0;
+    if let Some(harnesses_table) = harnesses_table {
+        output.push(harnesses_table);
+    } else {
+        let mut std_harness_index = 0;
+
+        for harnesses in (&list_metadata.standard_harnesses).values() {
+            for harness in harnesses {
+                output.push(format!("{}. {harness}", std_harness_index + 1));
+                std_harness_index += 1;
+            }
+        }
+    }
 
-    for harnesses in (&list_metadata.standard_harnesses).values() {
-        for harness in harnesses {
-            output.push(format!("{}. {harness}", std_harness_index + 1));
-            std_harness_index += 1;
+    if let Some(inactive) = &list_metadata.inactive_harnesses {
+        output.push(format!("\n{INACTIVE_SECTION}"));
+        if inactive.is_empty() {
+            output.push(NO_INACTIVE_MSG.to_string());
+        }
+        for harness in inactive {
+            output.push(format!(
+                "- {} ({}:{}) [inactive: requires `cfg({})`]",
+                harness.pretty_name, harness.original_file, harness.original_line, harness.cfg
+            ));
         }
     }
 
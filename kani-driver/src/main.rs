@@ -11,6 +11,7 @@
 use args_toml::join_args;
 
 use crate::args::StandaloneSubcommand;
+use crate::call_cargo::KeepGoing;
 use crate::concrete_playback::playback::{playback_cargo, playback_standalone};
 use crate::list::collect_metadata::{list_cargo, list_standalone};
 use crate::project::Project;
@@ -30,13 +31,23 @@
 mod call_single_file;
 mod cbmc_output_parser;
 mod cbmc_property_renderer;
+mod compile_commands;
 mod concrete_playback;
 mod coverage;
+mod dump_c;
+mod dump_mir;
+mod export_goto;
+mod github_output;
 mod harness_runner;
+mod json_messages;
+mod json_output;
 mod list;
 mod metadata;
 mod project;
+mod replay_trace;
+mod reporter;
 mod session;
+mod symtab;
 mod util;
 mod version;
 
@@ -72,6 +83,10 @@ fn cargokani_main(input_args: Vec<OsString>) -> Result<()> {
         return list_cargo(*list_args, args.verify_opts);
     }
 
+    if let Some(file) = &args.verify_opts.replay_trace {
+        return replay_trace::replay_trace(file, &args.verify_opts);
+    }
+
     let session = session::KaniSession::new(args.verify_opts)?;
 
     if !session.args.common_args.quiet {
@@ -93,7 +108,7 @@ fn cargokani_main(input_args: Vec<OsString>) -> Result<()> {
         return assess::run_assess(session, assess::AssessArgs::default());
     }
 
-    let project = project::cargo_project(&session, false)?;
+    let project = project::cargo_project(&session, KeepGoing::IfMultiplePackages)?;
     if session.args.only_codegen { Ok(()) } else { verify_project(project, session) }
 }
 
@@ -116,6 +131,10 @@ fn standalone_main() -> Result<()> {
             let project = project::std_project(&args.std_path, &session)?;
             (session, project)
         }
+        None if args.verify_opts.replay_trace.is_some() => {
+            let file = args.verify_opts.replay_trace.as_ref().unwrap();
+            return replay_trace::replay_trace(file, &args.verify_opts);
+        }
         None => {
             let session = KaniSession::new(args.verify_opts)?;
             if !session.args.common_args.quiet {
@@ -133,13 +152,42 @@ fn standalone_main() -> Result<()> {
 /// Run verification on the given project.
 fn verify_project(project: Project, session: KaniSession) -> Result<()> {
     debug!(?project, "verify_project");
-    let harnesses = session.determine_targets(&project.get_all_harnesses())?;
+    let harnesses = if let Some(function) = &session.args.check_contract {
+        session.determine_contract_targets(
+            function,
+            &project.metadata,
+            &project.get_all_harnesses(),
+        )?
+    } else {
+        session.determine_targets(&project.get_all_harnesses())?
+    };
     debug!(n = harnesses.len(), ?harnesses, "verify_project");
+    if session.args.print_unsupported {
+        session.print_unsupported(&harnesses);
+        return Ok(());
+    }
+    session.report_inactive_harnesses(&project.metadata, &harnesses);
+    session.report_applied_stubs(&harnesses);
+    session.check_crate_unwind(&harnesses);
+    session.check_unbounded_recursion(&harnesses)?;
+    session.check_export_goto(&harnesses)?;
+    session.check_dump_c(&harnesses)?;
+    session.write_json_symtabs(&project, &harnesses)?;
+    session.print_harness_mir(&project)?;
+    session.print_version_header()?;
+    session.check_cbmc_args();
+    session.warn_assume_panic_free();
+    session.log_assume_alloc_never_fails();
+    session.report_panic_strategy(&project);
 
     // Verification
     let runner = harness_runner::HarnessRunner { sess: &session, project: &project };
     let results = runner.check_all_harnesses(&harnesses)?;
 
+    if session.args.run.is_some() {
+        session.replay_counterexamples(&project, &results)?;
+    }
+
     if session.args.coverage {
         // We generate a timestamp to save the coverage data in a folder named
         // `kanicov_<date>` where `<date>` is the current date based on `format`
@@ -155,9 +203,11 @@ fn verify_project(project: Project, session: KaniSession) -> Result<()> {
 
         session.save_coverage_metadata(&project, &timestamp)?;
         session.save_coverage_results(&project, &results, &timestamp)?;
+        session.save_coverage_html_report(&project, &results, &timestamp)?;
     }
 
-    session.print_final_summary(&results)
+    session.report_run(&results)?;
+    session.print_final_summary(&results, project.failed_targets.as_deref().unwrap_or_default())
 }
 
 #[derive(Debug, PartialEq, Eq)]
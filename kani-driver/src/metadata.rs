@@ -6,13 +6,15 @@
 use tracing::{debug, trace};
 
 use kani_metadata::{
-    HarnessMetadata, InternedString, KaniMetadata, TraitDefinedMethod, VtableCtxResults,
+    ContractedFunction, DiagnosticCategory, HarnessKind, HarnessMetadata, InactiveHarness,
+    InternedString, KaniMetadata, TraitDefinedMethod, VtableCtxResults,
 };
-use std::collections::{BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fs::File;
 use std::io::{BufReader, BufWriter};
 
 use crate::session::KaniSession;
+use crate::util::{error, warning};
 use serde::Deserialize;
 
 /// From either a file or a path with multiple files, output the CBMC restrictions file we should use.
@@ -97,6 +99,7 @@ pub fn merge_kani_metadata(files: Vec<KaniMetadata>) -> KaniMetadata {
         unsupported_features: vec![],
         test_harnesses: vec![],
         contracted_functions: vec![],
+        inactive_harnesses: vec![],
     };
     for md in files {
         // Note that we're taking ownership of the original vec, and so we can move the data into the new data structure.
@@ -106,6 +109,7 @@ pub fn merge_kani_metadata(files: Vec<KaniMetadata>) -> KaniMetadata {
         result.unsupported_features.extend(md.unsupported_features);
         result.test_harnesses.extend(md.test_harnesses);
         result.contracted_functions.extend(md.contracted_functions);
+        result.inactive_harnesses.extend(md.inactive_harnesses);
     }
     result
 }
@@ -116,6 +120,46 @@ pub fn determine_targets<'a>(
         &self,
         all_harnesses: &[&'a HarnessMetadata],
     ) -> Result<Vec<&'a HarnessMetadata>> {
+        let selected = self.select_targets(all_harnesses)?;
+        if self.args.exclude_harnesses.is_empty() || selected.is_empty() {
+            return Ok(selected);
+        }
+
+        for filter in &self.args.exclude_harnesses {
+            let filter = BTreeSet::from([filter]);
+            if find_proof_harnesses(&filter, all_harnesses, self.args.exact).is_empty() {
+                warning(&format!(
+                    "No harness matched the filter `{}` given to `--exclude-harness`.",
+                    filter.first().unwrap()
+                ));
+            }
+        }
+        let excluded = BTreeSet::from_iter(self.args.exclude_harnesses.iter());
+        let excluded = find_proof_harnesses(&excluded, &selected, self.args.exact);
+        let targets: Vec<_> = selected
+            .into_iter()
+            .filter(|harness| !excluded.iter().any(|ex| ex.pretty_name == harness.pretty_name))
+            .collect();
+        if !self.args.common_args.quiet {
+            let names: Vec<_> = targets.iter().map(|h| h.pretty_name.as_str()).collect();
+            println!(
+                "[Kani] Verifying {} harness(es) after exclusions: {}",
+                names.len(),
+                names.join(", ")
+            );
+        }
+        Ok(targets)
+    }
+
+    /// Select the harnesses that match the `--harness` filters, or all of them if none was given.
+    /// With `--run`, select the single harness that matches its filter.
+    fn select_targets<'a>(
+        &self,
+        all_harnesses: &[&'a HarnessMetadata],
+    ) -> Result<Vec<&'a HarnessMetadata>> {
+        if let Some(target) = &self.args.run {
+            return select_run_target(target, all_harnesses);
+        }
         let harnesses = BTreeSet::from_iter(self.args.harnesses.iter());
         let total_harnesses = harnesses.len();
         let all_targets = &harnesses;
@@ -148,6 +192,276 @@ pub fn determine_targets<'a>(
             Ok(harnesses_found)
         }
     }
+
+    /// Determine the harnesses that verify the contract of `function`, i.e., the harnesses
+    /// annotated with `#[kani::proof_for_contract(function)]`.
+    ///
+    /// The function can be given by its fully qualified name or by a suffix of it. If the
+    /// function has no such harness, the compiler generates one named after the function.
+    pub fn determine_contract_targets<'a>(
+        &self,
+        function: &str,
+        metadata: &[KaniMetadata],
+        all_harnesses: &[&'a HarnessMetadata],
+    ) -> Result<Vec<&'a HarnessMetadata>> {
+        let contracted: Vec<&ContractedFunction> = metadata
+            .iter()
+            .flat_map(|crate_metadata| crate_metadata.contracted_functions.iter())
+            .filter(|cf| matches_qualified_name(&cf.function, function))
+            .collect();
+        if contracted.is_empty() {
+            bail!("Failed to find a function with a contract named `{function}`.");
+        }
+        if contracted.len() > 1 {
+            let names =
+                contracted.iter().map(|cf| cf.function.as_str()).collect::<Vec<_>>().join("`, `");
+            bail!(
+                "The name `{function}` matches more than one function with a contract: `{names}`.\n\
+                Please specify the fully-qualified name of the function."
+            );
+        }
+
+        let contract_harnesses = &contracted[0].harnesses;
+        let harnesses: Vec<&HarnessMetadata> = all_harnesses
+            .iter()
+            .copied()
+            .filter(|md| {
+                matches!(md.attributes.kind, HarnessKind::ProofForContract { .. })
+                    && contract_harnesses.contains(&md.pretty_name)
+            })
+            .collect();
+        if harnesses.is_empty() {
+            bail!(
+                "Function `{}` has a contract, but no harness verifies it.\n\
+                Add a harness annotated with `#[kani::proof_for_contract({})]`.",
+                contracted[0].function,
+                contracted[0].function
+            );
+        }
+        Ok(harnesses)
+    }
+
+    /// Log the harnesses whose unwind bound comes from the crate-level `#![kanitool::unwind]`
+    /// attribute instead of `--default-unwind`. The crate-level bound takes precedence, unless
+    /// `--unwind` is used.
+    pub fn check_crate_unwind(&self, harnesses: &[&HarnessMetadata]) {
+        if self.args.unwind.is_some() || self.args.common_args.quiet {
+            return;
+        }
+        if let Some(default_unwind) = self.args.default_unwind {
+            crate_unwind_messages(harnesses, default_unwind)
+                .iter()
+                .for_each(|msg| println!("{msg}"));
+        }
+    }
+
+    /// Warn about harnesses that reach recursive functions without an unwind bound, since CBMC
+    /// may never terminate for them. With `--deny recursion`, these are reported as errors instead.
+    pub fn check_unbounded_recursion(&self, harnesses: &[&HarnessMetadata]) -> Result<()> {
+        let has_global_unwind = self.args.default_unwind.is_some()
+            || self.args.unwind.is_some()
+            || self.args.cbmc_args.iter().any(|arg| arg == "--unwind");
+        if has_global_unwind {
+            return Ok(());
+        }
+        let messages = unbounded_recursion_messages(harnesses);
+        if let Some(flag) = self.args.denied_by(DiagnosticCategory::Recursion) {
+            messages.iter().for_each(|msg| error(msg));
+            if !messages.is_empty() {
+                bail!(
+                    "Found {} harness(es) with unbounded recursion, which is denied by `{flag}`.",
+                    messages.len()
+                );
+            }
+        } else {
+            messages.iter().for_each(|msg| warning(msg));
+        }
+        Ok(())
+    }
+
+    /// Log the harnesses that were excluded by their `#[cfg(...)]` attribute, followed by the
+    /// active harnesses that will be verified, so users know why a harness isn't verified.
+    pub fn report_inactive_harnesses(
+        &self,
+        metadata: &[KaniMetadata],
+        harnesses: &[&HarnessMetadata],
+    ) {
+        if self.args.common_args.quiet {
+            return;
+        }
+        let inactive: Vec<_> = metadata.iter().flat_map(|md| &md.inactive_harnesses).collect();
+        inactive_harnesses_messages(&inactive, harnesses).iter().for_each(|msg| println!("{msg}"));
+    }
+
+    /// Log the stubs applied to each harness, including the default stubs registered with
+    /// `kani::register_default_stub!`.
+    pub fn report_applied_stubs(&self, harnesses: &[&HarnessMetadata]) {
+        if self.args.common_args.quiet {
+            return;
+        }
+        applied_stubs_messages(harnesses).iter().for_each(|msg| println!("{msg}"));
+    }
+
+    /// Print the unsupported features that are reachable from each harness, for
+    /// `--print-unsupported`.
+    pub fn print_unsupported(&self, harnesses: &[&HarnessMetadata]) {
+        unsupported_features_rows(harnesses).iter().for_each(|row| println!("{row}"));
+    }
+}
+
+/// The maximum number of locations printed for each unsupported feature of a harness.
+const MAX_SAMPLE_LOCATIONS: usize = 3;
+
+/// Build the table printed by `--print-unsupported`, with one tab-separated row for each
+/// unsupported feature reachable from a harness, and one row for each harness that doesn't reach
+/// any, whose status is `clean`. The rows are sorted, and they start with the crate name, so the
+/// tables of several crates can be concatenated and aggregated.
+fn unsupported_features_rows(harnesses: &[&HarnessMetadata]) -> Vec<String> {
+    let mut rows = BTreeSet::new();
+    for harness in harnesses {
+        let (krate, name) = (&harness.crate_name, &harness.pretty_name);
+        if harness.unsupported_features.is_empty() {
+            rows.insert(format!("{krate}\t{name}\tclean\t-\t0\t-"));
+        }
+        for unsupported in &harness.unsupported_features {
+            let locations: BTreeSet<_> = unsupported
+                .locations
+                .iter()
+                .map(|loc| (loc.filename.as_str(), loc.start_line))
+                .collect();
+            let samples = locations
+                .iter()
+                .take(MAX_SAMPLE_LOCATIONS)
+                .map(|(file, line)| format!("{file}:{line}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            rows.insert(format!(
+                "{krate}\t{name}\tunsupported\t{}\t{}\t{samples}",
+                unsupported.feature,
+                locations.len()
+            ));
+        }
+    }
+    std::iter::once("crate\tharness\tstatus\tfeature\tlocations\tsamples".to_string())
+        .chain(rows)
+        .collect()
+}
+
+/// Build one message for each harness with stubs, listing its own stubs followed by the default
+/// ones.
+fn applied_stubs_messages(harnesses: &[&HarnessMetadata]) -> Vec<String> {
+    harnesses
+        .iter()
+        .filter(|harness| harness.attributes.all_stubs().next().is_some())
+        .map(|harness| {
+            let attributes = &harness.attributes;
+            let own = attributes.stubs.iter().map(|stub| (stub, ""));
+            let defaults = attributes.default_stubs.iter().map(|stub| (stub, " (default)"));
+            let stubs = own
+                .chain(defaults)
+                .map(|(stub, origin)| {
+                    format!("`{}` -> `{}`{origin}", stub.original, stub.replacement)
+                })
+                .collect::<Vec<_>>();
+            format!(
+                "[Kani] Stubs applied to harness `{}`: {}",
+                harness.pretty_name,
+                stubs.join(", ")
+            )
+        })
+        .collect()
+}
+
+/// Build the messages listing the inactive harnesses and the active ones, or nothing if every
+/// harness is active.
+fn inactive_harnesses_messages(
+    inactive: &[&InactiveHarness],
+    harnesses: &[&HarnessMetadata],
+) -> Vec<String> {
+    if inactive.is_empty() {
+        return vec![];
+    }
+    let mut messages = vec![format!(
+        "[Kani] Skipping {} harness(es) excluded by their `#[cfg(...)]` attribute:",
+        inactive.len()
+    )];
+    messages.extend(inactive.iter().map(|harness| {
+        format!(
+            "  - {} ({}:{}) requires `cfg({})`",
+            harness.pretty_name, harness.original_file, harness.original_line, harness.cfg
+        )
+    }));
+    let names: Vec<_> = harnesses.iter().map(|h| h.pretty_name.as_str()).collect();
+    messages.push(format!(
+        "[Kani] Verifying {} active harness(es): {}",
+        names.len(),
+        names.join(", ")
+    ));
+    messages
+}
+
+/// Build one message for each crate whose `#![kanitool::unwind]` bound takes precedence over a
+/// different `--default-unwind` value for some of its harnesses.
+fn crate_unwind_messages(harnesses: &[&HarnessMetadata], default_unwind: u32) -> Vec<String> {
+    let mut crates = BTreeMap::new();
+    for harness in harnesses {
+        if harness.attributes.unwind_from_crate {
+            let unwind = harness.attributes.unwind_value.unwrap();
+            if unwind != default_unwind {
+                crates.insert(harness.crate_name.as_str(), unwind);
+            }
+        }
+    }
+    crates
+        .into_iter()
+        .map(|(krate, unwind)| {
+            format!(
+                "[Kani] Using the unwind bound `{unwind}` from `#![kanitool::unwind]` in crate \
+                `{krate}` instead of `--default-unwind {default_unwind}` for its harnesses \
+                without `#[kani::unwind]`."
+            )
+        })
+        .collect()
+}
+
+/// Build one message for each harness without an unwind attribute that reaches recursive
+/// functions. Each message lists the recursive cycles found.
+///
+/// Contract harnesses are skipped: a recursive function under contract is verified inductively,
+/// i.e., its recursive calls are replaced by its contract, so the recursion is bounded.
+fn unbounded_recursion_messages(harnesses: &[&HarnessMetadata]) -> Vec<String> {
+    harnesses
+        .iter()
+        .filter(|harness| {
+            harness.attributes.unwind_value.is_none()
+                && !harness.recursive_cycles.is_empty()
+                && !matches!(harness.attributes.kind, HarnessKind::ProofForContract { .. })
+        })
+        .map(|harness| {
+            let cycles = harness
+                .recursive_cycles
+                .iter()
+                .map(|cycle| {
+                    let mut names: Vec<_> = cycle.iter().map(|name| format!("`{name}`")).collect();
+                    names.push(names[0].clone());
+                    format!("  - {}", names.join(" -> "))
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            format!(
+                "Harness `{}` reaches recursive functions without an unwind bound, so \
+                verification may not terminate:\n{cycles}\n\
+                Consider adding `#[kani::unwind(<value>)]` to the harness or using \
+                `--default-unwind <value>`.",
+                harness.pretty_name
+            )
+        })
+        .collect()
+}
+
+/// Check whether `name` is the fully qualified name `full_name` or a `::`-separated suffix of it.
+pub fn matches_qualified_name(full_name: &str, name: &str) -> bool {
+    full_name == name || full_name.strip_suffix(name).is_some_and(|prefix| prefix.ends_with("::"))
 }
 
 /// Sort harnesses such that for two harnesses in the same file, it is guaranteed that later
@@ -165,6 +479,28 @@ pub fn sort_harnesses_by_loc<'a>(harnesses: &[&'a HarnessMetadata]) -> Vec<&'a H
     harnesses_clone
 }
 
+/// Select the harness for `--run`. An exact match of the fully qualified name is preferred, and
+/// otherwise the filter must match exactly one harness.
+fn select_run_target<'a>(
+    target: &String,
+    all_harnesses: &[&'a HarnessMetadata],
+) -> Result<Vec<&'a HarnessMetadata>> {
+    let targets = BTreeSet::from([target]);
+    let mut found = find_proof_harnesses(&targets, all_harnesses, true);
+    if found.is_empty() {
+        found = find_proof_harnesses(&targets, all_harnesses, false);
+    }
+    match found.len() {
+        0 => bail!("No harness matches `{target}` given to `--run`."),
+        1 => Ok(found),
+        _ => bail!(
+            "`--run` expects a single harness, but `{target}` matches:\n{}\nPlease specify the \
+            fully-qualified name of a harness.",
+            found.iter().map(|harness| harness.pretty_name.as_str()).collect::<Vec<_>>().join("\n")
+        ),
+    }
+}
+
 /// Search for a proof harness with a particular name.
 /// At the present time, we use `no_mangle` so collisions shouldn't happen,
 /// but this function is written to be robust against that changing in the future.
@@ -202,7 +538,7 @@ fn find_proof_harnesses<'a>(
 #[cfg(test)]
 pub mod tests {
     use super::*;
-    use kani_metadata::{HarnessAttributes, HarnessKind};
+    use kani_metadata::{HarnessAttributes, HarnessKind, Stub, UnsupportedFeature};
     use std::path::PathBuf;
 
     pub fn mock_proof_harness(
@@ -224,9 +560,79 @@ pub fn mock_proof_harness(
             goto_file: model_file,
             contract: Default::default(),
             has_loop_contracts: false,
+            recursive_cycles: vec![],
+            unsupported_features: vec![],
+            codegen_error: None,
         }
     }
 
+    #[test]
+    fn check_unbounded_recursion_messages() {
+        let mut recursive = mock_proof_harness("check_recursive", None, None, None);
+        recursive.recursive_cycles =
+            vec![vec!["is_even".into(), "is_odd".into()], vec!["fib".into()]];
+        let mut bounded = mock_proof_harness("check_bounded", Some(10), None, None);
+        bounded.recursive_cycles = vec![vec!["fib".into()]];
+        let no_recursion = mock_proof_harness("check_no_recursion", None, None, None);
+        let mut contract = mock_proof_harness("check_contract", None, None, None);
+        contract.attributes.kind = HarnessKind::ProofForContract { target_fn: "fib".into() };
+        contract.recursive_cycles = vec![vec!["fib".into()]];
+
+        let messages =
+            unbounded_recursion_messages(&[&recursive, &bounded, &no_recursion, &contract]);
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].starts_with("Harness `check_recursive` reaches recursive functions"));
+        assert!(messages[0].contains("  - `is_even` -> `is_odd` -> `is_even`\n"));
+        assert!(messages[0].contains("  - `fib` -> `fib`\n"));
+    }
+
+    #[test]
+    fn check_inactive_harnesses_messages() {
+        let active = mock_proof_harness("check_unix", None, None, None);
+        let inactive = InactiveHarness {
+            pretty_name: "check_windows".to_string(),
+            original_file: "src/lib.rs".to_string(),
+            original_line: 12,
+            cfg: "windows".to_string(),
+        };
+        assert!(inactive_harnesses_messages(&[], &[&active]).is_empty());
+        assert_eq!(inactive_harnesses_messages(&[&inactive], &[&active]), vec![
+            "[Kani] Skipping 1 harness(es) excluded by their `#[cfg(...)]` attribute:",
+            "  - check_windows (src/lib.rs:12) requires `cfg(windows)`",
+            "[Kani] Verifying 1 active harness(es): check_unix",
+        ]);
+    }
+
+    #[test]
+    fn check_applied_stubs_messages() {
+        let stub = |original: &str, replacement: &str| Stub {
+            original: original.to_string(),
+            replacement: replacement.to_string(),
+        };
+        let mut with_stubs = mock_proof_harness("check_stubs", None, None, None);
+        with_stubs.attributes.stubs.push(stub("rand::random", "mock_random"));
+        with_stubs.attributes.default_stubs.push(stub("std::time::Instant::now", "fixed_now"));
+        let without_stubs = mock_proof_harness("check_plain", None, None, None);
+        assert_eq!(applied_stubs_messages(&[&with_stubs, &without_stubs]), vec![
+            "[Kani] Stubs applied to harness `check_stubs`: `rand::random` -> `mock_random`, \
+            `std::time::Instant::now` -> `fixed_now` (default)"
+        ]);
+    }
+
+    #[test]
+    fn check_crate_unwind_messages() {
+        let mut from_crate = mock_proof_harness("check_one", Some(4), Some("my_crate"), None);
+        from_crate.attributes.unwind_from_crate = true;
+        let mut same_bound = mock_proof_harness("check_two", Some(2), Some("other_crate"), None);
+        same_bound.attributes.unwind_from_crate = true;
+        let from_harness = mock_proof_harness("check_three", Some(8), Some("third_crate"), None);
+
+        let messages = crate_unwind_messages(&[&from_crate, &same_bound, &from_harness], 2);
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains("unwind bound `4` from `#![kanitool::unwind]`"));
+        assert!(messages[0].contains("crate `my_crate` instead of `--default-unwind 2`"));
+    }
+
     #[test]
     fn check_find_proof_harness_without_exact() {
         let harnesses = vec![
@@ -312,4 +718,56 @@ fn check_find_proof_harness_with_exact() {
             "module::not_check_three"
         );
     }
+
+    #[test]
+    fn check_select_run_target() {
+        let harnesses = vec![
+            mock_proof_harness("check", None, None, None),
+            mock_proof_harness("module::check_two", None, None, None),
+            mock_proof_harness("module::not_check_three", None, None, None),
+        ];
+        let ref_harnesses = harnesses.iter().collect::<Vec<_>>();
+
+        // An exact match wins even though the filter matches every harness.
+        let selected = select_run_target(&"check".to_string(), &ref_harnesses).unwrap();
+        assert_eq!(selected[0].pretty_name, "check");
+        let selected = select_run_target(&"check_two".to_string(), &ref_harnesses).unwrap();
+        assert_eq!(selected[0].pretty_name, "module::check_two");
+        assert!(select_run_target(&"module".to_string(), &ref_harnesses).is_err());
+        assert!(select_run_target(&"missing".to_string(), &ref_harnesses).is_err());
+    }
+
+    #[test]
+    fn check_unsupported_features_rows() {
+        let location = |filename: &str, start_line| kani_metadata::Location {
+            filename: filename.into(),
+            start_line,
+        };
+        let mut asm = mock_proof_harness("check_asm", None, Some("krate"), None);
+        asm.unsupported_features = vec![UnsupportedFeature {
+            feature: "TerminatorKind::InlineAsm".into(),
+            locations: [10, 2, 30, 4]
+                .into_iter()
+                .map(|line| location("src/lib.rs", line))
+                .collect(),
+        }];
+        let clean = mock_proof_harness("check_clean", None, Some("krate"), None);
+
+        let rows = unsupported_features_rows(&[&clean, &asm]);
+        assert_eq!(rows, [
+            "crate\tharness\tstatus\tfeature\tlocations\tsamples",
+            "krate\tcheck_asm\tunsupported\tTerminatorKind::InlineAsm\t4\t\
+                src/lib.rs:2,src/lib.rs:4,src/lib.rs:10",
+            "krate\tcheck_clean\tclean\t-\t0\t-",
+        ]);
+    }
+
+    #[test]
+    fn check_matches_qualified_name() {
+        assert!(matches_qualified_name("gcd", "gcd"));
+        assert!(matches_qualified_name("math::gcd", "gcd"));
+        assert!(matches_qualified_name("math::gcd", "math::gcd"));
+        assert!(!matches_qualified_name("math::fast_gcd", "gcd"));
+        assert!(!matches_qualified_name("gcd", "math::gcd"));
+    }
 }
@@ -4,6 +4,7 @@
 //! The goal is to provide one project view independent on the build system (cargo / standalone
 //! rustc) and its configuration (e.g.: linker type).
 
+use crate::call_cargo::KeepGoing;
 use crate::metadata::from_json;
 use crate::session::KaniSession;
 use crate::util::crate_name;
@@ -11,6 +12,7 @@
 use kani_metadata::{
     ArtifactType, ArtifactType::*, HarnessMetadata, KaniMetadata, artifact::convert_type,
 };
+use std::collections::{HashMap, HashSet};
 use std::env::current_dir;
 use std::fs;
 use std::ops::Deref;
@@ -96,8 +98,11 @@ fn try_new(
         // SymTabGoto file. Use that path to find all the other artifacts.
         let mut artifacts = vec![];
         for crate_metadata in &metadata {
-            for harness_metadata in
-                crate_metadata.test_harnesses.iter().chain(crate_metadata.proof_harnesses.iter())
+            for harness_metadata in crate_metadata
+                .test_harnesses
+                .iter()
+                .chain(crate_metadata.proof_harnesses.iter())
+                .filter(|harness| harness.codegen_error.is_none())
             {
                 let symtab_out = Artifact::try_new(
                     harness_metadata.goto_file.as_ref().expect("Expected a model file"),
@@ -173,14 +178,21 @@ pub fn try_from(artifact: &Artifact, typ: ArtifactType) -> Result<Self> {
 }
 
 /// Generate a project using `cargo`.
-/// Accept a boolean to build as many targets as possible. The number of failures in that case can
-/// be collected from the project.
-pub fn cargo_project(session: &KaniSession, keep_going: bool) -> Result<Project> {
+/// The `keep_going` mode controls whether to build as many targets as possible. The number of
+/// failures in that case can be collected from the project.
+pub fn cargo_project(session: &KaniSession, keep_going: KeepGoing) -> Result<Project> {
     let outputs = session.cargo_build(keep_going)?;
+    session.write_compile_commands()?;
     let outdir = outputs.outdir.canonicalize()?;
     // For the MIR Linker we know there is only one metadata per crate. Use that in our favor.
-    let metadata =
+    let mut metadata =
         outputs.metadata.iter().map(|md_file| from_json(md_file)).collect::<Result<Vec<_>>>()?;
+    for (crate_metadata, md_file) in metadata.iter_mut().zip(&outputs.metadata) {
+        if outputs.integration_tests.contains(md_file) {
+            label_integration_test_harnesses(crate_metadata);
+        }
+    }
+    qualify_ambiguous_harness_names(&mut metadata);
     Project::try_new(
         session,
         outdir,
@@ -191,6 +203,71 @@ pub fn cargo_project(session: &KaniSession, keep_going: bool) -> Result<Project>
     )
 }
 
+/// The prefix of the harnesses of an integration test, e.g., `tests/my_test::`.
+const INTEGRATION_TEST_PREFIX: &str = "tests/";
+
+/// Prefix the name of the harnesses of an integration test with the name of the test target,
+/// e.g., `check` in `tests/my_test.rs` becomes `tests/my_test::check`.
+///
+/// This tells them apart from the harnesses of the library, even if the test has the same name
+/// as the library crate. The prefix can't be confused with a module path, since it contains `/`.
+fn label_integration_test_harnesses(metadata: &mut KaniMetadata) {
+    let label = format!("{INTEGRATION_TEST_PREFIX}{}", metadata.crate_name);
+    let qualify = |name: &mut String| *name = format!("{label}::{name}");
+    for harness in metadata.proof_harnesses.iter_mut().chain(&mut metadata.test_harnesses) {
+        qualify(&mut harness.pretty_name);
+    }
+    for function in &mut metadata.contracted_functions {
+        function.harnesses.iter_mut().for_each(qualify);
+    }
+}
+
+/// The target that defines `harness`, which is its crate, or `tests/<name>` for the harnesses
+/// of an integration test.
+pub fn harness_target(harness: &HarnessMetadata) -> &str {
+    harness
+        .pretty_name
+        .split_once("::")
+        .map(|(label, _)| label)
+        .filter(|label| label.starts_with(INTEGRATION_TEST_PREFIX))
+        .unwrap_or(&harness.crate_name)
+}
+
+/// Prefix the name of the harnesses that are defined with the same name in more than one crate
+/// with their crate name, e.g., `check` becomes `krate::check`.
+///
+/// Otherwise, the results and the artifacts of these harnesses couldn't be told apart when
+/// verifying multiple packages of a workspace.
+fn qualify_ambiguous_harness_names(metadata: &mut [KaniMetadata]) {
+    let mut crates_by_harness: HashMap<String, HashSet<String>> = HashMap::new();
+    for crate_metadata in metadata.iter() {
+        for harness in crate_metadata.proof_harnesses.iter().chain(&crate_metadata.test_harnesses) {
+            crates_by_harness
+                .entry(harness.pretty_name.clone())
+                .or_default()
+                .insert(crate_metadata.crate_name.clone());
+        }
+    }
+
+    for crate_metadata in metadata.iter_mut() {
+        let crate_name = crate_metadata.crate_name.clone();
+        let qualify = |name: &mut String| {
+            if crates_by_harness.get(name).is_some_and(|crates| crates.len() > 1) {
+                *name = format!("{crate_name}::{name}");
+            }
+        };
+        for harness in
+            crate_metadata.proof_harnesses.iter_mut().chain(&mut crate_metadata.test_harnesses)
+        {
+            qualify(&mut harness.pretty_name);
+        }
+        // Keep the contract harnesses in sync with the new names.
+        for function in &mut crate_metadata.contracted_functions {
+            function.harnesses.iter_mut().for_each(qualify);
+        }
+    }
+}
+
 /// Generate a project directly using `kani-compiler` on a single crate.
 pub fn standalone_project(
     input: &Path,
@@ -246,6 +323,7 @@ fn build(self) -> Result<Project> {
         // Build and link the artifacts.
         debug!(krate=?self.crate_name, input=?self.input, ?rlib_path, "build compile");
         self.session.compile_single_rust_file(&self.input, &self.crate_name, &self.outdir)?;
+        self.session.write_compile_commands()?;
 
         let metadata = from_json(&self.metadata)?;
 
@@ -313,3 +391,57 @@ pub(crate) fn std_project(std_path: &Path, session: &KaniSession) -> Result<Proj
     let metadata = outputs.iter().map(|md_file| from_json(md_file)).collect::<Result<Vec<_>>>()?;
     Project::try_new(session, outdir, None, metadata, None, None)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::tests::mock_proof_harness;
+    use kani_metadata::ContractedFunction;
+
+    fn mock_metadata(krate: &str, names: &[&str]) -> KaniMetadata {
+        KaniMetadata {
+            crate_name: krate.into(),
+            proof_harnesses: names
+                .iter()
+                .map(|name| mock_proof_harness(name, None, Some(krate), None))
+                .collect(),
+            unsupported_features: vec![],
+            test_harnesses: vec![],
+            contracted_functions: vec![ContractedFunction {
+                function: "foo".into(),
+                file: "<unknown>".into(),
+                harnesses: names.iter().map(|name| name.to_string()).collect(),
+            }],
+            inactive_harnesses: vec![],
+        }
+    }
+
+    #[test]
+    fn check_qualify_ambiguous_harness_names() {
+        let mut metadata = vec![
+            mock_metadata("first", &["check", "only_first"]),
+            mock_metadata("second", &["check"]),
+        ];
+        qualify_ambiguous_harness_names(&mut metadata);
+        let names: Vec<_> =
+            metadata.iter().flat_map(|md| &md.proof_harnesses).map(|h| &h.pretty_name).collect();
+        assert_eq!(names, ["first::check", "only_first", "second::check"]);
+        assert_eq!(metadata[0].contracted_functions[0].harnesses, ["first::check", "only_first"]);
+        assert_eq!(metadata[1].contracted_functions[0].harnesses, ["second::check"]);
+    }
+
+    #[test]
+    fn check_label_integration_test_harnesses() {
+        // The integration test has the same name as the library.
+        let mut metadata =
+            vec![mock_metadata("krate", &["check"]), mock_metadata("krate", &["check"])];
+        label_integration_test_harnesses(&mut metadata[1]);
+        qualify_ambiguous_harness_names(&mut metadata);
+        let harnesses: Vec<_> = metadata.iter().flat_map(|md| &md.proof_harnesses).collect();
+        let names: Vec<_> = harnesses.iter().map(|h| h.pretty_name.as_str()).collect();
+        assert_eq!(names, ["check", "tests/krate::check"]);
+        assert_eq!(metadata[1].contracted_functions[0].harnesses, ["tests/krate::check"]);
+        let targets: Vec<_> = harnesses.iter().map(|h| harness_target(h)).collect();
+        assert_eq!(targets, ["krate", "tests/krate"]);
+    }
+}
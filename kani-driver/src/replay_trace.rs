@@ -0,0 +1,49 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This module implements `--replay-trace`, which renders the verification results saved with
+//! `--output-format json` again. It neither compiles the crate nor runs CBMC, so it works offline,
+//! and results archived with an older version of Kani can be displayed with the current output.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+
+use crate::args::{OutputFormat, VerificationArgs};
+use crate::json_output::parse_json_result;
+
+/// Render every result in `file`, which contains the output of `--output-format json`.
+///
+/// Lines that aren't JSON objects, e.g., `Checking harness ...`, are ignored, so the whole output
+/// of Kani can be saved as is.
+pub fn replay_trace(file: &Path, args: &VerificationArgs) -> Result<()> {
+    let content =
+        fs::read_to_string(file).with_context(|| format!("failed to read `{}`", file.display()))?;
+    let mut replayed = 0;
+    for (index, line) in content.lines().enumerate() {
+        if !line.trim_start().starts_with('{') {
+            continue;
+        }
+        let (harness, result) = parse_json_result(line).with_context(|| {
+            format!("invalid verification result at line {} of `{}`", index + 1, file.display())
+        })?;
+        if args.output_format == OutputFormat::Json {
+            println!("{}", result.render_json(&harness));
+        } else {
+            // The result only keeps the overall status of `should_panic` harnesses.
+            println!(
+                "Replaying harness {harness}...\n{}",
+                result.render(&args.output_format, false)
+            );
+        }
+        replayed += 1;
+    }
+    if replayed == 0 {
+        bail!(
+            "no verification results found in `{}`. Results can be saved with `--output-format json`.",
+            file.display()
+        );
+    }
+    Ok(())
+}
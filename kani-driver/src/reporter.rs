@@ -0,0 +1,75 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This module implements `--reporter`, which runs a user command with the verification results
+//! on its standard input, so the results can be formatted for a custom dashboard without
+//! patching Kani.
+//!
+//! The command receives one line per harness in the format of `--output-format json`, which is
+//! documented in `docs/src/reference/json-output.md`. By default, it runs once after all the
+//! harnesses are verified, and with `--reporter-per-harness`, once after each harness.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result, bail};
+use kani_metadata::HarnessMetadata;
+
+use crate::call_cbmc::VerificationResult;
+use crate::harness_runner::HarnessResult;
+use crate::session::KaniSession;
+use crate::util::{render_command, warning};
+
+impl KaniSession {
+    /// Run the `--reporter` command with the results of all the harnesses, unless it runs once
+    /// per harness.
+    pub(crate) fn report_run(&self, results: &[HarnessResult<'_>]) -> Result<()> {
+        if self.args.reporter.is_none() || self.args.reporter_per_harness {
+            return Ok(());
+        }
+        let input: String = results
+            .iter()
+            .map(|result| result.result.render_json(&result.harness.pretty_name) + "\n")
+            .collect();
+        self.run_reporter(&input)
+    }
+
+    /// Run the `--reporter` command with the result of `harness` if it runs once per harness.
+    pub(crate) fn report_harness(
+        &self,
+        harness: &HarnessMetadata,
+        result: &VerificationResult,
+    ) -> Result<()> {
+        if self.args.reporter.is_none() || !self.args.reporter_per_harness {
+            return Ok(());
+        }
+        self.run_reporter(&(result.render_json(&harness.pretty_name) + "\n"))
+    }
+
+    fn run_reporter(&self, input: &str) -> Result<()> {
+        let Some(reporter) = &self.args.reporter else { return Ok(()) };
+        let mut cmd = Command::new(reporter);
+        cmd.stdin(Stdio::piped());
+        if self.args.common_args.verbose {
+            println!("[Kani] Running: `{}`", render_command(&cmd).to_string_lossy());
+        }
+        let mut child = cmd
+            .spawn()
+            .with_context(|| format!("failed to run the reporter `{}`", reporter.display()))?;
+        // Ignore write errors, since the reporter may exit without reading all of its input.
+        let _ = child.stdin.take().unwrap().write_all(input.as_bytes());
+        let status = child
+            .wait()
+            .with_context(|| format!("failed to run the reporter `{}`", reporter.display()))?;
+        if !status.success() {
+            let msg = format!("the reporter `{}` failed with {status}", reporter.display());
+            if self.args.reporter_fail_on_error {
+                bail!(msg);
+            }
+            if !self.args.common_args.quiet {
+                warning(&msg);
+            }
+        }
+        Ok(())
+    }
+}
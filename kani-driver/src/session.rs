@@ -1,16 +1,19 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use crate::args::MessageFormat;
 use crate::args::Timeout;
 use crate::args::VerificationArgs;
 use crate::args::common::Verbosity;
+use crate::compile_commands::CompileCommand;
 use crate::util::render_command;
 use anyhow::{Context, Result, bail};
 use std::io::IsTerminal;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process::{Child, Command, Stdio};
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
 use std::time::Instant;
 use strum_macros::Display;
 use tokio::process::Command as TokioCommand;
@@ -37,10 +40,22 @@ pub struct KaniSession {
     pub kani_compiler: PathBuf,
     /// The location we found 'kani_lib.c'
     pub kani_lib_c: PathBuf,
+    /// The `cbmc` binary, which is looked up in `PATH` unless `--cbmc-path` is given.
+    pub cbmc: PathBuf,
+    /// The `goto-cc` binary, which is looked up in `PATH` unless `--goto-cc-path` is given.
+    pub goto_cc: PathBuf,
+    /// The output of `cbmc --version`, computed the first time it is needed.
+    pub cbmc_version: OnceLock<String>,
 
     /// The temporary files we littered that need to be cleaned up at the end of execution
     pub temporaries: Mutex<Vec<PathBuf>>,
 
+    /// The compiler invocations made so far. Used by `--write-compile-commands`.
+    pub compile_commands: Mutex<Vec<CompileCommand>>,
+
+    /// Set when a harness fails with `--fail-fast`, so no further harness is verified.
+    fail_fast_stopped: AtomicBool,
+
     /// The tokio runtime
     pub runtime: tokio::runtime::Runtime,
 }
@@ -56,20 +71,45 @@ pub enum InstallType {
 }
 
 impl KaniSession {
-    pub fn new(args: VerificationArgs) -> Result<Self> {
+    pub fn new(mut args: VerificationArgs) -> Result<Self> {
         init_logger(&args);
+        // The JSON messages replace the human-readable output.
+        if args.message_format == MessageFormat::Json {
+            args.common_args.quiet = true;
+        }
         let install = InstallType::new()?;
+        let cbmc = resolve_tool("cbmc", "--cbmc-path", args.cbmc_path.as_deref())?;
+        let goto_cc = resolve_tool("goto-cc", "--goto-cc-path", args.goto_cc_path.as_deref())?;
+        if args.common_args.verbose {
+            println!("[Kani] Using `cbmc` from {}", cbmc.display());
+            println!("[Kani] Using `goto-cc` from {}", goto_cc.display());
+        }
 
         Ok(KaniSession {
             args,
             codegen_tests: false,
             kani_compiler: install.kani_compiler()?,
             kani_lib_c: install.kani_lib_c()?,
+            cbmc,
+            goto_cc,
+            cbmc_version: OnceLock::new(),
             temporaries: Mutex::new(vec![]),
+            compile_commands: Mutex::new(vec![]),
+            fail_fast_stopped: AtomicBool::new(false),
             runtime: tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap(),
         })
     }
 
+    /// Stop the verification of the remaining harnesses because of a failure with `--fail-fast`.
+    pub fn stop_fail_fast(&self) {
+        self.fail_fast_stopped.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether a harness failed with `--fail-fast`, so the remaining harnesses should be skipped.
+    pub fn is_fail_fast_stopped(&self) -> bool {
+        self.fail_fast_stopped.load(Ordering::Relaxed)
+    }
+
     /// Record a temporary file so we can cleanup after ourselves at the end.
     /// Note that there will be no failure if the file does not exist.
     pub fn record_temporary_file<T: AsRef<Path>>(&self, temp: &T) {
@@ -382,6 +422,31 @@ fn expect_path(path: PathBuf) -> Result<PathBuf> {
     }
 }
 
+/// Return the path of the given CBMC tool. If the user provided one via `option`, check that it
+/// is an executable file. Otherwise, return the tool name so it is looked up in `PATH`, which
+/// includes the tools bundled with Kani.
+fn resolve_tool(name: &str, option: &str, user_path: Option<&Path>) -> Result<PathBuf> {
+    let Some(path) = user_path else { return Ok(PathBuf::from(name)) };
+    if !path.is_file() {
+        bail!("Invalid path `{}` given to `{option}`: no such file.", path.display());
+    }
+    if !is_executable(path) {
+        bail!("Invalid path `{}` given to `{option}`: file is not executable.", path.display());
+    }
+    Ok(path.to_owned())
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata().is_ok_and(|metadata| metadata.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(_path: &Path) -> bool {
+    true
+}
+
 /// Initialize the logger using the KANI_LOG environment variable and `--debug` argument.
 fn init_logger(args: &VerificationArgs) {
     let filter = EnvFilter::from_env(LOG_ENV_VAR);
@@ -424,3 +489,36 @@ pub fn setup_cargo_command() -> Result<Command> {
 
     Ok(cmd)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_resolve_tool() {
+        assert_eq!(resolve_tool("cbmc", "--cbmc-path", None).unwrap(), PathBuf::from("cbmc"));
+
+        let missing = Path::new("/this/path/does/not/exist/cbmc");
+        let err = resolve_tool("cbmc", "--cbmc-path", Some(missing)).unwrap_err();
+        assert!(err.to_string().contains("given to `--cbmc-path`: no such file"));
+
+        let dir = tempfile::tempdir().unwrap();
+        let err = resolve_tool("goto-cc", "--goto-cc-path", Some(dir.path())).unwrap_err();
+        assert!(err.to_string().contains("no such file"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn check_resolve_tool_executable() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::tempdir().unwrap();
+        let tool = dir.path().join("cbmc");
+        std::fs::write(&tool, "#!/bin/sh\n").unwrap();
+        std::fs::set_permissions(&tool, std::fs::Permissions::from_mode(0o644)).unwrap();
+        let err = resolve_tool("cbmc", "--cbmc-path", Some(&tool)).unwrap_err();
+        assert!(err.to_string().contains("file is not executable"));
+
+        std::fs::set_permissions(&tool, std::fs::Permissions::from_mode(0o755)).unwrap();
+        assert_eq!(resolve_tool("cbmc", "--cbmc-path", Some(&tool)).unwrap(), tool);
+    }
+}
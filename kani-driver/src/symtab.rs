@@ -0,0 +1,76 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This module implements `--write-json-symtab`, which saves the GotoC symbol table generated for
+//! each selected harness in JSON format.
+
+use std::fs;
+
+use anyhow::{Context, Result};
+use kani_metadata::{ArtifactType, HarnessMetadata};
+
+use crate::project::Project;
+use crate::session::KaniSession;
+
+impl KaniSession {
+    /// Copy the JSON symbol table of each harness in `harnesses` to the directory given by
+    /// `--write-json-symtab`. This is a no-op if the option was not provided.
+    pub fn write_json_symtabs(
+        &self,
+        project: &Project,
+        harnesses: &[&HarnessMetadata],
+    ) -> Result<()> {
+        let Some(dir) = &self.args.write_json_symtab else { return Ok(()) };
+        fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create directory `{}`", dir.display()))?;
+        for harness in harnesses {
+            let symtab =
+                project.get_harness_artifact(harness, ArtifactType::SymTab).with_context(|| {
+                    format!(
+                        "no JSON symbol table was generated for harness `{}`",
+                        harness.pretty_name
+                    )
+                })?;
+            let output = dir.join(symtab_file_name(harness));
+            fs::copy(symtab, &output)
+                .with_context(|| format!("failed to write `{}`", output.display()))?;
+            if !self.args.common_args.quiet {
+                println!(
+                    "[info] Symbol table for harness `{}` written to {}",
+                    harness.pretty_name,
+                    output.display()
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the name of the JSON symbol table file for the given harness.
+///
+/// The name includes the crate name, since harnesses from different crates may share a name.
+fn symtab_file_name(harness: &HarnessMetadata) -> String {
+    let sanitize = |name: &str| -> String {
+        name.chars().map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' }).collect()
+    };
+    format!(
+        "{}__{}.{}",
+        sanitize(&harness.crate_name),
+        sanitize(&harness.pretty_name),
+        &*ArtifactType::SymTab
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::tests::mock_proof_harness;
+
+    #[test]
+    fn check_symtab_file_name() {
+        let harness = mock_proof_harness("module::check_foo", None, Some("my_crate"), None);
+        assert_eq!(symtab_file_name(&harness), "my_crate__module__check_foo.symtab.json");
+        let harness = mock_proof_harness("<Foo as Bar>::check", None, Some("my_crate"), None);
+        assert_eq!(symtab_file_name(&harness), "my_crate___Foo_as_Bar___check.symtab.json");
+    }
+}
@@ -10,7 +10,7 @@
 //! to use the Rust compiler's error message utilities if you're working on the
 //! `kani-compiler`.
 
-use std::ffi::OsString;
+use std::ffi::{OsStr, OsString};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
@@ -55,18 +55,34 @@ pub fn render_command(cmd: &Command) -> OsString {
 
     for a in cmd.get_args() {
         str.push(" ");
-        if a.to_string_lossy().contains(' ') {
-            str.push("\"");
-            str.push(a);
-            str.push("\"");
-        } else {
-            str.push(a);
-        }
+        push_arg(&mut str, a);
     }
 
     str
 }
 
+/// Render a list of arguments as a string, quoting them the same way as [render_command].
+pub fn render_args(args: &[OsString]) -> OsString {
+    let mut str = OsString::new();
+    for (idx, a) in args.iter().enumerate() {
+        if idx > 0 {
+            str.push(" ");
+        }
+        push_arg(&mut str, a);
+    }
+    str
+}
+
+fn push_arg(str: &mut OsString, arg: &OsStr) {
+    if arg.to_string_lossy().contains(' ') {
+        str.push("\"");
+        str.push(arg);
+        str.push("\"");
+    } else {
+        str.push(arg);
+    }
+}
+
 /// Print a warning message. This will add a "warning:" tag before the message and style accordingly.
 pub fn warning(msg: &str) {
     let warning = console::style("warning:").bold().yellow();
@@ -126,4 +142,11 @@ fn check_render_command() {
         c1.env("PARAM", "VALUE");
         assert_eq!(render_command(&c1), OsString::from("PARAM=\"VALUE\" a b \"/c d/\""));
     }
+
+    #[test]
+    fn check_render_args() {
+        let args = ["--unwindset".into(), "f.0:2".into(), "a b".into()];
+        assert_eq!(render_args(&args), OsString::from("--unwindset f.0:2 \"a b\""));
+        assert_eq!(render_args(&[]), OsString::new());
+    }
 }
@@ -2,12 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use crate::InvocationType;
+use crate::args::common::Verbosity;
+use crate::call_cbmc::CbmcInvocation;
+use crate::session::KaniSession;
+use anyhow::{Context, Result};
+use std::process::Command;
 
 const KANI_RUST_VERIFIER: &str = "Kani Rust Verifier";
 /// We assume this is the same as the `kani-verifier` version, but we should
 /// make sure it's enforced through CI:
 /// <https://github.com/model-checking/kani/issues/2626>
 pub(crate) const KANI_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// The commit Kani was built from. This is only available when Kani is built from a git checkout.
+const KANI_GIT_COMMIT: Option<&str> = option_env!("KANI_GIT_COMMIT");
 
 /// Print Kani version. At present, this is only release version information.
 pub(crate) fn print_kani_version(invocation_type: InvocationType) {
@@ -29,3 +36,52 @@ fn kani_version_release(invocation_type: InvocationType) -> String {
     };
     format!("{KANI_RUST_VERIFIER} {KANI_VERSION} ({invocation_str})")
 }
+
+/// Return the Kani version followed by the commit it was built from, if known.
+fn kani_version_with_commit() -> String {
+    match KANI_GIT_COMMIT {
+        Some(commit) => format!("{KANI_VERSION} (commit {commit})"),
+        None => KANI_VERSION.to_string(),
+    }
+}
+
+impl KaniSession {
+    /// Print the versions of Kani and CBMC used in this session when `--verbose` is set.
+    ///
+    /// Together with the CBMC command line that is printed for each harness, this should be
+    /// enough information to reproduce a verification run.
+    pub fn print_version_header(&self) -> Result<()> {
+        if !self.args.common_args.verbose() {
+            return Ok(());
+        }
+        println!("[Kani] Kani version: {}", kani_version_with_commit());
+        println!("[Kani] CBMC version: {}", self.cbmc_version()?);
+        Ok(())
+    }
+
+    /// Return the output of `cbmc --version`, which is only computed once per session.
+    fn cbmc_version(&self) -> Result<String> {
+        if let Some(version) = self.cbmc_version.get() {
+            return Ok(version.clone());
+        }
+        let output = Command::new(&self.cbmc)
+            .arg("--version")
+            .output()
+            .context("Failed to invoke `cbmc --version`")?;
+        let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Ok(self.cbmc_version.get_or_init(|| version).clone())
+    }
+
+    /// Describe how CBMC is invoked by `cmd`, including every argument in full.
+    pub fn cbmc_invocation(&self, cmd: &Command) -> Result<CbmcInvocation> {
+        let cbmc_command = std::iter::once(cmd.get_program())
+            .chain(cmd.get_args())
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect();
+        Ok(CbmcInvocation {
+            kani_version: kani_version_with_commit(),
+            cbmc_version: self.cbmc_version()?,
+            cbmc_command,
+        })
+    }
+}
@@ -0,0 +1,41 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Categories of Kani diagnostics that can be reported as errors instead of warnings with
+//! `--deny <category>`.
+//!
+//! The names of the categories are part of Kani's command line interface, so a category must not
+//! be renamed or removed once it has been released. They are documented in
+//! `docs/src/reference/diagnostic-categories.md`.
+
+/// A category of warnings emitted by Kani.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    clap::ValueEnum,
+    strum_macros::Display,
+    strum_macros::AsRefStr
+)]
+#[strum(serialize_all = "kebab-case")]
+pub enum DiagnosticCategory {
+    /// A harness reaches recursive functions without an unwind bound.
+    Recursion,
+    /// A harness doesn't check any property, so it passes trivially.
+    Vacuous,
+    /// The code uses constructs that Kani doesn't support, which fail verification if reachable.
+    UnsupportedSoft,
+    /// The code uses concurrency constructs, which Kani treats as sequential operations.
+    Concurrency,
+}
+
+impl DiagnosticCategory {
+    /// Whether the diagnostics of this category are emitted by the compiler, which is then told
+    /// which categories are denied.
+    pub fn is_compiler_diagnostic(self) -> bool {
+        matches!(self, DiagnosticCategory::UnsupportedSoft | DiagnosticCategory::Concurrency)
+    }
+}
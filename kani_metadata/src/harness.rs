@@ -1,7 +1,7 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::CbmcSolver;
+use crate::{CbmcSolver, UnsupportedFeature};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -38,6 +38,14 @@ pub struct HarnessMetadata {
     pub contract: Option<AssignsContract>,
     /// If the harness contains some usage of loop contracts.
     pub has_loop_contracts: bool,
+    /// The cycles of recursive functions reachable from this harness. Each cycle lists the names
+    /// of the functions in the order they call each other.
+    pub recursive_cycles: Vec<Vec<String>>,
+    /// The unsupported features that are reachable from this harness.
+    pub unsupported_features: Vec<UnsupportedFeature>,
+    /// The error that stopped the code generation of this harness with `--keep-going`, in which
+    /// case the harness has no model and it cannot be verified.
+    pub codegen_error: Option<String>,
 }
 
 /// The attributes added by the user to control how a harness is executed.
@@ -47,14 +55,26 @@ pub struct HarnessAttributes {
     pub kind: HarnessKind,
     /// Whether the harness is expected to panic or not.
     pub should_panic: bool,
+    /// The verdict the harness is expected to have, given by `#[kani::proof(expected = "...")]`.
+    pub expected: ExpectedOutcome,
     /// Optional data to store solver.
     pub solver: Option<CbmcSolver>,
     /// Optional data to store unwind value.
     pub unwind_value: Option<u32>,
+    /// Whether `unwind_value` is the default given by `#![kanitool::unwind]` on the crate root,
+    /// since the harness doesn't have its own `#[kani::unwind]` attribute.
+    pub unwind_from_crate: bool,
     /// The stubs used in this harness.
     pub stubs: Vec<Stub>,
+    /// The stubs registered with `kani::register_default_stub!` that apply to this harness, i.e.,
+    /// the ones whose original function isn't stubbed by the harness itself. Their paths are
+    /// relative to the crate root.
+    pub default_stubs: Vec<Stub>,
     /// The name of the functions being stubbed by their contract.
     pub verified_stubs: Vec<String>,
+    /// The fully qualified names of the functions in `verified_stubs`, in the same order.
+    /// These match the function names in [crate::ContractedFunction].
+    pub verified_stub_paths: Vec<String>,
 }
 
 #[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
@@ -67,16 +87,30 @@ pub enum HarnessKind {
     Test,
 }
 
+/// The verdict a harness is expected to have.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum ExpectedOutcome {
+    /// The harness is expected to be successfully verified. This is the default.
+    #[default]
+    Pass,
+    /// The harness is expected to fail verification, e.g., a negative example.
+    Fail,
+}
+
 impl HarnessAttributes {
     /// Create a new harness of the provided kind.
     pub fn new(kind: HarnessKind) -> HarnessAttributes {
         HarnessAttributes {
             kind,
             should_panic: false,
+            expected: ExpectedOutcome::Pass,
             solver: None,
             unwind_value: None,
+            unwind_from_crate: false,
             stubs: vec![],
+            default_stubs: vec![],
             verified_stubs: vec![],
+            verified_stub_paths: vec![],
         }
     }
 
@@ -84,6 +118,12 @@ pub fn new(kind: HarnessKind) -> HarnessAttributes {
     pub fn is_proof_harness(&self) -> bool {
         matches!(self.kind, HarnessKind::Proof | HarnessKind::ProofForContract { .. })
     }
+
+    /// Return all the stubs applied to this harness, i.e., its own stubs followed by the default
+    /// ones.
+    pub fn all_stubs(&self) -> impl Iterator<Item = &Stub> {
+        self.stubs.iter().chain(&self.default_stubs)
+    }
 }
 
 /// The stubbing type.
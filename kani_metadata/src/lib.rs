@@ -8,11 +8,13 @@
 
 pub use artifact::ArtifactType;
 pub use cbmc_solver::CbmcSolver;
+pub use diagnostic::DiagnosticCategory;
 pub use harness::*;
 pub use vtable::*;
 
 pub mod artifact;
 mod cbmc_solver;
+mod diagnostic;
 mod harness;
 pub mod unstable;
 mod vtable;
@@ -33,6 +35,22 @@ pub struct KaniMetadata {
     pub test_harnesses: Vec<HarnessMetadata>,
     /// The functions with contracts in this crate
     pub contracted_functions: Vec<ContractedFunction>,
+    /// The proof harnesses that were removed from this crate by their `#[cfg(...)]` attribute.
+    pub inactive_harnesses: Vec<InactiveHarness>,
+}
+
+/// A proof harness that is not part of the crate because its `#[cfg(...)]` attribute doesn't
+/// hold for the current configuration, e.g., the target platform.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, PartialOrd, Ord)]
+pub struct InactiveHarness {
+    /// The fully qualified name the user gave to the function (i.e. includes the module path).
+    pub pretty_name: String,
+    /// The (currently full-) path to the file this proof harness was declared within.
+    pub original_file: String,
+    /// The line in that file where the name of the proof harness is declared.
+    pub original_line: usize,
+    /// The predicate of the `#[cfg(...)]` attribute that removed the harness, e.g., `windows`.
+    pub cfg: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, PartialOrd, Ord)]
@@ -45,7 +63,7 @@ pub struct ContractedFunction {
     pub harnesses: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct UnsupportedFeature {
     // We could replace this with an enum: https://github.com/model-checking/kani/issues/1765
     /// A string identifying the feature.
@@ -102,6 +102,10 @@ pub enum UnstableFeature {
     List,
     /// Kani APIs related to floating-point operations (e.g. `float_to_int_in_range`)
     FloatLib,
+    /// Kani APIs to permit or forbid panics in a scope (e.g. `kani::panic::catch`)
+    PanicScopes,
+    /// Only use the contract of a function as a verified stub once its contract harness passes.
+    VerifiedStubDependencies,
 }
 
 impl UnstableFeature {
@@ -3,8 +3,15 @@
 
 //! This module introduces the `Arbitrary` trait as well as implementation for
 //! primitive types and other std containers.
+//!
+//! It also introduces [`Unstructured`], which decodes values out of a byte buffer, to run fuzz
+//! targets that take bytes as input.
+
+mod unstructured;
 
 use crate::Arbitrary;
+pub use kani_macros::Decode;
+pub use unstructured::{Decode, NotEnoughData, Unstructured, decode};
 
 impl<T> Arbitrary for std::boxed::Box<T>
 where
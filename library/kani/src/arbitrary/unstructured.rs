@@ -0,0 +1,176 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This module introduces [`Unstructured`] and the [`Decode`] trait, which build structured
+//! values out of a byte buffer, in the style of the `arbitrary` crate used by fuzzers.
+//!
+//! This makes it possible to run fuzz targets that take their input as bytes under Kani: the
+//! bytes are given by a symbolic buffer of bounded length, e.g., [`crate::vec::any_vec`], and the
+//! values decoded from them are as symbolic as the bytes they consume.
+//!
+//! The decoding is deterministic: the same bytes always produce the same values, and the bytes
+//! are consumed from the front of the buffer in the order the values are decoded. Thus, the
+//! concrete bytes found in a counterexample reproduce the same values with concrete playback.
+
+/// The error returned when the buffer doesn't have enough bytes left to decode a value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NotEnoughData;
+
+/// A byte buffer that is consumed to decode structured values.
+///
+/// ```no_run
+/// # use kani::arbitrary::Unstructured;
+/// let data: Vec<u8> = kani::vec::any_vec::<u8, 8>();
+/// let mut input = Unstructured::new(&data);
+/// if let Ok((len, flag)) = input.decode::<(u16, bool)>() {
+///     // ...
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Unstructured<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Unstructured<'a> {
+    /// Create a buffer that decodes values from `data`.
+    pub fn new(data: &'a [u8]) -> Self {
+        Unstructured { data }
+    }
+
+    /// The number of bytes that haven't been consumed yet.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Whether all the bytes have been consumed.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Consume the next `size` bytes.
+    pub fn bytes(&mut self, size: usize) -> Result<&'a [u8], NotEnoughData> {
+        if size > self.data.len() {
+            return Err(NotEnoughData);
+        }
+        let (bytes, rest) = self.data.split_at(size);
+        self.data = rest;
+        Ok(bytes)
+    }
+
+    /// Consume all the bytes that are left.
+    pub fn take_rest(self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Decode a value of type `T` from the next bytes.
+    pub fn decode<T: Decode>(&mut self) -> Result<T, NotEnoughData> {
+        T::decode(self)
+    }
+}
+
+/// Decode a value of type `T` from the next bytes of `input`.
+pub fn decode<T: Decode>(input: &mut Unstructured) -> Result<T, NotEnoughData> {
+    T::decode(input)
+}
+
+/// A type whose values can be decoded from bytes.
+///
+/// Derive it with `#[derive(kani::arbitrary::Decode)]`, which decodes the fields in order and
+/// selects the variant of an enum with a `u32`, or implement it for your own types by decoding
+/// their fields in order, like an implementation of `arbitrary::Arbitrary`:
+///
+/// ```no_run
+/// # use kani::arbitrary::{Decode, NotEnoughData, Unstructured};
+/// struct Rgb {
+///     r: u8,
+///     g: u8,
+///     b: u8,
+/// }
+///
+/// impl Decode for Rgb {
+///     fn decode(input: &mut Unstructured) -> Result<Self, NotEnoughData> {
+///         Ok(Rgb { r: input.decode()?, g: input.decode()?, b: input.decode()? })
+///     }
+/// }
+/// ```
+///
+/// Unlike `arbitrary::Arbitrary`, this trait isn't implemented for every [`crate::Arbitrary`]
+/// type: `kani::any()` creates a value without consuming any bytes, so the value wouldn't be
+/// determined by the buffer, and concrete playback couldn't reproduce it from the bytes of a
+/// counterexample. Derive `Decode` for these types instead.
+pub trait Decode: Sized {
+    /// Decode a value from the next bytes of `input`, consuming the bytes that were used.
+    fn decode(input: &mut Unstructured) -> Result<Self, NotEnoughData>;
+}
+
+macro_rules! decode_from_le_bytes {
+    ( $( $type: ty ),* ) => {
+        $(
+            impl Decode for $type {
+                fn decode(input: &mut Unstructured) -> Result<Self, NotEnoughData> {
+                    let bytes = input.bytes(size_of::<$type>())?;
+                    Ok(<$type>::from_le_bytes(bytes.try_into().unwrap()))
+                }
+            }
+        )*
+    };
+}
+
+decode_from_le_bytes!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// Consumes one byte, and only its lowest bit is used.
+impl Decode for bool {
+    fn decode(input: &mut Unstructured) -> Result<Self, NotEnoughData> {
+        Ok(u8::decode(input)? & 1 == 1)
+    }
+}
+
+/// Consumes four bytes, and values that are not a valid `char` are decoded as
+/// [`char::REPLACEMENT_CHARACTER`].
+impl Decode for char {
+    fn decode(input: &mut Unstructured) -> Result<Self, NotEnoughData> {
+        Ok(char::from_u32(u32::decode(input)?).unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+}
+
+/// Consumes one byte for the variant, followed by the value if it is `Some`.
+impl<T: Decode> Decode for Option<T> {
+    fn decode(input: &mut Unstructured) -> Result<Self, NotEnoughData> {
+        if bool::decode(input)? { Ok(Some(T::decode(input)?)) } else { Ok(None) }
+    }
+}
+
+impl<T: Decode, const N: usize> Decode for [T; N] {
+    fn decode(input: &mut Unstructured) -> Result<Self, NotEnoughData> {
+        let items = (0..N).map(|_| T::decode(input)).collect::<Result<Vec<T>, _>>()?;
+        Ok(items.try_into().unwrap_or_else(|_| unreachable!()))
+    }
+}
+
+/// Every element is preceded by a byte that tells whether the vector has more elements. The
+/// vector ends when that byte is even or when the buffer is empty, so the number of elements is
+/// bounded by the length of the buffer.
+impl<T: Decode> Decode for Vec<T> {
+    fn decode(input: &mut Unstructured) -> Result<Self, NotEnoughData> {
+        let mut items = vec![];
+        while !input.is_empty() && bool::decode(input)? {
+            items.push(T::decode(input)?);
+        }
+        Ok(items)
+    }
+}
+
+macro_rules! decode_tuple {
+    ( $( $type: ident ),+ ) => {
+        impl<$( $type: Decode ),+> Decode for ( $( $type, )+ ) {
+            fn decode(input: &mut Unstructured) -> Result<Self, NotEnoughData> {
+                Ok(( $( $type::decode(input)?, )+ ))
+            }
+        }
+    };
+}
+
+decode_tuple!(A);
+decode_tuple!(A, B);
+decode_tuple!(A, B, C);
+decode_tuple!(A, B, C, D);
@@ -6,7 +6,11 @@
 use std::{
     future::Future,
     pin::Pin,
-    task::{Context, RawWaker, RawWakerVTable, Waker},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    task::{Context, RawWaker, RawWakerVTable, Wake, Waker},
 };
 
 /// A very simple executor: it polls the future in a busy loop until completion
@@ -14,24 +18,55 @@
 /// This is intended as a drop-in replacement for `futures::block_on`, which Kani cannot handle.
 /// Whereas a clever executor like `block_on` in `futures` or `tokio` would interact with the OS scheduler
 /// to be woken up when a resource becomes available, this is not supported by Kani.
-/// As a consequence, this function completely ignores the waker infrastructure and just polls the given future in a busy loop.
+/// As a consequence, this function just polls the given future in a busy loop.
+///
+/// Since the future is the only task, it can only make progress after returning [`std::task::Poll::Pending`]
+/// if it woke up its waker before doing so (e.g., [`yield_now`] does this).
+/// A future that returns `Pending` without waking the waker is waiting for an external event, such as I/O or a timer,
+/// which Kani does not model. This function panics in that case, instead of polling the future forever.
 ///
 /// Note that [`spawn`] is not supported with this function. Use [`block_on_with_spawn`] if you need it.
 #[crate::unstable(feature = "async-lib", issue = 2559, reason = "experimental async support")]
 pub fn block_on<T>(mut fut: impl Future<Output = T>) -> T {
-    let waker = unsafe { Waker::from_raw(NOOP_RAW_WAKER) };
+    let woken = Arc::new(WakeFlag::default());
+    let waker = Waker::from(woken.clone());
     let cx = &mut Context::from_waker(&waker);
     // SAFETY: we shadow the original binding, so it cannot be accessed again for the rest of the scope.
     // This is the same as what the pin_mut! macro in the futures crate does.
     let mut fut = unsafe { Pin::new_unchecked(&mut fut) };
     loop {
+        woken.0.store(false, Ordering::Relaxed);
         match fut.as_mut().poll(cx) {
             std::task::Poll::Ready(res) => return res,
-            std::task::Poll::Pending => continue,
+            std::task::Poll::Pending => {
+                if !woken.0.load(Ordering::Relaxed) {
+                    // An explicit panic instead of `assert!(...)` has better location information in Kani's output
+                    panic!(
+                        "the future is pending but did not wake up its waker. \
+                        Kani does not model external events such as I/O or timers"
+                    )
+                }
+            }
         }
     }
 }
 
+/// A waker that records whether it was woken up since the future was last polled by [`block_on`].
+///
+/// Each call to [`block_on`] has its own flag, so nested or consecutive calls don't affect each other.
+#[derive(Default)]
+struct WakeFlag(AtomicBool);
+
+impl Wake for WakeFlag {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
 /// A dummy waker, which is needed to call [`Future::poll`]
 const NOOP_RAW_WAKER: RawWaker = {
     #[inline]
@@ -224,10 +259,11 @@ impl Future for YieldNow {
 
         fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> std::task::Poll<Self::Output> {
             if self.yielded {
-                cx.waker().wake_by_ref(); // For completeness. But Kani currently ignores wakers.
                 std::task::Poll::Ready(())
             } else {
                 self.yielded = true;
+                // Notify the executor that this future can make progress if polled again.
+                cx.waker().wake_by_ref();
                 std::task::Poll::Pending
             }
         }
@@ -31,6 +31,8 @@
 pub mod futures;
 pub mod invariant;
 pub mod shadow;
+pub mod string;
+pub mod time;
 pub mod vec;
 
 mod models;
@@ -38,6 +40,7 @@
 #[cfg(feature = "concrete_playback")]
 pub use concrete_playback::concrete_playback_run;
 pub use invariant::Invariant;
+pub use vec::any_increasing;
 
 #[cfg(not(feature = "concrete_playback"))]
 /// NOP `concrete_playback` for type checking during verification mode.
@@ -60,6 +63,12 @@ pub fn concrete_playback_run<F: Fn()>(_: Vec<Vec<u8>>, _: F) {
 #[cfg(not(feature = "concrete_playback"))]
 pub use core::assert as __kani__workaround_core_assert;
 
+/// `cover!(cond, "message")` checks whether there is at least one execution that reaches this
+/// location and satisfies `cond`. See [`cover`] for how the result is reported.
+///
+/// The message is optional. It is shown next to the cover status, which helps telling apart
+/// multiple cover properties in the same function. Without a message, the description is the
+/// condition itself, and `cover!()` checks whether the location is reachable.
 #[macro_export]
 macro_rules! cover {
     () => {
@@ -68,21 +77,110 @@ macro_rules! cover {
     ($cond:expr $(,)?) => {
         kani::cover($cond, concat!("cover condition: ", stringify!($cond)));
     };
-    ($cond:expr, $msg:literal) => {
+    ($cond:expr, $msg:literal $(,)?) => {
         kani::cover($cond, $msg);
     };
 }
 
 /// `implies!(premise => conclusion)` means that if the `premise` is true, so
-/// must be the `conclusion`.
+/// must be the `conclusion`. It can also be written as `implies!(premise, conclusion)`.
 ///
 /// This simply expands to `!premise || conclusion` and is intended to make checks more readable,
 /// as the concept of an implication is more natural to think about than its expansion.
+///
+/// Note that the `conclusion` is only evaluated if the `premise` holds, just like with `||`.
+/// Thus, any side effect of the `conclusion` only happens when the `premise` is true.
 #[macro_export]
 macro_rules! implies {
     ($premise:expr => $conclusion:expr) => {
         !($premise) || ($conclusion)
     };
+    ($premise:expr, $conclusion:expr $(,)?) => {
+        !($premise) || ($conclusion)
+    };
+}
+
+/// `assert_implies!(premise, conclusion)` asserts that if the `premise` is true, so is the
+/// `conclusion`.
+///
+/// This is equivalent to `kani::assert(kani::implies!(premise, conclusion), ...)`. Just like with
+/// [`implies!`], the `conclusion` is only evaluated if the `premise` holds.
+#[macro_export]
+macro_rules! assert_implies {
+    ($premise:expr, $conclusion:expr $(,)?) => {
+        kani::assert(
+            kani::implies!($premise, $conclusion),
+            concat!("implication: ", stringify!($premise), " => ", stringify!($conclusion)),
+        );
+    };
+    ($premise:expr, $conclusion:expr, $msg:literal $(,)?) => {
+        kani::assert(kani::implies!($premise, $conclusion), $msg);
+    };
+}
+
+/// `loop_check!(cond)` asserts that `cond` holds every time this statement is executed. It is
+/// meant to be placed in the body of a loop, where it checks an invariant at every iteration.
+///
+/// This is a regular assertion, reported at the location of the macro with the description
+/// `loop check: <cond>`, or with the given message. It is unrelated to
+/// `#[kani::loop_invariant]`, which is a loop contract that lets CBMC verify a loop without
+/// unwinding it. In particular, `loop_check!` doesn't reduce the unwinding needed by the loop:
+/// the loop must still be unwound as many times as it iterates, and the check adds one property
+/// that must hold at each of these iterations.
+#[macro_export]
+macro_rules! loop_check {
+    ($cond:expr $(,)?) => {
+        kani::assert($cond, concat!("loop check: ", stringify!($cond)));
+    };
+    ($cond:expr, $msg:literal $(,)?) => {
+        kani::assert($cond, $msg);
+    };
+}
+
+/// `assume_all!(c1, c2, ...)` assumes that all of the given conditions hold.
+///
+/// This is equivalent to calling [`assume`] once for each condition, in order.
+#[macro_export]
+macro_rules! assume_all {
+    ($($cond:expr),+ $(,)?) => {
+        $(kani::assume($cond);)+
+    };
+}
+
+/// `assume_any!(c1, c2, ...)` assumes that at least one of the given conditions holds.
+///
+/// The disjunct that must hold is picked by a fresh symbolic selector, so every execution in which
+/// any of the conditions holds remains reachable. Note that, contrary to `||`, all the conditions
+/// are evaluated.
+#[macro_export]
+macro_rules! assume_any {
+    ($($cond:expr),+ $(,)?) => {{
+        let conditions: &[bool] = &[$($cond),+];
+        let selector: usize = kani::any_where(|idx| *idx < conditions.len());
+        kani::assume(conditions[selector]);
+    }};
+}
+
+/// `assert_unsat!()` checks that the current location is unreachable, i.e., that the conditions
+/// assumed so far on this path are contradictory.
+///
+/// This is the dual of [`cover!`]: the harness passes only if no execution reaches this point.
+/// It is useful to check that a set of assumptions really rules out a given case. Contrary to
+/// `kani::assume(false)`, which silently prunes the path, Kani reports a failure if this location
+/// is reachable.
+///
+/// An optional message literal can be given to describe the contradiction that is expected.
+#[macro_export]
+macro_rules! assert_unsat {
+    () => {
+        kani::assert(
+            false,
+            "assert_unsat: location is reachable, so the path constraints are satisfiable",
+        );
+    };
+    ($msg:literal $(,)?) => {
+        kani::assert(false, concat!("assert_unsat: location is reachable: ", $msg));
+    };
 }
 
 pub(crate) use kani_macros::unstable_feature as unstable;
@@ -124,6 +124,92 @@ pub(super) unsafe fn simd_bitmask<T, U, E, const LANES: usize>(input: T) -> U
     struct Simd<T, const LANES: usize>([T; LANES]);
 }
 
+/// Models used by `--hashmap-abstraction` to verify code that uses `HashMap` without reasoning
+/// about the hasher.
+#[allow(dead_code)]
+mod hashmap {
+    use std::collections::HashMap;
+    use std::collections::hash_map::Entry;
+    use std::hash::{BuildHasher, Hash};
+
+    /// Model of `hashbrown::map::make_hash` that returns the same hash for every key.
+    ///
+    /// Every entry collides, so lookups scan the entries and compare them with the key using
+    /// `PartialEq`. I.e., the map behaves like an association list. The hash is trivially
+    /// consistent, since equal keys always get the same hash.
+    #[kanitool::fn_marker = "HashMapMakeHashModel"]
+    pub fn make_hash<Q: Hash + ?Sized, S: BuildHasher>(_hash_builder: &S, _val: &Q) -> u64 {
+        0
+    }
+
+    /// Model of `HashMap::insert` that checks that the map stays within the given capacity.
+    ///
+    /// The compiler appends the capacity given to `--hashmap-capacity` to the call.
+    #[kanitool::fn_marker = "HashMapInsertModel"]
+    pub fn insert<K: Eq + Hash, V, S: BuildHasher>(
+        map: &mut HashMap<K, V, S>,
+        key: K,
+        value: V,
+        capacity: usize,
+    ) -> Option<V> {
+        crate::assert(
+            map.len() < capacity || map.contains_key(&key),
+            "HashMap exceeds the capacity given to `--hashmap-capacity`",
+        );
+        map.insert(key, value)
+    }
+
+    /// Model of `HashMap::entry` that checks that the map stays within the given capacity.
+    ///
+    /// The check happens when the entry is created, so a vacant entry on a full map fails
+    /// verification even if no value is inserted into it.
+    #[kanitool::fn_marker = "HashMapEntryModel"]
+    pub fn entry<K: Eq + Hash, V, S: BuildHasher>(
+        map: &mut HashMap<K, V, S>,
+        key: K,
+        capacity: usize,
+    ) -> Entry<'_, K, V> {
+        crate::assert(
+            map.len() < capacity || map.contains_key(&key),
+            "HashMap exceeds the capacity given to `--hashmap-capacity`",
+        );
+        map.entry(key)
+    }
+
+    /// Model of `<HashMap as Extend<(K, V)>>::extend` that checks the capacity of every insertion.
+    ///
+    /// This also covers `HashMap::from_iter` and `HashMap::from`, which call `extend`.
+    #[kanitool::fn_marker = "HashMapExtendModel"]
+    pub fn extend<K: Eq + Hash, V, S: BuildHasher, T: IntoIterator<Item = (K, V)>>(
+        map: &mut HashMap<K, V, S>,
+        iter: T,
+        capacity: usize,
+    ) {
+        for (key, value) in iter {
+            insert(map, key, value, capacity);
+        }
+    }
+
+    /// Model of `<HashMap as Extend<(&K, &V)>>::extend` that checks the capacity of every
+    /// insertion.
+    #[kanitool::fn_marker = "HashMapExtendCopiedModel"]
+    pub fn extend_copied<
+        'a,
+        K: Eq + Hash + Copy + 'a,
+        V: Copy + 'a,
+        S: BuildHasher,
+        T: IntoIterator<Item = (&'a K, &'a V)>,
+    >(
+        map: &mut HashMap<K, V, S>,
+        iter: T,
+        capacity: usize,
+    ) {
+        for (key, value) in iter {
+            insert(map, *key, *value, capacity);
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::intrinsics as kani_intrinsic;
@@ -0,0 +1,97 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Constructors of symbolic strings.
+//!
+//! A `str` must always contain valid UTF-8, so its bytes cannot be created with `kani::any()`
+//! directly. The functions in this module create symbolic bytes and assume that they follow the
+//! structure of a well-formed UTF-8 sequence, as specified in Table 3-7 of the Unicode Standard.
+
+use crate::{any, assume};
+
+/// Creates a symbolic string slice of exactly `len` bytes that is valid UTF-8.
+///
+/// Every well-formed UTF-8 sequence of `len` bytes is possible, including multi-byte characters.
+/// Note that `len` is the length in bytes, so the string may have fewer than `len` characters.
+///
+/// The validity of each byte is assumed in a loop over the bytes, so this function requires an
+/// unwind bound of at least `len + 1`. Use [any_ascii_str_of_len] for cheaper proofs that don't
+/// need multi-byte characters.
+///
+/// The string is leaked, so it can be used for the rest of the harness.
+///
+/// # Example:
+///
+/// ```no_run
+/// let s = kani::string::any_str_of_len(4);
+/// assert!(s.chars().count() <= 4);
+/// ```
+pub fn any_str_of_len(len: usize) -> &'static str {
+    let bytes = any_utf8_bytes(len);
+    // SAFETY: `any_utf8_bytes` only returns well-formed UTF-8 sequences.
+    unsafe { String::from_utf8_unchecked(bytes) }.leak()
+}
+
+/// Creates a symbolic string slice of exactly `len` bytes that only contains ASCII characters.
+///
+/// This is cheaper to verify than [any_str_of_len], since every byte is constrained
+/// independently. It also requires an unwind bound of at least `len + 1`.
+///
+/// The string is leaked, so it can be used for the rest of the harness.
+///
+/// # Example:
+///
+/// ```no_run
+/// let s = kani::string::any_ascii_str_of_len(4);
+/// assert_eq!(s.chars().count(), 4);
+/// ```
+pub fn any_ascii_str_of_len(len: usize) -> &'static str {
+    let mut bytes = Vec::with_capacity(len);
+    for _ in 0..len {
+        let byte: u8 = any();
+        assume(byte.is_ascii());
+        bytes.push(byte);
+    }
+    // SAFETY: ASCII is valid UTF-8.
+    unsafe { String::from_utf8_unchecked(bytes) }.leak()
+}
+
+/// Creates `len` symbolic bytes that form a well-formed UTF-8 sequence.
+///
+/// The bytes are constrained one at a time by keeping track of the number of continuation bytes
+/// that the current character still needs, and of the range allowed for the next continuation
+/// byte. The range is only narrower than `0x80..=0xBF` right after a lead byte, to exclude
+/// overlong encodings, surrogates and code points above `U+10FFFF`.
+fn any_utf8_bytes(len: usize) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(len);
+    let mut pending: u8 = 0;
+    let mut next_range: (u8, u8) = (0x80, 0xBF);
+    for _ in 0..len {
+        let byte: u8 = any();
+        if pending > 0 {
+            assume((next_range.0..=next_range.1).contains(&byte));
+            pending -= 1;
+            next_range = (0x80, 0xBF);
+        } else {
+            (pending, next_range) = match byte {
+                0x00..=0x7F => (0, (0x80, 0xBF)),
+                0xC2..=0xDF => (1, (0x80, 0xBF)),
+                0xE0 => (2, (0xA0, 0xBF)),
+                0xED => (2, (0x80, 0x9F)),
+                0xE1..=0xEF => (2, (0x80, 0xBF)),
+                0xF0 => (3, (0x90, 0xBF)),
+                0xF1..=0xF3 => (3, (0x80, 0xBF)),
+                0xF4 => (3, (0x80, 0x8F)),
+                // `0x80..=0xC1` and `0xF5..=0xFF` can never start a character.
+                _ => {
+                    assume(false);
+                    (0, (0x80, 0xBF))
+                }
+            };
+        }
+        bytes.push(byte);
+    }
+    // The last character must be complete.
+    assume(pending == 0);
+    bytes
+}
@@ -0,0 +1,34 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Helpers to verify code that manipulates time without depending on the system clock.
+//!
+//! Kani does not support reading the system clock, so `std::time::Instant` and
+//! `std::time::SystemTime` cannot be used in a harness. Code that only does arithmetic on
+//! durations can be verified by using a symbolic [`Duration`] instead.
+
+use crate::Arbitrary;
+use std::time::Duration;
+
+/// Creates a symbolic [`Duration`].
+///
+/// The number of seconds is unconstrained, and the sub-second nanoseconds are assumed to be
+/// less than one second, which is an invariant of `Duration`. Operations that may overflow,
+/// such as `+` and `*`, are still checked by Kani.
+///
+/// # Example:
+///
+/// ```no_run
+/// let timeout = kani::time::any_duration();
+/// if let Some(deadline) = timeout.checked_add(std::time::Duration::from_secs(1)) {
+///     assert!(deadline > timeout);
+/// }
+/// ```
+pub fn any_duration() -> Duration {
+    Duration::any()
+}
+
+/// Creates a symbolic [`Duration`] that is less than or equal to `max`.
+pub fn any_duration_up_to(max: Duration) -> Duration {
+    crate::any_where(|duration: &Duration| *duration <= max)
+}
@@ -1,6 +1,6 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
-use crate::{Arbitrary, any, any_where};
+use crate::{Arbitrary, any, any_where, assume};
 
 /// Generates an arbitrary vector whose length is at most MAX_LENGTH.
 pub fn any_vec<T, const MAX_LENGTH: usize>() -> Vec<T>
@@ -29,3 +29,39 @@ pub fn exact_vec<T, const EXACT_LENGTH: usize>() -> Vec<T>
     let boxed_array: Box<[T; EXACT_LENGTH]> = Box::new(any());
     <[T]>::into_vec(boxed_array)
 }
+
+/// Generates a vector of `count` arbitrary values that are strictly increasing and no greater
+/// than `max`.
+///
+/// This is useful for harnesses that require sorted inputs, e.g. to check `binary_search`.
+/// The ordering is enforced by assuming `values[i] < values[i + 1]` for every pair of
+/// consecutive values, so the loop inside this function requires an unwind bound of at
+/// least `count + 1`.
+///
+/// Note that there may be no sequence that satisfies these assumptions. For example, there
+/// are only `max + 1` distinct unsigned integers no greater than `max`, so `count > max + 1`
+/// makes the assumptions unsatisfiable, and every property checked after this call is
+/// vacuously true. Use `kani::cover!()` to check that the harness can make progress.
+///
+/// ```no_run
+/// let values: Vec<u8> = kani::any_increasing(3, 10);
+/// let target: u8 = kani::any();
+/// if let Ok(idx) = values.binary_search(&target) {
+///     assert_eq!(values[idx], target);
+/// }
+/// ```
+pub fn any_increasing<T>(count: usize, max: T) -> Vec<T>
+where
+    T: Arbitrary + PartialOrd,
+{
+    let mut values: Vec<T> = Vec::with_capacity(count);
+    for _ in 0..count {
+        let value: T = any();
+        assume(value <= max);
+        if let Some(last) = values.last() {
+            assume(*last < value);
+        }
+        values.push(value);
+    }
+    values
+}
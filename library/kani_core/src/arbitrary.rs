@@ -8,6 +8,7 @@
 //!
 //! TODO: Use this inside kani library so that we dont have to maintain two copies of the same proc macro for arbitrary.
 
+mod array;
 mod pointer;
 mod slice;
 
@@ -30,6 +31,16 @@ pub trait Arbitrary
             }
         }
 
+        /// A type with a builder that creates symbolic values where only some fields are given
+        /// concrete values. It is implemented by `#[derive(Arbitrary)]` for structs with the
+        /// `#[arbitrary(builder)]` attribute, and it is used through [`crate::kani::builder`].
+        pub trait ArbitraryBuilder: Arbitrary {
+            /// The builder, which has one method per field to set its value, and a `rest_any()`
+            /// method to create the value.
+            type Builder;
+            fn builder() -> Self::Builder;
+        }
+
         /// The given type can be represented by an unconstrained symbolic value of size_of::<T>.
         macro_rules! trivial_arbitrary {
             ( $type: ty ) => {
@@ -190,6 +201,10 @@ mod arbitrary_ptr {
             kani_core::ptr_generator!();
         }
 
+        pub mod array {
+            kani_core::array_generator!();
+        }
+
         pub mod slice {
             kani_core::slice_generator!();
         }
@@ -0,0 +1,88 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This macro generates the logic required to generate arrays with arbitrary contents.
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! array_generator {
+    () => {
+        use super::{
+            Arbitrary,
+            core_path::ops::{Deref, Index, IndexMut},
+        };
+        use crate::kani;
+
+        /// Generates an array of `N` independent arbitrary values.
+        ///
+        /// For types that can be represented by an unconstrained symbolic value (e.g.: integers),
+        /// the array is created with a single symbolic value, which is more efficient than `N`
+        /// separate calls to `kani::any()`.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let arr = kani::array::any_array::<u8, 16>();
+        /// let idx: usize = kani::any_where(|i| *i < 16);
+        /// assert!(arr[idx] <= u8::MAX);
+        /// ```
+        #[inline(always)]
+        pub fn any_array<T: Arbitrary, const N: usize>() -> [T; N] {
+            T::any_array::<N>()
+        }
+
+        /// Generates an arbitrary array, like [any_array], wrapped in a [CoveredArray].
+        ///
+        /// Indexing into the result adds cover statements that show whether the first, the last,
+        /// and an interior element of the array can be read.
+        pub fn any_covered_array<T: Arbitrary, const N: usize>() -> CoveredArray<T, N> {
+            CoveredArray(any_array())
+        }
+
+        /// An array that reports which access patterns are reachable through cover statements.
+        ///
+        /// Every access through [Index] or [IndexMut] checks three cover properties: whether the
+        /// index can be the first index, the last index, and an index in between.
+        /// Accesses through [Deref] (e.g.: iterators) are not instrumented.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct CoveredArray<T, const N: usize>(pub [T; N]);
+
+        impl<T, const N: usize> CoveredArray<T, N> {
+            /// Consumes the wrapper and returns the underlying array.
+            pub fn into_inner(self) -> [T; N] {
+                self.0
+            }
+
+            #[inline(always)]
+            fn cover_index(index: usize) {
+                let last = N.saturating_sub(1);
+                kani::cover(index == 0, "array access at the first index");
+                kani::cover(index == last, "array access at the last index");
+                kani::cover(0 < index && index < last, "array access at an interior index");
+            }
+        }
+
+        impl<T, const N: usize> Deref for CoveredArray<T, N> {
+            type Target = [T; N];
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl<T, const N: usize> Index<usize> for CoveredArray<T, N> {
+            type Output = T;
+
+            fn index(&self, index: usize) -> &Self::Output {
+                Self::cover_index(index);
+                &self.0[index]
+            }
+        }
+
+        impl<T, const N: usize> IndexMut<usize> for CoveredArray<T, N> {
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                Self::cover_index(index);
+                &mut self.0[index]
+            }
+        }
+    };
+}
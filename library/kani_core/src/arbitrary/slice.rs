@@ -32,10 +32,37 @@ macro_rules! slice_generator {
             &mut arr[from..to]
         }
 
+        /// Given a slice `slice`, this function returns a subslice `&slice[start..end]` with
+        /// non-deterministic `start <= end <= slice.len()`. Unlike [any_slice_of_array], the
+        /// length of `slice` doesn't need to be known at compile time. The subslice reuses the
+        /// storage of `slice`, and it may be empty.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let vec = vec![1, 2, 3];
+        /// let subslice = kani::slice::any_subslice(&vec);
+        /// assert!(subslice.len() <= vec.len());
+        /// ```
+        pub fn any_subslice<T>(slice: &[T]) -> &[T] {
+            let (from, to) = any_range_of_len(slice.len());
+            &slice[from..to]
+        }
+
+        /// A mutable version of [any_subslice]
+        pub fn any_subslice_mut<T>(slice: &mut [T]) -> &mut [T] {
+            let (from, to) = any_range_of_len(slice.len());
+            &mut slice[from..to]
+        }
+
         fn any_range<const LENGTH: usize>() -> (usize, usize) {
+            any_range_of_len(LENGTH)
+        }
+
+        fn any_range_of_len(len: usize) -> (usize, usize) {
             let from: usize = kani::any();
             let to: usize = kani::any();
-            kani::assume(to <= LENGTH);
+            kani::assume(to <= len);
             kani::assume(from <= to);
             (from, to)
         }
@@ -39,5 +39,53 @@ pub fn float_to_int_in_range<Float, Int>(value: Float) -> bool
         {
             kani_intrinsic()
         }
+
+        /// Assumes that the given `f32` value is finite, i.e., that it is neither NaN nor
+        /// infinite.
+        ///
+        /// This constrains the exponent bits directly, which is cheaper for the solver than
+        /// calling [`f32::is_finite`]. Zeros of either sign and subnormal values are still
+        /// allowed.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let x: f32 = kani::any();
+        /// kani::float::assume_finite_f32(x);
+        /// assert!(!x.is_nan() && !x.is_infinite());
+        /// ```
+        #[crate::kani::unstable_feature(
+            feature = "float-lib",
+            issue = "none",
+            reason = "experimental floating-point API"
+        )]
+        pub fn assume_finite_f32(value: f32) {
+            const EXPONENT_MASK: u32 = 0x7f80_0000;
+            super::assume(value.to_bits() & EXPONENT_MASK != EXPONENT_MASK);
+        }
+
+        /// Assumes that the given `f64` value is finite, i.e., that it is neither NaN nor
+        /// infinite.
+        ///
+        /// This constrains the exponent bits directly, which is cheaper for the solver than
+        /// calling [`f64::is_finite`]. Zeros of either sign and subnormal values are still
+        /// allowed.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let x: f64 = kani::any();
+        /// kani::float::assume_finite(x);
+        /// assert!(!x.is_nan() && !x.is_infinite());
+        /// ```
+        #[crate::kani::unstable_feature(
+            feature = "float-lib",
+            issue = "none",
+            reason = "experimental floating-point API"
+        )]
+        pub fn assume_finite(value: f64) {
+            const EXPONENT_MASK: u64 = 0x7ff0_0000_0000_0000;
+            super::assume(value.to_bits() & EXPONENT_MASK != EXPONENT_MASK);
+        }
     };
 }
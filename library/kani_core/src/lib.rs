@@ -25,6 +25,8 @@
 mod mem;
 mod mem_init;
 mod models;
+mod num;
+mod panic;
 
 pub use kani_macros::*;
 
@@ -54,6 +56,14 @@ pub mod mem {
                 kani_core::kani_mem!(core);
             }
 
+            pub mod num {
+                kani_core::generate_num!(core);
+            }
+
+            pub mod panic {
+                kani_core::kani_panic!(core);
+            }
+
             mod mem_init {
                 kani_core::kani_mem_init!(core);
             }
@@ -74,6 +84,14 @@ pub mod mem {
             kani_core::kani_mem!(std);
         }
 
+        pub mod num {
+            kani_core::generate_num!(std);
+        }
+
+        pub mod panic {
+            kani_core::kani_panic!(std);
+        }
+
         mod mem_init {
             kani_core::kani_mem_init!(std);
         }
@@ -114,6 +132,7 @@ macro_rules! kani_intrinsics {
         /// ```
         #[inline(never)]
         #[kanitool::fn_marker = "AssumeHook"]
+        #[track_caller]
         #[cfg(not(feature = "concrete_playback"))]
         pub fn assume(cond: bool) {
             let _ = cond;
@@ -121,11 +140,47 @@ pub fn assume(cond: bool) {
 
         #[inline(never)]
         #[kanitool::fn_marker = "AssumeHook"]
+        #[track_caller]
         #[cfg(feature = "concrete_playback")]
         pub fn assume(cond: bool) {
             assert!(cond, "`kani::assume` should always hold");
         }
 
+        /// Same as [`assume`], but also checks that the assumption doesn't contradict the
+        /// assumptions that precede it, i.e., that at least one execution that reaches this
+        /// statement satisfies `cond`.
+        ///
+        /// A contradictory assumption silently prunes every path that follows it, which makes
+        /// all properties after it hold vacuously. This function reports such an assumption as
+        /// a failed `assume_satisfiable` check instead. The check can be disabled with
+        /// `--no-assume-satisfiable-checks`, in which case this behaves like [`assume`].
+        ///
+        /// # Example:
+        ///
+        /// The verification of the snippet below fails, since `i` can't be both positive and
+        /// negative.
+        ///
+        /// ```no_run
+        /// let i : i32 = kani::any();
+        /// kani::assume(i > 10);
+        /// kani::assume_satisfiable(i < 0);
+        /// ```
+        #[inline(never)]
+        #[kanitool::fn_marker = "AssumeSatisfiableHook"]
+        #[track_caller]
+        #[cfg(not(feature = "concrete_playback"))]
+        pub fn assume_satisfiable(cond: bool) {
+            let _ = cond;
+        }
+
+        #[inline(never)]
+        #[kanitool::fn_marker = "AssumeSatisfiableHook"]
+        #[track_caller]
+        #[cfg(feature = "concrete_playback")]
+        pub fn assume_satisfiable(cond: bool) {
+            assert!(cond, "`kani::assume_satisfiable` should always hold");
+        }
+
         /// Creates an assertion of the specified condition and message.
         ///
         /// # Example:
@@ -138,6 +193,7 @@ pub fn assume(cond: bool) {
         #[cfg(not(feature = "concrete_playback"))]
         #[inline(never)]
         #[kanitool::fn_marker = "AssertHook"]
+        #[track_caller]
         pub const fn assert(cond: bool, msg: &'static str) {
             let _ = cond;
             let _ = msg;
@@ -146,6 +202,7 @@ pub const fn assert(cond: bool, msg: &'static str) {
         #[cfg(feature = "concrete_playback")]
         #[inline(never)]
         #[kanitool::fn_marker = "AssertHook"]
+        #[track_caller]
         pub const fn assert(cond: bool, msg: &'static str) {
             assert!(cond, "{}", msg);
         }
@@ -175,6 +232,7 @@ pub const fn assert(cond: bool, msg: &'static str) {
         ///
         #[inline(never)]
         #[kanitool::fn_marker = "CoverHook"]
+        #[track_caller]
         pub const fn cover(_cond: bool, _msg: &'static str) {}
 
         /// This creates an symbolic *valid* value of type `T`. You can assign the return value of this
@@ -197,6 +255,14 @@ pub const fn cover(_cond: bool, _msg: &'static str) {}
         /// Note: This is a safe construct and can only be used with types that implement the `Arbitrary`
         /// trait. The Arbitrary trait is used to build a symbolic value that represents all possible
         /// valid values for type `T`.
+        ///
+        /// Each call to this function gets an identifier `<function>::any#<n>`, where `<function>`
+        /// is the path of the function that contains the call, and `<n>` is the position of the
+        /// call among the calls to `kani::any()` in that function, in source order, starting at `0`.
+        /// The identifier doesn't depend on any other code, so it only changes if a call to
+        /// `kani::any()` is added, removed or moved before it in the same function. It is reported
+        /// in the counterexample traces of `--output-format json`, and it can be given to `--fix`
+        /// to pin the value of the call.
         #[kanitool::fn_marker = "AnyModel"]
         #[inline(always)]
         pub fn any<T: Arbitrary>() -> T {
@@ -246,6 +312,69 @@ pub fn any_where<T: Arbitrary, F: FnOnce(&T) -> bool>(f: F) -> T {
             result
         }
 
+        /// This creates a builder for a symbolic value of the struct `T`, where some of the
+        /// fields are given concrete values, and the others are symbolic.
+        /// The builder has one method per field of `T`, which sets the value of the field, and
+        /// a `rest_any()` method that creates the value. Every field that wasn't set gets its own
+        /// independent symbolic value, like with `kani::any()`.
+        ///
+        /// The builder is generated by `#[derive(Arbitrary)]` for structs with named fields that
+        /// have the `#[arbitrary(builder)]` attribute. The methods that set the fields have the
+        /// same visibility as the fields, so private fields can be set from the module of the
+        /// struct and its submodules.
+        ///
+        /// # Example:
+        ///
+        /// In the snippet below, `mode` and `retries` are concrete, and the other fields of
+        /// `Config` are symbolic.
+        ///
+        /// ```no_run
+        /// # use crate::kani;
+        /// #
+        /// #[derive(kani::Arbitrary)]
+        /// #[arbitrary(builder)]
+        /// struct Config {
+        ///     mode: u8,
+        ///     retries: u32,
+        ///     timeout: u64,
+        ///     verbose: bool,
+        /// }
+        ///
+        /// let config: Config = kani::builder::<Config>().mode(1).retries(3).rest_any();
+        /// ```
+        #[inline(always)]
+        pub fn builder<T: ArbitraryBuilder>() -> T::Builder {
+            T::builder()
+        }
+
+        /// This creates a symbolic value of type `T` in the half-open range `[lo, hi)`.
+        ///
+        /// This is equivalent to `kani::any_where(|x| lo <= *x && *x < hi)`, and it is mostly useful
+        /// for integer types. Note that `hi` itself is never returned, so `T::MAX` can only be
+        /// generated with `kani::any_where`.
+        ///
+        /// # Example:
+        ///
+        /// In the snippet below, `idx` may take any value between 0 and `v.len() - 1`.
+        ///
+        /// ```no_run
+        /// # use crate::kani;
+        /// #
+        /// let v = vec![1, 2, 3];
+        /// let idx: usize = kani::bounded(0, v.len());
+        /// assert!(v[idx] > 0);
+        /// ```
+        ///
+        /// The range must not be empty, i.e., `lo` must be smaller than `hi`. Kani reports a
+        /// verification failure otherwise.
+        #[inline(always)]
+        pub fn bounded<T: Arbitrary + PartialOrd>(lo: T, hi: T) -> T {
+            assert(lo < hi, "`kani::bounded` requires a non-empty range (`lo < hi`)");
+            let result = T::any();
+            assume(lo <= result && result < hi);
+            result
+        }
+
         /// This function creates a symbolic value of type `T`. This may result in an invalid value.
         ///
         /// # Safety
@@ -337,6 +466,53 @@ fn kani_intrinsic<T>() -> T {
             loop {}
         }
 
+        /// Symbolic values that can be pinned to a concrete value from the command line.
+        pub mod concrete {
+            use crate::kani::{Arbitrary, assume};
+
+            /// Creates a symbolic value of type `T` that is tagged with `id`.
+            ///
+            /// This behaves like [`any()`](crate::kani::any), unless the site is pinned with
+            /// `--fix <id>`, which restricts the value to `default`, or with `--fix <id>=<value>`,
+            /// which restricts it to the given value. Explicit values are only supported for
+            /// integers, `bool` and `char`. Sites that are not pinned, as well as any other call
+            /// to `kani::any()`, are not affected.
+            ///
+            /// A pin is an assumption, so the value is also subject to the harness constraints.
+            /// If they are inconsistent with the pin, the properties that come after it are
+            /// reported as unreachable. The pin of a site is resolved during compilation, so
+            /// `id` must be a string literal.
+            ///
+            /// In concrete playback, the value comes from the counterexample just like the
+            /// values created with `kani::any()`, and the generated test names the site of
+            /// the value.
+            ///
+            /// # Example:
+            ///
+            /// ```no_run
+            /// # use crate::kani;
+            /// #
+            /// let len: usize = kani::concrete::value(4, "len");
+            /// ```
+            #[kanitool::fn_marker = "ConcreteValueModel"]
+            #[inline(never)]
+            pub fn value<T: Arbitrary + PartialEq>(default: T, id: &'static str) -> T {
+                let _ = id;
+                any_or_default(default, false)
+            }
+
+            /// Creates a symbolic value of type `T`, which is restricted to `default` if
+            /// `pin_default` is set. The compiler replaces the calls to [`value`] by calls to
+            /// this function, after resolving the pin of their site.
+            #[doc(hidden)]
+            #[kanitool::fn_marker = "ConcreteAnyOrDefaultModel"]
+            pub fn any_or_default<T: Arbitrary + PartialEq>(default: T, pin_default: bool) -> T {
+                let value = T::any();
+                assume(!pin_default || value == default);
+                value
+            }
+        }
+
         #[doc(hidden)]
         pub mod internal {
             use crate::kani::Arbitrary;
@@ -456,6 +632,18 @@ pub unsafe fn write_any_str(_s: *mut str) {
                 unimplemented!("Kani does not support creating arbitrary `str`")
             }
 
+            /// Harness that Kani generates for `--check-contract` to verify the contract of the
+            /// function `F`, which must have no harness annotated with `proof_for_contract`.
+            ///
+            /// The compiler replaces its body by a call to `F` with one `kani::any()` value per
+            /// argument.
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            #[kanitool::fn_marker = "ContractHarnessIntrinsic"]
+            fn contract_harness<F>() {
+                super::kani_intrinsic()
+            }
+
             /// Function that calls a closure used to implement contracts.
             ///
             /// In contracts, we cannot invoke the generated closures directly, instead, we call register
@@ -480,6 +668,132 @@ fn run_loop_contract_fn<F: Fn() -> bool>(func: &F, _transformed: usize) -> bool
                 func()
             }
 
+            /// Conversion into the iterator used to implement loop contracts on for-loops.
+            ///
+            /// `#[kani::loop_invariant]` turns a for-loop into a while-loop over the elements of a
+            /// slice, so the invariant can bound the number of iterations by the slice length.
+            #[doc(hidden)]
+            pub trait IntoLoopIter {
+                type LoopIter;
+                fn into_loop_iter(self) -> Self::LoopIter;
+            }
+
+            /// Iterator over the shared references to the elements of a slice, in order.
+            #[doc(hidden)]
+            pub struct SliceLoopIter<'a, T> {
+                slice: &'a [T],
+                index: usize,
+            }
+
+            impl<'a, T> SliceLoopIter<'a, T> {
+                /// The index of the next element.
+                pub fn index(&self) -> usize {
+                    self.index
+                }
+
+                pub fn len(&self) -> usize {
+                    self.slice.len()
+                }
+
+                pub fn is_empty(&self) -> bool {
+                    self.len() == 0
+                }
+
+                pub fn has_next(&self) -> bool {
+                    self.index < self.slice.len()
+                }
+
+                /// Return the next element. Must only be called if `has_next()` holds.
+                pub fn next_unchecked(&mut self) -> &'a T {
+                    let item = unsafe { self.slice.get_unchecked(self.index) };
+                    self.index += 1;
+                    item
+                }
+            }
+
+            /// Iterator over the mutable references to the elements of a slice, in order.
+            #[doc(hidden)]
+            pub struct SliceLoopIterMut<'a, T> {
+                ptr: *mut T,
+                len: usize,
+                index: usize,
+                _marker: core::marker::PhantomData<&'a mut T>,
+            }
+
+            impl<'a, T> SliceLoopIterMut<'a, T> {
+                /// The index of the next element.
+                pub fn index(&self) -> usize {
+                    self.index
+                }
+
+                pub fn len(&self) -> usize {
+                    self.len
+                }
+
+                pub fn is_empty(&self) -> bool {
+                    self.len() == 0
+                }
+
+                pub fn has_next(&self) -> bool {
+                    self.index < self.len
+                }
+
+                /// Return the next element. Must only be called if `has_next()` holds.
+                pub fn next_unchecked(&mut self) -> &'a mut T {
+                    // SAFETY: Each element is only returned once, so the references don't alias.
+                    let item = unsafe { &mut *self.ptr.add(self.index) };
+                    self.index += 1;
+                    item
+                }
+            }
+
+            impl<'a, T> IntoLoopIter for &'a [T] {
+                type LoopIter = SliceLoopIter<'a, T>;
+                fn into_loop_iter(self) -> Self::LoopIter {
+                    SliceLoopIter { slice: self, index: 0 }
+                }
+            }
+
+            impl<'a, T, const N: usize> IntoLoopIter for &'a [T; N] {
+                type LoopIter = SliceLoopIter<'a, T>;
+                fn into_loop_iter(self) -> Self::LoopIter {
+                    self.as_slice().into_loop_iter()
+                }
+            }
+
+            impl<'a, T> IntoLoopIter for core::slice::Iter<'a, T> {
+                type LoopIter = SliceLoopIter<'a, T>;
+                fn into_loop_iter(self) -> Self::LoopIter {
+                    self.as_slice().into_loop_iter()
+                }
+            }
+
+            impl<'a, T> IntoLoopIter for &'a mut [T] {
+                type LoopIter = SliceLoopIterMut<'a, T>;
+                fn into_loop_iter(self) -> Self::LoopIter {
+                    SliceLoopIterMut {
+                        ptr: self.as_mut_ptr(),
+                        len: self.len(),
+                        index: 0,
+                        _marker: core::marker::PhantomData,
+                    }
+                }
+            }
+
+            impl<'a, T, const N: usize> IntoLoopIter for &'a mut [T; N] {
+                type LoopIter = SliceLoopIterMut<'a, T>;
+                fn into_loop_iter(self) -> Self::LoopIter {
+                    self.as_mut_slice().into_loop_iter()
+                }
+            }
+
+            impl<'a, T> IntoLoopIter for core::slice::IterMut<'a, T> {
+                type LoopIter = SliceLoopIterMut<'a, T>;
+                fn into_loop_iter(self) -> Self::LoopIter {
+                    self.into_slice().into_loop_iter()
+                }
+            }
+
             /// This is used by contracts to select which version of the contract to use during codegen.
             #[doc(hidden)]
             pub type Mode = u8;
@@ -516,6 +830,7 @@ fn run_loop_contract_fn<F: Fn() -> bool>(func: &F, _transformed: usize) -> bool
             #[cfg(not(feature = "concrete_playback"))]
             #[inline(never)]
             #[kanitool::fn_marker = "CheckHook"]
+            #[track_caller]
             pub(crate) const fn check(cond: bool, msg: &'static str) {
                 let _ = cond;
                 let _ = msg;
@@ -524,6 +839,7 @@ pub(crate) const fn check(cond: bool, msg: &'static str) {
             #[cfg(feature = "concrete_playback")]
             #[inline(never)]
             #[kanitool::fn_marker = "CheckHook"]
+            #[track_caller]
             pub(crate) const fn check(cond: bool, msg: &'static str) {
                 assert!(cond, "{}", msg);
             }
@@ -138,6 +138,117 @@ pub fn can_read_unaligned<T: ?Sized>(ptr: *const T) -> bool {
             is_inbounds(ptr) && assert_is_initialized(ptr) && unsafe { has_valid_value(ptr) }
         }
 
+        /// Check if the pointer is aligned to the alignment of `T`.
+        ///
+        /// The alignment is the one returned by `align_of::<T>()` for sized types, which takes
+        /// `#[repr(align(N))]` into account. For trait objects and other unsized types, the
+        /// alignment is retrieved from the pointer metadata. This function returns `false` if the
+        /// alignment cannot be computed.
+        ///
+        /// Unlike [self::can_dereference], this function doesn't check whether the pointer is
+        /// dereferenceable, so it can be used with dangling pointers.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let data = [0u8; 8];
+        /// let ptr = data.as_ptr() as *const u32;
+        /// if kani::mem::is_aligned(ptr) {
+        ///     let _value = unsafe { ptr.read() };
+        /// }
+        /// ```
+        #[crate::kani::unstable_feature(
+            feature = "mem-predicates",
+            issue = 2690,
+            reason = "experimental memory predicate API"
+        )]
+        pub fn is_aligned<T: ?Sized>(ptr: *const T) -> bool {
+            is_ptr_aligned(ptr)
+        }
+
+        /// Assume that the pointer is aligned to the alignment of `T`.
+        ///
+        /// This prunes every execution where the pointer is misaligned, which is useful to
+        /// constrain a symbolic pointer before reading or writing through it. See
+        /// [self::is_aligned] for how the alignment is computed.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let data = [0u64; 4];
+        /// let offset: usize = kani::any_where(|offset| *offset < 16);
+        /// let ptr = unsafe { data.as_ptr().cast::<u8>().add(offset) } as *const u32;
+        /// kani::mem::assume_aligned(ptr);
+        /// let _value = unsafe { ptr.read() }; // Cannot be a misaligned read.
+        /// ```
+        #[crate::kani::unstable_feature(
+            feature = "mem-predicates",
+            issue = 2690,
+            reason = "experimental memory predicate API"
+        )]
+        pub fn assume_aligned<T: ?Sized>(ptr: *const T) {
+            super::assume(is_ptr_aligned(ptr))
+        }
+
+        /// Assert that `ptr` can be dereferenced as a `T`.
+        ///
+        /// This generates one property for each requirement: the pointer must not be null, it
+        /// must be aligned to the alignment of `T`, and the `size_of::<T>()` bytes that it points
+        /// to must be inside of a single live allocation. The last property is checked against
+        /// the allocation tracked for the pointer, so a pointer to a deallocated object or past
+        /// the end of its object fails it.
+        ///
+        /// Unlike [self::can_dereference], this function doesn't check that the memory holds a
+        /// valid value of type `T`.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let value = 10u32;
+        /// let ptr = &value as *const u32;
+        /// kani::mem::assert_valid_ptr(ptr);
+        /// assert_eq!(unsafe { *ptr }, 10);
+        /// ```
+        #[crate::kani::unstable_feature(
+            feature = "mem-predicates",
+            issue = 2690,
+            reason = "experimental memory predicate API"
+        )]
+        pub fn assert_valid_ptr<T: ?Sized>(ptr: *const T) {
+            super::assert(!ptr.is_null(), "pointer must not be null");
+            super::assert(is_ptr_aligned(ptr), "pointer must be aligned");
+            super::assert(
+                is_live_object(ptr),
+                "pointer must point to `size_of::<T>()` bytes of a live allocation",
+            );
+        }
+
+        /// Assume that `ptr` can be dereferenced as a `T`, i.e., that it satisfies the properties
+        /// checked by [self::assert_valid_ptr].
+        ///
+        /// This is useful to constrain a symbolic pointer before using it. Note that Kani can only
+        /// rule out pointers to deallocated objects in an assertion context, so the pointer
+        /// should be derived from an allocation that is live.
+        /// See <https://github.com/model-checking/kani/issues/2690> for more details.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let data = [0u32; 4];
+        /// let idx: usize = kani::any();
+        /// let ptr = data.as_ptr().wrapping_add(idx);
+        /// kani::mem::assume_valid_ptr(ptr);
+        /// assert!(idx < 4);
+        /// ```
+        #[crate::kani::unstable_feature(
+            feature = "mem-predicates",
+            issue = 2690,
+            reason = "experimental memory predicate API"
+        )]
+        pub fn assume_valid_ptr<T: ?Sized>(ptr: *const T) {
+            super::assume(!ptr.is_null() && is_ptr_aligned(ptr) && is_live_object(ptr))
+        }
+
         /// Check if two pointers points to the same allocated object, and that both pointers
         /// are in bounds of that object.
         ///
@@ -159,6 +270,46 @@ pub(super) fn same_allocation_internal<T: ?Sized>(ptr1: *const T, ptr2: *const T
             cbmc::same_allocation(addr1, addr2)
         }
 
+        /// Check if offsetting `ptr` by `count` elements of type `T` stays in bounds of the
+        /// allocation that `ptr` points to, i.e., whether `ptr.offset(count)` is defined.
+        ///
+        /// A pointer is still considered in-bounds if it points to 1-byte past the allocation.
+        /// Offsets by zero bytes are always in bounds, and this function returns `false` if the
+        /// offset in bytes overflows an `isize`.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let data = [0u8; 4];
+        /// let count: isize = kani::any();
+        /// kani::assume(kani::mem::offset_in_bounds(data.as_ptr(), count));
+        /// let _ptr = unsafe { data.as_ptr().offset(count) };
+        /// assert!(count >= 0 && count <= 4);
+        /// ```
+        #[crate::kani::unstable_feature(
+            feature = "mem-predicates",
+            issue = 2690,
+            reason = "experimental memory predicate API"
+        )]
+        #[allow(clippy::not_unsafe_ptr_arg_deref)]
+        pub fn offset_in_bounds<T>(ptr: *const T, count: isize) -> bool {
+            let Some(delta) = count.checked_mul(core::mem::size_of::<T>() as isize) else {
+                return false;
+            };
+            if delta == 0 {
+                true
+            } else if ptr.is_null() {
+                false
+            } else {
+                let data_ptr = ptr as *const ();
+                super::assert(
+                    unsafe { is_allocated(data_ptr, 0) },
+                    "Kani does not support reasoning about pointer to unallocated memory",
+                );
+                unsafe { is_allocated(data_ptr, 0) && is_offset_in_allocation(data_ptr, delta) }
+            }
+        }
+
         /// Compute the size of the val pointed to if it is safe to do so.
         ///
         /// Return `None` if an overflow would occur, or if alignment is not power of two.
@@ -218,6 +369,15 @@ fn is_inbounds<T: ?Sized>(ptr: *const T) -> bool {
             }
         }
 
+        /// Checks that the size of the value pointed to by `ptr` is known, and that it fits in the
+        /// allocation of `ptr`. Unlike [is_inbounds], this doesn't report pointers to unallocated
+        /// memory as unsupported, so it returns `false` for null and dangling pointers.
+        fn is_live_object<T: ?Sized>(ptr: *const T) -> bool {
+            let Some(sz) = checked_size_of_raw(ptr) else { return false };
+            // Any non-null pointer is valid for zero-sized accesses.
+            sz == 0 || (!ptr.is_null() && unsafe { is_allocated(ptr as *const (), sz) })
+        }
+
         // Return whether the pointer is aligned
         #[allow(clippy::manual_is_power_of_two)]
         fn is_ptr_aligned<T: ?Sized>(ptr: *const T) -> bool {
@@ -247,6 +407,18 @@ unsafe fn is_allocated(_ptr: *const (), _size: usize) -> bool {
             kani_intrinsic()
         }
 
+        /// Check if `_ptr` offset by `_delta` bytes is still within its allocation, or points to
+        /// 1-byte past it.
+        ///
+        /// # Safety
+        ///
+        /// - Users have to ensure that the pointed to memory is allocated.
+        #[kanitool::fn_marker = "OffsetInAllocationHook"]
+        #[inline(never)]
+        unsafe fn is_offset_in_allocation(_ptr: *const (), _delta: isize) -> bool {
+            kani_intrinsic()
+        }
+
         /// Check if the value stored in the given location satisfies type `T` validity requirements.
         ///
         /// # Safety
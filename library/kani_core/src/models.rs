@@ -274,5 +274,85 @@ pub(crate) fn size_of_slice_object(
                 }
             }
         }
+
+        /// Model some standard library functions by only encoding their postconditions.
+        /// These are only used when the user enables `--std-abstractions`.
+        #[allow(dead_code)]
+        mod std_abstractions {
+            use crate::kani;
+            use core::cmp::Ordering;
+
+            /// Model `<[T]>::binary_search` without executing the search loop.
+            ///
+            /// The result is picked non-deterministically among the values that satisfy the
+            /// postcondition of `binary_search`:
+            ///  - `Ok(idx)` where `slice[idx] == *key`.
+            ///  - `Err(idx)` where `idx` is the position in which `key` could be inserted while
+            ///    keeping the slice sorted.
+            ///
+            /// This model is only sound if the slice is sorted, which is also a requirement of
+            /// `binary_search`. The conditions above compare `key` with the neighbors of `idx`,
+            /// and with the first and last elements, which must be on the same side of `key` as
+            /// the neighbors in a sorted slice. An unsorted slice may still get a result, which
+            /// may differ from the one of the real implementation, or no result at all, e.g.,
+            /// when `key` is smaller than the first element but not smaller than the last one.
+            /// The paths that reach such a search are pruned, which may make the verification
+            /// vacuous.
+            #[kanitool::fn_marker = "BinarySearchModel"]
+            pub fn binary_search<T: Ord>(slice: &[T], key: &T) -> Result<usize, usize> {
+                let len = slice.len();
+                let less = |i: usize| slice[i].cmp(key) == Ordering::Less;
+                let greater = |i: usize| slice[i].cmp(key) == Ordering::Greater;
+                let idx: usize = kani::any();
+                if kani::any() {
+                    kani::assume(idx < len);
+                    kani::assume(slice[idx].cmp(key) == Ordering::Equal);
+                    kani::assume(!greater(0) && !less(len - 1));
+                    Ok(idx)
+                } else {
+                    kani::assume(idx <= len);
+                    kani::assume(idx == 0 || (less(0) && less(idx - 1)));
+                    kani::assume(idx == len || (greater(idx) && greater(len - 1)));
+                    Err(idx)
+                }
+            }
+
+            /// Model `<[T]>::sort_by` and `<[T]>::sort_unstable_by` without executing the sort.
+            ///
+            /// The slice is rearranged into a non-deterministic permutation of its elements, and
+            /// only the permutations that are sorted according to `compare` are kept.
+            ///
+            /// This model doesn't preserve the order of equal elements, so it over-approximates
+            /// the stable sort. It may also call `compare` a different number of times than the
+            /// real implementation. If `compare` doesn't implement a total order, there may be no
+            /// sorted permutation, and the paths that reach this call will be pruned.
+            #[kanitool::fn_marker = "SortByModel"]
+            pub fn sort_by<T, F: FnMut(&T, &T) -> Ordering>(slice: &mut [T], mut compare: F) {
+                let len = slice.len();
+                // Every permutation can be built by swapping each position with a position at or
+                // after it, so the result is always a permutation of the input.
+                for i in 0..len {
+                    let j: usize = kani::any();
+                    kani::assume(j >= i && j < len);
+                    slice.swap(i, j);
+                }
+                for i in 1..len {
+                    kani::assume(compare(&slice[i - 1], &slice[i]) != Ordering::Greater);
+                }
+            }
+
+            /// Model `<[T]>::sort` and `<[T]>::sort_unstable`. See [sort_by] for more details.
+            #[kanitool::fn_marker = "SortModel"]
+            pub fn sort<T: Ord>(slice: &mut [T]) {
+                sort_by(slice, T::cmp)
+            }
+
+            /// Model `<[T]>::sort_by_key` and `<[T]>::sort_unstable_by_key`. See [sort_by] for
+            /// more details.
+            #[kanitool::fn_marker = "SortByKeyModel"]
+            pub fn sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(slice: &mut [T], mut f: F) {
+                sort_by(slice, |a, b| f(a).cmp(&f(b)))
+            }
+        }
     };
 }
@@ -0,0 +1,114 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This module contains constructors of symbolic numbers that satisfy common constraints.
+
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! generate_num {
+    ($core:path) => {
+        use crate::kani::{Arbitrary, assume};
+
+        /// Numeric types that can be compared against zero.
+        ///
+        /// This trait is implemented for all primitive integer types, as well as `f32` and `f64`.
+        pub trait Zero: Arbitrary + PartialOrd {
+            const ZERO: Self;
+        }
+
+        /// Floating-point types that can be NaN.
+        ///
+        /// This trait is implemented for `f32` and `f64`.
+        pub trait Float: Arbitrary {
+            fn is_nan(&self) -> bool;
+        }
+
+        macro_rules! impl_zero {
+            ($ty:ty, $zero:expr) => {
+                impl Zero for $ty {
+                    const ZERO: Self = $zero;
+                }
+            };
+        }
+
+        impl_zero!(u8, 0);
+        impl_zero!(u16, 0);
+        impl_zero!(u32, 0);
+        impl_zero!(u64, 0);
+        impl_zero!(u128, 0);
+        impl_zero!(usize, 0);
+
+        impl_zero!(i8, 0);
+        impl_zero!(i16, 0);
+        impl_zero!(i32, 0);
+        impl_zero!(i64, 0);
+        impl_zero!(i128, 0);
+        impl_zero!(isize, 0);
+
+        impl_zero!(f32, 0.0);
+        impl_zero!(f64, 0.0);
+
+        impl Float for f32 {
+            fn is_nan(&self) -> bool {
+                f32::is_nan(*self)
+            }
+        }
+
+        impl Float for f64 {
+            fn is_nan(&self) -> bool {
+                f64::is_nan(*self)
+            }
+        }
+
+        /// Creates a symbolic value that is strictly greater than zero.
+        ///
+        /// For unsigned integers, this excludes `0`. For floats, this excludes zeros of either
+        /// sign and NaN, but it includes positive infinity.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let divisor: u32 = kani::num::any_positive();
+        /// let quotient = 100 / divisor; // Cannot divide by zero.
+        /// ```
+        pub fn any_positive<T: Zero>() -> T {
+            let value = T::any();
+            assume(value > T::ZERO);
+            value
+        }
+
+        /// Creates a symbolic value that is greater than or equal to zero.
+        ///
+        /// For unsigned integers, this is the same as `kani::any()`. For floats, this excludes NaN
+        /// and includes both `0.0` and `-0.0`, since they are equal, as well as positive infinity.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let len: i64 = kani::num::any_nonneg();
+        /// assert!(len.checked_neg().is_some());
+        /// ```
+        pub fn any_nonneg<T: Zero>() -> T {
+            let value = T::any();
+            assume(value >= T::ZERO);
+            value
+        }
+
+        /// Creates a symbolic floating-point value that is not NaN.
+        ///
+        /// Infinite values, zeros of either sign and subnormal values are still allowed. See
+        /// `kani::float::assume_finite` to also exclude infinite values.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let x: f64 = kani::num::any_nonan();
+        /// assert!(x == x);
+        /// ```
+        pub fn any_nonan<T: Float>() -> T {
+            let value = T::any();
+            assume(!value.is_nan());
+            value
+        }
+    };
+}
@@ -0,0 +1,105 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This module contains functions to control where panics are reported as failures.
+//!
+//! By default, any reachable panic in a harness is a verification failure. The functions in this
+//! module allow users to permit panics in a lexically bounded scope, which is useful when the
+//! harness is only interested in the non-panicking behavior of a sub-computation.
+//!
+//! Note that Kani does not model unwinding. Thus, an execution that panics inside a scope where
+//! panics are permitted is not reported as a failure, but it also does not continue past the
+//! panic. Properties after the panic are only checked for executions that do not panic.
+//!
+//! These functions affect panics (e.g.: `panic!()`, `assert!()`, `unwrap()`), including the
+//! ones raised by Rust's runtime checks, such as out-of-bounds indexing. Checks for undefined
+//! behavior, such as invalid pointer dereferences, are still reported as usual.
+
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! kani_panic {
+    ($core:tt) => {
+        use super::kani_intrinsic;
+
+        /// Executes `f` in a scope where panics are permitted, and returns its result.
+        ///
+        /// Panics inside `f` are not reported as verification failures. Instead, executions
+        /// that panic are discarded. Scopes can be nested, and the innermost scope takes
+        /// precedence. I.e., a call to [assert_no_panic] inside `f` will still report panics.
+        ///
+        /// Use this function sparingly, and only for the computations you expect to panic.
+        /// Permitting panics in a large scope may hide bugs, and it can lead to vacuous
+        /// proofs, since properties after a panic are not checked.
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let index: usize = kani::any();
+        /// let values = [1, 2, 3];
+        /// // The executions where `index` is out of bounds panic, and they are discarded.
+        /// let value = kani::panic::catch(|| values[index]);
+        /// // Only the executions that didn't panic reach this point, so this assertion holds.
+        /// assert!(index < values.len() && values.contains(&value));
+        /// ```
+        #[crate::kani::unstable_feature(
+            feature = "panic-scopes",
+            issue = "none",
+            reason = "experimental panic scoping API"
+        )]
+        #[inline(always)]
+        pub fn catch<R, F: FnOnce() -> R>(f: F) -> R {
+            with_panic_permitted(true, f)
+        }
+
+        /// Executes `f` in a scope where panics are not permitted, and returns its result.
+        ///
+        /// Panics inside `f` are reported as verification failures, even if this function is
+        /// called inside a scope where panics are permitted (see [catch]).
+        ///
+        /// # Example:
+        ///
+        /// ```no_run
+        /// let divisor: u8 = kani::any();
+        /// kani::panic::catch(|| {
+        ///     // The executions where `divisor` is zero panic, and they are discarded.
+        ///     let ratio = 100 / divisor;
+        ///     // This assertion fails if `divisor` is one, and the failure is reported even
+        ///     // though we are inside `catch`.
+        ///     kani::panic::assert_no_panic(|| assert!(ratio < 100));
+        /// });
+        /// ```
+        #[crate::kani::unstable_feature(
+            feature = "panic-scopes",
+            issue = "none",
+            reason = "experimental panic scoping API"
+        )]
+        #[inline(always)]
+        pub fn assert_no_panic<R, F: FnOnce() -> R>(f: F) -> R {
+            with_panic_permitted(false, f)
+        }
+
+        /// Executes `f` with the given panic permission, restoring the previous one at the end.
+        #[inline(always)]
+        fn with_panic_permitted<R, F: FnOnce() -> R>(permitted: bool, f: F) -> R {
+            let previous = set_panic_permitted(permitted);
+            let result = f();
+            set_panic_permitted(previous);
+            result
+        }
+
+        /// Sets whether panics are permitted in the current scope, and returns the previous value.
+        #[kanitool::fn_marker = "SetPanicPermittedHook"]
+        #[inline(never)]
+        fn set_panic_permitted(permitted: bool) -> bool {
+            #[cfg(not(feature = "concrete_playback"))]
+            return kani_intrinsic();
+
+            // Concrete playback executes panics as usual.
+            #[cfg(feature = "concrete_playback")]
+            {
+                let _ = permitted;
+                false
+            }
+        }
+    };
+}
@@ -0,0 +1,152 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! This module implements `kani::assert_split!`, which checks each operand of a boolean
+//! expression built with `&&` and `||` as a separate property, so a failure points at the
+//! operand that doesn't hold.
+
+use proc_macro_error2::abort;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{BinOp, Expr, LitInt, LitStr, Token};
+
+/// The number of nested `&&` / `||` chains that are split when no `depth` is given.
+const DEFAULT_DEPTH: u32 = 1;
+
+struct AssertSplitArgs {
+    cond: Expr,
+    msg: Option<LitStr>,
+    depth: u32,
+}
+
+impl Parse for AssertSplitArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let cond = input.parse::<Expr>()?;
+        let mut args = AssertSplitArgs { cond, msg: None, depth: DEFAULT_DEPTH };
+        let _ = input.parse::<Option<Token![,]>>()?;
+        if input.peek(LitStr) {
+            args.msg = Some(input.parse()?);
+            let _ = input.parse::<Option<Token![,]>>()?;
+        }
+        if !input.is_empty() {
+            let ident = input.parse::<syn::Ident>()?;
+            if ident != "depth" {
+                abort!(ident, "`{}` is not a valid option for `kani::assert_split!`", ident;
+                    help = "the only option is `depth`, e.g. `depth = 2`");
+            }
+            let _ = input.parse::<Token![=]>()?;
+            args.depth = input.parse::<LitInt>()?.base10_parse()?;
+            let _ = input.parse::<Option<Token![,]>>()?;
+        }
+        Ok(args)
+    }
+}
+
+/// Expand `kani::assert_split!(cond, "message", depth = N)`.
+///
+/// Each operand of a `&&` chain is checked by its own `kani::assert`, and the remaining operands
+/// are only evaluated if it holds. For a `||` chain, only the last operand is checked, and only
+/// if none of the preceding operands holds. This way the expansion evaluates exactly the same
+/// operands as the original expression, and each failure is only reported when the whole
+/// expression is false.
+pub fn expand(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let args = syn::parse_macro_input!(item as AssertSplitArgs);
+    let check = Splitter { args: &args }.check(&args.cond, args.depth, false);
+    quote!({
+        let _: bool = #check;
+    })
+    .into()
+}
+
+struct Splitter<'a> {
+    args: &'a AssertSplitArgs,
+}
+
+impl Splitter<'_> {
+    /// Generate an expression that evaluates to the value of `expr` and asserts that it holds,
+    /// splitting up to `depth` nested `&&` / `||` chains.
+    ///
+    /// `after_alternatives` is set if `expr` is only evaluated because the preceding operands of
+    /// an enclosing `||` chain are false.
+    fn check(&self, expr: &Expr, depth: u32, after_alternatives: bool) -> TokenStream {
+        let inner = strip_parens(expr);
+        match (depth, logical_op(inner)) {
+            (1.., Some(op @ BinOp::And(_))) => {
+                let checks = flatten(inner, &op)
+                    .into_iter()
+                    .map(|operand| self.check(operand, depth - 1, after_alternatives));
+                quote!(#((#checks))&&*)
+            }
+            (1.., Some(op @ BinOp::Or(_))) => {
+                let operands = flatten(inner, &op);
+                let (last, rest) = operands.split_last().unwrap();
+                let last = self.check(last, depth - 1, true);
+                quote!(#((#rest) ||)* (#last))
+            }
+            _ => self.assert_operand(expr, after_alternatives),
+        }
+    }
+
+    fn assert_operand(&self, operand: &Expr, after_alternatives: bool) -> TokenStream {
+        let kani = kani_path!();
+        let whole = std::ptr::eq(operand, &self.args.cond);
+        let msg = match &self.args.msg {
+            Some(msg) if whole => quote!(#msg),
+            Some(msg) => self.split_msg(quote!(#msg), operand, after_alternatives),
+            None => self.split_msg(quote!("assertion failed"), operand, after_alternatives),
+        };
+        quote!({
+            let __kani_cond: bool = #operand;
+            #kani::assert(__kani_cond, #msg);
+            __kani_cond
+        })
+    }
+
+    fn split_msg(
+        &self,
+        prefix: TokenStream,
+        operand: &Expr,
+        after_alternatives: bool,
+    ) -> TokenStream {
+        let suffix = if after_alternatives { " (no preceding alternative holds)" } else { "" };
+        quote!(concat!(#prefix, ": ", stringify!(#operand), #suffix))
+    }
+}
+
+/// Remove the parentheses around an expression, including the invisible groups created when an
+/// expression is passed through a `macro_rules!` macro.
+fn strip_parens(mut expr: &Expr) -> &Expr {
+    loop {
+        expr = match expr {
+            Expr::Paren(paren) => &paren.expr,
+            Expr::Group(group) => &group.expr,
+            _ => return expr,
+        }
+    }
+}
+
+/// Return the operator of a `&&` or `||` expression.
+fn logical_op(expr: &Expr) -> Option<BinOp> {
+    match expr {
+        Expr::Binary(binary) if matches!(binary.op, BinOp::And(_) | BinOp::Or(_)) => {
+            Some(binary.op)
+        }
+        _ => None,
+    }
+}
+
+/// Collect the operands of a chain of the same logical operator, from left to right. For
+/// example, `a && (b && c)` has the operands `a`, `b` and `c`.
+fn flatten<'a>(expr: &'a Expr, op: &BinOp) -> Vec<&'a Expr> {
+    let inner = strip_parens(expr);
+    match (inner, logical_op(inner)) {
+        (Expr::Binary(binary), Some(inner_op))
+            if std::mem::discriminant(&inner_op) == std::mem::discriminant(op) =>
+        {
+            let mut operands = flatten(&binary.left, op);
+            operands.extend(flatten(&binary.right, op));
+            operands
+        }
+        _ => vec![expr],
+    }
+}
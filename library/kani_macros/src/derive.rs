@@ -1,6 +1,6 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
-//! This module provides Kani's `derive` macro for `Arbitrary`.
+//! This module provides Kani's `derive` macros for `Arbitrary`, `Invariant` and `Decode`.
 //!
 //! ```
 //! use kani::Arbitrary;
@@ -14,30 +14,10 @@
 use quote::{quote, quote_spanned};
 use syn::spanned::Spanned;
 use syn::{
-    Data, DataEnum, DeriveInput, Fields, GenericParam, Generics, Index, parse_macro_input,
-    parse_quote,
+    Data, DataEnum, DeriveInput, Expr, ExprLit, ExprRange, ExprUnary, Fields, GenericParam,
+    Generics, Index, Lit, LitStr, RangeLimits, UnOp, parse_macro_input, parse_quote,
 };
 
-#[cfg(feature = "no_core")]
-macro_rules! kani_path {
-    ($span:expr) => {
-        quote_spanned! { $span => core::kani }
-    };
-    () => {
-        quote! { core::kani }
-    };
-}
-
-#[cfg(not(feature = "no_core"))]
-macro_rules! kani_path {
-    ($span:expr) => {
-        quote_spanned! { $span => kani }
-    };
-    () => {
-        quote! { kani }
-    };
-}
-
 /// Generate the Arbitrary implementation for the given type.
 ///
 /// Note that we cannot use `proc_macro_crate::crate_name()` to discover the name for `kani` crate
@@ -51,14 +31,21 @@ pub fn expand_derive_arbitrary(item: proc_macro::TokenStream) -> proc_macro::Tok
     let kani_path = kani_path!();
 
     let body = fn_any_body(&item_name, &derive_item.data);
+    let has_builder = has_builder_attr(&derive_item);
     // Get the safety constraints (if any) to produce type-safe values
     let safety_conds_opt = safety_conds_opt(&item_name, &derive_item, trait_name);
 
     // Add a bound `T: Arbitrary` to every type parameter T.
-    let generics = add_trait_bound_arbitrary(derive_item.generics);
+    let generics = add_trait_bound_arbitrary(derive_item.generics.clone());
     // Generate an expression to sum up the heap size of each field.
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
+    let builder = if has_builder {
+        builder_impl(&derive_item, &generics, safety_conds_opt.as_ref())
+    } else {
+        TokenStream::new()
+    };
+
     let expanded = if let Some(safety_conds) = safety_conds_opt {
         let field_refs = field_refs(&item_name, &derive_item.data);
         quote! {
@@ -71,6 +58,7 @@ fn any() -> Self {
                     obj
                 }
             }
+            #builder
         }
     } else {
         quote! {
@@ -80,11 +68,140 @@ fn any() -> Self {
                     #body
                 }
             }
+            #builder
         }
     };
     proc_macro::TokenStream::from(expanded)
 }
 
+/// Check whether the item has the `#[arbitrary(builder)]` attribute.
+fn has_builder_attr(derive_input: &DeriveInput) -> bool {
+    let Some(attr) = derive_input.attrs.iter().find(|attr| attr.path().is_ident("arbitrary"))
+    else {
+        return false;
+    };
+    let result = attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("builder") {
+            Ok(())
+        } else {
+            Err(meta.error("unsupported `arbitrary` argument, expected `builder`"))
+        }
+    });
+    if let Err(err) = result {
+        abort!(err.span(), "invalid `#[arbitrary(...)]` attribute: {}", err);
+    }
+    true
+}
+
+/// Generate the builder of a struct with the `#[arbitrary(builder)]` attribute, and the
+/// implementation of `ArbitraryBuilder` that returns it. The builder has one optional value per
+/// field, which can be set with a method that has the name and the visibility of the field.
+/// E.g.:
+/// ```
+/// #[derive(Arbitrary)]
+/// #[arbitrary(builder)]
+/// pub struct Point { pub x: u8, y: u8 }
+/// ```
+/// will generate the following code:
+/// ```
+/// #[doc(hidden)]
+/// pub struct __KaniArbitraryBuilderPoint { x: Option<u8>, y: Option<u8> }
+///
+/// impl __KaniArbitraryBuilderPoint {
+///     pub fn x(mut self, value: u8) -> Self { self.x = Some(value); self }
+///     fn y(mut self, value: u8) -> Self { self.y = Some(value); self }
+///     pub fn rest_any(self) -> Point {
+///         Point {
+///             x: match self.x { Some(value) => value, None => kani::any() },
+///             y: match self.y { Some(value) => value, None => kani::any() },
+///         }
+///     }
+/// }
+///
+/// impl kani::ArbitraryBuilder for Point {
+///     type Builder = __KaniArbitraryBuilderPoint;
+///     fn builder() -> Self::Builder {
+///         __KaniArbitraryBuilderPoint { x: None, y: None }
+///     }
+/// }
+/// ```
+/// The safety constraints of the struct, if any, are assumed by `rest_any()`.
+fn builder_impl(
+    derive_input: &DeriveInput,
+    generics: &Generics,
+    safety_conds: Option<&TokenStream>,
+) -> TokenStream {
+    let item_name = &derive_input.ident;
+    let fields = match &derive_input.data {
+        Data::Struct(syn::DataStruct { fields: Fields::Named(fields), .. }) => &fields.named,
+        _ => abort!(Span::call_site(), "Cannot derive a builder for `{}`", item_name;
+            note = item_name.span() =>
+            "`#[arbitrary(builder)]` can only be used in structs with named fields"
+        ),
+    };
+    let kani_path = kani_path!();
+    let vis = &derive_input.vis;
+    let builder_name = Ident::new(&format!("__KaniArbitraryBuilder{item_name}"), item_name.span());
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let names: Vec<_> = fields.iter().map(|field| &field.ident).collect();
+    let types = fields.iter().map(|field| &field.ty);
+    let setters = fields.iter().map(|field| {
+        let field_vis = &field.vis;
+        let name = &field.ident;
+        let ty = &field.ty;
+        quote_spanned! {field.span()=>
+            #field_vis fn #name(mut self, value: #ty) -> Self {
+                self.#name = core::option::Option::Some(value);
+                self
+            }
+        }
+    });
+    let init = fields.iter().map(|field| {
+        let name = &field.ident;
+        let value = init_symbolic_field(field);
+        quote_spanned! {field.span()=>
+            #name: match self.#name {
+                core::option::Option::Some(value) => value,
+                core::option::Option::None => #value,
+            }
+        }
+    });
+    let rest_any_body = if let Some(safety_conds) = safety_conds {
+        let field_refs = field_refs(item_name, &derive_input.data);
+        quote! {
+            let obj = #item_name { #(#init,)* };
+            #field_refs
+            #kani_path::assume(#safety_conds);
+            obj
+        }
+    } else {
+        quote! { #item_name { #(#init,)* } }
+    };
+    quote! {
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        #vis struct #builder_name #impl_generics #where_clause {
+            #(#names: core::option::Option<#types>,)*
+        }
+
+        impl #impl_generics #builder_name #ty_generics #where_clause {
+            #(#setters)*
+
+            /// Create the value, where the fields that were not set are symbolic.
+            pub fn rest_any(self) -> #item_name #ty_generics {
+                #rest_any_body
+            }
+        }
+
+        impl #impl_generics #kani_path::ArbitraryBuilder for #item_name #ty_generics #where_clause {
+            type Builder = #builder_name #ty_generics;
+            fn builder() -> Self::Builder {
+                #builder_name { #(#names: core::option::Option::None,)* }
+            }
+        }
+    }
+}
+
 /// Add a bound `T: Arbitrary` to every type parameter T.
 fn add_trait_bound_arbitrary(mut generics: Generics) -> Generics {
     let kani_path = kani_path!();
@@ -238,8 +355,9 @@ fn init_symbolic_item(ident: &Ident, fields: &Fields) -> TokenStream {
             // is. An example is shown in the readme of the parent directory.
             let init = fields.named.iter().map(|field| {
                 let name = &field.ident;
+                let value = init_symbolic_field(field);
                 quote_spanned! {field.span()=>
-                    #name: kani::any()
+                    #name: #value
                 }
             });
             quote! {
@@ -249,13 +367,7 @@ fn init_symbolic_item(ident: &Ident, fields: &Fields) -> TokenStream {
         Fields::Unnamed(ref fields) => {
             // Expands to an expression like
             // Self(kani::any(), kani::any(), ..., kani::any());
-            let init = fields.unnamed.iter().map(|field| {
-                let span = field.span();
-                let kani_path = kani_path!(span);
-                quote_spanned! {span=>
-                    #kani_path::any()
-                }
-            });
+            let init = fields.unnamed.iter().map(init_symbolic_field);
             quote! {
                 #ident(#( #init,)*)
             }
@@ -268,6 +380,130 @@ fn init_symbolic_item(ident: &Ident, fields: &Fields) -> TokenStream {
     }
 }
 
+/// Generate the expression that creates a symbolic value for the given field.
+///
+/// This is `kani::any()`, unless the field has an `#[arbitrary(range = "<range>")]` attribute,
+/// in which case the value is constrained to the range:
+/// ```
+/// {
+///     let value: u8 = kani::any();
+///     kani::assume((0..=100).contains(&value));
+///     value
+/// }
+/// ```
+fn init_symbolic_field(field: &syn::Field) -> TokenStream {
+    let span = field.span();
+    let kani_path = kani_path!(span);
+    if let Some(range) = parse_range_attr(field) {
+        let ty = &field.ty;
+        quote_spanned! {span=>
+            {
+                let value: #ty = #kani_path::any();
+                #kani_path::assume((#range).contains(&value));
+                value
+            }
+        }
+    } else {
+        quote_spanned! {span=>
+            #kani_path::any()
+        }
+    }
+}
+
+/// Extract and parse the range in the `#[arbitrary(range = "<range>")]` attribute helper
+/// associated with a given field. Return `None` if the attribute isn't specified.
+///
+/// The range can be any of `a..b`, `a..=b`, `a..`, `..b` and `..=b`. If the field has a primitive
+/// integer type and the bounds are integer literals, we also check that the bounds fit in the
+/// type and that the range isn't empty. Otherwise, the range has to type check against the
+/// field type.
+fn parse_range_attr(field: &syn::Field) -> Option<ExprRange> {
+    let attr = field.attrs.iter().find(|attr| attr.path().is_ident("arbitrary"))?;
+    let mut range = None;
+    let result = attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("range") {
+            let value: LitStr = meta.value()?.parse()?;
+            let expr: ExprRange = value
+                .parse()
+                .map_err(|_| syn::Error::new(value.span(), "expected a range such as `0..10`"))?;
+            if expr.start.is_none() && expr.end.is_none() {
+                return Err(syn::Error::new(
+                    value.span(),
+                    "the range must have at least one bound",
+                ));
+            }
+            range = Some((expr, value.value()));
+            Ok(())
+        } else {
+            Err(meta.error("unsupported `arbitrary` argument, expected `range = \"<range>\"`"))
+        }
+    });
+    if let Err(err) = result {
+        abort!(err.span(), "invalid `#[arbitrary(...)]` attribute: {}", err);
+    }
+    let Some((range, text)) = range else {
+        abort!(attr.span(), "expected `#[arbitrary(range = \"<range>\")]`");
+    };
+    check_int_range(&field.ty, &range, &text);
+    Some(range)
+}
+
+/// Check that the bounds of `range` fit in the primitive integer type `ty`, and that the range
+/// isn't empty. This is a no-op if `ty` isn't a primitive integer type, or if the bounds aren't
+/// integer literals. `text` is the range as written by the user.
+fn check_int_range(ty: &syn::Type, range: &ExprRange, text: &str) {
+    let syn::Type::Path(type_path) = ty else { return };
+    let Some(ty_name) = type_path.path.get_ident().map(|ident| ident.to_string()) else { return };
+    // Kani only supports 64-bit targets, so `usize` and `isize` have 64 bits.
+    let (min, max) = match ty_name.as_str() {
+        "u8" => (0, u8::MAX as i128),
+        "u16" => (0, u16::MAX as i128),
+        "u32" => (0, u32::MAX as i128),
+        "u64" | "usize" => (0, u64::MAX as i128),
+        "u128" => (0, i128::MAX),
+        "i8" => (i8::MIN as i128, i8::MAX as i128),
+        "i16" => (i16::MIN as i128, i16::MAX as i128),
+        "i32" => (i32::MIN as i128, i32::MAX as i128),
+        "i64" | "isize" => (i64::MIN as i128, i64::MAX as i128),
+        "i128" => (i128::MIN, i128::MAX),
+        _ => return,
+    };
+    let bound = |expr: &Option<Box<Expr>>| -> Option<i128> {
+        let expr = expr.as_deref()?;
+        let value = int_literal(expr)?;
+        if value < min || value > max {
+            abort!(expr.span(), "range bound `{}` is out of range for `{}`", value, ty_name;
+                note = ty.span() => "the field has type `{}`, whose range is `{}..={}`", ty_name, min, max
+            );
+        }
+        Some(value)
+    };
+    let start = bound(&range.start);
+    let end = bound(&range.end);
+    if let (Some(start), Some(end)) = (start, end) {
+        let empty = match range.limits {
+            RangeLimits::HalfOpen(_) => start >= end,
+            RangeLimits::Closed(_) => start > end,
+        };
+        if empty {
+            abort!(range.span(), "the range `{}` is empty, so no value can be generated", text);
+        }
+    }
+}
+
+/// Return the value of an integer literal, possibly negated, e.g. `10`, `-5` or `0xff_u8`.
+/// Return `None` if the expression is anything else, or if the value doesn't fit in an `i128`.
+fn int_literal(expr: &Expr) -> Option<i128> {
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Int(lit), .. }) => lit.base10_parse().ok(),
+        Expr::Unary(ExprUnary { op: UnOp::Neg(_), expr, .. }) => {
+            int_literal(expr).and_then(i128::checked_neg)
+        }
+        Expr::Paren(paren) => int_literal(&paren.expr),
+        _ => None,
+    }
+}
+
 /// Extract, parse and return the expression `cond` (i.e., `Some(cond)`) in the
 /// `#[safety_constraint(<cond>)]` attribute helper associated with a given field.
 /// Return `None` if the attribute isn't specified.
@@ -589,3 +825,126 @@ fn safe_body_from_fields_attr_inner(ident: &Ident, fields: &Fields) -> TokenStre
         }
     }
 }
+
+/// Generate the `Decode` implementation for the given type, which decodes its fields in order.
+///
+/// E.g.:
+/// ```
+/// #[derive(kani::arbitrary::Decode)]
+/// enum Command { Push(u8), Pop }
+/// ```
+/// will generate the following implementation:
+/// ```
+/// impl kani::arbitrary::Decode for Command {
+///     fn decode(input: &mut kani::arbitrary::Unstructured)
+///         -> Result<Self, kani::arbitrary::NotEnoughData> {
+///         Ok(match <u32 as kani::arbitrary::Decode>::decode(input)? % 2 {
+///             0 => Command::Push(kani::arbitrary::Decode::decode(input)?),
+///             _ => Command::Pop,
+///         })
+///     }
+/// }
+/// ```
+pub fn expand_derive_decode(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let derive_item = parse_macro_input!(item as DeriveInput);
+    let item_name = &derive_item.ident;
+    let kani_path = kani_path!();
+    let body = fn_decode_body(item_name, &derive_item.data);
+
+    // Add a bound `T: Decode` to every type parameter T.
+    let mut generics = derive_item.generics.clone();
+    generics.params.iter_mut().for_each(|param| {
+        if let GenericParam::Type(type_param) = param {
+            type_param.bounds.push(parse_quote!(#kani_path::arbitrary::Decode));
+        }
+    });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let expanded = quote! {
+        // The generated implementation.
+        impl #impl_generics #kani_path::arbitrary::Decode for #item_name #ty_generics #where_clause {
+            fn decode(
+                input: &mut #kani_path::arbitrary::Unstructured,
+            ) -> core::result::Result<Self, #kani_path::arbitrary::NotEnoughData> {
+                core::result::Result::Ok(#body)
+            }
+        }
+    };
+    proc_macro::TokenStream::from(expanded)
+}
+
+/// Generate the expression that decodes a value of the given type from `input`.
+fn fn_decode_body(ident: &Ident, data: &Data) -> TokenStream {
+    match data {
+        Data::Struct(struct_data) => decode_item(ident, &struct_data.fields),
+        Data::Enum(enum_data) => fn_decode_enum(ident, enum_data),
+        Data::Union(_) => {
+            abort!(Span::call_site(), "Cannot derive `Decode` for `{}` union", ident;
+                note = ident.span() =>
+                "`#[derive(Decode)]` cannot be used for unions such as `{}`", ident
+            )
+        }
+    }
+}
+
+/// Generate the expression that decodes the fields of `ident` in order.
+fn decode_item(ident: &Ident, fields: &Fields) -> TokenStream {
+    let decode_field = |field: &syn::Field| {
+        let kani_path = kani_path!(field.span());
+        quote_spanned! {field.span()=>
+            #kani_path::arbitrary::Decode::decode(input)?
+        }
+    };
+    match fields {
+        Fields::Named(fields) => {
+            let init = fields.named.iter().map(|field| {
+                let name = &field.ident;
+                let value = decode_field(field);
+                quote! { #name: #value }
+            });
+            quote! { #ident { #(#init,)* } }
+        }
+        Fields::Unnamed(fields) => {
+            let init = fields.unnamed.iter().map(decode_field);
+            quote! { #ident(#(#init,)*) }
+        }
+        Fields::Unit => quote! { #ident },
+    }
+}
+
+/// Generate the expression that decodes an enum. The variant is selected by a `u32`, reduced
+/// modulo the number of variants, which is followed by the fields of the variant.
+fn fn_decode_enum(ident: &Ident, data: &DataEnum) -> TokenStream {
+    if data.variants.is_empty() {
+        abort!(Span::call_site(), "Cannot derive `Decode` for `{}` enum", ident;
+            note = ident.span() =>
+            "`#[derive(Decode)]` cannot be used for enums with zero variants such as `{}`", ident
+        )
+    } else if data.variants.len() == 1 {
+        let variant = data.variants.first().unwrap();
+        let init = decode_item(&variant.ident, &variant.fields);
+        quote! {
+            #ident::#init
+        }
+    } else {
+        let arms = data.variants.iter().enumerate().map(|(idx, variant)| {
+            let init = decode_item(&variant.ident, &variant.fields);
+            if idx + 1 < data.variants.len() {
+                let index = Index::from(idx);
+                quote! {
+                    #index => #ident::#init,
+                }
+            } else {
+                quote! {
+                    _ => #ident::#init,
+                }
+            }
+        });
+        let kani_path = kani_path!();
+        let count = data.variants.len() as u32;
+        quote! {
+            match <u32 as #kani_path::arbitrary::Decode>::decode(input)? % #count {
+                #(#arms)*
+            }
+        }
+    }
+}
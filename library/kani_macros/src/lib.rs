@@ -9,6 +9,30 @@
 //   RUSTFLAGS="-Zcrate-attr=feature(register_tool) -Zcrate-attr=register_tool(kanitool)"
 #![feature(proc_macro_diagnostic)]
 #![feature(proc_macro_span)]
+
+/// The path to the `kani` module in the generated code. When building `core`, Kani's definitions
+/// live inside of it.
+#[cfg(feature = "no_core")]
+macro_rules! kani_path {
+    ($span:expr) => {
+        quote::quote_spanned! { $span => core::kani }
+    };
+    () => {
+        quote::quote! { core::kani }
+    };
+}
+
+#[cfg(not(feature = "no_core"))]
+macro_rules! kani_path {
+    ($span:expr) => {
+        quote::quote_spanned! { $span => kani }
+    };
+    () => {
+        quote::quote! { kani }
+    };
+}
+
+mod assert_split;
 mod derive;
 
 // proc_macro::quote is nightly-only, so we'll cobble things together instead
@@ -29,12 +53,50 @@
 /// e.g. `#[kani::proof(schedule = kani::RoundRobin::default())]`.
 ///
 /// This will wrap the async function in a call to [`block_on_with_spawn`](https://model-checking.github.io/kani/crates/doc/kani/futures/fn.block_on_with_spawn.html) (see its documentation for more information).
+///
+/// Fuzz targets, i.e., functions with a single `&[u8]` argument, can be used as harnesses by
+/// bounding the input length, e.g. `#[kani::proof(max_input = 32)]`. Kani will verify the function
+/// for every input slice of up to `max_input` bytes.
+///
+/// Harnesses that are expected to fail verification, e.g. negative examples in a regression suite,
+/// can be marked with `#[kani::proof(expected = "fail")]`. Kani then reports the harness as
+/// successful if its verification fails, and as an unexpected pass otherwise. The default is
+/// `expected = "pass"`.
+///
+/// The `unwind`, `solver` and `should_panic` options are equivalent to the corresponding
+/// attributes, so several settings can be given at once, e.g.
+/// `#[kani::proof(unwind = 5, solver = cadical, should_panic)]`.
 #[proc_macro_error]
 #[proc_macro_attribute]
 pub fn proof(attr: TokenStream, item: TokenStream) -> TokenStream {
     attr_impl::proof(attr, item)
 }
 
+/// Verify a generic proof harness for the given instantiations of its generic parameters.
+///
+/// The attribute `#[kani::instantiate(...)]` can only be used alongside `#[kani::proof]`.
+/// It takes a comma-separated list of substitutions, and Kani generates one harness per
+/// substitution. A substitution is either a single assignment, e.g. `T = u32`, or a parenthesized
+/// list of assignments when the harness has more than one generic parameter, e.g.
+/// `(T = u8, N = 4)`. Const generic parameters take constant values.
+///
+/// Each harness is named after the generic harness and its arguments, e.g. `check__u32` or
+/// `check__u8_4`, so they can be selected individually with `--harness`.
+///
+/// ```ignore
+/// #[kani::proof]
+/// #[kani::instantiate(T = u32, T = i64)]
+/// fn check_roundtrip<T: kani::Arbitrary + Copy + PartialEq>() {
+///     let value: T = kani::any();
+///     assert!(value == value);
+/// }
+/// ```
+#[proc_macro_error]
+#[proc_macro_attribute]
+pub fn instantiate(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attr_impl::instantiate(attr, item)
+}
+
 /// Specifies that a proof harness is expected to panic.**
 ///
 /// This attribute allows users to exercise *negative verification*.
@@ -83,6 +145,20 @@ pub fn stub(attr: TokenStream, item: TokenStream) -> TokenStream {
     attr_impl::stub(attr, item)
 }
 
+/// Register a function/method stub pair that is used by every proof harness in the crate
+///
+/// `kani::register_default_stub!(original, replacement);` can only be used at the crate root, and
+/// its paths are resolved from there. A harness with its own `#[kani::stub]` for `original` uses
+/// that stub instead.
+///
+/// # Arguments
+/// * `original` - The function or method to replace, specified as a path.
+/// * `replacement` - The function or method to use as a replacement, specified as a path.
+#[proc_macro]
+pub fn register_default_stub(item: TokenStream) -> TokenStream {
+    attr_impl::register_default_stub(item)
+}
+
 /// Select the SAT solver to use with CBMC for this harness
 ///
 /// The attribute `#[kani::solver(arg)]` can only be used alongside `#[kani::proof]`.
@@ -101,6 +177,35 @@ pub fn unstable_feature(attr: TokenStream, item: TokenStream) -> TokenStream {
     attr_impl::unstable(attr, item)
 }
 
+/// Assert a boolean condition, checking each operand of its `&&` / `||` operators as a separate
+/// property, so a failure points at the operand that doesn't hold.
+///
+/// `kani::assert_split!(a < b && c)` reports a failure of `a < b` and of `c` as two different
+/// properties, `"assertion failed: a < b"` and `"assertion failed: c"`. The operands are evaluated
+/// with the same short-circuit semantics as the original expression, so `c` is only evaluated
+/// (and checked) when `a < b` holds.
+///
+/// A `||` chain only fails if none of its operands holds. Hence, only its last operand is checked,
+/// with a description that ends in `(no preceding alternative holds)`.
+///
+/// By default, only the top-level chain of `&&` or `||` is split. The `depth` option sets how many
+/// nested chains are split, e.g. `depth = 2` also splits `x || y` in `a && (x || y)`, while
+/// `depth = 0` checks the condition as a single property. A message literal can also be given,
+/// which replaces the `assertion failed` prefix of each description:
+///
+/// ```ignore
+/// kani::assert_split!(idx < len && buf[idx] != 0, "invalid terminator");
+/// kani::assert_split!(is_empty || (first <= last && len > 0), depth = 2);
+/// ```
+///
+/// This macro is not named `assert!` so that `use kani::*;` doesn't shadow the standard
+/// `assert!` macro.
+#[proc_macro_error]
+#[proc_macro]
+pub fn assert_split(item: TokenStream) -> TokenStream {
+    assert_split::expand(item)
+}
+
 /// Allow users to auto generate `Arbitrary` implementations by using
 /// `#[derive(Arbitrary)]` macro.
 ///
@@ -201,12 +306,89 @@ pub fn unstable_feature(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///     y: i32,
 /// }
 /// ```
+///
+/// ## Ranges of numeric fields with the `#[arbitrary(range = "...")]` attribute
+///
+/// For numeric fields, the `#[arbitrary(range = "<range>")]` attribute is a shorter way to
+/// constrain the values generated with `kani::any()` to a range. The range can be written
+/// as `a..b`, `a..=b`, `a..`, `..b` or `..=b`:
+///
+/// ```rust
+/// #[derive(kani::Arbitrary)]
+/// struct Percent(#[arbitrary(range = "0..=100")] u8);
+///
+/// #[kani::proof]
+/// fn check_percent() {
+///     let val: Percent = kani::any();
+///     assert!(val.0 <= 100);
+/// }
+/// ```
+///
+/// For fields with a primitive integer type, it is a compilation error if a bound doesn't fit
+/// in the type, e.g. `"0..=300"` for a `u8`, or if the range is empty. Unlike
+/// `#[safety_constraint(...)]`, this attribute can also be used in enum variants, and it is not
+/// taken into account by `#[derive(Invariant)]`.
+///
+/// ## Builders with the `#[arbitrary(builder)]` attribute
+///
+/// When only some fields of a large struct matter for a proof, the `#[arbitrary(builder)]`
+/// attribute generates a builder that gives concrete values to these fields, and independent
+/// symbolic values to all the others:
+///
+/// ```rust
+/// #[derive(kani::Arbitrary)]
+/// #[arbitrary(builder)]
+/// struct Header {
+///     version: u8,
+///     flags: u8,
+///     length: u16,
+/// }
+///
+/// #[kani::proof]
+/// fn check_version() {
+///     let header: Header = kani::builder::<Header>().version(2).rest_any();
+///     assert_eq!(header.version, 2);
+/// }
+/// ```
+///
+/// The builder has one method per field to set its value, with the same visibility as the
+/// field, so private fields can be set from the module of the struct and its submodules.
+/// The fields that weren't set are generated like in `kani::any()`, and the value created by
+/// `rest_any()` is constrained by the `#[safety_constraint(...)]` attributes of the struct.
+/// This attribute can only be used in structs with named fields.
 #[proc_macro_error]
-#[proc_macro_derive(Arbitrary, attributes(safety_constraint))]
+#[proc_macro_derive(Arbitrary, attributes(safety_constraint, arbitrary))]
 pub fn derive_arbitrary(item: TokenStream) -> TokenStream {
     derive::expand_derive_arbitrary(item)
 }
 
+/// Allow users to auto generate `Decode` implementations by using `#[derive(Decode)]` macro.
+///
+/// The fields are decoded in order, and the variant of an enum is selected by a `u32` that is
+/// decoded before its fields, reduced modulo the number of variants. Every type parameter must
+/// implement `Decode`.
+///
+/// ```rust
+/// use kani::arbitrary::{Decode, Unstructured};
+///
+/// #[derive(Decode)]
+/// enum Command {
+///     Push(u8),
+///     Pop,
+/// }
+///
+/// #[kani::proof]
+/// fn check_decode() {
+///     let data: [u8; 5] = kani::any();
+///     let _command: Command = Unstructured::new(&data).decode().unwrap();
+/// }
+/// ```
+#[proc_macro_error]
+#[proc_macro_derive(Decode)]
+pub fn derive_decode(item: TokenStream) -> TokenStream {
+    derive::expand_derive_decode(item)
+}
+
 /// Allow users to auto generate `Invariant` implementations by using
 /// `#[derive(Invariant)]` macro.
 ///
@@ -414,9 +596,11 @@ mod sysroot {
     use proc_macro_error2::{abort, abort_call_site};
 
     mod contracts;
+    mod instantiate;
     mod loop_contracts;
 
     pub use contracts::{ensures, modifies, proof_for_contract, requires, stub_verified};
+    pub use instantiate::instantiate;
     pub use loop_contracts::loop_invariant;
 
     use super::*;
@@ -455,30 +639,196 @@ pub fn $name(attr: TokenStream, item: TokenStream) -> TokenStream {
 
     struct ProofOptions {
         schedule: Option<syn::Expr>,
+        max_input: Option<syn::Expr>,
+        expected: Option<syn::Expr>,
+        on_error: Option<syn::Expr>,
+        unwind: Option<syn::Expr>,
+        solver: Option<syn::Expr>,
+        /// The `should_panic` key, which doesn't take a value.
+        should_panic: Option<syn::Ident>,
     }
 
     impl Parse for ProofOptions {
         fn parse(input: ParseStream) -> syn::Result<Self> {
-            if input.is_empty() {
-                Ok(ProofOptions { schedule: None })
-            } else {
+            let mut options = ProofOptions {
+                schedule: None,
+                max_input: None,
+                expected: None,
+                on_error: None,
+                unwind: None,
+                solver: None,
+                should_panic: None,
+            };
+            while !input.is_empty() {
                 let ident = input.parse::<syn::Ident>()?;
-                if ident != "schedule" {
+                if ident == "should_panic" {
+                    if options.should_panic.is_some() {
+                        abort!(ident, "option `{}` was specified more than once", ident);
+                    }
+                    if input.peek(syn::Token![=]) {
+                        abort!(ident, "option `should_panic` doesn't take a value";
+                            help = "use `#[kani::proof(should_panic)]` instead");
+                    }
+                    options.should_panic = Some(ident);
+                    if !input.is_empty() {
+                        let _ = input.parse::<syn::Token![,]>()?;
+                    }
+                    continue;
+                }
+                let option = if ident == "schedule" {
+                    &mut options.schedule
+                } else if ident == "max_input" {
+                    &mut options.max_input
+                } else if ident == "expected" {
+                    &mut options.expected
+                } else if ident == "on_error" {
+                    &mut options.on_error
+                } else if ident == "unwind" {
+                    &mut options.unwind
+                } else if ident == "solver" {
+                    &mut options.solver
+                } else {
                     abort_call_site!("`{}` is not a valid option for `#[kani::proof]`.", ident;
-                        help = "did you mean `schedule`?";
-                        note = "for now, `schedule` is the only option for `#[kani::proof]`.";
+                        help = "did you mean `schedule`, `max_input`, `expected`, `on_error`, `unwind`, `solver` or `should_panic`?";
+                        note = "for now, `schedule`, `max_input`, `expected`, `on_error`, `unwind`, `solver` and `should_panic` are the only options for `#[kani::proof]`.";
                     );
+                };
+                if option.is_some() {
+                    abort!(ident, "option `{}` was specified more than once", ident);
                 }
                 let _ = input.parse::<syn::Token![=]>()?;
-                let schedule = Some(input.parse::<syn::Expr>()?);
-                Ok(ProofOptions { schedule })
+                *option = Some(input.parse::<syn::Expr>()?);
+                if !input.is_empty() {
+                    let _ = input.parse::<syn::Token![,]>()?;
+                }
             }
+            Ok(options)
+        }
+    }
+
+    /// What to do when a harness that returns a `Result` returns an `Err`.
+    #[derive(Clone, Copy)]
+    enum OnError {
+        /// Fail the verification, which is the default.
+        Fail,
+        /// Assume that the harness doesn't return an `Err`, i.e., discard those executions.
+        Assume,
+    }
+
+    impl OnError {
+        /// Parse the value of `#[kani::proof(on_error = ...)]`, if any.
+        fn parse(value: Option<&syn::Expr>) -> Self {
+            let Some(value) = value else { return OnError::Fail };
+            if let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit), .. }) = value {
+                match lit.value().as_str() {
+                    "fail" => return OnError::Fail,
+                    "assume" => return OnError::Assume,
+                    _ => {}
+                }
+            }
+            abort!(value, "invalid value for `on_error`";
+                help = "the value should be either `\"fail\"` or `\"assume\"`");
+        }
+    }
+
+    /// Whether a function with the given return type returns a `Result`. Since we can't resolve
+    /// types here, this is any type named `Result`, e.g., `std::io::Result<()>`.
+    fn returns_result(output: &syn::ReturnType) -> bool {
+        let syn::ReturnType::Type(_, ty) = output else { return false };
+        let syn::Type::Path(path) = ty.as_ref() else { return false };
+        path.qself.is_none() && path.path.segments.last().is_some_and(|seg| seg.ident == "Result")
+    }
+
+    /// Generate the statements that run the harness with `call`, where `output` is the return
+    /// type of the harness.
+    ///
+    /// Harnesses may return a `Result`, so they can use the `?` operator. An `Err` result fails
+    /// the verification, unless `on_error` is [OnError::Assume]. The value returned by other
+    /// harnesses is ignored.
+    fn call_harness(
+        call: proc_macro2::TokenStream,
+        output: &syn::ReturnType,
+        on_error: OnError,
+    ) -> proc_macro2::TokenStream {
+        if !returns_result(output) {
+            return quote!(#call;);
         }
+        let check = match on_error {
+            OnError::Fail => quote!(kani::assert(result.is_ok(), "harness returned an `Err`")),
+            OnError::Assume => quote!(kani::assume(result.is_ok())),
+        };
+        quote!(
+            let result: ::core::result::Result<_, _> = #call;
+            #check;
+        )
+    }
+
+    /// Check whether the given type is `&[u8]`.
+    fn is_byte_slice_ref(ty: &syn::Type) -> bool {
+        let syn::Type::Reference(reference) = ty else { return false };
+        let syn::Type::Slice(slice) = reference.elem.as_ref() else { return false };
+        let syn::Type::Path(elem) = slice.elem.as_ref() else { return false };
+        reference.mutability.is_none() && elem.qself.is_none() && elem.path.is_ident("u8")
+    }
+
+    /// Convert the attributes of a `#[test]` function, so it can be verified as a harness.
+    ///
+    /// This removes `#[test]`, which would otherwise remove the function outside of test builds,
+    /// as well as `#[ignore]`, and translates `#[should_panic]` to `#[kanitool::should_panic]`.
+    /// Note that the expected message of `#[should_panic(expected = "...")]` is not checked.
+    fn convert_test_attrs(attrs: &mut Vec<syn::Attribute>) -> proc_macro2::TokenStream {
+        let is_test = attrs.iter().any(|attr| attr.path().is_ident("test"));
+        let should_panic = attrs.iter().any(|attr| attr.path().is_ident("should_panic"));
+        attrs.retain(|attr| {
+            let path = attr.path();
+            !(path.is_ident("test")
+                || path.is_ident("should_panic")
+                || (is_test && path.is_ident("ignore")))
+        });
+        let mut kani_attrs = proc_macro2::TokenStream::new();
+        if is_test {
+            kani_attrs.extend(quote!(#[kanitool::from_test]));
+        }
+        if should_panic {
+            kani_attrs.extend(quote!(#[kanitool::should_panic]));
+        }
+        kani_attrs
     }
 
     pub fn proof(attr: TokenStream, item: TokenStream) -> TokenStream {
         let proof_options = parse_macro_input!(attr as ProofOptions);
-        let fn_item = parse_macro_input!(item as ItemFn);
+        let mut fn_item = parse_macro_input!(item as ItemFn);
+        let test_attributes = convert_test_attrs(&mut fn_item.attrs);
+        // These options are equivalent to the corresponding Kani attributes.
+        if let Some(expected) = &proof_options.expected {
+            fn_item.attrs.push(syn::parse_quote!(#[kanitool::expected = #expected]));
+        }
+        if let Some(unwind) = &proof_options.unwind {
+            fn_item.attrs.push(syn::parse_quote!(#[kanitool::unwind(#unwind)]));
+        }
+        if let Some(solver) = &proof_options.solver {
+            fn_item.attrs.push(syn::parse_quote!(#[kanitool::solver(#solver)]));
+        }
+        if proof_options.should_panic.is_some() {
+            fn_item.attrs.push(syn::parse_quote!(#[kanitool::should_panic]));
+        }
+        let on_error = OnError::parse(proof_options.on_error.as_ref());
+        if let Some(value) = &proof_options.on_error {
+            if !returns_result(&fn_item.sig.output) {
+                abort!(value, "`on_error` can only be used with harnesses that return a `Result`");
+            }
+        }
+
+        if let Some(idx) = fn_item.attrs.iter().position(instantiate::is_instantiate_attr) {
+            if proof_options.schedule.is_some() || proof_options.max_input.is_some() {
+                abort_call_site!(
+                    "`#[kani::instantiate(...)]` cannot be combined with `#[kani::proof]` options."
+                );
+            }
+            let instantiate_attr = fn_item.attrs.remove(idx);
+            return instantiate::expand_harnesses(fn_item, &instantiate_attr, on_error);
+        }
+
         let attrs = fn_item.attrs;
         let vis = fn_item.vis;
         let sig = fn_item.sig;
@@ -487,8 +837,64 @@ pub fn proof(attr: TokenStream, item: TokenStream) -> TokenStream {
         let kani_attributes = quote!(
             #[allow(dead_code)]
             #[kanitool::proof]
+            #test_attributes
         );
 
+        if let Some(max_input) = proof_options.max_input {
+            // Harnesses for fuzz targets, i.e., functions that take a single `&[u8]` argument.
+            // Specifically, it translates
+            // ```ignore
+            // #[kani::proof(max_input = N)]
+            // #[attribute]
+            // pub fn target(data: &[u8]) { ... }
+            // ```
+            // to
+            // ```ignore
+            // #[kanitool::proof]
+            // #[attribute]
+            // pub fn target() {
+            //   fn target(data: &[u8]) { ... }
+            //   let input: [u8; N] = kani::any();
+            //   target(kani::slice::any_slice_of_array(&input));
+            // }
+            // ```
+            if sig.asyncness.is_some() || proof_options.schedule.is_some() {
+                abort_call_site!(
+                    "`#[kani::proof(max_input = ...)]` cannot be used with `async` functions."
+                );
+            }
+            let is_byte_slice_arg = |arg: &syn::FnArg| matches!(arg, syn::FnArg::Typed(arg) if is_byte_slice_ref(&arg.ty));
+            let is_fuzz_target = sig.inputs.len() == 1
+                && sig.generics.params.is_empty()
+                && sig.inputs.first().is_some_and(is_byte_slice_arg);
+            if !is_fuzz_target {
+                abort!(
+                    sig,
+                    "`#[kani::proof(max_input = ...)]` can only be applied to functions with a single `&[u8]` argument";
+                    help = "the signature should be `fn {}(data: &[u8])`", sig.ident;
+                );
+            }
+            let mut modified_sig = sig.clone();
+            modified_sig.inputs.clear();
+            modified_sig.output = syn::ReturnType::Default;
+            let fn_name = &sig.ident;
+            let call = call_harness(
+                quote!(#fn_name(kani::slice::any_slice_of_array(&input))),
+                &sig.output,
+                on_error,
+            );
+            return quote!(
+                #kani_attributes
+                #(#attrs)*
+                #vis #modified_sig {
+                    #sig #body
+                    let input: [u8; #max_input] = kani::any();
+                    #call
+                }
+            )
+            .into();
+        }
+
         if sig.asyncness.is_none() {
             if proof_options.schedule.is_some() {
                 abort_call_site!(
@@ -496,6 +902,36 @@ pub fn proof(attr: TokenStream, item: TokenStream) -> TokenStream {
                     help = "did you mean to make this function `async`?";
                 );
             }
+            if returns_result(&sig.output) {
+                // Harnesses that return a `Result` are wrapped in a harness that checks the
+                // result. Specifically, it translates
+                // ```ignore
+                // #[kani::proof]
+                // pub fn harness() -> Result<(), E> { ... }
+                // ```
+                // to
+                // ```ignore
+                // #[kanitool::proof]
+                // pub fn harness() {
+                //   fn harness() -> Result<(), E> { ... }
+                //   let result: Result<_, _> = harness();
+                //   kani::assert(result.is_ok(), "harness returned an `Err`");
+                // }
+                // ```
+                let mut modified_sig = sig.clone();
+                modified_sig.output = syn::ReturnType::Default;
+                let fn_name = &sig.ident;
+                let call = call_harness(quote!(#fn_name()), &sig.output, on_error);
+                return quote!(
+                    #kani_attributes
+                    #(#attrs)*
+                    #vis #modified_sig {
+                        #sig #body
+                        #call
+                    }
+                )
+                .into();
+            }
             // Adds `#[kanitool::proof]` and other attributes
             quote!(
                 #kani_attributes
@@ -532,6 +968,7 @@ pub fn proof(attr: TokenStream, item: TokenStream) -> TokenStream {
             }
             let mut modified_sig = sig.clone();
             modified_sig.asyncness = None;
+            modified_sig.output = syn::ReturnType::Default;
             let fn_name = &sig.ident;
             let schedule = proof_options.schedule;
             let block_on_call = if let Some(schedule) = schedule {
@@ -539,18 +976,30 @@ pub fn proof(attr: TokenStream, item: TokenStream) -> TokenStream {
             } else {
                 quote!(kani::block_on(#fn_name()))
             };
+            let call = call_harness(block_on_call, &sig.output, on_error);
             quote!(
                 #kani_attributes
                 #(#attrs)*
                 #vis #modified_sig {
                     #sig #body
-                    #block_on_call
+                    #call
                 }
             )
             .into()
         }
     }
 
+    /// Expand to an anonymous constant annotated with `#[kanitool::stub_by_default(...)]`, which
+    /// the compiler collects into the crate's default stubs.
+    pub fn register_default_stub(item: TokenStream) -> TokenStream {
+        let args = proc_macro2::TokenStream::from(item);
+        quote!(
+            #[kanitool::stub_by_default(#args)]
+            const _: () = ();
+        )
+        .into()
+    }
+
     kani_attribute!(should_panic, no_args);
     kani_attribute!(recursion, no_args);
     kani_attribute!(solver);
@@ -596,4 +1045,10 @@ pub fn proof(_attr: TokenStream, item: TokenStream) -> TokenStream {
     no_op!(proof_for_contract);
     no_op!(stub_verified);
     no_op!(loop_invariant);
+    no_op!(instantiate);
+
+    /// Default stubs are only used by Kani.
+    pub fn register_default_stub(_item: TokenStream) -> TokenStream {
+        TokenStream::new()
+    }
 }
@@ -0,0 +1,247 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Implementation of `#[kani::instantiate(...)]`, which turns a generic harness into one concrete
+//! harness per substitution of its generic parameters.
+//!
+//! For example,
+//! ```ignore
+//! #[kani::proof]
+//! #[kani::instantiate(T = u32, T = i64)]
+//! #[kani::unwind(2)]
+//! fn check<T: kani::Arbitrary>() { ... }
+//! ```
+//! expands to
+//! ```ignore
+//! #[allow(dead_code)]
+//! fn check<T: kani::Arbitrary>() { ... }
+//!
+//! #[kanitool::proof]
+//! #[kani::unwind(2)]
+//! fn check__u32() { check::<u32>() }
+//!
+//! #[kanitool::proof]
+//! #[kani::unwind(2)]
+//! fn check__i64() { check::<i64>() }
+//! ```
+
+use super::{OnError, call_harness};
+use proc_macro::TokenStream;
+use proc_macro_error2::abort;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::{ToTokens, format_ident, quote};
+use std::collections::HashSet;
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Attribute, GenericArgument, GenericParam, ItemFn, Token, parenthesized};
+
+/// One substitution of the generic parameters, e.g., `T = u32` or `(T = u8, N = 4)`.
+type Substitution = Vec<(Ident, GenericArgument)>;
+
+/// Whether the attribute is `#[kani::instantiate(...)]`.
+pub fn is_instantiate_attr(attr: &Attribute) -> bool {
+    is_kani_attr(attr, "instantiate")
+}
+
+/// Whether the attribute is `#[kani::<name>]` or `#[<name>]`.
+fn is_kani_attr(attr: &Attribute, name: &str) -> bool {
+    let segments: Vec<_> = attr.path().segments.iter().map(|seg| seg.ident.to_string()).collect();
+    matches!(segments.as_slice(), [kani, attr_name] if kani == "kani" && attr_name == name)
+        || attr.path().is_ident(name)
+}
+
+/// The implementation of `#[kani::instantiate(...)]` when it is expanded before
+/// `#[kani::proof]`. In that case, move it after the `#[kani::proof]` attribute, which
+/// generates the harnesses.
+pub fn instantiate(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = TokenStream2::from(attr);
+    let fn_item: ItemFn = match syn::parse(item) {
+        Ok(fn_item) => fn_item,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    if !fn_item.attrs.iter().any(|attr| is_kani_attr(attr, "proof")) {
+        abort!(
+            fn_item.sig.ident,
+            "`#[kani::instantiate(...)]` can only be used together with `#[kani::proof]`"
+        );
+    }
+    // Place the attribute after `#[kani::proof]`, so the proof macro finds it.
+    let mut fn_item = fn_item;
+    fn_item.attrs.push(syn::parse_quote!(#[kani::instantiate(#args)]));
+    quote!(#fn_item).into()
+}
+
+/// Generate one harness per substitution listed in the `#[kani::instantiate(...)]` attribute
+/// `instantiate_attr` of the generic harness `fn_item`, which must have already been removed from
+/// the function attributes. The generic function itself is kept without the Kani attributes.
+pub fn expand_harnesses(
+    fn_item: ItemFn,
+    instantiate_attr: &Attribute,
+    on_error: OnError,
+) -> TokenStream {
+    let substitutions = match instantiate_attr.parse_args_with(parse_substitutions) {
+        Ok(substitutions) => substitutions,
+        Err(err) => abort!(err.span(), "invalid `#[kani::instantiate(...)]` attribute: {}", err),
+    };
+    let sig = &fn_item.sig;
+    let fn_name = &sig.ident;
+    if sig.generics.params.is_empty() {
+        abort!(
+            sig.ident,
+            "`#[kani::instantiate(...)]` can only be used with generic harnesses";
+            help = "remove the attribute, or add the generic parameters to `{}`", fn_name
+        );
+    }
+    if !sig.inputs.is_empty() || sig.asyncness.is_some() {
+        abort!(
+            sig,
+            "`#[kani::instantiate(...)]` can only be used with functions that are not `async` and \
+            have no arguments"
+        );
+    }
+    let params: Vec<(Ident, bool)> = sig
+        .generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Type(ty) => (ty.ident.clone(), false),
+            GenericParam::Const(constant) => (constant.ident.clone(), true),
+            GenericParam::Lifetime(lifetime) => {
+                abort!(lifetime, "`#[kani::instantiate(...)]` doesn't support lifetime parameters")
+            }
+        })
+        .collect();
+
+    if let Some(other) = fn_item.attrs.iter().find(|attr| is_instantiate_attr(attr)) {
+        abort!(
+            other,
+            "`#[kani::instantiate(...)]` can only be used once per harness";
+            help = "list all the substitutions in a single attribute"
+        );
+    }
+
+    let mut names = HashSet::new();
+    let harnesses = substitutions.iter().map(|substitution| {
+        let args = generic_args(&params, substitution, instantiate_attr.span());
+        let suffix = args.iter().map(harness_suffix).collect::<Vec<_>>().join("_");
+        let harness_name = format_ident!("{}__{}", fn_name, suffix, span = fn_name.span());
+        if !names.insert(harness_name.to_string()) {
+            abort!(
+                instantiate_attr,
+                "`{}` is instantiated more than once with the same arguments",
+                fn_name
+            );
+        }
+        let attrs = fn_item.attrs.iter().filter(|attr| !is_kani_attr(attr, "proof"));
+        let vis = &fn_item.vis;
+        let call = call_harness(quote!(#fn_name::<#(#args),*>()), &sig.output, on_error);
+        quote!(
+            #[allow(dead_code, non_snake_case)]
+            #[kanitool::proof]
+            #(#attrs)*
+            #vis fn #harness_name() {
+                #call
+            }
+        )
+    });
+
+    // The generic function is no longer a harness, so drop the Kani attributes.
+    let mut generic_fn = fn_item.clone();
+    generic_fn.attrs.retain(|attr| {
+        attr.path()
+            .segments
+            .first()
+            .is_none_or(|segment| segment.ident != "kani" && segment.ident != "kanitool")
+            && !is_kani_attr(attr, "proof")
+    });
+    quote!(
+        #[allow(dead_code)]
+        #generic_fn
+        #(#harnesses)*
+    )
+    .into()
+}
+
+/// Parse the list of substitutions, e.g., `T = u32, T = i64` or `(T = u8, N = 4), (T = i8, N = 2)`.
+fn parse_substitutions(input: ParseStream) -> syn::Result<Vec<Substitution>> {
+    let parse_assignment = |input: ParseStream| -> syn::Result<(Ident, GenericArgument)> {
+        let name: Ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+        Ok((name, input.parse()?))
+    };
+    let mut substitutions = vec![];
+    while !input.is_empty() {
+        if input.peek(syn::token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            let assignments =
+                Punctuated::<_, Token![,]>::parse_terminated_with(&content, parse_assignment)?;
+            substitutions.push(assignments.into_iter().collect());
+        } else {
+            substitutions.push(vec![parse_assignment(input)?]);
+        }
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+    if substitutions.is_empty() {
+        return Err(input.error("expected at least one substitution, e.g., `T = u32`"));
+    }
+    Ok(substitutions)
+}
+
+/// Return the generic arguments, in the order of the parameters, for the given substitution.
+fn generic_args(
+    params: &[(Ident, bool)],
+    substitution: &Substitution,
+    span: Span,
+) -> Vec<TokenStream2> {
+    for (name, _) in substitution {
+        if !params.iter().any(|(param, _)| param == name) {
+            abort!(name, "`{}` is not a generic parameter of this harness", name);
+        }
+        if substitution.iter().filter(|(other, _)| other == name).count() > 1 {
+            abort!(name, "`{}` is given more than once in the same substitution", name);
+        }
+    }
+    params
+        .iter()
+        .map(|(param, is_const)| {
+            let Some((_, arg)) = substitution.iter().find(|(name, _)| name == param) else {
+                abort!(
+                    span,
+                    "missing a value for the generic parameter `{}`", param;
+                    help = "group the values of every parameter in one substitution, e.g., \
+                    `(T = u32, N = 4)`"
+                );
+            };
+            match (arg, is_const) {
+                (GenericArgument::Type(_), false) | (GenericArgument::Const(_), true) => {
+                    arg.to_token_stream()
+                }
+                // A path to a constant, e.g., `N = SIZE`, is parsed as a type.
+                (GenericArgument::Type(syn::Type::Path(path)), true) => quote!({ #path }),
+                (_, false) => abort!(arg, "expected a type for the generic parameter `{}`", param),
+                (_, true) => {
+                    abort!(arg, "expected a constant value for the const parameter `{}`", param)
+                }
+            }
+        })
+        .collect()
+}
+
+/// Encode a generic argument in the harness name, e.g., `Vec<u8>` becomes `Vec_u8`, and `-1`
+/// becomes `neg1`.
+fn harness_suffix(arg: &TokenStream2) -> String {
+    let mut suffix = String::new();
+    for c in arg.to_string().chars() {
+        match c {
+            c if c.is_ascii_alphanumeric() => suffix.push(c),
+            '-' => suffix.push_str("neg"),
+            _ if !suffix.ends_with('_') => suffix.push('_'),
+            _ => {}
+        }
+    }
+    suffix.trim_matches('_').to_string()
+}
@@ -9,6 +9,7 @@
 use quote::{format_ident, quote};
 use syn::spanned::Spanned;
 use syn::token::AndAnd;
+use syn::visit_mut::{self, VisitMut};
 use syn::{BinOp, Expr, ExprBinary, Stmt};
 
 /// Expand loop contracts macros.
@@ -30,6 +31,28 @@
 ///      body
 ///  }
 /// ```
+///
+/// A for loop over a slice, an array, or their `iter()` or `iter_mut()` iterators
+/// ``` rust
+///  for pat in expr {
+///      body
+///  }
+/// ```
+/// is first turned into a while loop over the elements of the slice, whose invariant also bounds
+/// the index of the next element by the length of the slice
+/// ``` rust
+///  let mut __kani_loop_iter = kani::internal::IntoLoopIter::into_loop_iter(expr);
+///  while kani_register_loop_contract_id(|| -> bool {
+///      __kani_loop_iter.index() <= __kani_loop_iter.len() && inv
+///  };) && __kani_loop_iter.has_next() {
+///      let pat = __kani_loop_iter.next_unchecked();
+///      body
+///  }
+/// ```
+/// where every occurrence of `kani::index` in `inv` is replaced by `__kani_loop_iter.index()`.
+///
+/// In both cases, `_id` in the name of the register function stands for a unique id of the
+/// loop, computed from its span.
 pub fn loop_invariant(attr: TokenStream, item: TokenStream) -> TokenStream {
     // parse the stmt of the loop
     let mut loop_stmt: Stmt = syn::parse(item.clone()).unwrap();
@@ -64,15 +87,39 @@ pub fn loop_invariant(attr: TokenStream, item: TokenStream) -> TokenStream {
                     right: ew.cond.clone(),
                 });
             }
+            Expr::ForLoop(ef) => {
+                let mut inv_expr = inv_expr;
+                ReplaceLoopIndex.visit_expr_mut(&mut inv_expr);
+                let label = &ef.label;
+                let pat = &ef.pat;
+                let iter_expr = &ef.expr;
+                let body = &ef.body;
+                let iter = format_ident!("__kani_loop_iter");
+                *e = syn::parse(
+                    quote!({
+                        let mut #iter = kani::internal::IntoLoopIter::into_loop_iter(#iter_expr);
+                        #label while #register_ident(&||->bool{
+                            #iter.index() <= #iter.len() && (#inv_expr)
+                        }, 0) && #iter.has_next() {
+                            let #pat = #iter.next_unchecked();
+                            #body
+                        }
+                    })
+                    .into(),
+                )
+                .unwrap();
+            }
             _ => {
-                abort_call_site!("`#[kani::loop_invariant]` is now only supported for while-loops.";
-                    note = "for now, loop contracts is only supported for while-loops.";
+                abort_call_site!("`#[kani::loop_invariant]` is only supported for while-loops and for-loops.";
+                    note = "for-loops are only supported over slices and arrays, or their `iter()` and `iter_mut()` iterators.";
                 )
             }
         },
-        _ => abort_call_site!("`#[kani::loop_invariant]` is now only supported for while-loops.";
-            note = "for now, loop contracts is only supported for while-loops.";
-        ),
+        _ => {
+            abort_call_site!("`#[kani::loop_invariant]` is only supported for while-loops and for-loops.";
+                note = "for-loops are only supported over slices and arrays, or their `iter()` and `iter_mut()` iterators.";
+            )
+        }
     }
     quote!(
         {
@@ -88,6 +135,29 @@ const fn #register_ident<F: Fn() -> bool>(_f: &F, _transformed: usize) -> bool {
     .into()
 }
 
+/// Replace `kani::index` in the invariant of a for-loop by the index of the next element.
+struct ReplaceLoopIndex;
+
+impl VisitMut for ReplaceLoopIndex {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        let is_index = match expr {
+            Expr::Path(path) => {
+                let segments = &path.path.segments;
+                path.qself.is_none()
+                    && segments.len() == 2
+                    && segments[0].ident == "kani"
+                    && segments[1].ident == "index"
+            }
+            _ => false,
+        };
+        if is_index {
+            *expr = syn::parse_quote!(__kani_loop_iter.index());
+        } else {
+            visit_mut::visit_expr_mut(self, expr);
+        }
+    }
+}
+
 fn generate_unique_id_from_span(stmt: &Stmt) -> String {
     // Extract the span of the expression
     let span = stmt.span().unwrap();
@@ -5,6 +5,9 @@
 set -o pipefail
 set -o nounset
 
+# The arguments of this script are forwarded to compiletest, e.g., use
+# `--kani-flag=--enable-slicing` to run the perf suite with an extra Kani flag.
+
 SCRIPT_DIR="$( cd "$( dirname "${BASH_SOURCE[0]}" )" >/dev/null 2>&1 && pwd )"
 KANI_DIR=$SCRIPT_DIR/..
 
@@ -27,7 +30,7 @@ done
 suite="perf"
 mode="cargo-kani-test"
 echo "Check compiletest suite=$suite mode=$mode"
-cargo run -p compiletest -- --suite $suite --mode $mode --no-fail-fast
+cargo run -p compiletest -- --suite $suite --mode $mode --no-fail-fast "$@"
 exit_code=$?
 
 echo "Cleaning up..."
@@ -17,3 +17,10 @@ fn reads_static_var_in_crate_with_global_asm() {
     let x = unsafe { crate_with_global_asm::STATIC_VAR };
     assert_eq!(x, 98);
 }
+
+// Call a function defined by global ASM
+// Should fail with --ignore-global-asm, since the ASM was not verified
+#[kani::proof]
+fn calls_global_asm_fn() {
+    unsafe { crate_with_global_asm::foo() };
+}
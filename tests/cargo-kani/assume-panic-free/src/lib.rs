@@ -0,0 +1,20 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `--assume-panic-free` turns the panics of the given dependency into assumptions,
+//! while panics in the crate under verification are still reported.
+
+#[kani::proof]
+fn check_dependency_panics_are_assumed() {
+    let (dividend, divisor): (u32, u32) = kani::any();
+    let quotient = trusted_dep::divide(dividend, divisor);
+    // The paths where the dependency panics are discarded.
+    assert!(divisor != 0);
+    assert!(quotient <= dividend);
+}
+
+#[kani::proof]
+fn check_local_panics_are_checked() {
+    let divisor: u32 = kani::any();
+    let _ = 10 / divisor;
+}
@@ -0,0 +1,8 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A dependency that panics if `divisor` is zero.
+
+pub fn divide(dividend: u32, divisor: u32) -> u32 {
+    dividend / divisor
+}
@@ -0,0 +1,13 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that `--message-format json` replaces the human-readable output with JSON messages,
+//! alongside the messages of cargo.
+
+fn main() {}
+
+#[kani::proof]
+fn check_json_messages() {
+    let x: u8 = kani::any();
+    assert!(x < 200, "x is small");
+}
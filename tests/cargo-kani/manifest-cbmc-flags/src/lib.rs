@@ -0,0 +1,11 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that CBMC flags and limits from `Cargo.toml` are applied, that unknown flags are ignored
+//! with a warning, and that the flags are printed in verbose mode.
+
+#[kani::proof]
+fn check_with_manifest_flags() {
+    let x: u32 = kani::any();
+    assert_eq!(x.wrapping_mul(2) % 2, 0);
+}
@@ -0,0 +1,21 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that Kani reports the `abort` panic strategy configured by the crate.
+
+pub fn checked_half(value: u32) -> u32 {
+    assert!(value % 2 == 0, "odd value");
+    value / 2
+}
+
+#[cfg(kani)]
+mod verify {
+    use super::*;
+
+    #[kani::proof]
+    fn check_half() {
+        let value: u32 = kani::any();
+        kani::assume(value % 2 == 0);
+        assert_eq!(checked_half(value) * 2, value);
+    }
+}
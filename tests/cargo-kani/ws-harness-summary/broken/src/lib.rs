@@ -0,0 +1,10 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A crate that fails to compile, which shouldn't prevent the verification of the others.
+
+#[kani::proof]
+fn check() {
+    let x: u8 = "not a number";
+    assert!(x == 0);
+}
@@ -0,0 +1,15 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Harnesses with the same name as in `second`, which must be reported separately.
+
+#[kani::proof]
+fn check() {
+    assert!(1 + 1 == 2);
+}
+
+#[kani::proof]
+fn check_first_fails() {
+    let x: u8 = kani::any();
+    assert!(x < 100, "first crate failure");
+}
@@ -0,0 +1,9 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Harness with the same name as in `first`.
+
+#[kani::proof]
+fn check() {
+    assert!(2 * 2 == 4);
+}
@@ -0,0 +1,40 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z unstable-options --output-format json --fix check_pinned::any#1=7 --fix flag::any#0=true --fix check_invalid_pin::any#0=300
+
+//! Check that every call to `kani::any()` gets an identifier from its position among the calls
+//! in its function, that the identifier is reported in the JSON trace, and that `--fix` pins
+//! the value of a call by its identifier.
+
+fn flag() -> bool {
+    kani::any()
+}
+
+#[kani::proof]
+fn check_pinned() {
+    // The code around the calls doesn't affect their identifiers, including the calls in `flag`.
+    let enabled = flag();
+    let free: u8 = kani::any();
+    let mut sum = 0u32;
+    for i in 0..3 {
+        sum += i;
+    }
+    let pinned: u8 = kani::any();
+    assert!(enabled);
+    assert_eq!(pinned, 7);
+    assert_eq!(sum, 3);
+    kani::cover!(free != 0, "unpinned calls remain symbolic");
+}
+
+#[kani::proof]
+fn check_trace() {
+    let x: u8 = kani::any();
+    assert!(x < 200);
+}
+
+#[kani::proof]
+fn check_invalid_pin() {
+    let x: u8 = kani::any();
+    assert!(x < 200);
+}
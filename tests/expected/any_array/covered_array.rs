@@ -0,0 +1,17 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that indexing into a `CoveredArray` reports which access patterns are reachable.
+
+#[kani::proof]
+fn check_all_patterns() {
+    let arr = kani::array::any_covered_array::<u8, 5>();
+    let idx: usize = kani::any_where(|i| *i < 5);
+    let _ = arr[idx];
+}
+
+#[kani::proof]
+fn check_first_only() {
+    let mut arr = kani::array::any_covered_array::<u8, 5>();
+    arr[0] = 0;
+}
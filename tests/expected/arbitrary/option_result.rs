@@ -0,0 +1,30 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Ensure that kani::any can generate every variant of Option and Result, including nested ones.
+
+#[kani::proof]
+fn check_option() {
+    let any_option: Option<u8> = kani::any();
+    kani::cover!(any_option.is_none(), "None");
+    kani::cover!(any_option == Some(0), "Some(0)");
+    kani::cover!(any_option == Some(u8::MAX), "Some(MAX)");
+}
+
+#[kani::proof]
+fn check_nested_option() {
+    let any_option: Option<Option<bool>> = kani::any();
+    kani::cover!(any_option.is_none(), "Outer None");
+    kani::cover!(any_option == Some(None), "Inner None");
+    kani::cover!(any_option == Some(Some(true)), "Some(Some(true))");
+    kani::cover!(any_option == Some(Some(false)), "Some(Some(false))");
+}
+
+#[kani::proof]
+fn check_result() {
+    let any_result: Result<u8, i8> = kani::any();
+    kani::cover!(any_result == Ok(u8::MAX), "Ok(MAX)");
+    kani::cover!(any_result == Err(i8::MIN), "Err(MIN)");
+    kani::cover!(matches!(any_result, Ok(_) | Err(_)), "Any variant");
+    assert!(any_result.is_ok() != any_result.is_err(), "Exactly one variant");
+}
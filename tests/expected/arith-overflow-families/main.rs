@@ -0,0 +1,76 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that the `wrapping_*`, `saturating_*`, `checked_*` and `overflowing_*` families of
+//! integer methods are modeled precisely, without any overflow check, by comparing them against
+//! the same operation computed in a wider type.
+
+macro_rules! check_family {
+    ($ty:ty, $wide:ty) => {{
+        let a: $ty = kani::any();
+        let b: $ty = kani::any();
+        let min = <$ty>::MIN as $wide;
+        let max = <$ty>::MAX as $wide;
+
+        let sum = a as $wide + b as $wide;
+        let diff = a as $wide - b as $wide;
+        let prod = a as $wide * b as $wide;
+        let in_range = |value: $wide| min <= value && value <= max;
+        // The result of the wrapping operation is congruent to the exact result.
+        let wraps_to = |res: $ty, value: $wide| (res as $wide - value) % (max - min + 1) == 0;
+
+        assert!(wraps_to(a.wrapping_add(b), sum));
+        assert!(wraps_to(a.wrapping_sub(b), diff));
+        assert!(wraps_to(a.wrapping_mul(b), prod));
+
+        assert_eq!(a.saturating_add(b) as $wide, sum.clamp(min, max));
+        assert_eq!(a.saturating_sub(b) as $wide, diff.clamp(min, max));
+        assert_eq!(a.saturating_mul(b) as $wide, prod.clamp(min, max));
+
+        assert_eq!(a.checked_add(b).is_some(), in_range(sum));
+        assert_eq!(a.checked_sub(b).is_some(), in_range(diff));
+        assert_eq!(a.checked_mul(b).is_some(), in_range(prod));
+
+        assert_eq!(a.overflowing_add(b), (a.wrapping_add(b), !in_range(sum)));
+        assert_eq!(a.overflowing_sub(b), (a.wrapping_sub(b), !in_range(diff)));
+        assert_eq!(a.overflowing_mul(b), (a.wrapping_mul(b), !in_range(prod)));
+
+        if b != 0 {
+            assert_eq!(a.checked_div(b).is_none(), a as $wide / b as $wide > max);
+            assert_eq!(a.wrapping_div(b).wrapping_mul(b).wrapping_add(a.wrapping_rem(b)), a);
+        }
+    }};
+}
+
+#[kani::proof]
+fn check_unsigned() {
+    check_family!(u8, i32);
+}
+
+#[kani::proof]
+fn check_signed() {
+    check_family!(i8, i32);
+}
+
+/// The overflow branch of `checked_*` must be reachable.
+#[kani::proof]
+fn check_checked_none_reachable() {
+    let a: i8 = kani::any();
+    let b: i8 = kani::any();
+    match a.checked_mul(b) {
+        Some(res) => kani::cover!(res == i8::MIN, "checked_mul returns Some(MIN)"),
+        None => kani::cover!(true, "checked_mul returns None"),
+    }
+    kani::cover!(a.checked_div(b).is_none() && b != 0, "checked_div overflows");
+    kani::cover!(a.checked_neg().is_none(), "checked_neg overflows");
+}
+
+/// Wrapping and saturating results at the bounds must be reachable too.
+#[kani::proof]
+fn check_bounds_reachable() {
+    let a: u8 = kani::any();
+    let b: u8 = kani::any();
+    kani::cover!(a.wrapping_add(b) < a, "wrapping_add wraps around");
+    kani::cover!(a.saturating_sub(b) == 0 && a != b, "saturating_sub saturates");
+    kani::cover!(a.saturating_mul(b) == u8::MAX && a != 1 && b != 1, "saturating_mul saturates");
+}
@@ -0,0 +1,27 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::assert_split!` reports the operand of a `&&` / `||` chain that fails, and
+//! that it preserves the short-circuit semantics of the condition.
+
+#[kani::proof]
+fn check_conjunct() {
+    let idx: usize = kani::any();
+    let buf = [1u8, 2, 0];
+    kani::assume(idx <= buf.len());
+    // `buf[idx]` is only evaluated if `idx < buf.len()` holds, so there's no out-of-bounds access.
+    kani::assert_split!(idx < buf.len() && buf[idx] != 0);
+}
+
+#[kani::proof]
+fn check_alternative() {
+    let x: u8 = kani::any();
+    let y: u8 = kani::any();
+    kani::assert_split!(x == 0 || (y > 0 && x < 10), "invalid input", depth = 2);
+}
+
+#[kani::proof]
+fn check_success() {
+    let x: u8 = kani::any();
+    kani::assert_split!(x <= u8::MAX && (x == 0 || x > 0));
+}
@@ -0,0 +1,26 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::assert_unsat!()` passes when the assumptions are contradictory and fails when
+//! the location is reachable.
+
+#[kani::proof]
+fn check_contradiction() {
+    let x: u8 = kani::any();
+    kani::assume(x > 10);
+    if x < 5 {
+        kani::assert_unsat!();
+    }
+    if x.checked_add(250).is_some() {
+        kani::assert_unsat!("adding 250 to a value larger than 10 must overflow");
+    }
+}
+
+#[kani::proof]
+fn check_satisfiable() {
+    let x: u8 = kani::any();
+    kani::assume(x < 10);
+    if x == 9 {
+        kani::assert_unsat!("x should be smaller than 9");
+    }
+}
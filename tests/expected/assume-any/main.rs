@@ -0,0 +1,24 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `assume_any!` keeps every disjunct reachable, and that both `assume_all!` and
+//! `assume_any!` prune the executions where the conditions don't hold.
+
+#[kani::proof]
+fn check_assume_any_reachability() {
+    let x: u8 = kani::any();
+    kani::assume_any!(x == 1, x == 5, x > 250);
+    kani::cover!(x == 1);
+    kani::cover!(x == 5);
+    kani::cover!(x == 255);
+    kani::cover!(x == 3);
+}
+
+#[kani::proof]
+fn check_assume_all_pruning() {
+    let x: u8 = kani::any();
+    kani::assume_all!(x > 10, x % 2 == 0);
+    kani::cover!(x == 12);
+    kani::cover!(x == 13);
+    kani::cover!(x == 10);
+}
@@ -0,0 +1,11 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --no-assume-satisfiable-checks
+
+//! Check that `--no-assume-satisfiable-checks` turns `kani::assume_satisfiable` into an assumption.
+
+#[kani::proof]
+fn assume_false() {
+    kani::assume_satisfiable(false);
+    assert!(false);
+}
@@ -0,0 +1,32 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::assume_satisfiable` reports contradictory assumptions.
+
+#[kani::proof]
+fn satisfiable() {
+    let x: u8 = kani::any();
+    kani::assume_satisfiable(x > 10);
+    assert!(x != 0);
+}
+
+#[kani::proof]
+fn contradiction() {
+    let x: u8 = kani::any();
+    kani::assume(x > 10);
+    kani::assume_satisfiable(x < 5);
+    assert!(x == 0);
+}
+
+#[kani::proof]
+fn assume_false() {
+    kani::assume_satisfiable(false);
+}
+
+#[kani::proof]
+fn unreachable() {
+    let x: u8 = kani::any();
+    if x > 10 && x < 5 {
+        kani::assume_satisfiable(true);
+    }
+}
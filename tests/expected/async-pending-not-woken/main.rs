@@ -0,0 +1,28 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// compile-flags: --edition 2018
+// kani-flags: -Z async-lib
+
+//! Check that `kani::block_on` reports an error for a future that waits for an external event,
+//! i.e., returns `Poll::Pending` without waking up its waker.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Models a timer: it is only ready after an event which never happens in Kani.
+struct Timer;
+
+impl Future for Timer {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Poll::Pending
+    }
+}
+
+#[kani::proof]
+async fn check_timer() {
+    Timer.await;
+}
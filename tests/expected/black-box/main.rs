@@ -0,0 +1,43 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `std::hint::black_box` returns its argument unchanged, and that a symbolic value
+//! wrapped in `black_box` still reaches every branch that depends on it.
+
+use std::hint::black_box;
+
+#[kani::proof]
+fn check_symbolic_branches() {
+    let x: u8 = kani::any();
+    let y = black_box(x);
+    assert_eq!(x, y);
+    if y > 200 {
+        kani::cover!(true, "large value");
+    } else if y == 0 {
+        kani::cover!(true, "zero value");
+    } else {
+        kani::cover!(true, "small value");
+    }
+}
+
+#[kani::proof]
+fn check_constant_unchanged() {
+    let x = black_box(5u32);
+    assert_eq!(x, 5);
+    kani::cover!(x != 5, "constant changed");
+}
+
+#[kani::proof]
+fn check_other_types() {
+    let nan = black_box(f32::NAN);
+    assert!(nan.is_nan());
+    let neg_zero = black_box(-0.0f64);
+    assert!(neg_zero.is_sign_negative());
+    let flag: bool = kani::any();
+    assert_eq!(black_box(flag), flag);
+    let data = [1u8, 2, 3];
+    let ptr = black_box(data.as_ptr());
+    assert_eq!(unsafe { *ptr.add(2) }, 3);
+    let pair = black_box((flag, 'x'));
+    assert_eq!(pair, (flag, 'x'));
+}
@@ -0,0 +1,20 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::bounded` can reach both ends of its range but nothing outside of it, and that
+//! an empty range is reported as a failure.
+
+#[kani::proof]
+fn check_range_ends() {
+    let x: i16 = kani::bounded(-3, 7);
+    kani::cover!(x == -3, "lower bound");
+    kani::cover!(x == 6, "upper bound minus one");
+    kani::cover!(x == 7, "upper bound");
+    kani::cover!(x == -4, "below lower bound");
+}
+
+#[kani::proof]
+fn check_empty_range() {
+    let lo: u8 = kani::any();
+    let _x: u8 = kani::bounded(lo, lo);
+}
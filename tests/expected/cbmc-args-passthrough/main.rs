@@ -0,0 +1,13 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// kani-flags: --enable-unstable --cbmc-args --xml-ui --object-bits 10 --verbosity 2
+
+//! Check that flags that would break the processing of CBMC's output are dropped from
+//! `--cbmc-args`, and that the user is warned about flags that override Kani's defaults.
+
+#[kani::proof]
+fn check_passthrough() {
+    let x: u8 = kani::any();
+    assert!(x.wrapping_add(1) != x);
+}
@@ -0,0 +1,20 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z unstable-options --cbmc-seed 7 --output-format json
+
+//! Check that `--cbmc-seed` is given to the solvers that accept a seed and recorded in the JSON
+//! output, and that it's ignored with a warning for the other solvers.
+
+#[kani::proof]
+#[kani::solver(kissat)]
+fn check_seeded() {
+    let x: u8 = kani::any();
+    assert!(x.wrapping_add(1) != x);
+}
+
+#[kani::proof]
+fn check_unseeded() {
+    let x: u8 = kani::any();
+    assert!(x.wrapping_mul(2) % 2 == 0);
+}
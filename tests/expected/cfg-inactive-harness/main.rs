@@ -0,0 +1,52 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// compile-flags: --edition 2021
+//! Check that Kani reports the harnesses excluded by `#[cfg(...)]` and the active harnesses that
+//! it verifies.
+
+#[kani::proof]
+fn check_active() {
+    let x: u8 = kani::any();
+    assert!(x.checked_add(0).is_some());
+}
+
+#[cfg(target_os = "kani_unknown_os")]
+#[kani::proof]
+fn check_other_os() {
+    assert!(false);
+}
+
+mod verify {
+    #[kani::proof]
+    #[cfg(feature = "missing")]
+    fn check_missing_feature() {
+        assert!(false);
+    }
+}
+
+mod imported {
+    use kani::proof;
+
+    #[proof]
+    fn check_imported_active() {}
+
+    #[cfg(feature = "missing")]
+    #[proof]
+    fn check_imported_inactive() {
+        assert!(false);
+    }
+}
+
+mod renamed {
+    use kani::proof as harness;
+
+    #[cfg(feature = "missing")]
+    #[harness]
+    fn check_renamed_inactive() {
+        assert!(false);
+    }
+
+    #[harness]
+    fn check_renamed_active() {}
+}
@@ -0,0 +1,33 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// kani-flags: -Z unstable-options --fix len --fix idx=7
+
+//! Check that `--fix` pins the values created by `kani::concrete::value`, either to their
+//! default or to the given value, without affecting other symbolic values.
+
+#[kani::proof]
+fn check_pinned_values() {
+    let len: usize = kani::concrete::value(4, "len");
+    let idx: u8 = kani::concrete::value(0, "idx");
+    let free: u8 = kani::concrete::value(0, "free");
+    let other: u8 = kani::any();
+    assert_eq!(len, 4);
+    assert_eq!(idx, 7);
+    kani::cover!(free != 0, "unpinned sites remain symbolic");
+    kani::cover!(other != 0, "kani::any() is not affected");
+}
+
+#[kani::proof]
+fn check_inconsistent_pin() {
+    let idx: u8 = kani::concrete::value(0, "idx");
+    kani::assume(idx < 5);
+    // The pin conflicts with the assumption above, so this is unreachable.
+    assert!(idx == 7);
+}
+
+#[kani::proof]
+fn check_invalid_pin() {
+    let idx: bool = kani::concrete::value(false, "idx");
+    assert!(!idx);
+}
@@ -0,0 +1,17 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --output-format terse
+
+//! Check that the terse output lists each cover property with its message and status, and that
+//! the form without a message is still supported.
+
+#[kani::proof]
+fn cover_branches() {
+    let v: Vec<u8> = if kani::any() { vec![] } else { vec![1] };
+    if v.is_empty() {
+        kani::cover!(true, "reached empty branch");
+    } else {
+        kani::cover!(v[0] == 2, "first element may be 2",);
+    }
+    kani::cover!(v.len() < 2);
+}
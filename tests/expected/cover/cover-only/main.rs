@@ -0,0 +1,17 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --cover-only
+
+//! Check that `--cover-only` only checks cover properties. The assertion and the
+//! overflow check below would fail otherwise, but they still prune the executions
+//! in which they fail.
+
+#[kani::proof]
+fn cover_only() {
+    let x: u8 = kani::any();
+    kani::assume(x > 10);
+    assert!(x < 20, "may fail");
+    let y = x + 240;
+    kani::cover!(y == 251);
+    kani::cover!(x >= 20);
+}
@@ -0,0 +1,21 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --cover-only --strict-cover
+
+//! Check that `--strict-cover` fails the harnesses with cover properties that aren't satisfied.
+
+#[kani::proof]
+fn all_satisfied() {
+    let x: u8 = kani::any();
+    kani::cover!(x == 0);
+    kani::cover!(x == u8::MAX);
+}
+
+#[kani::proof]
+fn one_unreachable() {
+    let x: u8 = kani::any();
+    kani::cover!(x == 0);
+    if x > 10 && x < 5 {
+        kani::cover!(true, "unreachable");
+    }
+}
@@ -0,0 +1,29 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --default-unwind 2
+
+//! Check that `#![kanitool::unwind(n)]` on the crate root sets the unwind bound of the harnesses
+//! without `#[kani::unwind]`, and that it takes precedence over `--default-unwind`.
+
+#![cfg_attr(kani, kanitool::unwind(5))]
+
+fn sum(n: u8) -> u32 {
+    let mut total = 0;
+    for i in 0..n {
+        total += i as u32;
+    }
+    total
+}
+
+/// Needs 5 iterations, so it only succeeds with the crate-level bound.
+#[kani::proof]
+fn check_crate_bound() {
+    assert_eq!(sum(4), 6);
+}
+
+/// The harness attribute takes precedence over the crate-level bound.
+#[kani::proof]
+#[kani::unwind(2)]
+fn check_harness_bound() {
+    assert_eq!(sum(4), 6);
+}
@@ -0,0 +1,14 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --deny vacuous
+
+//! Check that `--deny vacuous` reports harnesses that don't check any property as failures.
+
+#[kani::proof]
+fn check_nothing() {}
+
+#[kani::proof]
+fn check_something() {
+    let x: u8 = kani::any();
+    assert!(x.checked_add(0).is_some());
+}
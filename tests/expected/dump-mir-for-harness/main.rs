@@ -0,0 +1,26 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --dump-mir-for-harness check_add
+
+//! Check that `--dump-mir-for-harness` prints the MIR of the items reachable from the given
+//! harness, delimited by the harness name.
+
+fn add(a: u8, b: u8) -> u8 {
+    a.wrapping_add(b)
+}
+
+fn sub(a: u8, b: u8) -> u8 {
+    a.wrapping_sub(b)
+}
+
+mod proofs {
+    #[kani::proof]
+    fn check_add() {
+        assert_eq!(super::add(1, 2), 3);
+    }
+
+    #[kani::proof]
+    fn check_sub() {
+        assert_eq!(super::sub(3, 2), 1);
+    }
+}
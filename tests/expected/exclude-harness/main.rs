@@ -0,0 +1,22 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// kani-flags: --harness check --exclude-harness slow --exclude-harness missing
+
+//! Check that `--exclude-harness` skips the harnesses that match it, even if they also match
+//! `--harness`, and that filters that don't match any harness only produce a warning.
+
+#[kani::proof]
+fn check_fast() {
+    assert!(kani::any::<u8>() <= u8::MAX);
+}
+
+#[kani::proof]
+fn check_slow() {
+    assert!(false, "this harness should have been excluded");
+}
+
+#[kani::proof]
+fn check_other() {
+    assert!(kani::any::<bool>() || true);
+}
@@ -0,0 +1,17 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfloat-lib
+
+//! This test checks that `kani::float::assume_finite` still allows signed zeros and subnormal
+//! values, while pruning NaN and infinite values.
+
+#[kani::proof]
+fn check_assume_finite_values() {
+    let x: f64 = kani::any();
+    kani::float::assume_finite(x);
+    kani::cover!(x == 0.0 && x.is_sign_negative(), "negative zero");
+    kani::cover!(x.is_subnormal(), "subnormal");
+    kani::cover!(x == f64::MAX, "max");
+    kani::cover!(x.is_nan(), "nan");
+    kani::cover!(x.is_infinite(), "infinite");
+}
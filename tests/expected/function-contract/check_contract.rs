@@ -0,0 +1,38 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts --check-contract sum_to --unwind 11
+
+//! Check that `--check-contract` only runs the harnesses that verify the contract of the given
+//! function, and that it composes with `--unwind`.
+
+#[kani::requires(n <= 10)]
+#[kani::ensures(|result: &u32| *result == n * (n + 1) / 2)]
+fn sum_to(n: u32) -> u32 {
+    let mut sum = 0;
+    for i in 1..=n {
+        sum += i;
+    }
+    sum
+}
+
+#[kani::requires(x < 100)]
+#[kani::ensures(|result: &u32| *result == x + 2)]
+fn add_two(x: u32) -> u32 {
+    // Bug: the implementation does not satisfy the contract.
+    x + 1
+}
+
+#[kani::proof_for_contract(sum_to)]
+fn check_sum_to() {
+    sum_to(kani::any());
+}
+
+#[kani::proof_for_contract(add_two)]
+fn check_add_two() {
+    add_two(kani::any());
+}
+
+#[kani::proof]
+fn check_unrelated() {
+    assert!(false);
+}
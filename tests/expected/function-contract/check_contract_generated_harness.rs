@@ -0,0 +1,18 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts --check-contract add_one
+
+//! Check that `--check-contract` generates a harness that calls the function with arbitrary
+//! arguments if no harness verifies its contract.
+
+#[kani::requires(x < 100)]
+#[kani::ensures(|result: &u32| *result == x + 1)]
+fn add_one(x: u32) -> u32 {
+    x + 1
+}
+
+#[kani::proof]
+#[kani::stub_verified(add_one)]
+fn check_add_one_stub() {
+    assert!(add_one(1) == 2);
+}
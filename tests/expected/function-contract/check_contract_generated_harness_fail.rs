@@ -0,0 +1,13 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts --check-contract add_two
+
+//! Check that the harness generated by `--check-contract` detects a violation of the
+//! postcondition of the function.
+
+#[kani::requires(x < 100)]
+#[kani::ensures(|result: &u32| *result == x + 2)]
+fn add_two(x: u32) -> u32 {
+    // Bug: the implementation does not satisfy the contract.
+    x + 1
+}
@@ -0,0 +1,11 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts --check-contract first
+
+//! Check that `--check-contract` reports an error if no harness verifies the contract of the
+//! given function, and Kani cannot generate one since an argument isn't `kani::Arbitrary`.
+
+#[kani::requires(!s.is_empty())]
+fn first(s: &[u8]) -> u8 {
+    s[0]
+}
@@ -0,0 +1,26 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that `kani::implies!` can be used in contract clauses, and that a violated implication
+//! is reported.
+
+#[kani::ensures(|result: &u32| kani::implies!(x > 0, *result < x))]
+fn decrement(x: u32) -> u32 {
+    x.saturating_sub(1)
+}
+
+#[kani::ensures(|result: &u32| kani::implies!(x > 0, *result > x))]
+fn wrong_increment(x: u32) -> u32 {
+    x.saturating_add(1)
+}
+
+#[kani::proof_for_contract(decrement)]
+fn check_decrement() {
+    decrement(kani::any());
+}
+
+#[kani::proof_for_contract(wrong_increment)]
+fn check_wrong_increment() {
+    wrong_increment(kani::any());
+}
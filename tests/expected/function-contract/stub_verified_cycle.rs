@@ -0,0 +1,29 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts -Zverified-stub-dependencies
+
+//! Check that circular dependencies between contract harnesses are detected.
+
+#[kani::requires(x < 100)]
+#[kani::ensures(|result: &u32| *result <= x)]
+fn first(x: u32) -> u32 {
+    if x == 0 { 0 } else { second(x - 1) }
+}
+
+#[kani::requires(x < 100)]
+#[kani::ensures(|result: &u32| *result <= x)]
+fn second(x: u32) -> u32 {
+    if x == 0 { 0 } else { first(x - 1) }
+}
+
+#[kani::proof_for_contract(first)]
+#[kani::stub_verified(second)]
+fn check_first() {
+    first(kani::any());
+}
+
+#[kani::proof_for_contract(second)]
+#[kani::stub_verified(first)]
+fn check_second() {
+    second(kani::any());
+}
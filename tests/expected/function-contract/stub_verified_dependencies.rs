@@ -0,0 +1,41 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts -Zverified-stub-dependencies
+
+//! Check that the contract of a function is only used as a verified stub after its contract
+//! harness succeeds.
+
+#[kani::requires(x < 100)]
+#[kani::ensures(|result: &u32| *result == x + 1)]
+fn add_one(x: u32) -> u32 {
+    x + 1
+}
+
+#[kani::requires(x < 100)]
+#[kani::ensures(|result: &u32| *result == x + 2)]
+fn add_two(x: u32) -> u32 {
+    // Bug: the implementation does not satisfy the contract.
+    x + 1
+}
+
+#[kani::proof]
+#[kani::stub_verified(add_one)]
+fn use_add_one() {
+    assert_eq!(add_one(10), 11);
+}
+
+#[kani::proof]
+#[kani::stub_verified(add_two)]
+fn use_add_two() {
+    assert_eq!(add_two(10), 12);
+}
+
+#[kani::proof_for_contract(add_one)]
+fn check_add_one() {
+    add_one(kani::any());
+}
+
+#[kani::proof_for_contract(add_two)]
+fn check_add_two() {
+    add_two(kani::any());
+}
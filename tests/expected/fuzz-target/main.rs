@@ -0,0 +1,21 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that fuzz targets can be used as harnesses, and that Kani explores every input up to
+//! the given `max_input` length.
+
+#[kani::proof(max_input = 4)]
+fn parse_header(data: &[u8]) {
+    kani::cover!(data.is_empty(), "empty input");
+    kani::cover!(data.len() == 4, "max length input");
+    kani::cover!(data.len() == 5, "input longer than max_input");
+    if data.len() >= 3 && data[0] == b'K' && data[1] == b'N' {
+        assert!(data[2] != 0, "header version cannot be zero");
+    }
+}
+
+#[kani::proof(max_input = 8)]
+fn checked_parse(data: &[u8]) -> Option<u16> {
+    let bytes = data.get(..2)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
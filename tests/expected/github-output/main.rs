@@ -0,0 +1,25 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: --output-format github
+//! Check that `--output-format github` prints an `::error` workflow command for each failed
+//! property, in addition to the regular output and the final summary.
+
+#[kani::proof]
+fn check_two_failures() {
+    let x: u8 = kani::any();
+    assert!(x > 10, "x is too small");
+    assert!(x < 5, "x is too large");
+}
+
+#[kani::proof]
+fn check_pass() {
+    let x: u8 = kani::any();
+    assert!(x <= u8::MAX);
+}
+
+#[kani::proof]
+#[kani::should_panic]
+fn check_expected_panic() {
+    panic!("expected");
+}
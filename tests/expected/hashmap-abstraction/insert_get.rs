@@ -0,0 +1,50 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --enable-unstable --hashmap-abstraction --hashmap-capacity 2
+
+//! Check that `--hashmap-abstraction` models `HashMap` as a logical map, and that inserting more
+//! keys than the given capacity fails verification.
+
+use std::collections::HashMap;
+
+#[kani::proof]
+fn check_insert_then_get() {
+    let key: u32 = kani::any();
+    let value: u64 = kani::any();
+    let mut map = HashMap::new();
+    assert_eq!(map.insert(key, value), None);
+    assert_eq!(map.get(&key), Some(&value));
+    assert!(map.contains_key(&key));
+}
+
+#[kani::proof]
+fn check_overwrite() {
+    let (k1, k2): (u8, u8) = kani::any();
+    let mut map = HashMap::new();
+    map.insert(k1, 'a');
+    map.insert(k2, 'b');
+    assert_eq!(map.insert(k1, 'c'), Some(if k1 == k2 { 'b' } else { 'a' }));
+    assert_eq!(map.len(), if k1 == k2 { 1 } else { 2 });
+    assert_eq!(map.get(&k2), Some(if k1 == k2 { &'c' } else { &'b' }));
+}
+
+#[kani::proof]
+fn check_capacity_exceeded() {
+    let mut map = HashMap::new();
+    for key in 0..3u8 {
+        map.insert(key, ());
+    }
+}
+
+#[kani::proof]
+fn check_entry_capacity_exceeded() {
+    let mut map = HashMap::from([(0u8, 0u8), (1, 1)]);
+    *map.entry(1).or_default() += 1;
+    *map.entry(2).or_default() += 1;
+}
+
+#[kani::proof]
+fn check_collect_capacity_exceeded() {
+    let map: HashMap<u8, u8> = (0..3).map(|key| (key, key)).collect();
+    assert_eq!(map.len(), 3);
+}
@@ -0,0 +1,19 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that the addition of symbolic durations may overflow, and that reading the system
+//! clock is reported as unsupported with a pointer to `kani::time`.
+
+use std::time::{Duration, Instant};
+
+#[kani::proof]
+fn check_add_overflow() {
+    let first = kani::time::any_duration();
+    let _sum = first + Duration::from_secs(1);
+}
+
+#[kani::proof]
+fn check_instant_now() {
+    let start = Instant::now();
+    let _elapsed = start.elapsed();
+}
@@ -0,0 +1,20 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: --harness check_len__
+
+//! Check the names of the harnesses generated by `#[kani::instantiate(...)]`, and that they can be
+//! selected with `--harness`.
+
+#[kani::proof]
+#[kani::instantiate((T = u8, N = 1), (T = Option<u8>, N = -1))]
+fn check_len<T: kani::Arbitrary, const N: i8>() {
+    let _value: T = kani::any();
+    assert!(N != 0);
+}
+
+#[kani::proof]
+#[kani::instantiate(T = u8)]
+fn check_other<T: Default + PartialEq>() {
+    assert!(T::default() == T::default());
+}
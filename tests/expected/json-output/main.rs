@@ -0,0 +1,27 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: --output-format json
+
+//! Check that `--output-format json` prints one JSON line per harness, with the counterexample
+//! trace of failed properties only.
+
+#[kani::proof]
+fn check_fail() {
+    let x: u8 = kani::any();
+    assert!(x < 200);
+}
+
+#[kani::proof]
+fn check_pass() {
+    let x: u8 = kani::any();
+    assert!(x <= u8::MAX);
+}
+
+#[kani::proof]
+#[kani::should_panic]
+fn check_should_panic() {
+    let x: u8 = kani::any();
+    kani::assume(x == 3);
+    assert!(x != 3);
+}
@@ -0,0 +1,24 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: --keep-going
+
+//! Check that with `--keep-going`, a harness whose model can't be generated is reported as an
+//! error, while the other harnesses of the crate are still verified.
+//! Kani reports an error for `ctpop` on a float while generating the model of
+//! `check_codegen_error`, which would otherwise fail the compilation of the crate.
+
+#![feature(core_intrinsics)]
+#![allow(internal_features)]
+
+#[kani::proof]
+fn check_codegen_error() {
+    let x: f32 = kani::any();
+    let _ = std::intrinsics::ctpop(x);
+}
+
+#[kani::proof]
+fn check_success() {
+    let x: u8 = kani::any();
+    assert!(x.count_ones() <= 8);
+}
@@ -0,0 +1,55 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z loop-contracts
+
+//! Check that `kani::loop_check!` is an assertion evaluated at every iteration of a loop,
+//! reported at the location of the macro, and that, unlike a loop contract, it doesn't remove the
+//! need to unwind the loop.
+
+#![feature(stmt_expr_attributes)]
+#![feature(proc_macro_hygiene)]
+
+#[kani::proof]
+#[kani::unwind(11)]
+fn check_holds() {
+    let mut sum: u32 = 0;
+    for i in 0..10u32 {
+        sum += i;
+        kani::loop_check!(sum <= i * (i + 1) / 2);
+    }
+    assert_eq!(sum, 45);
+}
+
+#[kani::proof]
+#[kani::unwind(11)]
+fn check_fails_at_later_iteration() {
+    let mut sum: u32 = 0;
+    for i in 0..10u32 {
+        sum += i;
+        kani::loop_check!(sum < 20, "sum stays below 20");
+    }
+}
+
+/// The loop check doesn't help to bound the loop, so the unwinding assertion fails.
+#[kani::proof]
+#[kani::unwind(3)]
+fn check_still_needs_unwinding() {
+    let mut idx: u8 = 0;
+    while idx < 5 {
+        kani::loop_check!(idx < 5);
+        idx += 1;
+    }
+}
+
+/// In contrast, a loop invariant abstracts the loop, so the same loop is verified with a smaller
+/// unwind bound.
+#[kani::proof]
+#[kani::unwind(3)]
+fn check_invariant_needs_no_unwinding() {
+    let mut idx: u8 = 0;
+    #[kani::loop_invariant(idx <= 5)]
+    while idx < 5 {
+        idx += 1;
+    }
+    assert!(idx == 5);
+}
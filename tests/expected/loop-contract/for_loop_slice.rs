@@ -0,0 +1,54 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// kani-flags: -Z loop-contracts
+
+//! Check that loop contracts can be applied to for-loops over slices, arrays and their iterators.
+
+#![feature(stmt_expr_attributes)]
+#![feature(proc_macro_hygiene)]
+
+#[kani::proof]
+fn for_loop_slice_harness() {
+    let arr: [u8; 10] = kani::any();
+    let slice = kani::slice::any_slice_of_array(&arr);
+    let mut sum: u32 = 0;
+
+    #[kani::loop_invariant(sum <= kani::index as u32 * 255)]
+    for x in slice {
+        sum += *x as u32;
+    }
+
+    assert!(sum <= slice.len() as u32 * 255);
+}
+
+#[kani::proof]
+fn for_loop_iter_harness() {
+    let arr: [u8; 20] = kani::any();
+    let mut count: usize = 0;
+
+    #[kani::loop_invariant(count == kani::index)]
+    for _ in arr.iter() {
+        count += 1;
+    }
+
+    assert!(count == arr.len());
+}
+
+#[kani::proof]
+fn for_loop_iter_mut_harness() {
+    let mut arr: [u8; 10] = kani::any();
+    // The invariant reads the array through a raw pointer, since `iter_mut` borrows it.
+    let first = arr.as_ptr();
+    let mut count: usize = 0;
+
+    #[kani::loop_invariant(count == kani::index
+        && (kani::index == 0 || unsafe { *first == 0 && *first.add(kani::index - 1) == 0 }))]
+    for x in arr.iter_mut() {
+        *x = 0;
+        count += 1;
+    }
+
+    assert!(count == 10);
+    assert!(arr[0] == 0 && arr[9] == 0);
+}
@@ -0,0 +1,23 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that leaking memory is not reported, but that using a `ManuallyDrop` box after dropping
+//! it explicitly is still reported as a use-after-free.
+
+use std::mem::ManuallyDrop;
+
+#[kani::proof]
+fn check_leak_is_not_an_error() {
+    let leaked: &'static mut u32 = Box::leak(Box::new(kani::any()));
+    *leaked = 5;
+    let _kept = ManuallyDrop::new(Box::new(10u32));
+    assert_eq!(*leaked, 5);
+}
+
+#[kani::proof]
+fn check_use_after_manual_drop() {
+    let mut boxed = ManuallyDrop::new(Box::new(10u32));
+    let ptr: *const u32 = &**boxed;
+    unsafe { ManuallyDrop::drop(&mut boxed) };
+    let _val = unsafe { *ptr };
+}
@@ -0,0 +1,12 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z unstable-options --max-memory 1
+
+//! Check that Kani stops CBMC when it goes over `--max-memory`, and reports it separately from
+//! verification failures. CBMC uses more than 1 MB to load any program.
+
+#[kani::proof]
+fn check_over_limit() {
+    let x: u32 = kani::any();
+    assert!(x.wrapping_add(1) != 0 || x == u32::MAX);
+}
@@ -0,0 +1,66 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z mem-predicates
+
+//! Check `kani::mem::is_aligned` and `kani::mem::assume_aligned`, and that a misaligned read
+//! is flagged if the pointer isn't constrained.
+
+#[repr(C, align(8))]
+struct Buffer([u8; 64]);
+
+#[repr(align(32))]
+struct OverAligned(u64);
+
+/// Return a pointer to a symbolic offset inside the buffer.
+fn any_ptr_in(buffer: &Buffer) -> *const u32 {
+    let offset: usize = kani::any_where(|offset| *offset < 32);
+    unsafe { buffer.0.as_ptr().add(offset) as *const u32 }
+}
+
+#[kani::proof]
+fn check_misaligned_read_fails() {
+    let buffer = Buffer([0; 64]);
+    let ptr = any_ptr_in(&buffer);
+    let _value = unsafe { *ptr };
+}
+
+#[kani::proof]
+fn check_assume_aligned_read() {
+    let buffer = Buffer([0; 64]);
+    let ptr = any_ptr_in(&buffer);
+    kani::mem::assume_aligned(ptr);
+    assert!(kani::mem::is_aligned::<u32>(ptr));
+    assert_eq!(ptr as usize % 4, 0);
+    let value = unsafe { *ptr };
+    assert_eq!(value, 0);
+}
+
+#[kani::proof]
+fn check_is_aligned() {
+    let buffer = Buffer([0; 64]);
+    let ptr = buffer.0.as_ptr();
+    assert!(kani::mem::is_aligned(ptr as *const u64));
+    assert!(!kani::mem::is_aligned(unsafe { ptr.add(2) } as *const u64));
+    assert!(kani::mem::is_aligned(unsafe { ptr.add(2) } as *const u16));
+    // Every pointer is aligned for `u8`.
+    assert!(kani::mem::is_aligned(unsafe { ptr.add(3) }));
+}
+
+#[kani::proof]
+fn check_over_aligned() {
+    let value = OverAligned(0);
+    let ptr = &value as *const OverAligned;
+    assert!(kani::mem::is_aligned(ptr));
+    let inner = unsafe { ptr.byte_add(8) };
+    assert!(!kani::mem::is_aligned(inner));
+    assert!(kani::mem::is_aligned(inner as *const u64));
+}
+
+#[kani::proof]
+fn check_trait_object() {
+    let value = OverAligned(0);
+    let ptr = &value as *const OverAligned as *const dyn Send;
+    assert!(kani::mem::is_aligned(ptr));
+    assert!(!kani::mem::is_aligned(unsafe { ptr.byte_add(16) }));
+}
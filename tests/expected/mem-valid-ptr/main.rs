@@ -0,0 +1,47 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z mem-predicates
+
+//! Check `kani::mem::assert_valid_ptr` and `kani::mem::assume_valid_ptr`.
+
+#[kani::proof]
+fn check_valid_ptr() {
+    let value = 10u32;
+    let ptr = &value as *const u32;
+    kani::mem::assert_valid_ptr(ptr);
+    kani::mem::assert_valid_ptr(std::ptr::NonNull::<()>::dangling().as_ptr());
+    assert_eq!(unsafe { *ptr }, 10);
+}
+
+#[kani::proof]
+fn check_dangling_ptr() {
+    let ptr = {
+        let boxed = Box::new(10u32);
+        &*boxed as *const u32
+    };
+    kani::mem::assert_valid_ptr(ptr);
+}
+
+#[kani::proof]
+fn check_null_ptr() {
+    kani::mem::assert_valid_ptr(std::ptr::null::<u64>());
+}
+
+#[kani::proof]
+fn check_out_of_bounds_ptr() {
+    let data = [0u32; 4];
+    // The pointer is aligned, but only the first element of the pair is inside of the array.
+    let ptr = data.as_ptr().wrapping_add(3) as *const [u32; 2];
+    kani::mem::assert_valid_ptr(ptr);
+}
+
+#[kani::proof]
+fn check_assume_valid_ptr() {
+    let data = [0u32; 4];
+    let idx: usize = kani::any();
+    let ptr = data.as_ptr().wrapping_add(idx);
+    kani::mem::assume_valid_ptr(ptr);
+    assert!(idx < 4);
+    assert_eq!(unsafe { *ptr }, 0);
+}
@@ -0,0 +1,33 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that Kani reports references and reads through misaligned pointers to fields of
+//! `#[repr(packed)]` structs.
+
+use std::ptr::addr_of;
+
+#[repr(C, packed)]
+struct Packed {
+    tag: u8,
+    value: u32,
+}
+
+/// Ensure the packed struct starts at an aligned address, so `value` is always misaligned.
+#[repr(C, align(4))]
+struct Wrapper {
+    packed: Packed,
+}
+
+#[kani::proof]
+fn check_misaligned_reference() {
+    let wrapper = Wrapper { packed: Packed { tag: 0, value: 10 } };
+    let ptr = addr_of!(wrapper.packed.value);
+    let _ref = unsafe { &*ptr };
+}
+
+#[kani::proof]
+fn check_misaligned_read() {
+    let wrapper = Wrapper { packed: Packed { tag: 0, value: 10 } };
+    let ptr = addr_of!(wrapper.packed.value);
+    let _val = unsafe { *ptr };
+}
@@ -0,0 +1,41 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a panic stops the harness without running the `Drop` implementation of the values
+//! in scope, since Kani verifies crates with the `abort` panic strategy.
+
+static mut LOCKED: bool = false;
+
+struct Lock;
+
+impl Lock {
+    fn acquire() -> Lock {
+        unsafe { LOCKED = true };
+        Lock
+    }
+}
+
+impl Drop for Lock {
+    fn drop(&mut self) {
+        unsafe { LOCKED = false };
+        kani::cover!(unsafe { PANICKED }, "cleanup after panic");
+    }
+}
+
+static mut PANICKED: bool = false;
+
+fn update(value: u8) {
+    let _lock = Lock::acquire();
+    if value > 100 {
+        unsafe { PANICKED = true };
+        panic!("value too large");
+    }
+}
+
+#[kani::proof]
+fn check_no_cleanup_after_panic() {
+    let value: u8 = kani::any();
+    update(value);
+    kani::cover!(value > 100, "returned after panic");
+    assert!(unsafe { !LOCKED });
+}
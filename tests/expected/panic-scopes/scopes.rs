@@ -0,0 +1,54 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zpanic-scopes
+
+//! Check that panics are only reported outside of the scopes that permit them.
+
+fn checked_div(a: u8, b: u8) -> u8 {
+    if b == 0 {
+        panic!("division by zero");
+    }
+    a / b
+}
+
+#[kani::proof]
+fn check_panic_in_permitted_scope() {
+    let divisor: u8 = kani::any();
+    let result = kani::panic::catch(|| checked_div(10, divisor));
+    // Only executions that did not panic reach this point.
+    assert!(divisor != 0);
+    assert!(result <= 10);
+}
+
+#[kani::proof]
+fn check_panic_outside_scope() {
+    let divisor: u8 = kani::any();
+    let _ = kani::panic::catch(|| checked_div(10, 1));
+    checked_div(10, divisor);
+}
+
+#[kani::proof]
+fn check_runtime_check_in_permitted_scope() {
+    let index: usize = kani::any();
+    let values = [1, 2, 3];
+    let value = kani::panic::catch(|| values[index]);
+    assert!(values.contains(&value));
+}
+
+#[kani::proof]
+fn check_nested_no_panic_scope() {
+    let divisor: u8 = kani::any();
+    kani::panic::catch(|| {
+        kani::panic::assert_no_panic(|| checked_div(10, divisor));
+    });
+}
+
+#[kani::proof]
+fn check_nested_permitted_scope() {
+    let divisor: u8 = kani::any();
+    kani::panic::assert_no_panic(|| {
+        kani::panic::catch(|| checked_div(10, divisor));
+        // The previous scope is restored after `catch` returns.
+        checked_div(10, 0);
+    });
+}
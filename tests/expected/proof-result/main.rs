@@ -0,0 +1,65 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that harnesses can return a `Result` and use `?`, that an `Err` fails the verification
+//! by default, and that `on_error = "assume"` discards the executions that return an `Err`.
+
+#[derive(Debug)]
+struct TooLarge;
+
+fn half(x: u8) -> Result<u8, TooLarge> {
+    if x > 100 { Err(TooLarge) } else { Ok(x / 2) }
+}
+
+/// An error that is dropped by the harness wrapper, i.e., after the result was checked.
+#[derive(Debug)]
+struct Discarded;
+
+impl From<TooLarge> for Discarded {
+    fn from(_: TooLarge) -> Self {
+        Discarded
+    }
+}
+
+impl Drop for Discarded {
+    fn drop(&mut self) {
+        kani::cover!(true, "error is dropped after the result was checked");
+    }
+}
+
+#[kani::proof]
+fn check_ok() -> Result<(), TooLarge> {
+    let x: u8 = kani::any_where(|x| *x <= 100);
+    let h = half(x)?;
+    assert!(h <= 50);
+    Ok(())
+}
+
+#[kani::proof]
+fn check_err_fails() -> Result<(), TooLarge> {
+    let x: u8 = kani::any();
+    let h = half(x)?;
+    assert!(h <= 50);
+    Ok(())
+}
+
+#[kani::proof(on_error = "assume")]
+fn check_err_assumed() -> Result<(), Discarded> {
+    let x: u8 = kani::any();
+    let result = half(x);
+    // The error path is reachable, but it is discarded when the harness returns, so the error is
+    // never dropped.
+    kani::cover!(result.is_err(), "error path is reachable");
+    let h = result?;
+    assert!(h <= 50);
+    Ok(())
+}
+
+/// Type aliases of `Result` are also supported.
+#[kani::proof]
+fn check_io_result() -> std::io::Result<()> {
+    let x: u8 = kani::any_where(|x| *x != 0);
+    let inverse = if x == 0 { Err(std::io::ErrorKind::InvalidInput)? } else { u8::MAX / x };
+    assert!(inverse >= 1);
+    Ok(())
+}
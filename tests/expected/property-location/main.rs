@@ -0,0 +1,32 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --output-format regular
+
+//! Check that the location of the properties generated by `kani::assert`, `kani::cover` and the
+//! `assert!` family of macros is the line where the user wrote them, even through nested macros.
+
+macro_rules! check_positive {
+    ($val:expr) => {
+        assert!($val > 0, "value must be positive")
+    };
+}
+
+macro_rules! check_twice {
+    ($val:expr) => {
+        check_positive!($val);
+        kani::cover!($val == 1, "value can be one");
+    };
+}
+
+#[kani::proof]
+fn check_direct() {
+    let x: i8 = kani::any();
+    kani::assert(x != 10, "direct assert");
+    kani::cover!(x == 5, "direct cover");
+}
+
+#[kani::proof]
+fn check_nested_macros() {
+    let x: i8 = kani::any();
+    check_twice!(x);
+}
@@ -0,0 +1,24 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Check that Kani detects an access to the contents of an `Rc` after its last strong reference
+//! has been dropped, while accesses through a live reference succeed.
+
+use std::rc::Rc;
+
+#[kani::proof]
+fn check_access_after_last_drop() {
+    let first = Rc::new(kani::any::<u32>());
+    let second = first.clone();
+    let ptr = Rc::as_ptr(&first);
+
+    // The value is still alive since `second` holds a reference to it.
+    drop(first);
+    assert_eq!(Rc::strong_count(&second), 1);
+    let value = unsafe { *ptr };
+    assert_eq!(value, *second);
+
+    // This deallocates the value, so the access below is a use-after-free.
+    drop(second);
+    let _invalid = unsafe { *ptr };
+}
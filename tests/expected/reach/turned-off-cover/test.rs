@@ -0,0 +1,20 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// This test checks that turning off assertion reachability checks does not
+// affect cover properties, whose status depends on their reachability.
+
+// kani-flags: --no-assertion-reach-checks
+
+#[kani::proof]
+fn main() {
+    let x: i32 = kani::any();
+    if x > 10 {
+        if x < 5 {
+            kani::cover!(x == 2); // unreachable
+        }
+        assert!(x != 2);
+    } else {
+        kani::cover!(x == 5); // satisfiable
+    }
+}
@@ -0,0 +1,14 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --deny-warnings
+
+//! Check that `--deny-warnings` turns the unbounded recursion warning into an error.
+
+fn count_down(n: u8) -> u8 {
+    if n == 0 { 0 } else { count_down(n - 1) }
+}
+
+#[kani::proof]
+fn check_recursion() {
+    assert_eq!(count_down(3), 0);
+}
@@ -0,0 +1,33 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that Kani warns about harnesses that reach recursive functions without an unwind bound,
+//! including mutually recursive functions.
+
+fn is_even(n: u32) -> bool {
+    if n == 0 { true } else { is_odd(n - 1) }
+}
+
+fn is_odd(n: u32) -> bool {
+    if n == 0 { false } else { is_even(n - 1) }
+}
+
+fn fib(n: u32) -> u32 {
+    if n < 2 { n } else { fib(n - 1) + fib(n - 2) }
+}
+
+#[kani::proof]
+fn check_mutual_recursion() {
+    assert!(is_even(4));
+}
+
+#[kani::proof]
+fn check_self_recursion() {
+    assert_eq!(fib(5), 5);
+}
+
+#[kani::proof]
+#[kani::unwind(7)]
+fn check_bounded_recursion() {
+    assert_eq!(fib(5), 5);
+}
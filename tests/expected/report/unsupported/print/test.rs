@@ -0,0 +1,22 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --print-unsupported
+//! Check that `--print-unsupported` lists the unsupported features reachable from each harness,
+//! and the harnesses that don't reach any as clean, without running the verification.
+
+fn unsupp() {
+    unsafe {
+        std::arch::asm!("nop");
+    }
+}
+
+#[kani::proof]
+fn check_asm() {
+    unsupp();
+}
+
+#[kani::proof]
+fn check_clean() {
+    let x: u8 = kani::any();
+    assert!(x.checked_add(1).is_some() || x == u8::MAX);
+}
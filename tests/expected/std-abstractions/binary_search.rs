@@ -0,0 +1,50 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z unstable-options --std-abstractions
+
+//! Check that `--std-abstractions` replaces `<[T]>::binary_search` by a model of its
+//! postcondition, and that both `Ok` and `Err` results are reachable for sorted slices.
+
+fn is_sorted(slice: &[u8]) -> bool {
+    slice.windows(2).all(|w| w[0] <= w[1])
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_sorted() {
+    let arr: [u8; 4] = kani::any();
+    kani::assume(is_sorted(&arr));
+    let key: u8 = kani::any();
+    match arr.binary_search(&key) {
+        Ok(idx) => {
+            kani::cover!(true, "found");
+            assert_eq!(arr[idx], key);
+        }
+        Err(idx) => {
+            kani::cover!(true, "not found");
+            assert!(idx <= arr.len());
+            assert!(arr[..idx].iter().all(|e| *e < key));
+            assert!(arr[idx..].iter().all(|e| *e > key));
+            assert!(!arr.contains(&key));
+        }
+    }
+}
+
+/// The abstraction is only sound for sorted slices. Show that the code after searching a slice
+/// where no index is consistent with a sorted slice is unreachable: `1` is only equal to the last
+/// element, but it is smaller than the first one.
+#[kani::proof]
+fn check_unsorted() {
+    let arr: [u8; 3] = [2, 0, 1];
+    let result = arr.binary_search(&1);
+    kani::cover!(result.is_ok() || result.is_err(), "unsorted");
+}
+
+/// An unsorted slice may still get a result, which only agrees with the neighbors of the returned
+/// index and with the first and last elements, e.g., `2` is reported missing.
+#[kani::proof]
+fn check_unsorted_inconsistent() {
+    let arr: [u8; 4] = [1, 3, 2, 4];
+    let result = arr.binary_search(&2);
+    kani::cover!(result == Err(1), "key reported missing");
+}
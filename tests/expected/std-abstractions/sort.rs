@@ -0,0 +1,56 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z unstable-options --std-abstractions
+
+//! Check that `--std-abstractions` replaces the slice sorting functions by a model that returns a
+//! sorted permutation of the input, and that the comparator is respected.
+
+/// Check that `sorted` contains the same elements as `orig`, counting repetitions.
+fn is_permutation(orig: &[u8], sorted: &[u8]) -> bool {
+    orig.len() == sorted.len()
+        && orig.iter().all(|x| {
+            orig.iter().filter(|y| *y == x).count() == sorted.iter().filter(|y| *y == x).count()
+        })
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_sort() {
+    let orig: [u8; 4] = kani::any();
+    let mut arr = orig;
+    arr.sort();
+    assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+    assert!(is_permutation(&orig, &arr));
+    // A proof that relies on sortedness succeeds.
+    assert!(orig.iter().all(|x| *x >= arr[0] && *x <= arr[3]));
+    kani::cover!(orig != arr, "sorting changed the order");
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_sort_unstable_by_reverse() {
+    let orig: [u8; 4] = kani::any();
+    let mut arr = orig;
+    arr.sort_unstable_by(|a, b| b.cmp(a));
+    assert!(arr.windows(2).all(|w| w[0] >= w[1]));
+    assert!(is_permutation(&orig, &arr));
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_vec_sort_by_key() {
+    let orig: [(u8, char); 3] = [(2, 'a'), (0, 'b'), (1, 'c')];
+    let mut vec = orig.to_vec();
+    vec.sort_by_key(|(key, _)| *key);
+    assert_eq!(vec, [(0, 'b'), (1, 'c'), (2, 'a')]);
+}
+
+/// The model doesn't keep equal elements in their original order.
+#[kani::proof]
+#[kani::unwind(4)]
+fn check_sort_not_stable() {
+    let mut arr = [(1u8, 'a'), (0, 'b'), (1, 'c')];
+    arr.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(arr[0], (0, 'b'));
+    kani::cover!(arr[1] == (1, 'c'), "equal elements swapped");
+}
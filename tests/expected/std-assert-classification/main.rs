@@ -0,0 +1,30 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `assert!` and `debug_assert!` from std are reported as regular assertions, just
+//! like `kani::assert`. Kani's version of the standard library maps these macros to
+//! `kani::assert`, and `core::assert!` lowers to a panic, which is also an assertion.
+
+#[kani::proof]
+fn check_std_assert() {
+    let x: u8 = kani::any();
+    assert!(x > 0);
+}
+
+#[kani::proof]
+fn check_std_debug_assert() {
+    let x: u8 = kani::any();
+    debug_assert!(x > 0);
+}
+
+#[kani::proof]
+fn check_core_assert() {
+    let x: u8 = kani::any();
+    core::assert!(x > 0);
+}
+
+#[kani::proof]
+fn check_kani_assert() {
+    let x: u8 = kani::any();
+    kani::assert(x > 0, "assertion failed: x > 0");
+}
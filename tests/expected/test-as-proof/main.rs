@@ -0,0 +1,35 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `#[test]` functions can be verified with `#[kani::proof]`.
+
+fn checked_average(a: u8, b: u8) -> Option<u8> {
+    a.checked_add(b).map(|sum| sum / 2)
+}
+
+#[kani::proof]
+#[test]
+fn check_average() {
+    assert_eq!(checked_average(2, 4), Some(3));
+}
+
+#[kani::proof]
+#[test]
+fn check_average_overflow() {
+    assert_eq!(checked_average(200, 100), Some(150), "average overflows");
+}
+
+#[kani::proof]
+#[test]
+#[should_panic]
+fn check_average_unwrap() {
+    checked_average(255, 1).unwrap();
+}
+
+#[kani::proof]
+#[test]
+#[ignore]
+fn check_average_any() {
+    let a: u8 = kani::any();
+    assert!(checked_average(a, 0).is_some());
+}
@@ -0,0 +1,35 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z uninit-checks
+
+//! Check that with `-Z uninit-checks`, Kani flags transmutes that read the padding bytes of the source value, while
+//! transmutes that only read data bytes succeed.
+
+#[repr(C)]
+#[derive(kani::Arbitrary)]
+struct WithPadding {
+    byte: u8,
+    // There are 3 padding bytes before this field.
+    word: u32,
+}
+
+#[repr(C)]
+#[derive(kani::Arbitrary)]
+struct NoPadding {
+    first: u16,
+    second: u16,
+}
+
+#[kani::proof]
+fn check_padding_to_bytes() {
+    let value: WithPadding = kani::any();
+    let _bytes = unsafe { std::mem::transmute::<WithPadding, [u8; 8]>(value) };
+}
+
+#[kani::proof]
+fn check_no_padding_to_u32() {
+    let value: NoPadding = kani::any();
+    let first = value.first;
+    let num = unsafe { std::mem::transmute::<NoPadding, u32>(value) };
+    assert_eq!(num.to_ne_bytes()[0..2], first.to_ne_bytes());
+}
@@ -0,0 +1,15 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that, without `-Z uninit-checks`, reading an uninitialized `MaybeUninit` yields a
+//! nondeterministic value instead of zero.
+
+use std::mem::MaybeUninit;
+
+#[kani::proof]
+fn check_uninit_is_nondet() {
+    let m = MaybeUninit::<u8>::uninit();
+    let v = unsafe { m.assume_init() };
+    kani::cover!(v == 0);
+    kani::cover!(v != 0);
+}
@@ -0,0 +1,49 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z uninit-checks
+
+//! Checks that Kani catches reads of uninitialized memory through `MaybeUninit`, and that reading
+//! fully initialized values does not produce false positives.
+
+use std::mem::MaybeUninit;
+use std::ptr::addr_of_mut;
+
+#[kani::proof]
+fn assume_init_uninit_should_fail() {
+    let m = MaybeUninit::<u8>::uninit();
+    let _v = unsafe { m.assume_init() }; // ~ERROR: reading from uninitialized memory is UB.
+}
+
+#[kani::proof]
+fn as_ptr_uninit_should_fail() {
+    let m = MaybeUninit::<u32>::uninit();
+    let _v = unsafe { *m.as_ptr() }; // ~ERROR: reading from uninitialized memory is UB.
+}
+
+#[kani::proof]
+fn write_then_assume_init_should_pass() {
+    let mut m = MaybeUninit::<u8>::uninit();
+    m.write(5);
+    assert_eq!(unsafe { m.assume_init() }, 5);
+}
+
+#[repr(C)]
+struct S {
+    a: u8,
+    // There is one byte of padding here, which is never initialized.
+    b: u16,
+}
+
+/// Initializing every field is enough, even if the padding bytes are never written.
+#[kani::proof]
+fn init_fields_then_assume_init_should_pass() {
+    let mut m = MaybeUninit::<S>::uninit();
+    let ptr = m.as_mut_ptr();
+    unsafe {
+        addr_of_mut!((*ptr).a).write(1);
+        addr_of_mut!((*ptr).b).write(2);
+    }
+    let s = unsafe { m.assume_init() };
+    assert_eq!(s.a, 1);
+    assert_eq!(s.b, 2);
+}
@@ -0,0 +1,104 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a fuzz target written for bytes decoded with `Unstructured` can be verified by
+//! decoding its input from a symbolic buffer of bounded length.
+
+extern crate kani;
+
+use kani::arbitrary::{Decode, Unstructured};
+
+#[derive(Debug, Decode)]
+enum Command {
+    Push(u8),
+    Pop,
+}
+
+/// A stack with a fixed capacity that rejects pushes when it is full.
+struct Stack {
+    items: [u8; 2],
+    len: usize,
+}
+
+impl Stack {
+    fn push(&mut self, item: u8) -> bool {
+        if self.len == self.items.len() {
+            return false;
+        }
+        self.items[self.len] = item;
+        self.len += 1;
+        true
+    }
+
+    fn pop(&mut self) -> Option<u8> {
+        self.len = self.len.checked_sub(1)?;
+        Some(self.items[self.len])
+    }
+}
+
+/// The body of a fuzz target, e.g., `fuzz_target!(|data: &[u8]| fuzz_stack(data))`.
+fn fuzz_stack(data: &[u8]) {
+    let mut input = Unstructured::new(data);
+    let Ok(commands) = input.decode::<Vec<Command>>() else { return };
+    let mut stack = Stack { items: [0; 2], len: 0 };
+    for command in commands {
+        match command {
+            Command::Push(item) => {
+                if stack.push(item) {
+                    assert_eq!(stack.items[stack.len - 1], item);
+                }
+            }
+            Command::Pop => {
+                stack.pop();
+            }
+        }
+        assert!(stack.len <= 2);
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(9)]
+fn check_fuzz_stack() {
+    let data: Vec<u8> = kani::vec::any_vec::<u8, 8>();
+    fuzz_stack(&data);
+}
+
+/// The same bytes always decode to the same values, in the order they are consumed.
+#[kani::proof]
+fn check_deterministic_decoding() {
+    let data: [u8; 6] = kani::any();
+    let mut input = Unstructured::new(&data);
+    let (first, second) = input.decode::<(u16, Option<u8>)>().unwrap();
+    assert_eq!(first, u16::from_le_bytes([data[0], data[1]]));
+    assert_eq!(second.is_some(), data[2] & 1 == 1);
+    if let Some(value) = second {
+        assert_eq!(value, data[3]);
+    }
+    assert!(input.len() >= 2);
+    assert!(kani::arbitrary::decode::<u64>(&mut input).is_err());
+}
+
+#[derive(Decode)]
+struct Point<T> {
+    x: T,
+    y: T,
+}
+
+/// A derived implementation decodes the variant with a `u32`, and then the fields in order.
+#[kani::proof]
+fn check_derived_decoding() {
+    let data: [u8; 5] = kani::any();
+    let mut input = Unstructured::new(&data);
+    let command: Command = input.decode().unwrap();
+    match command {
+        Command::Push(item) => {
+            assert_eq!(u32::from_le_bytes([data[0], data[1], data[2], data[3]]) % 2, 0);
+            assert_eq!(item, data[4]);
+        }
+        Command::Pop => assert!(input.len() == 1),
+    }
+    let mut input = Unstructured::new(&data);
+    let point: Point<u16> = input.decode().unwrap();
+    assert_eq!(point.x, u16::from_le_bytes([data[0], data[1]]));
+    assert_eq!(point.y, u16::from_le_bytes([data[2], data[3]]));
+}
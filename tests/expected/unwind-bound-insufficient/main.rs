@@ -0,0 +1,31 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that Kani reports an insufficient unwind bound separately from genuine
+//! failures. The loop below requires an unwind bound of at least 11.
+
+fn sum_up_to(n: u32) -> u32 {
+    let mut sum = 0;
+    for i in 0..n {
+        sum += i;
+    }
+    sum
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_bound_too_small() {
+    assert_eq!(sum_up_to(10), 45);
+}
+
+#[kani::proof]
+#[kani::unwind(11)]
+fn check_bound_large_enough() {
+    assert_eq!(sum_up_to(10), 45);
+}
+
+#[kani::proof]
+#[kani::unwind(11)]
+fn check_genuine_failure() {
+    assert_eq!(sum_up_to(10), 46);
+}
@@ -0,0 +1,43 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that reachable `unwrap` and `expect` calls fail with a message that names the kind of
+//! unwrap and the call site, and that unreachable ones don't fail.
+
+use std::convert::TryFrom;
+
+#[kani::proof]
+fn check_unwrap_none() {
+    let value: Option<u8> = kani::any();
+    let _ = value.unwrap();
+}
+
+#[kani::proof]
+fn check_expect_none() {
+    let value: Option<u8> = kani::any();
+    let _ = value.expect("value must be set");
+}
+
+#[kani::proof]
+fn check_unwrap_err() {
+    let value: Result<u8, i32> = if kani::any() { Ok(1) } else { Err(-1) };
+    let _ = value.unwrap();
+}
+
+#[kani::proof]
+fn check_expect_err() {
+    let code: u32 = kani::any();
+    kani::assume(code <= 0xDFFF);
+    let value = char::try_from(code);
+    let _ = value.expect("code must be a char");
+}
+
+#[kani::proof]
+fn check_unreachable_unwrap() {
+    let value: Option<u8> = kani::any();
+    if value.is_some() {
+        assert!(value.unwrap() <= u8::MAX);
+    }
+    let result: Result<u8, ()> = Ok(kani::any());
+    let _ = result.expect("result is always ok");
+}
@@ -0,0 +1,52 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z valid-value-checks
+//! Check that transmuting an integer into a fieldless enumeration is only valid if the integer
+//! is the discriminant of one of the variants, even if the discriminants are not contiguous.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u8)]
+enum Level {
+    Low = 1,
+    Medium = 3,
+    High = 5,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(i8)]
+enum Sign {
+    Negative = -1,
+    Zero = 0,
+    Positive = 1,
+}
+
+#[kani::proof]
+fn check_transmute_gap() {
+    let value: u8 = kani::any();
+    kani::assume(value >= 1 && value <= 5);
+    // Fails for 2 and 4, which are in the range of the discriminants.
+    let level: Level = unsafe { std::mem::transmute(value) };
+    kani::cover!(level == Level::Medium);
+}
+
+#[kani::proof]
+fn check_transmute_valid() {
+    let value: u8 = kani::any_where(|v| *v == 1 || *v == 3 || *v == 5);
+    let level: Level = unsafe { std::mem::transmute(value) };
+    assert_eq!(level as u8, value);
+}
+
+#[kani::proof]
+fn check_transmute_negative() {
+    let value: i8 = kani::any_where(|v| *v >= -1 && *v <= 1);
+    let sign: Sign = unsafe { std::mem::transmute(value) };
+    assert_eq!(sign as i8, value);
+}
+
+#[kani::proof]
+fn check_enum_to_int() {
+    let level = if kani::any() { Level::Low } else { Level::High };
+    // Casts from an enumeration to an integer are always valid.
+    let value = level as u8;
+    assert!(value == 1 || value == 5);
+}
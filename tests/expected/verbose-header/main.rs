@@ -0,0 +1,11 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: --verbose
+
+//! Check that `--verbose` prints the Kani and CBMC versions, as well as the full CBMC command line.
+
+#[kani::proof]
+fn check_header() {
+    let x: u8 = kani::any();
+    assert!(x.wrapping_add(1) != x);
+}
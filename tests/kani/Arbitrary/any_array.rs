@@ -0,0 +1,30 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::array::any_array` generates arrays with independent values, and that it
+//! can be used in const generic contexts.
+
+fn first_and_last<const N: usize>() -> (u32, u32) {
+    let arr = kani::array::any_array::<u32, N>();
+    (arr[0], arr[N - 1])
+}
+
+#[kani::proof]
+fn check_independent_values() {
+    let arr: [u8; 4] = kani::array::any_array();
+    kani::cover!(arr[0] != arr[1]);
+    kani::cover!(arr[0] == arr[1]);
+    kani::cover!(arr[2] == 0 && arr[3] == u8::MAX);
+}
+
+#[kani::proof]
+fn check_const_generic() {
+    let (first, last) = first_and_last::<8>();
+    kani::cover!(first != last);
+}
+
+#[kani::proof]
+fn check_non_primitive() {
+    let arr: [Option<bool>; 3] = kani::array::any_array();
+    kani::cover!(arr[0].is_none() && arr[1] == Some(true) && arr[2] == Some(false));
+}
@@ -0,0 +1,78 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Check that the builder generated by `#[arbitrary(builder)]` gives concrete values to the
+//! fields that were set, and independent symbolic values to the other fields.
+
+mod device {
+    /// A struct with ten fields, most of them private.
+    #[derive(kani::Arbitrary)]
+    #[arbitrary(builder)]
+    pub struct Device {
+        pub id: u32,
+        pub enabled: bool,
+        mode: u8,
+        retries: u8,
+        timeout_ms: u64,
+        #[arbitrary(range = "1..=8")]
+        lanes: u8,
+        speed: u16,
+        vendor: u16,
+        revision: char,
+        checksum: u32,
+    }
+
+    impl Device {
+        /// The number of attempts to reach the device before giving up.
+        pub fn attempts(&self) -> u16 {
+            if self.enabled && self.mode == 1 { self.retries as u16 + 1 } else { 0 }
+        }
+    }
+
+    #[cfg(kani)]
+    mod verification {
+        use super::*;
+
+        /// Focus the proof on the two fields that `attempts()` depends on.
+        #[kani::proof]
+        fn check_attempts() {
+            let device: Device = kani::builder::<Device>().enabled(true).mode(1).rest_any();
+            assert!(device.attempts() >= 1);
+            assert!(device.lanes >= 1 && device.lanes <= 8);
+            kani::cover!(device.attempts() == 256);
+        }
+
+        /// The fields that weren't set are independent from each other.
+        #[kani::proof]
+        fn check_rest_independent() {
+            let device: Device = kani::builder::<Device>().id(7).rest_any();
+            assert_eq!(device.id, 7);
+            kani::cover!(device.mode != device.retries);
+            kani::cover!(device.speed != device.vendor);
+            kani::cover!(device.checksum != device.id);
+        }
+    }
+}
+
+#[derive(kani::Arbitrary)]
+#[arbitrary(builder)]
+#[safety_constraint(*low <= *high)]
+struct Interval<T: PartialOrd> {
+    low: T,
+    high: T,
+}
+
+#[kani::proof]
+fn check_generic_with_constraint() {
+    let interval: Interval<i32> = kani::builder::<Interval<i32>>().low(10).rest_any();
+    assert_eq!(interval.low, 10);
+    assert!(interval.high >= 10);
+    kani::cover!(interval.high == i32::MAX);
+}
+
+#[kani::proof]
+fn check_public_fields() {
+    let device = kani::builder::<device::Device>().id(1).enabled(false).rest_any();
+    assert_eq!(device.id, 1);
+    assert_eq!(device.attempts(), 0);
+}
@@ -0,0 +1,64 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Check that the `#[arbitrary(range = "...")]` attribute constrains the values generated by
+//! `#[derive(Arbitrary)]`, and that every value in the range can still be generated.
+
+#[derive(kani::Arbitrary)]
+struct Percent(#[arbitrary(range = "0..=100")] u8);
+
+#[derive(kani::Arbitrary)]
+struct Reading {
+    #[arbitrary(range = "-40..85")]
+    celsius: i16,
+    #[arbitrary(range = "1..")]
+    samples: u32,
+    #[arbitrary(range = "..=-1")]
+    offset: i64,
+    raw: u8,
+}
+
+#[derive(kani::Arbitrary)]
+enum Level {
+    Low(#[arbitrary(range = "..10")] u8),
+    High {
+        #[arbitrary(range = "10..=20")]
+        value: u8,
+    },
+}
+
+#[kani::proof]
+fn check_inclusive() {
+    let percent: Percent = kani::any();
+    assert!(percent.0 <= 100);
+    kani::cover!(percent.0 == 0);
+    kani::cover!(percent.0 == 100);
+}
+
+#[kani::proof]
+fn check_fields() {
+    let reading: Reading = kani::any();
+    assert!((-40..85).contains(&reading.celsius));
+    assert!(reading.samples > 0);
+    assert!(reading.offset < 0);
+    kani::cover!(reading.celsius == -40);
+    kani::cover!(reading.celsius == 84);
+    kani::cover!(reading.samples == u32::MAX);
+    kani::cover!(reading.offset == i64::MIN);
+    // Fields without the attribute are unconstrained.
+    kani::cover!(reading.raw == u8::MAX);
+}
+
+#[kani::proof]
+fn check_enum() {
+    match kani::any() {
+        Level::Low(value) => {
+            assert!(value < 10);
+            kani::cover!(value == 9);
+        }
+        Level::High { value } => {
+            assert!((10..=20).contains(&value));
+            kani::cover!(value == 20);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::bounded` generates values in the given half-open range, including ranges
+//! that touch the type bounds.
+
+#[kani::proof]
+fn check_bounded_u8() {
+    let x: u8 = kani::bounded(10, 20);
+    assert!(x >= 10 && x < 20);
+}
+
+#[kani::proof]
+fn check_bounded_i32_negative() {
+    let x: i32 = kani::bounded(-5, 5);
+    assert!(x >= -5 && x < 5);
+}
+
+#[kani::proof]
+fn check_bounded_type_limits() {
+    let x = kani::bounded(u64::MAX - 1, u64::MAX);
+    assert_eq!(x, u64::MAX - 1);
+    let y = kani::bounded(i8::MIN, i8::MIN + 1);
+    assert_eq!(y, i8::MIN);
+    let z = kani::bounded(usize::MIN, usize::MAX);
+    assert_ne!(z, usize::MAX);
+}
@@ -0,0 +1,27 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `assume_all!` and `assume_any!` restrict the inputs as expected.
+
+#[kani::proof]
+fn check_assume_all() {
+    let x: u8 = kani::any();
+    let y: u8 = kani::any();
+    kani::assume_all!(x > 10, x < 20, y == x + 1,);
+    assert!(x > 10 && x < 20);
+    assert_eq!(y, x + 1);
+}
+
+#[kani::proof]
+fn check_assume_any() {
+    let x: u8 = kani::any();
+    kani::assume_any!(x == 1, x == 5, x > 250);
+    assert!(x == 1 || x == 5 || x > 250);
+}
+
+#[kani::proof]
+fn check_assume_any_single() {
+    let x: u8 = kani::any();
+    kani::assume_any!(x == 7);
+    assert_eq!(x, 7);
+}
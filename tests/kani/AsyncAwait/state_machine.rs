@@ -0,0 +1,100 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// compile-flags: --edition 2018
+// kani-flags: -Z async-lib
+
+//! Verify a hand-written future state machine, where each step may be ready immediately or
+//! pending, using the executor from the Kani library.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A future that counts down from `remaining` to zero, one step per poll.
+/// If `immediate` is set, it completes all the steps in a single poll.
+struct Countdown {
+    remaining: u8,
+    immediate: bool,
+    polls: u8,
+}
+
+impl Future for Countdown {
+    type Output = u8;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.polls += 1;
+        if self.immediate {
+            self.remaining = 0;
+        }
+        if self.remaining == 0 {
+            Poll::Ready(self.polls)
+        } else {
+            self.remaining -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+async fn add_countdowns(first: u8, second: u8, immediate: bool) -> u8 {
+    let polls_first = Countdown { remaining: first, immediate, polls: 0 }.await;
+    let polls_second = Countdown { remaining: second, immediate: false, polls: 0 }.await;
+    polls_first + polls_second
+}
+
+#[kani::proof]
+#[kani::unwind(8)]
+fn check_countdown_state_machine() {
+    let first: u8 = kani::any_where(|x| *x < 3);
+    let second: u8 = kani::any_where(|x| *x < 3);
+    let immediate: bool = kani::any();
+    let polls = kani::block_on(add_countdowns(first, second, immediate));
+    if immediate {
+        assert_eq!(polls, second + 2);
+    } else {
+        assert_eq!(polls, first + second + 2);
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(4)]
+async fn check_yield_now() {
+    let x: u8 = kani::any();
+    kani::yield_now().await;
+    let y = x.wrapping_add(1);
+    kani::yield_now().await;
+    assert_eq!(y.wrapping_sub(1), x);
+}
+
+/// A future that wakes a clone of its waker, and then runs another future to completion with
+/// `kani::block_on` before returning `Pending`.
+struct WakeThenBlockOn {
+    done: bool,
+}
+
+impl Future for WakeThenBlockOn {
+    type Output = u8;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.done {
+            return Poll::Ready(1);
+        }
+        self.done = true;
+        cx.waker().clone().wake();
+        let inner = kani::block_on(async {
+            kani::yield_now().await;
+            1
+        });
+        assert_eq!(inner, 1);
+        Poll::Pending
+    }
+}
+
+/// The wake-up of the outer future must not be lost when `kani::block_on` is called while it is
+/// polled, since each call keeps track of its own waker.
+#[kani::proof]
+#[kani::unwind(3)]
+fn check_nested_block_on() {
+    assert_eq!(kani::block_on(WakeThenBlockOn { done: false }), 1);
+}
@@ -0,0 +1,72 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that memory leaked with `Box::leak` or wrapped in `ManuallyDrop` stays valid and that
+//! its destructor is never called.
+
+use std::mem::ManuallyDrop;
+
+static mut DROPS: u32 = 0;
+
+struct CountDrops(u32);
+impl Drop for CountDrops {
+    fn drop(&mut self) {
+        unsafe {
+            DROPS += 1;
+        }
+    }
+}
+
+fn leak_value(val: u32) -> &'static mut CountDrops {
+    let boxed = Box::new(CountDrops(val));
+    Box::leak(boxed)
+}
+
+#[kani::proof]
+fn check_box_leak_outlives_scope() {
+    let val: u32 = kani::any();
+    let leaked = leak_value(val);
+    assert_eq!(leaked.0, val);
+    leaked.0 = leaked.0.wrapping_add(1);
+    assert_eq!(leaked.0, val.wrapping_add(1));
+    assert_eq!(unsafe { DROPS }, 0);
+}
+
+#[kani::proof]
+fn check_box_leak_slice() {
+    let leaked: &'static mut [u8] = Box::leak(vec![1u8, 2, 3].into_boxed_slice());
+    leaked[2] = 10;
+    assert_eq!(leaked, [1, 2, 10]);
+}
+
+#[kani::proof]
+fn check_manually_drop_skips_glue() {
+    {
+        let _value = ManuallyDrop::new(CountDrops(0));
+        let _boxed = ManuallyDrop::new(Box::new(CountDrops(1)));
+    }
+    assert_eq!(unsafe { DROPS }, 0);
+}
+
+#[kani::proof]
+fn check_manually_drop_into_raw() {
+    let ptr = {
+        let boxed = ManuallyDrop::new(Box::new(CountDrops(kani::any())));
+        &**boxed as *const CountDrops
+    };
+    // The box went out of scope without being dropped, so its memory is still allocated.
+    let val = unsafe { (*ptr).0 };
+    assert_eq!(unsafe { DROPS }, 0);
+    // Reclaim the allocation so the destructor runs exactly once.
+    let reclaimed = unsafe { Box::from_raw(ptr as *mut CountDrops) };
+    assert_eq!(reclaimed.0, val);
+    drop(reclaimed);
+    assert_eq!(unsafe { DROPS }, 1);
+}
+
+#[kani::proof]
+fn check_manually_drop_explicit_drop() {
+    let mut value = ManuallyDrop::new(CountDrops(0));
+    unsafe { ManuallyDrop::drop(&mut value) };
+    assert_eq!(unsafe { DROPS }, 1);
+}
@@ -0,0 +1,44 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that chains of `Option` combinators are modeled with the same semantics as `core`.
+//! In particular, the closure passed to `map` must only be called for `Some` values.
+
+use std::cell::Cell;
+
+#[kani::proof]
+fn check_as_ref_map_unwrap_or() {
+    let opt: Option<u32> = kani::any();
+    let calls = Cell::new(0u8);
+    let result = opt
+        .as_ref()
+        .map(|v| {
+            calls.set(calls.get() + 1);
+            v.wrapping_add(1)
+        })
+        .unwrap_or(0);
+    match opt {
+        Some(v) => {
+            assert_eq!(result, v.wrapping_add(1));
+            assert_eq!(calls.get(), 1);
+        }
+        None => {
+            assert_eq!(result, 0);
+            assert_eq!(calls.get(), 0, "`map` on `None` must not call the closure");
+        }
+    }
+    kani::cover!(opt.is_some());
+    kani::cover!(opt.is_none());
+}
+
+#[kani::proof]
+fn check_as_mut_map() {
+    let mut opt: Option<u8> = kani::any();
+    let original = opt;
+    let updated = opt.as_mut().map(|v| {
+        *v = v.wrapping_mul(2);
+        *v
+    });
+    assert_eq!(updated, original.map(|v| v.wrapping_mul(2)));
+    assert_eq!(opt, updated);
+}
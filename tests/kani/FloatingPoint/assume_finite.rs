@@ -0,0 +1,24 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfloat-lib
+
+//! This test checks that `kani::float::assume_finite` and `assume_finite_f32` exclude NaN and
+//! infinite values.
+
+#[kani::proof]
+fn check_assume_finite_f64() {
+    let x: f64 = kani::any();
+    kani::float::assume_finite(x);
+    assert!(!x.is_nan());
+    assert!(x.is_finite());
+    assert!(x <= f64::MAX && x >= f64::MIN);
+}
+
+#[kani::proof]
+fn check_assume_finite_f32() {
+    let x: f32 = kani::any();
+    kani::float::assume_finite_f32(x);
+    assert!(!x.is_nan());
+    assert!(x.is_finite());
+    assert!(x <= f32::MAX && x >= f32::MIN);
+}
@@ -0,0 +1,85 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that `std::mem::swap` and `std::mem::replace` move values with drop glue without
+//! dropping them, and that every value is dropped exactly once afterwards.
+//! The values are large, and the harnesses are verified without unwinding loops, since both
+//! functions are modeled as value moves instead of byte-wise copies.
+
+use std::mem;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+struct Tracked {
+    id: u8,
+    payload: [u64; 512],
+    heap: Box<u8>,
+}
+
+impl Tracked {
+    fn new(id: u8) -> Self {
+        Tracked { id, payload: [id as u64; 512], heap: Box::new(id) }
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        DROPS.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+fn drops() -> usize {
+    DROPS.load(Ordering::Relaxed)
+}
+
+#[kani::proof]
+#[kani::unwind(2)]
+fn check_swap_drop_glue() {
+    {
+        let mut a = Tracked::new(1);
+        let mut b = Tracked::new(2);
+        mem::swap(&mut a, &mut b);
+        assert_eq!((a.id, a.payload[511], *a.heap), (2, 2, 2));
+        assert_eq!((b.id, b.payload[511], *b.heap), (1, 1, 1));
+        assert_eq!(drops(), 0);
+    }
+    assert_eq!(drops(), 2);
+}
+
+#[kani::proof]
+#[kani::unwind(2)]
+fn check_replace_drop_glue() {
+    {
+        let mut a = Tracked::new(1);
+        let old = mem::replace(&mut a, Tracked::new(2));
+        assert_eq!((old.id, old.payload[0], *old.heap), (1, 1, 1));
+        assert_eq!((a.id, a.payload[0], *a.heap), (2, 2, 2));
+        assert_eq!(drops(), 0);
+        drop(old);
+        assert_eq!(drops(), 1);
+    }
+    assert_eq!(drops(), 2);
+}
+
+#[derive(Clone, Copy)]
+struct Large {
+    words: [u64; 1024],
+    tag: u8,
+}
+
+#[kani::proof]
+#[kani::unwind(2)]
+fn check_swap_replace_copy() {
+    let x: u64 = kani::any();
+    let y: u64 = kani::any();
+    let mut a = Large { words: [x; 1024], tag: 1 };
+    let mut b = Large { words: [y; 1024], tag: 2 };
+    mem::swap(&mut a, &mut b);
+    assert!(a.words[1023] == y && a.tag == 2);
+    assert!(b.words[0] == x && b.tag == 1);
+
+    let old = mem::replace(&mut a, b);
+    assert!(old.words[7] == y && old.tag == 2);
+    assert!(a.words[7] == x && a.tag == 1);
+}
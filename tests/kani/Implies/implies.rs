@@ -0,0 +1,33 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check the semantics of `kani::implies!` and `kani::assert_implies!`.
+
+#[kani::proof]
+fn check_truth_table() {
+    let p: bool = kani::any();
+    let q: bool = kani::any();
+    assert_eq!(kani::implies!(p, q), !p || q);
+    assert_eq!(kani::implies!(p => q), !p || q);
+    kani::assert_implies!(p && q, q);
+    kani::assert_implies!(false, p, "a false premise implies anything");
+}
+
+#[kani::proof]
+fn check_short_circuit() {
+    let p: bool = kani::any();
+    let mut evaluated = false;
+    let result = kani::implies!(p, {
+        evaluated = true;
+        true
+    });
+    assert!(result);
+    assert_eq!(evaluated, p);
+}
+
+#[kani::proof]
+fn check_implies_in_assert() {
+    let x: u8 = kani::any();
+    kani::assert(kani::implies!(x > 200, x > 100), "x > 200 implies x > 100");
+    kani::assert_implies!(x.is_power_of_two(), x.count_ones() == 1);
+}
@@ -0,0 +1,31 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `#[kani::instantiate(...)]` generates one harness per instantiation of a generic
+//! harness, for both type and const generic parameters.
+
+const LEN: usize = 3;
+
+#[kani::proof]
+#[kani::instantiate(T = u8, T = i64, T = bool)]
+fn check_eq<T: kani::Arbitrary + PartialEq>() {
+    let value: T = kani::any();
+    assert!(value == value);
+}
+
+#[kani::proof]
+#[kani::instantiate((T = u8, N = 2), (T = u16, N = 4), (T = i32, N = LEN))]
+#[kani::unwind(5)]
+fn check_array<T: kani::Arbitrary + Copy + Ord, const N: usize>() {
+    let array: [T; N] = kani::any();
+    let max = array.iter().max().copied();
+    assert!(array.iter().all(|elem| Some(*elem) <= max));
+}
+
+#[kani::instantiate(N = 0, N = -8)]
+#[kani::proof]
+fn check_shift<const N: i32>() {
+    let value: i32 = kani::any();
+    kani::assume(value > 0);
+    assert!(value.wrapping_add(N) >= i32::MIN);
+}
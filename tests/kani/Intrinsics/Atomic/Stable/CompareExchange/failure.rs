@@ -0,0 +1,33 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Check that `compare_exchange` and `compare_exchange_weak` fail when the current
+// value is different from the expected one, in which case they return the current
+// value in `Err` and leave it unchanged.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[kani::proof]
+fn check_compare_exchange() {
+    let initial: u32 = kani::any();
+    let current: u32 = kani::any();
+    let new: u32 = kani::any();
+    let atomic = AtomicU32::new(initial);
+    let result = atomic.compare_exchange(current, new, Ordering::SeqCst, Ordering::Relaxed);
+    if initial == current {
+        assert_eq!(result, Ok(initial));
+        assert_eq!(atomic.load(Ordering::Relaxed), new);
+    } else {
+        assert_eq!(result, Err(initial));
+        assert_eq!(atomic.load(Ordering::Relaxed), initial);
+    }
+}
+
+#[kani::proof]
+fn check_compare_exchange_weak() {
+    let atomic = AtomicU32::new(10);
+    assert_eq!(atomic.compare_exchange_weak(5, 20, Ordering::AcqRel, Ordering::Acquire), Err(10));
+    assert_eq!(atomic.load(Ordering::Acquire), 10);
+    assert_eq!(atomic.compare_exchange_weak(10, 20, Ordering::AcqRel, Ordering::Acquire), Ok(10));
+    assert_eq!(atomic.load(Ordering::Acquire), 20);
+}
@@ -0,0 +1,67 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Check that code that uses an `AtomicUsize` for interior mutability can be
+// verified in a single-threaded harness, where the atomic operations behave
+// like their non-atomic equivalents regardless of the ordering.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A counter that can be incremented through a shared reference, up to a limit.
+struct Counter {
+    count: AtomicUsize,
+    limit: usize,
+}
+
+impl Counter {
+    fn new(limit: usize) -> Self {
+        Counter { count: AtomicUsize::new(0), limit }
+    }
+
+    /// Increment the counter, returning `false` if it already reached its limit.
+    fn increment(&self) -> bool {
+        let mut current = self.count.load(Ordering::Acquire);
+        loop {
+            if current >= self.limit {
+                return false;
+            }
+            match self.count.compare_exchange(
+                current,
+                current + 1,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return true,
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    fn add(&self, value: usize) -> usize {
+        self.count.fetch_add(value, Ordering::Relaxed)
+    }
+
+    fn get(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_counter() {
+    let limit: usize = kani::any_where(|limit| *limit <= 3);
+    let counter = Counter::new(limit);
+    let shared = &counter;
+    for expected in 0..3 {
+        assert_eq!(shared.increment(), expected < limit);
+    }
+    assert_eq!(shared.get(), limit.min(3));
+
+    let before = shared.get();
+    assert_eq!(shared.add(2), before);
+    assert_eq!(shared.get(), before + 2);
+
+    counter.count.store(limit, Ordering::Release);
+    assert!(!counter.increment());
+    assert_eq!(counter.get(), limit);
+}
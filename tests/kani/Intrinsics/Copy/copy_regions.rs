@@ -0,0 +1,43 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check the region checks of `copy` and `copy_nonoverlapping`: copies between disjoint parts of
+//! the same allocation are allowed, `copy` handles overlapping regions, and copies of zero
+//! elements don't access memory.
+
+use std::ptr::{self, NonNull};
+
+#[kani::proof]
+fn check_adjacent_nonoverlapping() {
+    let mut arr = [1u16, 2, 3, 4];
+    let len: usize = kani::any_where(|len| *len <= 2);
+    unsafe {
+        let src = arr.as_ptr();
+        let dst = arr.as_mut_ptr().add(2);
+        ptr::copy_nonoverlapping(src, dst, len);
+    }
+    assert!(len == 0 || arr[2] == 1);
+    assert!(len < 2 || arr[3] == 2);
+}
+
+#[kani::proof]
+fn check_overlapping_copy() {
+    let mut arr = [1u8, 2, 3, 4];
+    unsafe {
+        let src = arr.as_ptr();
+        let dst = arr.as_mut_ptr().add(1);
+        ptr::copy(src, dst, 3);
+    }
+    assert_eq!(arr, [1, 1, 2, 3]);
+}
+
+#[kani::proof]
+fn check_zero_count() {
+    let dangling = NonNull::<u64>::dangling().as_ptr();
+    let mut val = 10u64;
+    unsafe {
+        ptr::copy_nonoverlapping(dangling, dangling, 0);
+        ptr::copy(dangling, &mut val, 0);
+    }
+    assert_eq!(val, 10);
+}
@@ -0,0 +1,19 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Checks that transmutes that read padding bytes are only flagged with `-Z uninit-checks`.
+
+#[repr(C)]
+#[derive(kani::Arbitrary)]
+struct WithPadding {
+    byte: u8,
+    word: u32,
+}
+
+#[kani::proof]
+fn check_padding_to_bytes() {
+    let value: WithPadding = kani::any();
+    let byte = value.byte;
+    let bytes = unsafe { std::mem::transmute::<WithPadding, [u8; 8]>(value) };
+    assert_eq!(bytes[0], byte);
+}
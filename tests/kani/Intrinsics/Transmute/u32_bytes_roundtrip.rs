@@ -0,0 +1,21 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Checks that transmuting a symbolic `u32` into `[u8; 4]` and back reinterprets the same bytes.
+
+#[kani::proof]
+fn check_u32_bytes_roundtrip() {
+    let num: u32 = kani::any();
+    let bytes = unsafe { std::mem::transmute::<u32, [u8; 4]>(num) };
+    assert_eq!(bytes, num.to_ne_bytes());
+    let back = unsafe { std::mem::transmute::<[u8; 4], u32>(bytes) };
+    assert_eq!(back, num);
+}
+
+#[kani::proof]
+fn check_bytes_u32_roundtrip() {
+    let bytes: [u8; 4] = kani::any();
+    let num = unsafe { std::mem::transmute::<[u8; 4], u32>(bytes) };
+    assert_eq!(num, u32::from_ne_bytes(bytes));
+    assert_eq!(unsafe { std::mem::transmute::<u32, [u8; 4]>(num) }, bytes);
+}
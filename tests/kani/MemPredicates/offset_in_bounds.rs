@@ -0,0 +1,53 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z mem-predicates
+//! Check the offset in bounds predicate.
+
+extern crate kani;
+
+use kani::mem::offset_in_bounds;
+
+#[kani::proof]
+fn check_positive_offsets() {
+    let data = [0u32; 4];
+    let ptr = data.as_ptr();
+    assert!(offset_in_bounds(ptr, 0));
+    assert!(offset_in_bounds(ptr, 3));
+    // One past the end is still in bounds.
+    assert!(offset_in_bounds(ptr, 4));
+    assert!(!offset_in_bounds(ptr, 5));
+    assert!(!offset_in_bounds(ptr, isize::MAX));
+}
+
+#[kani::proof]
+fn check_negative_offsets() {
+    let data = [0u16; 4];
+    let ptr = unsafe { data.as_ptr().add(2) };
+    assert!(offset_in_bounds(ptr, -2));
+    assert!(!offset_in_bounds(ptr, -3));
+    assert!(offset_in_bounds(ptr, 2));
+    assert!(!offset_in_bounds(ptr, 3));
+    assert!(!offset_in_bounds(ptr, isize::MIN));
+}
+
+#[kani::proof]
+fn check_zero_sized() {
+    let ptr = std::ptr::null::<u8>();
+    assert!(offset_in_bounds(ptr, 0));
+    assert!(!offset_in_bounds(ptr, 1));
+    let zst = [(); 4];
+    assert!(offset_in_bounds(zst.as_ptr(), 100));
+}
+
+#[kani::proof]
+fn check_assume_then_read() {
+    let data = [1u8, 2, 3, 4, 5];
+    let ptr = data.as_ptr();
+    let count: isize = kani::any();
+    kani::assume(offset_in_bounds(ptr, count));
+    assert!(count >= 0 && count <= 5);
+    if count < 5 {
+        let val = unsafe { *ptr.offset(count) };
+        assert_eq!(val as isize, count + 1);
+    }
+}
@@ -0,0 +1,39 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Test the Kani library's API for creating a non-det subslice of an existing slice
+
+#[kani::proof]
+fn check_any_subslice() {
+    let arr = [1, 2, 3, 4];
+    let subslice = kani::slice::any_subslice(&arr);
+    assert!(subslice.len() <= arr.len());
+    if !subslice.is_empty() {
+        let idx: usize = kani::any_where(|idx| *idx < subslice.len());
+        assert!(arr.contains(&subslice[idx]));
+    }
+    kani::cover!(subslice.is_empty());
+    kani::cover!(subslice.len() == arr.len());
+}
+
+#[kani::proof]
+#[kani::unwind(4)]
+fn check_any_subslice_of_vec() {
+    let vec = vec![10u8; 3];
+    let subslice = kani::slice::any_subslice(&vec);
+    assert!(subslice.iter().all(|x| *x == 10));
+    // The subslice points into the storage of the vector.
+    if let Some(first) = subslice.first() {
+        let offset = (first as *const u8 as usize) - (vec.as_ptr() as usize);
+        assert!(offset + subslice.len() <= vec.len());
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(4)]
+fn check_any_subslice_mut() {
+    let mut arr = [0u8; 3];
+    let subslice = kani::slice::any_subslice_mut(&mut arr);
+    let len = subslice.len();
+    subslice.fill(1);
+    assert_eq!(arr.iter().filter(|x| **x == 1).count(), len);
+}
@@ -0,0 +1,54 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that the constructors in `kani::num` constrain the value as documented.
+
+macro_rules! check_integer {
+    ($name:ident, $ty:ty) => {
+        #[kani::proof]
+        fn $name() {
+            let positive: $ty = kani::num::any_positive();
+            assert!(positive > 0);
+            assert!(positive != 0);
+            kani::cover!(positive == <$ty>::MAX);
+
+            let nonneg: $ty = kani::num::any_nonneg();
+            assert!(nonneg >= 0);
+            kani::cover!(nonneg == 0);
+        }
+    };
+}
+
+check_integer!(check_u8, u8);
+check_integer!(check_u64, u64);
+check_integer!(check_usize, usize);
+check_integer!(check_i8, i8);
+check_integer!(check_i32, i32);
+check_integer!(check_i128, i128);
+
+#[kani::proof]
+fn check_f32() {
+    let positive: f32 = kani::num::any_positive();
+    assert!(positive > 0.0 && !positive.is_nan());
+    assert!(!positive.is_sign_negative());
+
+    let nonneg: f32 = kani::num::any_nonneg();
+    assert!(nonneg >= 0.0 && !nonneg.is_nan());
+    kani::cover!(nonneg == 0.0 && nonneg.is_sign_negative());
+
+    let nonan: f32 = kani::num::any_nonan();
+    assert!(!nonan.is_nan());
+    kani::cover!(nonan.is_infinite());
+}
+
+#[kani::proof]
+fn check_f64() {
+    let positive: f64 = kani::num::any_positive();
+    assert!(positive > 0.0 && !positive.is_nan());
+
+    let nonneg: f64 = kani::num::any_nonneg();
+    assert!(nonneg >= 0.0 && !nonneg.is_nan());
+
+    let nonan: f64 = kani::num::any_nonan();
+    assert!(nonan == nonan);
+}
@@ -0,0 +1,61 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Check that Kani tracks the reference count of `Rc` and `Arc` across clones and drops, since
+//! it verifies the actual implementation of these types from the standard library.
+
+use std::rc::{Rc, Weak};
+use std::sync::Arc;
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_rc_strong_count() {
+    let first = Rc::new(kani::any::<u32>());
+    assert_eq!(Rc::strong_count(&first), 1);
+
+    let clones = kani::any_where(|n: &usize| *n <= 3);
+    let mut others = Vec::new();
+    for _ in 0..clones {
+        others.push(Rc::clone(&first));
+    }
+    assert_eq!(Rc::strong_count(&first), clones + 1);
+
+    // The count goes down by one for every dropped clone.
+    while let Some(other) = others.pop() {
+        assert!(Rc::ptr_eq(&first, &other));
+        drop(other);
+        assert_eq!(Rc::strong_count(&first), others.len() + 1);
+    }
+    assert!(Rc::try_unwrap(first).is_ok());
+}
+
+#[kani::proof]
+fn check_rc_weak_after_last_drop() {
+    let strong = Rc::new(kani::any::<u8>());
+    let weak: Weak<u8> = Rc::downgrade(&strong);
+    assert_eq!(Rc::weak_count(&strong), 1);
+
+    let other = strong.clone();
+    drop(strong);
+    assert_eq!(weak.strong_count(), 1);
+    assert!(weak.upgrade().is_some());
+
+    // Dropping the last strong reference drops the value.
+    drop(other);
+    assert_eq!(weak.strong_count(), 0);
+    assert!(weak.upgrade().is_none());
+}
+
+#[kani::proof]
+fn check_arc_strong_count() {
+    let first = Arc::new(kani::any::<u64>());
+    let second = Arc::clone(&first);
+    let third = second.clone();
+    assert_eq!(Arc::strong_count(&first), 3);
+
+    drop(second);
+    assert_eq!(Arc::strong_count(&third), 2);
+    drop(first);
+    assert_eq!(Arc::strong_count(&third), 1);
+    assert!(Arc::into_inner(third).is_some());
+}
@@ -0,0 +1,64 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that the layout of `#[repr(packed)]` structs is modelled faithfully, and that their fields
+//! can be read and written by value and through unaligned accesses.
+
+use std::mem::{offset_of, size_of};
+use std::ptr::{addr_of, addr_of_mut};
+
+#[repr(C, packed)]
+#[derive(Clone, Copy)]
+struct Packed {
+    tag: u8,
+    value: u32,
+    extra: u16,
+}
+
+#[repr(C, packed(2))]
+#[derive(Clone, Copy)]
+struct Packed2 {
+    tag: u8,
+    value: u64,
+}
+
+#[kani::proof]
+fn check_packed_layout() {
+    assert_eq!(size_of::<Packed>(), 7);
+    assert_eq!(offset_of!(Packed, value), 1);
+    assert_eq!(offset_of!(Packed, extra), 5);
+    assert_eq!(size_of::<Packed2>(), 10);
+    assert_eq!(offset_of!(Packed2, value), 2);
+}
+
+#[kani::proof]
+fn check_by_value_access() {
+    let value: u32 = kani::any();
+    let mut packed = Packed { tag: 1, value, extra: 2 };
+    // Copy the fields out before comparing them, since references to them are not allowed.
+    let read = packed.value;
+    assert_eq!(read, value);
+    packed.value = read.wrapping_add(1);
+    let updated = packed.value;
+    assert_eq!(updated, value.wrapping_add(1));
+    let (tag, extra) = (packed.tag, packed.extra);
+    assert_eq!((tag, extra), (1, 2));
+}
+
+#[kani::proof]
+fn check_unaligned_access() {
+    let mut packed = Packed2 { tag: 0, value: kani::any() };
+    let expected = packed.value;
+    let read = unsafe { addr_of!(packed.value).read_unaligned() };
+    assert_eq!(read, expected);
+    unsafe { addr_of_mut!(packed.value).write_unaligned(!read) };
+    let written = packed.value;
+    assert_eq!(written, !expected);
+}
+
+#[kani::proof]
+fn check_bytes_match_layout() {
+    let packed = Packed { tag: 0xAA, value: 0x11223344, extra: 0x5566 };
+    let bytes: [u8; 7] = unsafe { std::mem::transmute(packed) };
+    assert_eq!(bytes, [0xAA, 0x44, 0x33, 0x22, 0x11, 0x66, 0x55]);
+}
@@ -0,0 +1,44 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::string::any_str_of_len` and `kani::string::any_ascii_str_of_len` only create
+//! valid UTF-8 strings, and that multi-byte characters are possible.
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn check_any_str_is_valid() {
+    let s = kani::string::any_str_of_len(4);
+    assert_eq!(s.len(), 4);
+    assert!(std::str::from_utf8(s.as_bytes()).is_ok());
+    let count = s.chars().count();
+    assert!(count >= 1 && count <= 4);
+    kani::cover!(count == 1, "a single 4-byte character");
+    kani::cover!(count == 2, "two 2-byte characters, or a 3-byte and an ASCII character");
+    kani::cover!(count == 4, "only ASCII characters");
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_any_str_chars() {
+    let s = kani::string::any_str_of_len(3);
+    let encoded_len: usize = s.chars().map(char::len_utf8).sum();
+    assert_eq!(encoded_len, 3);
+    for c in s.chars() {
+        assert!(!(0xD800..=0xDFFF).contains(&(c as u32)));
+    }
+    kani::cover!(s.chars().any(|c| c as u32 > 0x7FF), "a 3-byte character");
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn check_any_ascii_str() {
+    let s = kani::string::any_ascii_str_of_len(4);
+    assert!(s.is_ascii());
+    assert_eq!(s.chars().count(), 4);
+}
+
+#[kani::proof]
+fn check_empty_str() {
+    assert!(kani::string::any_str_of_len(0).is_empty());
+    assert!(kani::string::any_ascii_str_of_len(0).is_empty());
+}
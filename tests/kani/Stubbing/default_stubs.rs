@@ -0,0 +1,50 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z stubbing
+//! Check that the default stubs registered with `kani::register_default_stub!` are applied to
+//! every harness, unless the harness has its own stub for the same function.
+
+pub fn magic_number() -> u32 {
+    0
+}
+
+pub fn magic_number_default() -> u32 {
+    1
+}
+
+pub fn magic_number_override() -> u32 {
+    2
+}
+
+pub fn other_number() -> u32 {
+    10
+}
+
+pub fn other_number_default() -> u32 {
+    11
+}
+
+#[cfg(kani)]
+kani::register_default_stub!(magic_number, magic_number_default);
+
+#[cfg(kani)]
+kani::register_default_stub!(other_number, other_number_default);
+
+#[cfg(kani)]
+mod verify {
+    use super::*;
+
+    #[kani::proof]
+    fn check_default_stubs() {
+        assert_eq!(magic_number(), 1);
+        assert_eq!(other_number(), 11);
+    }
+
+    #[kani::proof]
+    #[kani::stub(magic_number, magic_number_override)]
+    fn check_override() {
+        assert_eq!(magic_number(), 2);
+        assert_eq!(other_number(), 11);
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z stubbing
+//
+//! Check that a foreign function declared in an `extern` block can be stubbed by a Rust model.
+
+use std::ffi::{c_int, c_void};
+
+mod ffi {
+    use super::*;
+    extern "C" {
+        pub fn memcmp(s1: *const c_void, s2: *const c_void, n: usize) -> c_int;
+    }
+}
+
+mod models {
+    use super::*;
+
+    /// A model of `memcmp` that compares the bytes one by one.
+    pub unsafe extern "C" fn memcmp(s1: *const c_void, s2: *const c_void, n: usize) -> c_int {
+        let (s1, s2) = (s1 as *const u8, s2 as *const u8);
+        for i in 0..n {
+            let (a, b) = unsafe { (*s1.add(i), *s2.add(i)) };
+            if a != b {
+                return a as c_int - b as c_int;
+            }
+        }
+        0
+    }
+}
+
+/// Safe wrapper that compares two byte slices of the same length with `memcmp`.
+fn compare(a: &[u8], b: &[u8]) -> c_int {
+    assert_eq!(a.len(), b.len());
+    unsafe { ffi::memcmp(a.as_ptr() as *const c_void, b.as_ptr() as *const c_void, a.len()) }
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+#[kani::stub(ffi::memcmp, models::memcmp)]
+fn check_compare() {
+    let a: [u8; 4] = kani::any();
+    let b: [u8; 4] = kani::any();
+    let result = compare(&a, &b);
+    assert_eq!(result == 0, a == b);
+    assert_eq!(result < 0, a < b);
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+#[kani::stub(ffi::memcmp, models::memcmp)]
+fn check_compare_fn_ptr() {
+    let memcmp: unsafe extern "C" fn(*const c_void, *const c_void, usize) -> c_int = ffi::memcmp;
+    let a: [u8; 2] = kani::any();
+    let result = unsafe { memcmp(a.as_ptr() as *const c_void, a.as_ptr() as *const c_void, 2) };
+    assert_eq!(result, 0);
+}
@@ -0,0 +1,37 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that the symbolic durations created by `kani::time` uphold the `Duration` invariant
+//! and can be used to verify duration arithmetic.
+
+use std::time::Duration;
+
+#[kani::proof]
+fn check_any_duration_invariant() {
+    let duration = kani::time::any_duration();
+    assert!(duration.subsec_nanos() < 1_000_000_000);
+    assert_eq!(Duration::new(duration.as_secs(), duration.subsec_nanos()), duration);
+}
+
+#[kani::proof]
+fn check_checked_add() {
+    let first = kani::time::any_duration();
+    let second = kani::time::any_duration();
+    match first.checked_add(second) {
+        Some(sum) => assert!(sum >= first && sum >= second),
+        None => {
+            // Either the seconds overflow, or the nanoseconds carry over into `u64::MAX` seconds.
+            let secs = first.as_secs().checked_add(second.as_secs());
+            assert!(secs.is_none_or(|secs| secs == u64::MAX));
+        }
+    }
+}
+
+#[kani::proof]
+fn check_bounded_mul() {
+    let duration = kani::time::any_duration_up_to(Duration::from_secs(60));
+    let factor: u32 = kani::any_where(|f| *f <= 10);
+    // This cannot overflow since the duration is bounded.
+    let total = duration * factor;
+    assert!(total <= Duration::from_secs(600));
+}
@@ -32,3 +32,19 @@ fn test_tuple_size_12() {
 
     assert!(sum <= u8::MAX as u16 * 12);
 }
+
+/// Each component of a tuple of mixed types is an independent symbolic value that only takes
+/// the valid values of its type.
+#[kani::proof]
+fn test_tuple_size_5_mixed() {
+    let tuple5: (bool, char, i16, Option<u8>, [u8; 2]) = kani::any();
+    let (flag, c, num, opt, arr) = tuple5;
+
+    assert!(flag as u8 <= 1);
+    assert!(char::from_u32(c as u32).is_some());
+    kani::cover!(flag && num < 0);
+    kani::cover!(!flag && opt.is_none());
+    if let Some(value) = opt {
+        kani::cover!(value == arr[0] && arr[0] != arr[1]);
+    }
+}
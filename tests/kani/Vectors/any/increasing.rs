@@ -0,0 +1,41 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::any_increasing` generates strictly increasing values that can be
+//! used directly as the sorted input of `binary_search`.
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_strictly_increasing() {
+    let values: Vec<u8> = kani::any_increasing(4, 10);
+    assert_eq!(values.len(), 4);
+    for i in 1..values.len() {
+        assert!(values[i - 1] < values[i]);
+    }
+    assert!(values[3] <= 10);
+    kani::cover!(values[0] == 0 && values[3] == 10);
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_binary_search() {
+    let values: Vec<u32> = kani::any_increasing(4, 100);
+    let target: u32 = kani::any();
+    match values.binary_search(&target) {
+        Ok(idx) => assert_eq!(values[idx], target),
+        Err(idx) => {
+            assert!(idx == 0 || values[idx - 1] < target);
+            assert!(idx == values.len() || target < values[idx]);
+        }
+    }
+}
+
+/// There are only 3 distinct `u8` values no greater than 2, so no sequence of 4 values
+/// satisfies the assumptions, and the cover statement is unsatisfiable.
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_vacuous() {
+    let values: Vec<u8> = kani::any_increasing(4, 2);
+    kani::cover!(values.len() == 4);
+    assert!(false);
+}
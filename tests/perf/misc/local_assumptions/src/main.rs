@@ -0,0 +1,43 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This test measures the effect of `--enable-slicing` on a harness with many local assumptions.
+//! The assumptions constrain a chain of non-deterministic values that no property depends on, so
+//! `--enable-slicing` removes them and CBMC's formula slicing drops the whole chain. Without the
+//! option, the solver has to find values that satisfy every assumption. The verification result is
+//! the same in both cases. To compare the solver time with and without the option, run
+//! `benchcomp -c tools/benchcomp/configs/enable-slicing.yaml`.
+
+/// Mix a fresh non-deterministic value into `state`, and constrain the result.
+macro_rules! local_assumptions {
+    ($state:ident, $($name:ident),+) => {
+        $(
+            let $name: u64 = kani::any();
+            $state = $state ^ ($name & ($state >> 3));
+            $state = $state ^ ($state << 13);
+            $state = $state ^ ($state >> 7);
+            $state = $state ^ ($state << 17);
+            kani::assume($state & 0xff != $name & 0xff);
+            kani::assume($state | $name != u64::MAX);
+        )+
+    };
+}
+
+#[kani::proof]
+fn check_with_local_assumptions() {
+    let value: u32 = kani::any();
+    kani::assume(value < 1000);
+
+    // No property depends on the values below.
+    let mut state: u64 = kani::any();
+    kani::assume(state != 0);
+    local_assumptions!(state, a0, a1, a2, a3, a4, a5, a6, a7, a8, a9);
+    local_assumptions!(state, b0, b1, b2, b3, b4, b5, b6, b7, b8, b9);
+    local_assumptions!(state, c0, c1, c2, c3, c4, c5, c6, c7, c8, c9);
+    local_assumptions!(state, d0, d1, d2, d3, d4, d5, d6, d7, d8, d9);
+    kani::assume(state == 0x0123_4567_89ab_cdef);
+
+    assert!(value.checked_mul(1000).is_some());
+}
+
+fn main() {}
@@ -0,0 +1,44 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This test checks the performance of `mem::swap` and `mem::replace` on a large struct.
+//! Both are modeled as value moves, so no loop needs to be unwound: the unwinding assertions of a
+//! byte-wise copy, which would need 64 KiB iterations, would fail with `#[kani::unwind(2)]`.
+
+use std::mem;
+
+struct Buffer {
+    data: [u8; 65536],
+    len: usize,
+    name: String,
+}
+
+impl Buffer {
+    fn new(byte: u8, len: usize) -> Self {
+        Buffer { data: [byte; 65536], len, name: String::from("buffer") }
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(2)]
+fn check_swap_large() {
+    let x: u8 = kani::any();
+    let y: u8 = kani::any();
+    let mut a = Buffer::new(x, 1);
+    let mut b = Buffer::new(y, 2);
+    mem::swap(&mut a, &mut b);
+    assert!(a.data[65535] == y && a.len == 2);
+    assert!(b.data[0] == x && b.len == 1);
+    assert_eq!(a.name.len(), b.name.len());
+}
+
+#[kani::proof]
+#[kani::unwind(2)]
+fn check_replace_large() {
+    let x: u8 = kani::any();
+    let y: u8 = kani::any();
+    let mut a = Buffer::new(x, 1);
+    let old = mem::replace(&mut a, Buffer::new(y, 2));
+    assert!(old.data[1000] == x && old.len == 1);
+    assert!(a.data[1000] == y && a.len == 2);
+}
@@ -0,0 +1,14 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Harness whose allocations may fail in theory, since `Vec::push` has to grow the vector.
+
+#[kani::proof]
+#[kani::unwind(4)]
+fn check_push() {
+    let len: usize = kani::any_where(|len| *len < 4);
+    let mut v = Vec::new();
+    for i in 0..len {
+        v.push(i);
+    }
+    assert_eq!(v.len(), len);
+}
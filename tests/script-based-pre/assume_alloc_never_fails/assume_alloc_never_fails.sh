@@ -0,0 +1,30 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that allocation failure checks are reported in their own section by default, and that
+# `--assume-alloc-never-fails` prunes them, which leaves fewer checks in the proof.
+
+set -u
+
+count_checks() {
+    grep -oE '\*\* [0-9]+ of [0-9]+ failed' $1 | head -1 | awk '{ print $4 }'
+}
+
+kani assume_alloc_never_fails.rs > default.out
+echo "default: exit code $?"
+grep -A1 "^Allocation failure checks" default.out
+
+kani assume_alloc_never_fails.rs -Z unstable-options --assume-alloc-never-fails > assumed.out
+echo "assumed: exit code $?"
+grep "^INFO: Assuming that memory allocations never fail" assumed.out
+grep -c "^Allocation failure checks" assumed.out
+
+default_checks=$(count_checks default.out)
+assumed_checks=$(count_checks assumed.out)
+if [ "${assumed_checks}" -lt "${default_checks}" ]; then
+    echo "Fewer checks when allocation failures are assumed away"
+else
+    echo "Unexpected number of checks: ${default_checks} by default, ${assumed_checks} assumed"
+fi
+rm default.out assumed.out
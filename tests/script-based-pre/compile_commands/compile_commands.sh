@@ -0,0 +1,23 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `--write-compile-commands` emits one entry per workspace member, and
+# that the entries include the Kani specific compiler flags.
+
+set -u
+
+OUT_FILE=compile_commands.json
+
+cd ws
+rm -f ${OUT_FILE}
+cargo kani --workspace --write-compile-commands ${OUT_FILE}
+
+echo "Number of entries: $(grep -c '"file":' ${OUT_FILE})"
+grep -o '"[^"]*member_a/src/lib.rs"' ${OUT_FILE} | head -1
+grep -o '"[^"]*member_b/src/lib.rs"' ${OUT_FILE} | head -1
+grep -o '"--kani-compiler"' ${OUT_FILE} | head -1
+grep -o '"-Cllvm-args=--reachability=harnesses"' ${OUT_FILE} | head -1
+
+rm -f ${OUT_FILE}
+cargo clean
@@ -0,0 +1,8 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[kani::proof]
+fn check_b() {
+    let x: u8 = kani::any();
+    assert!(x.wrapping_sub(0) == x);
+}
@@ -0,0 +1,31 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `--coverage` produces an HTML report which aggregates the results
+# of all harnesses in the run.
+
+set -u
+
+rm -rf kanicov_*
+kani test.rs --coverage -Z source-coverage
+
+index=$(ls kanicov_*/html/index.html)
+if [ ! -f "$index" ]; then
+    echo "error: HTML coverage report was not generated"
+    exit 1
+fi
+echo "Found index page"
+
+page=$(dirname "$index")/test.rs.html
+# `None` is only reachable from `check_no_positive`, so the aggregated report
+# should mark it as covered.
+grep "<td>    None</td>" "$page"
+# The `Some(num)` branch is covered by one harness only, so the line must not
+# be reported as uncovered.
+if grep -q "class=\"uncovered\".*return Some(num)" "$page"; then
+    echo "error: line covered by one harness reported as uncovered"
+    exit 1
+fi
+
+rm -rf kanicov_*
@@ -0,0 +1,26 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+fn find_positive(nums: &[i32]) -> Option<i32> {
+    for &num in nums {
+        if num > 0 {
+            return Some(num);
+        }
+    }
+    // `None` is only reached when there is no positive number, as in `check_no_positive`.
+    None
+}
+
+#[kani::proof]
+fn check_find_positive() {
+    let numbers = [-3, -1, 0, 2, 4];
+    let result = find_positive(&numbers);
+    assert_eq!(result, Some(2));
+}
+
+#[kani::proof]
+fn check_no_positive() {
+    let numbers = [-3, -1, 0];
+    let result = find_positive(&numbers);
+    assert_eq!(result, None);
+}
@@ -0,0 +1,16 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Harnesses used to check that `--dump-c` writes the C rendition of a single harness.
+
+#[kani::proof]
+fn check_overflow() {
+    let x: u8 = kani::any();
+    let _ = x + 1;
+}
+
+#[kani::proof]
+fn check_ok() {
+    let x: u8 = kani::any();
+    assert!(x.checked_add(1).is_some() || x == u8::MAX);
+}
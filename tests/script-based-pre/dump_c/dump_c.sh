@@ -0,0 +1,27 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `--dump-c` requires a single harness, and that it writes the C rendition of the
+# selected harness, marked as a debugging aid.
+
+set -u
+
+OUT_DIR=dumped
+
+rm -rf ${OUT_DIR}
+mkdir ${OUT_DIR}
+
+# More than one harness is selected.
+kani dump_c.rs --dump-c ${OUT_DIR}/harness.c
+echo "Exit status: $?"
+
+kani dump_c.rs --harness check_overflow --dump-c ${OUT_DIR}/harness.c
+ls ${OUT_DIR}
+
+head -n 2 ${OUT_DIR}/harness.c
+if grep -q "check_overflow" ${OUT_DIR}/harness.c; then
+    echo "Found harness function"
+fi
+
+rm -rf ${OUT_DIR}
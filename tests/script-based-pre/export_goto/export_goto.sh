@@ -0,0 +1,28 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `--export-goto` requires a single harness, and that the exported GOTO binary
+# reproduces the result of Kani when verified with the exported CBMC command.
+
+set -u
+
+OUT_DIR=exported
+
+rm -rf ${OUT_DIR}
+mkdir ${OUT_DIR}
+
+# More than one harness is selected.
+kani export_goto.rs --export-goto ${OUT_DIR}/repro.goto
+echo "Exit status: $?"
+
+kani export_goto.rs --harness check_overflow --export-goto ${OUT_DIR}/repro.goto
+ls ${OUT_DIR}
+
+# Replay the exported script without Kani from another directory, which uses CBMC's plain text
+# output. The overflow check is the assertion that Kani added to the harness.
+SCRIPT="$(pwd)/${OUT_DIR}/repro.sh"
+RUN_DIR=$(mktemp -d)
+(cd "${RUN_DIR}" && "${SCRIPT}") | grep -E "attempt to add with overflow|^VERIFICATION"
+
+rm -rf ${OUT_DIR} "${RUN_DIR}"
@@ -0,0 +1,21 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Harnesses used to check `--fail-fast`. They are verified in the order they are declared.
+
+#[kani::proof]
+fn check_first() {
+    let x: u8 = kani::any();
+    assert!(x < 10, "x is too large");
+}
+
+#[kani::proof]
+fn check_second() {
+    let x: u8 = kani::any();
+    assert!(x <= u8::MAX);
+}
+
+#[kani::proof]
+fn check_third() {
+    let x: u8 = kani::any();
+    assert!(x != 42, "x is the answer");
+}
@@ -0,0 +1,22 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `--fail-fast` stops at the first failing harness and keeps the failure exit code,
+# and that all harnesses are verified by default.
+
+set -u
+
+echo "[default]"
+kani fail_fast.rs --output-format terse > output.txt
+echo "Exit code: $?"
+grep -E "^(PASS|FAIL)" output.txt | sed -E 's/ \([0-9.]+s\)//'
+grep "^Complete" output.txt
+
+echo "[fail-fast]"
+kani fail_fast.rs --output-format terse --fail-fast > output.txt
+echo "Exit code: $?"
+grep -E "^(PASS|FAIL)" output.txt | sed -E 's/ \([0-9.]+s\)//'
+grep -e "^Stopped" -e "^Complete" output.txt
+
+rm -f output.txt
@@ -0,0 +1,13 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[kani::proof(max_input = 16)]
+fn fuzz_decode(data: &[u8]) {
+    let _ = core::str::from_utf8(data);
+}
+
+#[kani::proof]
+fn check_regular() {
+    let x: u8 = kani::any();
+    assert!(x.checked_add(0).is_some());
+}
@@ -0,0 +1,12 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that harnesses generated for fuzz targets with `#[kani::proof(max_input = ...)]` are
+# listed as standard harnesses by `kani list`.
+
+set -e
+
+kani list -Z list fuzz_target.rs --format markdown > /dev/null
+cat kani-list.md
+rm -f kani-list.md
@@ -0,0 +1,23 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that a status line is printed as each harness completes, that it is attributed to the
+# thread that verified the harness with `--jobs`, and that the detailed output is still printed.
+
+set -u
+
+kani harness_status_stream.rs --enable-unstable --jobs 2 > output.txt
+echo "Exit code: $?"
+
+# Every status line must be printed by the thread that checked the harness.
+for harness in check_pass check_fail check_pass_again; do
+    checking=$(grep -E "^Thread [0-9]+: Checking harness ${harness}\.\.\." output.txt | cut -d: -f1)
+    status=$(grep -E "^Thread [0-9]+: (PASS|FAIL) ${harness} \(" output.txt | cut -d: -f1)
+    [[ -n "${checking}" && "${checking}" == "${status}" ]] && echo "${harness}: same thread"
+done
+
+grep -E "^Thread [0-9]+: (PASS|FAIL) " output.txt | sed -E 's/^Thread [0-9]+: //; s/ \([0-9.]+s\)//' | sort
+echo "Detailed results: $(grep -c 'VERIFICATION:-' output.txt)"
+
+rm -f output.txt
@@ -0,0 +1,19 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+fn add(a: u8, b: u8) -> u8 {
+    a.wrapping_add(b)
+}
+
+#[kani::proof]
+fn check_add() {
+    let a: u8 = kani::any();
+    assert_eq!(add(a, 0), a);
+}
+
+mod checks {
+    #[kani::proof]
+    fn check_zero() {
+        assert_eq!(super::add(0, 0), 0);
+    }
+}
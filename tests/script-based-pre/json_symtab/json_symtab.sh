@@ -0,0 +1,29 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `--write-json-symtab` writes one symbol table per selected harness, that the output
+# is deterministic, and that it does not affect verification.
+
+set -u
+
+OUT_DIR=symtabs
+
+rm -rf ${OUT_DIR} ${OUT_DIR}_first
+kani json_symtab.rs --write-json-symtab ${OUT_DIR}
+ls ${OUT_DIR}
+grep -o '"symbolTable"' ${OUT_DIR}/json_symtab__check_add.symtab.json
+grep -o '"check_add"' ${OUT_DIR}/json_symtab__check_add.symtab.json | head -1
+
+# Only the selected harness is written, and a second run produces the same output.
+mv ${OUT_DIR} ${OUT_DIR}_first
+kani json_symtab.rs --harness check_zero --write-json-symtab ${OUT_DIR}
+ls ${OUT_DIR}
+if cmp -s ${OUT_DIR}/json_symtab__checks__check_zero.symtab.json \
+    ${OUT_DIR}_first/json_symtab__checks__check_zero.symtab.json; then
+    echo "Symbol tables are identical"
+else
+    echo "Symbol tables differ"
+fi
+
+rm -rf ${OUT_DIR} ${OUT_DIR}_first
@@ -0,0 +1,24 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `kani list --compare-harnesses` reports how the harnesses changed since a previous
+# inventory, and that it fails when harnesses are removed.
+
+rm -rf tmp && mkdir tmp && cd tmp
+
+cp ../old.rs lib.rs
+kani list -Z list lib.rs --format json > /dev/null
+mv kani-list.json old.json
+
+cp ../new.rs lib.rs
+echo "[compare against old]"
+kani list -Z list lib.rs --compare-harnesses old.json 2>&1
+echo "exit code: $?"
+
+kani list -Z list lib.rs --format json > /dev/null
+echo "[compare against new]"
+kani list -Z list lib.rs --compare-harnesses kani-list.json 2>&1
+echo "exit code: $?"
+
+cd .. && rm -rf tmp
@@ -0,0 +1,14 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[kani::proof]
+fn check_unchanged() {}
+
+#[kani::proof]
+fn check_new_name() {}
+
+#[kani::proof]
+fn check_added() {}
+
+#[kani::proof]
+fn check_moved() {}
@@ -0,0 +1,14 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[kani::proof]
+fn check_unchanged() {}
+
+#[kani::proof]
+fn check_old_name() {}
+
+#[kani::proof]
+fn check_moved() {}
+
+#[kani::proof]
+fn check_removed() {}
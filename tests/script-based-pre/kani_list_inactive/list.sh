@@ -0,0 +1,15 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `kani list --include-inactive` lists the harnesses excluded by `#[cfg(...)]` as
+# inactive, and that they are omitted by default.
+
+set -e
+
+kani list -Z list src/lib.rs --include-inactive
+
+if kani list -Z list src/lib.rs | grep -q "Inactive Harnesses"; then
+    echo "Inactive harnesses should only be listed with --include-inactive"
+    exit 1
+fi
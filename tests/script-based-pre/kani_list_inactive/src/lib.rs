@@ -0,0 +1,24 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Harnesses excluded by `#[cfg(...)]` should be listed as inactive with `--include-inactive`.
+
+#[kani::proof]
+fn check_active() {
+    assert!(1 + 1 == 2);
+}
+
+#[kani::proof]
+#[cfg(target_os = "kani_unknown_os")]
+fn check_other_os() {}
+
+#[cfg(feature = "missing")]
+#[kani::proof]
+fn check_missing_feature() {}
+
+#[cfg(feature = "missing")]
+#[cfg_attr(kani, kani::proof, kani::unwind(2))]
+fn check_cfg_attr() {}
+
+/// Not a harness, so it shouldn't be listed.
+#[cfg(feature = "missing")]
+fn helper() {}
@@ -69,3 +69,18 @@ fn check_modify() {}
         fn check_new() {}
     }
 }
+
+/// The harness is defined before the function under contract, so it is visited first.
+mod harness_first {
+    #[kani::proof_for_contract(contracted::baz)]
+    fn check_baz() {
+        contracted::baz(1);
+    }
+
+    mod contracted {
+        #[kani::requires(x > 0)]
+        pub fn baz(x: u8) -> u8 {
+            x
+        }
+    }
+}
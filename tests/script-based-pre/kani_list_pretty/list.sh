@@ -0,0 +1,10 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that the default output format of `kani list` prints a table of the standard harnesses
+# with their location and unwind bound, sorted by file and then by line.
+
+set -e
+
+kani list -Z list src/lib.rs
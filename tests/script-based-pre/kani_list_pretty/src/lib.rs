@@ -0,0 +1,19 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Harnesses used to check the table printed by `kani list`.
+
+#[kani::proof]
+#[kani::unwind(3)]
+fn check_second() {
+    let mut sum = 0u8;
+    for i in 0..2u8 {
+        sum += i;
+    }
+    assert_eq!(sum, 1);
+}
+
+#[kani::proof]
+fn check_first() {
+    assert!(kani::any::<bool>() || true);
+}
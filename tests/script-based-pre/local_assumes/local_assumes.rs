@@ -0,0 +1,31 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+/// No property depends on `b`, so its assumption is removed, but the one on `a` is kept.
+#[kani::proof]
+fn check_local() {
+    let a: u8 = kani::any();
+    kani::assume(a < 10);
+    let b: u64 = kani::any();
+    let c = b ^ (b >> 3);
+    kani::assume(c & 0xff == 7);
+    assert!(a < 20);
+}
+
+/// `b` is related to `a` by an assumption, so both assumptions on `b` are kept.
+#[kani::proof]
+fn check_related() {
+    let a: u8 = kani::any();
+    let b: u8 = kani::any();
+    kani::assume(b < 10);
+    kani::assume(a == b);
+    assert!(a < 10);
+}
+
+/// The condition doesn't read any value from `kani::any`, so the assumption is kept.
+#[kani::proof]
+fn check_constant() {
+    let x = 5u8;
+    kani::assume(x > 10);
+    assert!(false);
+}
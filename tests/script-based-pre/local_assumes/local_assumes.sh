@@ -0,0 +1,25 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `--enable-slicing` removes the `kani::assume` calls that only constrain values that no
+# property depends on, and only those, without changing the verification results. We count the
+# assumptions in the GotoC symbol table of each harness with and without the option.
+
+set -u
+
+count() {
+    jq --arg harness $2 '[.symbolTable[] | select(.prettyName == $harness) | .value
+        | .. | objects | select(.id? == "assume")] | length' $1/local_assumes__$2.symtab.json
+}
+
+for harness in check_local check_related check_constant; do
+    kani local_assumes.rs --harness ${harness} --exact --write-json-symtab default > /dev/null
+    echo "${harness}: exit code $? without slicing"
+    kani local_assumes.rs --harness ${harness} --exact --write-json-symtab sliced \
+        -Z unstable-options --enable-slicing > /dev/null
+    echo "${harness}: exit code $? with slicing"
+    echo "${harness}: $(( $(count default ${harness}) - $(count sliced ${harness}) )) assumption(s) removed"
+done
+
+rm -rf default sliced
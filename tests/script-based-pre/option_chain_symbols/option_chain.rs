@@ -0,0 +1,42 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Harnesses with chains of `Option` combinators of increasing length, whose symbol tables are
+//! compared by `option_chain.sh`.
+
+#[kani::proof]
+fn chain_2() {
+    let opt: Option<u32> = kani::any();
+    let result = opt.as_ref().map(|v| v.wrapping_add(1)).map(|v| v.wrapping_add(1)).unwrap_or(0);
+    kani::assert(opt.is_some() || result == 0, "None is mapped to the default");
+}
+
+#[kani::proof]
+fn chain_4() {
+    let opt: Option<u32> = kani::any();
+    let result = opt
+        .as_ref()
+        .map(|v| v.wrapping_add(1))
+        .map(|v| v.wrapping_add(1))
+        .map(|v| v.wrapping_add(1))
+        .map(|v| v.wrapping_add(1))
+        .unwrap_or(0);
+    kani::assert(opt.is_some() || result == 0, "None is mapped to the default");
+}
+
+#[kani::proof]
+fn chain_8() {
+    let opt: Option<u32> = kani::any();
+    let result = opt
+        .as_ref()
+        .map(|v| v.wrapping_add(1))
+        .map(|v| v.wrapping_add(1))
+        .map(|v| v.wrapping_add(1))
+        .map(|v| v.wrapping_add(1))
+        .map(|v| v.wrapping_add(1))
+        .map(|v| v.wrapping_add(1))
+        .map(|v| v.wrapping_add(1))
+        .map(|v| v.wrapping_add(1))
+        .unwrap_or(0);
+    kani::assert(opt.is_some() || result == 0, "None is mapped to the default");
+}
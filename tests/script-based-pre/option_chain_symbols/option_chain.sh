@@ -0,0 +1,31 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that the number of symbols generated for a chain of `Option` combinators is proportional
+# to the length of the chain, i.e., that every combinator adds the same number of symbols, and
+# that no adapter machinery grows with the chain.
+
+set -u
+
+OUT_DIR=symtabs
+
+rm -rf ${OUT_DIR}
+kani option_chain.rs --write-json-symtab ${OUT_DIR}
+
+count() {
+    jq '.symbolTable | length' ${OUT_DIR}/option_chain__chain_$1.symtab.json
+}
+
+CHAIN_2=$(count 2)
+CHAIN_4=$(count 4)
+CHAIN_8=$(count 8)
+# Two combinators are added from 2 to 4, and four from 4 to 8.
+PER_COMBINATOR=$(( (CHAIN_4 - CHAIN_2) / 2 ))
+if [[ $(( CHAIN_8 - CHAIN_4 )) -eq $(( 4 * PER_COMBINATOR )) && ${PER_COMBINATOR} -gt 0 ]]; then
+    echo "Symbols grow linearly with the chain length"
+else
+    echo "Symbols don't grow linearly: ${CHAIN_2}, ${CHAIN_4} and ${CHAIN_8} symbols for chains of 2, 4 and 8"
+fi
+
+rm -rf ${OUT_DIR}
@@ -0,0 +1,15 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Check that `--run` replays the counterexample of a failed harness.
+
+#[kani::proof]
+fn check_lt() {
+    let x: u8 = kani::any();
+    assert!(x < 5, "x is too big");
+}
+
+#[kani::proof]
+fn check_passes() {
+    let x: u8 = kani::any();
+    assert!(x <= u8::MAX);
+}
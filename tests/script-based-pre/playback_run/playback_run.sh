@@ -0,0 +1,21 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+# Test that `--run` verifies a harness, replays its counterexample, and restores the source code.
+set -o pipefail
+set -o nounset
+
+RS_FILE="modified.rs"
+cp original.rs ${RS_FILE}
+
+echo "[TEST] Run failing harness..."
+kani ${RS_FILE} -Z concrete-playback --run check_lt
+
+echo "[TEST] Check source is restored..."
+cmp original.rs ${RS_FILE} && echo "Source code unchanged"
+
+echo "[TEST] Run passing harness..."
+kani ${RS_FILE} -Z concrete-playback --run check_passes
+
+# Cleanup
+rm -f ${RS_FILE} kani_concrete_playback
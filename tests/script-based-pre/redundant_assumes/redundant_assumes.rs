@@ -0,0 +1,43 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+/// The assumption in the loop re-states the one before the loop, so it is removed.
+#[kani::proof]
+#[kani::unwind(6)]
+fn check_redundant() {
+    let len: usize = kani::any();
+    kani::assume(len < 10);
+    let mut sum = 0;
+    for i in 0..5 {
+        kani::assume(len < 10);
+        sum += i * len;
+    }
+    assert!(sum < 100);
+}
+
+/// `len` changes between the assumptions, so they are both kept.
+#[kani::proof]
+#[kani::unwind(6)]
+fn check_needed() {
+    let mut len: usize = kani::any();
+    kani::assume(len < 10);
+    let mut sum = 0;
+    for i in 0..5 {
+        len = kani::any();
+        kani::assume(len < 10);
+        sum += i * len;
+    }
+    assert!(sum < 100);
+}
+
+/// The assumptions are on different branches, so neither dominates the other.
+#[kani::proof]
+fn check_branches() {
+    let x: u8 = kani::any();
+    if kani::any() {
+        kani::assume(x < 10);
+    } else {
+        kani::assume(x < 10);
+    }
+    assert!(x < 10);
+}
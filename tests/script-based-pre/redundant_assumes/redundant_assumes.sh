@@ -0,0 +1,27 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that Kani removes `kani::assume` calls that are dominated by an identical assumption
+# whose condition cannot have changed, and only those, by counting the assumptions of each harness
+# in its GotoC symbol table that come from a call to `kani::assume`.
+
+set -u
+
+OUT_DIR=symtabs
+# The lines of the calls to `kani::assume`, to ignore the assumptions that Kani adds for its checks.
+ASSUME_LINES=$(grep -n "kani::assume(" redundant_assumes.rs | cut -d: -f1 | jq -s 'map(tostring)')
+
+rm -rf ${OUT_DIR}
+for harness in check_redundant check_needed check_branches; do
+    kani redundant_assumes.rs --harness ${harness} --exact --write-json-symtab ${OUT_DIR} \
+        > /dev/null
+    echo "${harness}: exit code $?"
+    count=$(jq --arg harness ${harness} --argjson lines "${ASSUME_LINES}" '
+        [.symbolTable[] | select(.prettyName == $harness) | .value | .. | objects
+            | select(.namedSub.statement.id? == "assume")
+            | select(.namedSub["#source_location"].namedSub.line.id | IN($lines[]))]
+        | length' ${OUT_DIR}/redundant_assumes__${harness}.symtab.json)
+    echo "${harness}: ${count} assumption(s)"
+done
+rm -rf ${OUT_DIR}
@@ -0,0 +1,16 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Harnesses whose results are saved and replayed by `replay_trace.sh`.
+
+#[kani::proof]
+fn check_overflow() {
+    let x: u8 = kani::any();
+    let _ = x + 1;
+}
+
+#[kani::proof]
+fn check_ok() {
+    let x: u8 = kani::any();
+    assert!(x <= u8::MAX);
+}
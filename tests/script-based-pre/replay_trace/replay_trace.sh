@@ -0,0 +1,22 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `--replay-trace` renders the results saved with `--output-format json` without
+# verifying the crate again.
+
+set -u
+
+RESULTS=results.json
+
+kani replay_trace.rs --output-format json > ${RESULTS}
+echo "Exit status: $?"
+
+kani --replay-trace ${RESULTS}
+echo "Exit status: $?"
+
+echo "not a result" > empty.txt
+kani --replay-trace empty.txt
+echo "Exit status: $?"
+
+rm -f ${RESULTS} empty.txt
@@ -0,0 +1,15 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Harnesses used to check `--reporter`.
+
+#[kani::proof]
+fn check_pass() {
+    let x: u8 = kani::any();
+    assert!(x <= u8::MAX);
+}
+
+#[kani::proof]
+fn check_fail() {
+    let x: u8 = kani::any();
+    assert!(x != 42, "x is the answer");
+}
@@ -0,0 +1,48 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `--reporter` runs the given command with the JSON results of the harnesses on its
+# standard input, once per run by default and once per harness with `--reporter-per-harness`, and
+# that a failing reporter only fails the run with `--reporter-fail-on-error`.
+
+set -u
+
+cat > record.sh <<'SCRIPT'
+#!/usr/bin/env bash
+# Record the number of results and the harnesses of each invocation.
+input=$(cat)
+echo "Invocation with $(echo "$input" | wc -l) result(s): $(echo "$input" | grep -o '"harness":"[a-z_]*"' | sort | tr '\n' ' ')" >> invocations.txt
+echo "$input" | grep -q '"schema_version":1' && echo "Schema version 1" >> invocations.txt
+SCRIPT
+cat > fail.sh <<'SCRIPT'
+#!/usr/bin/env bash
+cat > /dev/null
+exit 3
+SCRIPT
+chmod +x record.sh fail.sh
+rm -f invocations.txt
+
+echo "[once per run]"
+kani reporter.rs --reporter ./record.sh > /dev/null
+echo "Exit code: $?"
+cat invocations.txt
+rm -f invocations.txt
+
+echo "[once per harness]"
+kani reporter.rs --reporter ./record.sh --reporter-per-harness > /dev/null
+echo "Exit code: $?"
+sort invocations.txt | uniq -c | sed -E 's/^ +//'
+rm -f invocations.txt
+
+echo "[failing reporter]"
+kani reporter.rs --harness check_pass --reporter ./fail.sh > output.txt
+echo "Exit code: $?"
+grep "warning: the reporter" output.txt
+
+echo "[failing reporter with --reporter-fail-on-error]"
+kani reporter.rs --harness check_pass --reporter ./fail.sh --reporter-fail-on-error > output.txt 2>&1
+echo "Exit code: $?"
+grep -o "the reporter \`./fail.sh\` failed with exit status: 3" output.txt
+
+rm -f output.txt record.sh fail.sh
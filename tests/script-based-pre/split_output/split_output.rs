@@ -0,0 +1,14 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[kani::proof]
+fn check_pass() {
+    let x: u8 = kani::any();
+    assert!(x.checked_mul(1).is_some());
+}
+
+#[kani::proof]
+fn check_fail() {
+    let x: u8 = kani::any();
+    assert!(x < 200, "x is too large");
+}
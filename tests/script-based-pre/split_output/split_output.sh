@@ -0,0 +1,22 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `--split-output` writes the full output of each harness to its own file, creating
+# the output directory if needed, and that it works with parallel verification.
+
+set -u
+
+OUT_DIR=split/output
+
+rm -rf split
+kani split_output.rs --split-output ${OUT_DIR} --jobs 2
+
+echo "Number of files: $(ls ${OUT_DIR}/*.txt | wc -l)"
+for file in ${OUT_DIR}/*.txt; do
+    # Each file contains the output of a single harness.
+    echo "$(grep -c 'Checking harness' ${file}) harness in $(grep -o 'check_[a-z]*' ${file} | head -1)"
+done
+grep -h "x is too large" ${OUT_DIR}/*.txt | head -1
+
+rm -rf split
@@ -0,0 +1,14 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that harnesses without properties pass trivially by default, and that they fail with
+# `--strict-no-properties`.
+
+set -u
+
+kani strict_no_properties.rs
+echo "Default exit code: $?"
+
+kani strict_no_properties.rs --strict-no-properties
+echo "Strict exit code: $?"
@@ -0,0 +1,25 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Check that `--summary-only` only prints one line per harness for every output format, that it
+# keeps the exit code, and that it works together with `--split-output`.
+
+set -u
+
+for format in regular terse old; do
+    echo "[${format}]"
+    kani summary_only.rs --summary-only --output-format ${format} > output.txt
+    echo "Exit code: $?"
+    grep -E "^(PASS|FAIL)" output.txt | sed -E 's/ \([0-9.]+s\)//' | sort
+    echo "Property lines: $(grep -c -e 'Check [0-9]*:' -e 'Status:' -e 'x is too large' output.txt)"
+done
+
+rm -rf split
+echo "[split]"
+kani summary_only.rs --summary-only --split-output split > output.txt
+echo "Exit code: $?"
+grep "^Harness" output.txt | sort
+grep -h "x is too large again" split/*.txt | head -1
+
+rm -rf split output.txt
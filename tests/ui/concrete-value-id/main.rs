@@ -0,0 +1,15 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that the id of `kani::concrete::value` must be known during compilation, since the pins
+//! given with `--fix` are resolved then.
+
+fn site(first: bool) -> &'static str {
+    if first { "first" } else { "second" }
+}
+
+#[kani::proof]
+fn check_non_literal_id() {
+    let value: u8 = kani::concrete::value(0, site(kani::any()));
+    assert!(value == 0 || value != 0);
+}
@@ -0,0 +1,12 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that Kani reports invalid crate-level Kani attributes.
+
+#![cfg_attr(kani, kanitool::unwind("ten"))]
+#![cfg_attr(kani, kanitool::should_panic)]
+
+#[kani::proof]
+fn check() {
+    assert!(1 + 1 == 2);
+}
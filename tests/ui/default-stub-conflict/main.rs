@@ -0,0 +1,30 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z stubbing
+//! Check that Kani reports conflicting default stubs, and default stubs registered outside of the
+//! crate root.
+
+fn magic_number() -> u32 {
+    0
+}
+
+fn one() -> u32 {
+    1
+}
+
+fn two() -> u32 {
+    2
+}
+
+kani::register_default_stub!(magic_number, one);
+kani::register_default_stub!(magic_number, two);
+
+mod inner {
+    kani::register_default_stub!(super::magic_number, super::one);
+}
+
+#[kani::proof]
+fn check() {
+    assert!(magic_number() > 0);
+}
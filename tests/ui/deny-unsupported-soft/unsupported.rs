@@ -0,0 +1,16 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: --only-codegen --deny unsupported-soft
+//! Check that `--deny unsupported-soft` reports unsupported constructs as an error.
+
+pub fn asm() {
+    unsafe {
+        std::arch::asm!("NOP");
+    }
+}
+
+#[kani::proof]
+fn harness() {
+    asm();
+}
@@ -0,0 +1,13 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Check that there's a compilation error if the `#[arbitrary(builder)]` attribute is used in a
+//! struct without named fields.
+
+#[derive(kani::Arbitrary)]
+#[arbitrary(builder)]
+struct Pair(u8, u8);
+
+#[kani::proof]
+fn dead_harness() {
+    panic!("This shouldn't compile");
+}
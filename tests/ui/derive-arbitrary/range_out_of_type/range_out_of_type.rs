@@ -0,0 +1,21 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Check that there's a compilation error if the range given to `#[arbitrary(range = "...")]`
+//! doesn't fit in the field type or is empty.
+
+#[derive(kani::Arbitrary)]
+struct Percent(#[arbitrary(range = "0..=300")] u8);
+
+#[derive(kani::Arbitrary)]
+struct Offset {
+    #[arbitrary(range = "-1..10")]
+    value: u32,
+}
+
+#[derive(kani::Arbitrary)]
+struct Empty(#[arbitrary(range = "5..5")] i8);
+
+#[kani::proof]
+fn dead_harness() {
+    panic!("This shouldn't compile");
+}
@@ -0,0 +1,7 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that the `expected` option of `#[kani::proof]` only accepts `"pass"` or `"fail"`.
+
+#[kani::proof(expected = "timeout")]
+fn check() {}
@@ -0,0 +1,23 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that the verdict of harnesses with `#[kani::proof(expected = "...")]` is compared with
+//! the expected one, and that an unexpected pass makes the session fail.
+
+#[kani::proof(expected = "fail")]
+fn expected_fail_fails() {
+    let x: u8 = kani::any();
+    assert!(x < 100, "x is small");
+}
+
+#[kani::proof(expected = "fail")]
+fn expected_fail_passes() {
+    let x: u8 = kani::any();
+    assert!(x <= u8::MAX);
+}
+
+#[kani::proof(expected = "pass")]
+fn expected_pass_passes() {
+    let x: u8 = kani::any();
+    assert!(x <= u8::MAX);
+}
@@ -0,0 +1,10 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that `expected = "fail"` cannot be combined with `#[kani::should_panic]`.
+
+#[kani::proof(expected = "fail")]
+#[kani::should_panic]
+fn check() {
+    panic!("oops");
+}
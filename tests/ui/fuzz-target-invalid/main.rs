@@ -0,0 +1,23 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// compile-flags: --edition 2018
+
+//! Check that `#[kani::proof(max_input = ...)]` is rejected for functions that do not have the
+//! signature of a fuzz target.
+
+#[kani::proof(max_input = 4)]
+fn mutable_input(data: &mut [u8]) {
+    data.fill(0);
+}
+
+#[kani::proof(max_input = 4)]
+fn two_inputs(data: &[u8], other: &[u8]) {
+    assert!(data.len() + other.len() < 8);
+}
+
+#[kani::proof(max_input = 4)]
+fn no_input() {}
+
+#[kani::proof(max_input = 4)]
+async fn async_input() {}
@@ -0,0 +1,27 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `#[kani::instantiate(...)]` rejects substitutions that don't match the harness.
+
+#[kani::proof]
+#[kani::instantiate(U = u32)]
+fn unknown_param<T>() {}
+
+#[kani::proof]
+#[kani::instantiate(T = 4)]
+fn const_for_type<T>() {}
+
+#[kani::proof]
+#[kani::instantiate(T = u8)]
+fn missing_param<T, const N: usize>() {}
+
+#[kani::proof]
+#[kani::instantiate(T = u8)]
+fn not_generic() {}
+
+#[kani::instantiate(T = u8)]
+fn not_harness<T>() {}
+
+#[kani::proof]
+#[kani::instantiate(T = u8, T = u8)]
+fn duplicated<T>() {}
@@ -0,0 +1,18 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that the `unwind`, `solver` and `should_panic` options of `#[kani::proof]` have the same
+//! effect as the corresponding attributes.
+
+#[kani::proof(unwind = 4, solver = cadical, should_panic)]
+fn check_combined() {
+    let mut sum: u8 = 0;
+    for i in 0..3 {
+        sum += i;
+    }
+    assert!(sum == 3);
+    let idx: usize = kani::any();
+    kani::assume(idx < 4);
+    let a = [1, 2, 3];
+    let _ = a[idx];
+}
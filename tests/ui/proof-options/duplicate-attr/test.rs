@@ -0,0 +1,8 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that an option of `#[kani::proof]` can't be combined with the equivalent attribute.
+
+#[kani::proof(solver = kissat)]
+#[kani::solver(kissat)]
+fn check() {}
@@ -0,0 +1,11 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that unknown options and values given to `should_panic` are reported when the
+//! `#[kani::proof]` attribute is expanded.
+
+#[kani::proof(unwind = 5, timeout = 10)]
+fn check_unknown() {}
+
+#[kani::proof(should_panic = true)]
+fn check_should_panic_value() {}
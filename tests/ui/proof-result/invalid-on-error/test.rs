@@ -0,0 +1,13 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that the `on_error` option of `#[kani::proof]` only accepts `"fail"` or `"assume"`, and
+//! that it can only be used with harnesses that return a `Result`.
+
+#[kani::proof(on_error = "ignore")]
+fn check_invalid() -> Result<(), ()> {
+    Ok(())
+}
+
+#[kani::proof(on_error = "assume")]
+fn check_no_result() {}
@@ -0,0 +1,25 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that Kani emits a note for the `assert!` and `debug_assert!` from the standard library
+//! that are reachable from a harness, but not for `kani::assert`. The note is only emitted once
+//! per call site, even if the site is reachable from more than one harness.
+
+fn check_positive(x: u8) {
+    debug_assert!(x > 0, "x is {}", x);
+}
+
+#[kani::proof]
+fn check_std_assert() {
+    let x: u8 = kani::any();
+    assert!(x > 0);
+    core::assert!(x > 1);
+    check_positive(x);
+}
+
+#[kani::proof]
+fn check_kani_assert() {
+    let x: u8 = kani::any();
+    kani::assert(x > 0, "x is positive");
+    check_positive(x);
+}
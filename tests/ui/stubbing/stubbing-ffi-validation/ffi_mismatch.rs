@@ -0,0 +1,53 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z stubbing
+//
+//! Check that Kani rejects stubs of foreign functions with a different ABI or signature, and
+//! stubs of variadic foreign functions.
+
+use std::ffi::{c_char, c_int, c_void};
+
+mod ffi {
+    use super::*;
+    extern "C" {
+        pub fn memcmp(s1: *const c_void, s2: *const c_void, n: usize) -> c_int;
+        pub fn printf(format: *const c_char, ...) -> c_int;
+    }
+}
+
+mod models {
+    use super::*;
+
+    pub fn memcmp_rust_abi(_s1: *const c_void, _s2: *const c_void, _n: usize) -> c_int {
+        0
+    }
+
+    pub extern "C" fn memcmp_wrong_ret(_s1: *const c_void, _s2: *const c_void, _n: usize) -> u8 {
+        0
+    }
+
+    pub extern "C" fn printf(_format: *const c_char) -> c_int {
+        0
+    }
+}
+
+#[kani::proof]
+#[kani::stub(ffi::memcmp, models::memcmp_rust_abi)]
+fn check_rust_abi() {
+    let a = [0u8; 2];
+    assert_eq!(unsafe { ffi::memcmp(a.as_ptr() as _, a.as_ptr() as _, 2) }, 0);
+}
+
+#[kani::proof]
+#[kani::stub(ffi::memcmp, models::memcmp_wrong_ret)]
+fn check_wrong_ret() {
+    let a = [0u8; 2];
+    assert_eq!(unsafe { ffi::memcmp(a.as_ptr() as _, a.as_ptr() as _, 2) }, 0);
+}
+
+#[kani::proof]
+#[kani::stub(ffi::printf, models::printf)]
+fn check_variadic() {
+    unsafe { ffi::printf(b"%d\0".as_ptr() as *const c_char, 1) };
+}